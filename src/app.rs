@@ -1,13 +1,34 @@
-use crate::models::storage::SnippetDatabase;
-use crate::models::{CodeSnippet, Notebook, SnippetLanguage, StorageManager, TagManager};
+use crate::handlers::transfer::TransferJob;
+use crate::models::backup::RetentionPolicy;
+use crate::models::export::{ExportFormat, ExportOptions, export_database_with_tags};
+use crate::models::storage::{SnippetDatabase, UiState};
+use crate::models::{
+    Boilerplate, CodeSnippet, IntegrityReport, LibraryStats, Notebook, Settings, SnippetLanguage,
+    SnippetVersion, SnippetWatcher, SortBy, StorageManager, Tag, TagManager, TrashEntry,
+    TrashedItem,
+};
 use crate::ui::backup_restore::BackupRestoreState;
+use crate::ui::colors::{Theme, set_active_theme};
 use crate::ui::export_import::ExportImportState;
 use crate::ui::ollama::OllamaState;
+use crate::ui::toast::Toasts;
 use crate::ui::{code_snippets, components, export_import, start_page};
 use chrono::{DateTime, Utc};
 use ratatui::Frame;
 use uuid::Uuid;
 
+/// How many existing tags the tag editor suggests at once
+const MAX_TAG_SUGGESTIONS: usize = 5;
+
+/// How many snippets the Recent page lists
+pub const RECENT_SNIPPET_LIMIT: usize = 30;
+
+/// Root notebook that quick snippets are filed into
+pub const INBOX_NOTEBOOK_NAME: &str = "Inbox";
+
+/// Keys used for tree jump labels, home row first
+const JUMP_LABEL_CHARS: &str = "asdfghjklqwertyuiopzxcvbnm";
+
 /// Application State Enumeration
 /// Represents all possible states (pages) that the application can be in.
 /// Each variant corresponds to a different screen or page in the user interface.
@@ -23,6 +44,10 @@ pub enum AppState {
     InfoPage,
     Settings,
     ExportImport,
+    Trash,
+    Statistics,
+    Tags,
+    Recent,
 }
 
 impl Default for AppState {
@@ -42,6 +67,8 @@ pub enum CodeSnippetsState {
     CreateSnippet { notebook_id: Uuid },
     SearchSnippets,
     Settings,
+    VersionHistory { snippet_id: Uuid },
+    CompareSnippets { left: Uuid, right: Uuid },
 }
 
 /// Tree view item types for navigation
@@ -122,7 +149,20 @@ pub struct SearchResult {
     pub name: String,
     pub result_type: SearchResultType,
     pub match_context: String,
+    /// Lines around a code content match, empty for other results
+    pub context_lines: Vec<ContextLine>,
     pub parent_id: Option<Uuid>,
+    pub score: i64,
+}
+
+/// One line of code shown under a content search result
+#[derive(Debug, Clone)]
+pub struct ContextLine {
+    /// 1-based line number in the snippet
+    pub number: usize,
+    pub text: String,
+    /// Whether this is the line the query matched
+    pub is_match: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -168,20 +208,68 @@ pub struct App {
     pub search_query: String,
     pub search_results: Vec<SearchResult>,
     pub selected_search_result: usize,
+    pub regex_search: bool,
+    /// Pattern compiled by the last regex search, reused to highlight results
+    pub search_regex: Option<regex::Regex>,
     pub show_favorites_only: bool,
+    /// Only snippets in this language are shown in the tree
+    pub language_filter: Option<SnippetLanguage>,
     pub show_favorites_popup: bool,
+    /// Whether the metadata overlay for the selected snippet is open
+    pub show_snippet_info: bool,
     pub show_about_popup: bool,
+    pub show_quit_prompt: bool,
+    /// Set by `render` while the terminal is below the minimum size and no page is drawn
+    pub terminal_too_small: bool,
+    /// Why storage could not be opened, shown full screen until the user decides what to do
+    pub storage_error: Option<String>,
     pub selected_about_tab: usize,
+    /// Question waiting for Enter or Esc, such as a delete confirmation
     pub error_message: Option<String>,
     pub success_message: Option<String>,
+    /// Transient feedback stacked in the bottom-right corner
+    pub toasts: Toasts,
     pub input_buffer: String,
+    /// Cursor in the focused text field, counted in characters back from its end
+    pub input_cursor: usize,
     pub input_mode: InputMode,
+    /// Commands run from the `:` prompt, oldest first
+    pub command_history: Vec<String>,
+    /// Position while browsing `command_history` with Up/Down
+    pub command_history_index: Option<usize>,
+    /// Highlighted row in the Ctrl+P quick open picker
+    pub quick_open_selected: usize,
     pub selected_language: usize,
     pub pending_snippet_title: String,
+    /// Clipboard text waiting for a title before it becomes a snippet, set by `P`
+    pub pending_paste: Option<String>,
     pub needs_redraw: bool,
     pub content_scroll_position: usize,
+    /// Largest scroll offset of the last rendered preview, in visual rows
+    pub preview_scroll_limit: std::cell::Cell<usize>,
+    /// First column shown by the preview while word wrap is off
+    pub horizontal_scroll: usize,
+    /// Largest `horizontal_scroll` of the last rendered preview
+    pub preview_column_limit: std::cell::Cell<usize>,
+    /// Tree rows visible in the last rendered frame
+    pub tree_viewport: std::ops::Range<usize>,
+    /// Keys typed so far while jump labels are shown, `None` when hidden
+    pub jump_input: Option<String>,
     pub selected_details_tab: usize,
+    pub snippet_versions: Vec<SnippetVersion>,
+    pub selected_version: usize,
+    pub trash: Vec<TrashEntry>,
+    pub selected_trash_item: usize,
+    pub selected_recent_snippet: usize,
+    /// Last delete or move, reversed by `u`
+    pub undo_action: Option<UndoAction>,
+    pub settings: Settings,
+    pub selected_theme: usize,
+    /// Result of the last storage check run from Settings
+    pub integrity_report: Option<IntegrityReport>,
     pub collapsed_notebooks: std::collections::HashSet<Uuid>,
+    pub multi_select_mode: bool,
+    pub marked_snippets: std::collections::HashSet<Uuid>,
     pub confirmation_state: ConfirmationState,
     pub recent_searches: Vec<RecentSearchEntry>,
     pub selected_recent_search: usize,
@@ -190,11 +278,73 @@ pub struct App {
     pub backup_restore_state: Option<BackupRestoreState>,
     pub show_backup_restore_overlay: bool,
     pub ollama_state: Option<OllamaState>,
+    pub snippet_watcher: Option<SnippetWatcher>,
+    pub transfer_job: Option<TransferJob>,
+    pub pending_template: Option<PendingTemplate>,
+    /// Whether the notebook being cloned takes its snippets along, toggled with Tab
+    pub clone_with_snippets: bool,
+    pub boilerplates: Vec<Boilerplate>,
+    pub selected_boilerplate: usize,
+    pub boilerplate_prompt: Option<BoilerplatePrompt>,
+    pub library_stats: Option<LibraryStats>,
+    pub selected_tag: usize,
+    pub tag_prompt: Option<TagPrompt>,
+    /// Whether the tag page cursor is in the snippet list rather than the tag list
+    pub tag_snippets_focused: bool,
+    pub selected_tagged_snippet: usize,
+    pub selected_tag_suggestion: usize,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum SortBy {
-    _Updated,
+/// A snippet template waiting for the user to supply its remaining variables
+#[derive(Debug, Clone)]
+pub struct PendingTemplate {
+    pub snippet_id: Uuid,
+    pub values: std::collections::HashMap<String, String>,
+    pub missing: Vec<String>,
+}
+
+/// The last destructive change, kept in memory so `u` can reverse it
+#[derive(Debug, Clone)]
+pub enum UndoAction {
+    /// Items moved to the trash, in the order they were trashed
+    Delete {
+        label: String,
+        entries: Vec<TrashEntry>,
+    },
+    /// Where every notebook and snippet sat before a move
+    Move { label: String, layout: TreeLayout },
+}
+
+impl UndoAction {
+    /// What the action did, e.g. "deleted 'main.rs'"
+    pub fn label(&self) -> &str {
+        match self {
+            UndoAction::Delete { label, .. } | UndoAction::Move { label, .. } => label,
+        }
+    }
+}
+
+/// Parents and child order of the whole tree, enough to put moved items back
+#[derive(Debug, Clone)]
+pub struct TreeLayout {
+    root_notebooks: Vec<Uuid>,
+    notebooks: std::collections::HashMap<Uuid, (Option<Uuid>, Vec<Uuid>)>,
+    snippets: std::collections::HashMap<Uuid, Uuid>,
+}
+
+/// Answers collected while scaffolding a boilerplate: first the target directory, then its variables
+#[derive(Debug, Clone, Default)]
+pub struct BoilerplatePrompt {
+    pub target: Option<std::path::PathBuf>,
+    pub values: std::collections::HashMap<String, String>,
+    pub missing: Vec<String>,
+}
+
+/// Text prompt open on the tag management page
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TagPrompt {
+    Rename(Uuid),
+    Merge(Uuid),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -204,8 +354,12 @@ pub enum InputMode {
     CreateNestedNotebook,
     CreateSnippet,
     _RenameNotebook,
-    _RenameSnippet,
+    RenameSnippet,
+    ExportSelection,
+    ExportNotebookMarkdown,
     EditSnippetDescription,
+    /// Editor command used for the selected snippet only
+    EditSnippetEditor,
     SelectLanguage,
     Search,
     HelpMenu,
@@ -213,12 +367,38 @@ pub enum InputMode {
     SelectNotebookColor,
     EditNotebookName,
     EditTags,
+    /// Tag typed for every snippet in the selected notebook, `-tag` to remove it
+    BulkTagNotebook,
+    /// Name for a copy of the notebook in the details view, see `clone_with_snippets`
+    CloneNotebook,
+    FillTemplate,
+    Command,
+    QuickOpen,
 }
 
 impl App {
     /// Creates a new instance of the application with default initial state
     pub fn new() -> Self {
-        let storage_manager = StorageManager::new().ok();
+        let storage = StorageManager::new().and_then(|manager| {
+            manager.check_writable()?;
+            Ok(manager)
+        });
+        let mut app = match storage {
+            Ok(manager) => Self::with_storage(Some(manager), None),
+            Err(e) => Self::with_storage(None, Some(format!("{:#}", e))),
+        };
+
+        // Chats and prompt templates live in ~/.snix even when the library failed to load
+        app.ollama_state = Some(OllamaState::from_settings(&app.settings));
+        app
+    }
+
+    /// Builds the app around `storage_manager`, loading everything it holds.
+    /// Without one nothing is read from or written to disk; the Ollama state is left to `new`.
+    fn with_storage(
+        storage_manager: Option<StorageManager>,
+        storage_error: Option<String>,
+    ) -> Self {
         let snippet_database = if let Some(ref manager) = storage_manager {
             manager.load_database().unwrap_or_default()
         } else {
@@ -232,6 +412,38 @@ impl App {
             TagManager::new()
         };
 
+        let trash = if let Some(ref manager) = storage_manager {
+            manager.load_trash().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        // Load settings and activate the saved theme before the first frame
+        let settings = if let Some(ref manager) = storage_manager {
+            manager.load_settings().unwrap_or_default()
+        } else {
+            Settings::default()
+        };
+        // Collapsed notebooks that were deleted since the last session are dropped
+        let mut collapsed_notebooks = if let Some(ref manager) = storage_manager {
+            manager
+                .load_ui_state()
+                .unwrap_or_default()
+                .collapsed_notebooks
+        } else {
+            std::collections::HashSet::new()
+        };
+        collapsed_notebooks.retain(|id| snippet_database.notebooks.contains_key(id));
+
+        let theme = Theme::from_name(&settings.theme).unwrap_or(Theme::RosePine);
+        set_active_theme(theme);
+        let selected_theme = Theme::ALL.iter().position(|t| *t == theme).unwrap_or(0);
+
+        // Without a watcher, external edits are simply picked up on the next start
+        let snippet_watcher = storage_manager
+            .as_ref()
+            .and_then(|manager| SnippetWatcher::new(manager.snippets_dir()).ok());
+
         let mut app = Self {
             state: AppState::StartPage,
             selected_menu_item: 0,
@@ -246,20 +458,49 @@ impl App {
             search_query: String::new(),
             search_results: Vec::new(),
             selected_search_result: 0,
+            regex_search: false,
+            search_regex: None,
             show_favorites_only: false,
+            language_filter: None,
             show_favorites_popup: false,
+            show_snippet_info: false,
             show_about_popup: false,
+            show_quit_prompt: false,
+            terminal_too_small: false,
+            storage_error,
             selected_about_tab: 0,
             error_message: None,
             success_message: None,
+            toasts: Toasts::default(),
             input_buffer: String::new(),
+            input_cursor: 0,
             input_mode: InputMode::Normal,
+            command_history: Vec::new(),
+            quick_open_selected: 0,
+            command_history_index: None,
             selected_language: 0,
             pending_snippet_title: String::new(),
+            pending_paste: None,
             needs_redraw: true,
             content_scroll_position: 0,
+            preview_scroll_limit: std::cell::Cell::new(usize::MAX),
+            horizontal_scroll: 0,
+            preview_column_limit: std::cell::Cell::new(0),
+            tree_viewport: 0..0,
+            jump_input: None,
             selected_details_tab: 0,
-            collapsed_notebooks: std::collections::HashSet::new(),
+            snippet_versions: Vec::new(),
+            selected_version: 0,
+            trash,
+            selected_trash_item: 0,
+            selected_recent_snippet: 0,
+            undo_action: None,
+            settings,
+            selected_theme,
+            integrity_report: None,
+            collapsed_notebooks,
+            multi_select_mode: false,
+            marked_snippets: std::collections::HashSet::new(),
             confirmation_state: ConfirmationState::None,
             recent_searches: Vec::new(),
             selected_recent_search: 0,
@@ -267,7 +508,20 @@ impl App {
             export_import_state: None,
             backup_restore_state: None,
             show_backup_restore_overlay: false,
-            ollama_state: Some(OllamaState::new()),
+            ollama_state: None,
+            snippet_watcher,
+            transfer_job: None,
+            pending_template: None,
+            clone_with_snippets: false,
+            boilerplates: Vec::new(),
+            selected_boilerplate: 0,
+            boilerplate_prompt: None,
+            library_stats: None,
+            selected_tag: 0,
+            tag_prompt: None,
+            tag_snippets_focused: false,
+            selected_tagged_snippet: 0,
+            selected_tag_suggestion: 0,
         };
 
         app.refresh_tree_items();
@@ -278,20 +532,20 @@ impl App {
     /// Increments the selected menu item index, wrapping around to 0 when it
     /// reaches the maximum number of menu items. This allows users to navigate
     /// through menu options using the down arrow or 'j' key.
-    /// The total number of menu items is currently 8 (indices 0-7), so the
+    /// The total number of menu items is currently 10 (indices 0-9), so the
     /// selection will cycle through all available options.
     pub fn next_menu_item(&mut self) {
-        self.selected_menu_item = (self.selected_menu_item + 1) % 8;
+        self.selected_menu_item = (self.selected_menu_item + 1) % 10;
     }
 
     /// Moves the menu selection to the previous item in a circular fashion
     /// Decrements the selected menu item index, wrapping around to the last item
     /// when it reaches 0. This allows users to navigate through menu options
     /// using the up arrow or 'k' key.
-    /// The total number of menu items is currently 8 (indices 0-7), so the
+    /// The total number of menu items is currently 10 (indices 0-9), so the
     /// selection will cycle through all available options.
     pub fn previous_menu_item(&mut self) {
-        self.selected_menu_item = (self.selected_menu_item + 8 - 1) % 8;
+        self.selected_menu_item = (self.selected_menu_item + 10 - 1) % 10;
     }
 
     /// Navigates to a new application state and updates the page history
@@ -310,6 +564,26 @@ impl App {
                 self.code_snippets_state = CodeSnippetsState::NotebookList;
                 self.refresh_tree_items();
             }
+
+            // Pick up boilerplates added or edited on disk since the last visit
+            if self.state == AppState::Boilerplates {
+                self.reload_boilerplates();
+            }
+
+            if self.state == AppState::Statistics {
+                self.refresh_library_stats();
+            }
+
+            if self.state == AppState::Recent {
+                self.selected_recent_snippet = 0;
+            }
+
+            if self.state == AppState::Tags {
+                self.selected_tag = 0;
+                self.tag_prompt = None;
+                self.tag_snippets_focused = false;
+                self.selected_tagged_snippet = 0;
+            }
         }
     }
 
@@ -337,7 +611,20 @@ impl App {
     pub fn refresh_tree_items(&mut self) {
         self.tree_items.clear();
 
-        let root_notebooks = self.snippet_database.root_notebooks.clone();
+        // Pinned snippets sit above every notebook; the favorites filter does not apply to them
+        let mut pinned: Vec<&CodeSnippet> = self
+            .snippet_database
+            .snippets
+            .values()
+            .filter(|s| s.is_pinned && self.matches_language_filter(s))
+            .collect();
+        pinned.sort_by_key(|s| s.title.to_lowercase());
+        let pinned: Vec<Uuid> = pinned.into_iter().map(|s| s.id).collect();
+        for snippet_id in pinned {
+            self.tree_items.push(TreeItem::Snippet(snippet_id, 0));
+        }
+
+        let root_notebooks = self.sorted_notebooks(&self.snippet_database.root_notebooks);
         for notebook_id in root_notebooks {
             self.add_notebook_to_tree(notebook_id, 0);
         }
@@ -350,6 +637,11 @@ impl App {
     }
 
     fn add_notebook_to_tree(&mut self, notebook_id: Uuid, depth: usize) {
+        // Filtered views leave out notebooks with nothing left to show
+        if self.tree_filter_active() && !self.notebook_has_visible_snippets(notebook_id) {
+            return;
+        }
+
         self.tree_items.push(TreeItem::Notebook(notebook_id, depth));
 
         // Skip children if this notebook is collapsed
@@ -357,27 +649,172 @@ impl App {
             return;
         }
 
-        let snippets: Vec<_> = self
+        let mut snippets: Vec<&CodeSnippet> = self
             .snippet_database
             .snippets
             .values()
-            .filter(|s| s.notebook_id == notebook_id)
-            .map(|s| s.id)
+            .filter(|s| s.notebook_id == notebook_id && self.is_snippet_visible(s))
             .collect();
 
+        // Snippets have no stored order, so "manual" falls back to creation time
+        match self.settings.tree_sort {
+            SortBy::Manual => snippets.sort_by_key(|s| s.created_at),
+            SortBy::Name | SortBy::SnippetCount => snippets.sort_by_key(|s| s.title.to_lowercase()),
+            SortBy::Created => snippets.sort_by_key(|s| std::cmp::Reverse(s.created_at)),
+            SortBy::Updated => snippets.sort_by_key(|s| std::cmp::Reverse(s.updated_at)),
+        }
+        let snippets: Vec<Uuid> = snippets.into_iter().map(|s| s.id).collect();
+
         for snippet_id in snippets {
             self.tree_items
                 .push(TreeItem::Snippet(snippet_id, depth + 1));
         }
 
         if let Some(notebook) = self.snippet_database.notebooks.get(&notebook_id) {
-            let children = notebook.children.clone();
+            let children = self.sorted_notebooks(&notebook.children);
             for child_id in children {
                 self.add_notebook_to_tree(child_id, depth + 1);
             }
         }
     }
 
+    /// Whether the favorites or language filter is narrowing the tree
+    pub fn tree_filter_active(&self) -> bool {
+        self.show_favorites_only || self.language_filter.is_some()
+    }
+
+    fn matches_language_filter(&self, snippet: &CodeSnippet) -> bool {
+        self.language_filter
+            .as_ref()
+            .is_none_or(|language| snippet.language == *language)
+    }
+
+    fn is_snippet_visible(&self, snippet: &CodeSnippet) -> bool {
+        (!self.show_favorites_only
+            || snippet.is_favorited()
+            || self.is_in_favorite_notebook(snippet.notebook_id))
+            && self.matches_language_filter(snippet)
+    }
+
+    /// Whether the notebook or one of its ancestors is a favorite
+    pub fn is_in_favorite_notebook(&self, notebook_id: Uuid) -> bool {
+        let mut current = Some(notebook_id);
+        for _ in 0..=self.snippet_database.notebooks.len() {
+            let Some(notebook) = current.and_then(|id| self.snippet_database.notebooks.get(&id))
+            else {
+                return false;
+            };
+            if notebook.is_favorite {
+                return true;
+            }
+            current = notebook.parent_id;
+        }
+        false
+    }
+
+    fn notebook_has_visible_snippets(&self, notebook_id: Uuid) -> bool {
+        // Favorite notebooks stay in the favorites view even when empty
+        if self.show_favorites_only
+            && self.language_filter.is_none()
+            && self.is_in_favorite_notebook(notebook_id)
+        {
+            return true;
+        }
+
+        let direct = self
+            .snippet_database
+            .snippets
+            .values()
+            .any(|s| s.notebook_id == notebook_id && self.is_snippet_visible(s));
+
+        direct
+            || self
+                .snippet_database
+                .notebooks
+                .get(&notebook_id)
+                .is_some_and(|notebook| {
+                    notebook
+                        .children
+                        .iter()
+                        .any(|child_id| self.notebook_has_visible_snippets(*child_id))
+                })
+    }
+
+    /// Languages used by at least one snippet, offered by the language filter picker
+    pub fn filter_languages(&self) -> Vec<SnippetLanguage> {
+        let mut languages: Vec<SnippetLanguage> = self
+            .snippet_database
+            .snippets
+            .values()
+            .map(|s| s.language.clone())
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+        languages.sort_by_key(|language| language.display_name().to_lowercase());
+        languages
+    }
+
+    /// Narrows the tree to one language, or shows every language again with `None`
+    pub fn set_language_filter(&mut self, language: Option<SnippetLanguage>) {
+        self.language_filter = language;
+        self.selected_tree_item = 0;
+        self.refresh_tree_items();
+    }
+
+    /// Sibling notebooks in the order picked by the tree sort setting
+    fn sorted_notebooks(&self, ids: &[Uuid]) -> Vec<Uuid> {
+        let mut notebooks: Vec<&Notebook> = ids
+            .iter()
+            .filter_map(|id| self.snippet_database.notebooks.get(id))
+            .collect();
+
+        match self.settings.tree_sort {
+            SortBy::Manual => {}
+            SortBy::Name => notebooks.sort_by_key(|n| n.name.to_lowercase()),
+            SortBy::Created => notebooks.sort_by_key(|n| std::cmp::Reverse(n.created_at)),
+            SortBy::Updated => notebooks.sort_by_key(|n| std::cmp::Reverse(n.updated_at)),
+            SortBy::SnippetCount => notebooks
+                .sort_by_key(|n| (std::cmp::Reverse(n.snippet_count), n.name.to_lowercase())),
+        }
+        notebooks.into_iter().map(|n| n.id).collect()
+    }
+
+    /// Switches to the next tree sort order, keeping the selected item selected
+    pub fn cycle_tree_sort(&mut self) -> Result<(), String> {
+        let selected = self.get_selected_item().cloned();
+        self.settings.tree_sort = self.settings.tree_sort.next();
+        self.refresh_tree_items();
+
+        if let Some(index) =
+            selected.and_then(|item| self.tree_items.iter().position(|i| *i == item))
+        {
+            self.selected_tree_item = index;
+        }
+        self.hovered_tree_item = None;
+        self.needs_redraw = true;
+        self.save_settings()?;
+
+        self.set_success_message(format!("Sorted by {}", self.settings.tree_sort.label()));
+        Ok(())
+    }
+
+    /// Label and tree index for every row currently on screen
+    pub fn jump_targets(&self) -> Vec<(String, usize)> {
+        let end = self.tree_viewport.end.min(self.tree_items.len());
+        let rows = self.tree_viewport.start.min(end)..end;
+        jump_labels(rows.len()).into_iter().zip(rows).collect()
+    }
+
+    /// Moves the tree selection straight to `index`
+    pub fn select_tree_item(&mut self, index: usize) {
+        if index < self.tree_items.len() {
+            self.selected_tree_item = index;
+            self.hovered_tree_item = Some(index);
+            self.reset_scroll_position();
+            self.needs_redraw = true;
+        }
+    }
+
     pub fn next_tree_item(&mut self) {
         if !self.tree_items.is_empty() {
             self.selected_tree_item = (self.selected_tree_item + 1) % self.tree_items.len();
@@ -475,6 +912,12 @@ impl App {
         let snippet = CodeSnippet::new(title, language, notebook_id);
         let snippet_id = snippet.id;
 
+        // Written right away so the integrity check does not see a new snippet as broken
+        if let Some(ref storage) = self.storage_manager {
+            storage
+                .save_snippet_content(&snippet)
+                .map_err(|e| format!("Failed to write snippet file: {}", e))?;
+        }
         self.snippet_database.snippets.insert(snippet_id, snippet);
 
         if let Some(notebook) = self.snippet_database.notebooks.get_mut(&notebook_id) {
@@ -495,13 +938,364 @@ impl App {
         Ok(snippet_id)
     }
 
+    /// Creates a snippet and writes the given content to its file
+    pub fn create_snippet_with_content(
+        &mut self,
+        title: String,
+        language: SnippetLanguage,
+        notebook_id: Uuid,
+        content: String,
+    ) -> Result<Uuid, String> {
+        let snippet_id = self.create_snippet(title, language, notebook_id)?;
+
+        if let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) {
+            snippet.content = content;
+            if let Some(ref storage) = self.storage_manager {
+                storage
+                    .save_snippet_content(snippet)
+                    .map_err(|e| format!("Failed to write snippet file: {}", e))?;
+            }
+        }
+
+        self.save_database()?;
+        Ok(snippet_id)
+    }
+
+    /// Uploads a snippet to a new secret GitHub Gist and returns its URL
+    pub fn push_snippet_to_gist(&self, snippet_id: Uuid) -> Result<String, String> {
+        let snippet = self
+            .snippet_database
+            .snippets
+            .get(&snippet_id)
+            .ok_or_else(|| "Snippet not found".to_string())?;
+
+        crate::models::gist::push_snippet(snippet).map_err(|e| format!("Gist upload failed: {}", e))
+    }
+
+    /// Pulls every file of a Gist into the current notebook, or a "Gists" notebook if none is open
+    pub fn pull_gist_into_notebook(&mut self, url_or_id: &str) -> Result<usize, String> {
+        let gist = crate::models::gist::pull_gist(url_or_id)
+            .map_err(|e| format!("Gist download failed: {}", e))?;
+
+        let notebook_id = match self
+            .current_notebook_id
+            .filter(|id| self.snippet_database.notebooks.contains_key(id))
+        {
+            Some(id) => id,
+            None => self.root_notebook_named("Gists"),
+        };
+
+        let count = gist.files.len();
+        for file in gist.files {
+            let snippet_id = self.create_snippet_with_content(
+                file.title,
+                file.language,
+                notebook_id,
+                file.content,
+            )?;
+            if let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) {
+                snippet.description = gist.description.clone();
+            }
+        }
+
+        self.save_database()?;
+        self.refresh_tree_items();
+        Ok(count)
+    }
+
+    /// Adds the snippets of a VS Code snippet file to a notebook, or to one named after the file
+    pub fn import_vscode_snippets(
+        &mut self,
+        path: &std::path::Path,
+        notebook_id: Option<Uuid>,
+    ) -> Result<usize, String> {
+        let snippets = crate::models::vscode::read_snippet_file(path)
+            .map_err(|e| format!("Import failed: {}", e))?;
+        if snippets.is_empty() {
+            return Err(format!("No snippets found in {}", path.display()));
+        }
+
+        let notebook_id =
+            match notebook_id.filter(|id| self.snippet_database.notebooks.contains_key(id)) {
+                Some(id) => id,
+                None => self.root_notebook_named(&crate::models::vscode::notebook_name(path)),
+            };
+
+        let count = snippets.len();
+        for imported in snippets {
+            let snippet_id = self.create_snippet_with_content(
+                imported.title,
+                imported.language,
+                notebook_id,
+                imported.content,
+            )?;
+            for tag in &imported.tags {
+                self.tag_manager.add_tag_to_snippet(snippet_id, tag.clone());
+            }
+            if let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) {
+                snippet.description = imported.description;
+                snippet.tags = imported.tags;
+            }
+        }
+
+        self.save_database()?;
+        self.refresh_tree_items();
+        Ok(count)
+    }
+
+    /// Creates an empty snippet in the Inbox notebook, making the notebook if needed
+    pub fn create_quick_snippet(&mut self) -> Result<Uuid, String> {
+        let inbox_id = self.root_notebook_named(INBOX_NOTEBOOK_NAME);
+        let title = format!(
+            "Quick snippet {}",
+            chrono::Local::now().format("%Y-%m-%d %H:%M")
+        );
+        self.create_snippet(title, SnippetLanguage::Text, inbox_id)
+    }
+
+    /// Finds or creates the root notebook called `name`, used for pulled Gists and quick snippets
+    fn root_notebook_named(&mut self, name: &str) -> Uuid {
+        if let Some(id) = self.snippet_database.root_notebooks.iter().find(|id| {
+            self.snippet_database
+                .notebooks
+                .get(id)
+                .is_some_and(|notebook| notebook.name == name)
+        }) {
+            return *id;
+        }
+
+        let notebook = Notebook::new(name.to_string());
+        let id = notebook.id;
+        self.snippet_database.notebooks.insert(id, notebook);
+        self.snippet_database.root_notebooks.push(id);
+        id
+    }
+
+    /// Creates a copy of a snippet in the same notebook, titled "Copy of X"
+    pub fn duplicate_snippet(&mut self, snippet_id: Uuid) -> Result<Uuid, String> {
+        let original = self
+            .snippet_database
+            .snippets
+            .get(&snippet_id)
+            .ok_or_else(|| "Snippet not found".to_string())?;
+
+        let mut copy = CodeSnippet::new(
+            format!("Copy of {}", original.title),
+            original.language.clone(),
+            original.notebook_id,
+        );
+        copy.description = original.description.clone();
+        copy.content = original.content.clone();
+        copy.tags = original.tags.clone();
+        copy.file_extension = original.file_extension.clone();
+        copy.syntax_theme = original.syntax_theme.clone();
+        copy.editor_override = original.editor_override.clone();
+
+        let copy_id = copy.id;
+        let notebook_id = copy.notebook_id;
+
+        if let Some(ref storage) = self.storage_manager {
+            storage
+                .save_snippet_content(&copy)
+                .map_err(|e| format!("Failed to write snippet file: {}", e))?;
+        }
+
+        for tag in &copy.tags {
+            self.tag_manager.add_tag_to_snippet(copy_id, tag.clone());
+        }
+        self.snippet_database.snippets.insert(copy_id, copy);
+
+        if let Some(notebook) = self.snippet_database.notebooks.get_mut(&notebook_id) {
+            notebook.snippet_count += 1;
+            notebook.updated_at = Utc::now();
+        }
+
+        if let Err(e) = self.save_database() {
+            return Err(format!("Failed to save snippet: {}", e));
+        }
+
+        self.refresh_tree_items();
+        if let Some(index) = self
+            .tree_items
+            .iter()
+            .position(|item| matches!(item, TreeItem::Snippet(id, _) if *id == copy_id))
+        {
+            self.selected_tree_item = index;
+        }
+
+        Ok(copy_id)
+    }
+
+    /// Copies a notebook and every notebook nested in it, placing the copy after the original.
+    /// Colors, icons and descriptions carry over; snippets are copied when `with_snippets` is set.
+    pub fn clone_notebook(
+        &mut self,
+        notebook_id: Uuid,
+        name: String,
+        with_snippets: bool,
+    ) -> Result<Uuid, String> {
+        if name.trim().is_empty() {
+            return Err("Notebook name cannot be empty".to_string());
+        }
+        let parent_id = self
+            .snippet_database
+            .notebooks
+            .get(&notebook_id)
+            .ok_or_else(|| "Notebook not found".to_string())?
+            .parent_id;
+
+        let mut visited = std::collections::HashSet::new();
+        let mut notebooks = Vec::new();
+        let mut snippets = Vec::new();
+        let clone_id = self.clone_notebook_subtree(
+            notebook_id,
+            parent_id,
+            with_snippets,
+            &mut visited,
+            &mut notebooks,
+            &mut snippets,
+        )?;
+
+        // Write every file before touching the database, and take them back on failure
+        if let Some(ref storage) = self.storage_manager {
+            for (index, snippet) in snippets.iter().enumerate() {
+                if let Err(e) = storage.save_snippet_content(snippet) {
+                    for written in &snippets[..index] {
+                        let _ = storage.delete_snippet_file(written);
+                    }
+                    return Err(format!("Failed to write snippet file: {}", e));
+                }
+            }
+        }
+
+        for snippet in snippets {
+            for tag in &snippet.tags {
+                self.tag_manager.add_tag_to_snippet(snippet.id, tag.clone());
+            }
+            self.snippet_database.snippets.insert(snippet.id, snippet);
+        }
+        for mut notebook in notebooks {
+            if notebook.id == clone_id {
+                notebook.name = name.trim().to_string();
+            }
+            self.snippet_database
+                .notebooks
+                .insert(notebook.id, notebook);
+        }
+
+        let database = &mut self.snippet_database;
+        let siblings = match parent_id.and_then(|id| database.notebooks.get_mut(&id)) {
+            Some(parent) => &mut parent.children,
+            None => &mut database.root_notebooks,
+        };
+        let position = siblings
+            .iter()
+            .position(|id| *id == notebook_id)
+            .map_or(siblings.len(), |index| index + 1);
+        siblings.insert(position, clone_id);
+
+        if let Err(e) = self.save_database() {
+            return Err(format!("Failed to save notebook: {}", e));
+        }
+
+        self.refresh_tree_items();
+        if let Some(index) = self
+            .tree_items
+            .iter()
+            .position(|item| matches!(item, TreeItem::Notebook(id, _) if *id == clone_id))
+        {
+            self.selected_tree_item = index;
+        }
+
+        Ok(clone_id)
+    }
+
+    /// Copies one notebook under `parent_id`, then its nested notebooks, into `notebooks` and
+    /// `snippets` without changing the database; returns the copy's id
+    fn clone_notebook_subtree(
+        &self,
+        source_id: Uuid,
+        parent_id: Option<Uuid>,
+        with_snippets: bool,
+        visited: &mut std::collections::HashSet<Uuid>,
+        notebooks: &mut Vec<Notebook>,
+        snippets: &mut Vec<CodeSnippet>,
+    ) -> Result<Uuid, String> {
+        let source = self
+            .snippet_database
+            .notebooks
+            .get(&source_id)
+            .cloned()
+            .ok_or_else(|| "Notebook not found".to_string())?;
+        visited.insert(source_id);
+
+        let mut copy = Notebook::new(source.name);
+        copy.description = source.description;
+        copy.tags = source.tags;
+        copy.color = source.color;
+        copy.icon = source.icon;
+        copy.color_index = source.color_index;
+        copy.metadata = source.metadata;
+        copy.parent_id = parent_id;
+        let copy_id = copy.id;
+
+        if with_snippets {
+            let originals: Vec<CodeSnippet> = self
+                .snippet_database
+                .snippets
+                .values()
+                .filter(|snippet| snippet.notebook_id == source_id)
+                .cloned()
+                .collect();
+            for original in originals {
+                let mut snippet = CodeSnippet::new(original.title, original.language, copy_id);
+                snippet.description = original.description;
+                snippet.content = original.content;
+                snippet.tags = original.tags;
+                snippet.file_extension = original.file_extension;
+                snippet.syntax_theme = original.syntax_theme;
+                snippet.editor_override = original.editor_override;
+                snippets.push(snippet);
+                copy.snippet_count += 1;
+            }
+        }
+
+        for child_id in source.children {
+            // Skips links that point back up a broken tree instead of copying forever
+            if visited.contains(&child_id)
+                || !self.snippet_database.notebooks.contains_key(&child_id)
+            {
+                continue;
+            }
+            let child_copy = self.clone_notebook_subtree(
+                child_id,
+                Some(copy_id),
+                with_snippets,
+                visited,
+                notebooks,
+                snippets,
+            )?;
+            copy.children.push(child_copy);
+        }
+
+        notebooks.push(copy);
+        Ok(copy_id)
+    }
+
+    /// Moves a notebook, its nested notebooks and all of their snippets to the trash
     pub fn delete_notebook(&mut self, notebook_id: Uuid) -> Result<(), String> {
         // Check if notebook exists
-        if !self.snippet_database.notebooks.contains_key(&notebook_id) {
-            return Err("Notebook not found".to_string());
+        let (name, children) = match self.snippet_database.notebooks.get(&notebook_id) {
+            Some(notebook) => (notebook.name.clone(), notebook.children.clone()),
+            None => return Err("Notebook not found".to_string()),
+        };
+        let trash_len = self.trash.len();
+
+        for child_id in children {
+            self.delete_notebook(child_id)?;
         }
 
-        // Delete all snippets in this notebook
+        // Trash all snippets in this notebook
         let snippet_ids: Vec<_> = self
             .snippet_database
             .snippets
@@ -514,6 +1308,8 @@ impl App {
             self.delete_snippet(snippet_id)?;
         }
 
+        let original_location = self.notebook_location(notebook_id);
+
         // Remove from parent's children or root list
         if let Some(notebook) = self.snippet_database.notebooks.get(&notebook_id) {
             if let Some(parent_id) = notebook.parent_id {
@@ -527,54 +1323,56 @@ impl App {
             }
         }
 
-        self.snippet_database.notebooks.remove(&notebook_id);
-        if let Some(ref storage) = self.storage_manager {
-            if let Err(e) = storage.delete_notebook_directory(notebook_id) {
-                eprintln!("Warning: Failed to delete notebook directory: {}", e);
-            }
+        if let Some(notebook) = self.snippet_database.notebooks.remove(&notebook_id) {
+            self.trash.push(TrashEntry::new(
+                TrashedItem::Notebook(notebook),
+                original_location,
+            ));
         }
 
+        self.save_trash()?;
         if let Err(e) = self.save_database() {
             return Err(format!("Failed to save changes: {}", e));
         }
 
+        self.record_delete_undo(trash_len, format!("deleted notebook '{}'", name));
         self.refresh_tree_items();
         Ok(())
     }
 
+    /// Moves a snippet to the trash, keeping its file until the trash is purged
     pub fn delete_snippet(&mut self, snippet_id: Uuid) -> Result<(), String> {
         // Check if the snippet exists
-        if !self.snippet_database.snippets.contains_key(&snippet_id) {
-            return Err("Snippet not found".to_string());
-        }
-
-        // Get the notebook ID before we remove the snippet
-        let notebook_id = self
-            .snippet_database
-            .snippets
-            .get(&snippet_id)
-            .map(|s| s.notebook_id);
-
-        // Delete the snippet file (if storage is available)
-        if let Some(ref storage) = self.storage_manager {
-            if let Some(snippet) = self.snippet_database.snippets.get(&snippet_id) {
-                if let Err(e) = storage.delete_snippet_file(snippet) {
-                    return Err(format!("Failed to delete snippet file: {}", e));
-                }
-            }
+        let (title, notebook_id) = match self.snippet_database.snippets.get(&snippet_id) {
+            Some(snippet) => (snippet.title.clone(), snippet.notebook_id),
+            None => return Err("Snippet not found".to_string()),
+        };
+        let trash_len = self.trash.len();
+
+        // Move the snippet file into the trash (if storage is available)
+        if let Some(ref storage) = self.storage_manager
+            && let Some(snippet) = self.snippet_database.snippets.get(&snippet_id)
+            && let Err(e) = storage.move_snippet_to_trash(snippet)
+        {
+            return Err(format!("Failed to move snippet to trash: {}", e));
         }
 
+        let original_location = self.notebook_location(notebook_id);
         self.tag_manager.handle_snippet_deleted(&snippet_id);
-        self.snippet_database.snippets.remove(&snippet_id);
+        if let Some(snippet) = self.snippet_database.snippets.remove(&snippet_id) {
+            self.trash.push(TrashEntry::new(
+                TrashedItem::Snippet(snippet),
+                original_location,
+            ));
+        }
 
         // Decrease the snippet count in the parent notebook
-        if let Some(id) = notebook_id {
-            if let Some(notebook) = self.snippet_database.notebooks.get_mut(&id) {
-                notebook.snippet_count = notebook.snippet_count.saturating_sub(1);
-                notebook.updated_at = chrono::Utc::now();
-            }
+        if let Some(notebook) = self.snippet_database.notebooks.get_mut(&notebook_id) {
+            notebook.snippet_count = notebook.snippet_count.saturating_sub(1);
+            notebook.updated_at = chrono::Utc::now();
         }
 
+        self.save_trash()?;
         if let Err(e) = self.save_database() {
             return Err(format!(
                 "Failed to save database after snippet deletion: {}",
@@ -582,6 +1380,7 @@ impl App {
             ));
         }
 
+        self.record_delete_undo(trash_len, format!("deleted '{}'", title));
         self.refresh_tree_items();
         self.selected_tree_item = self
             .selected_tree_item
@@ -590,11 +1389,879 @@ impl App {
         Ok(())
     }
 
-    pub fn get_selected_item(&self) -> Option<&TreeItem> {
-        self.tree_items.get(self.selected_tree_item)
-    }
+    /// Puts a trashed item back where it came from
+    pub fn restore_trash_item(&mut self, index: usize) -> Result<(), String> {
+        let entry = self
+            .trash
+            .get(index)
+            .cloned()
+            .ok_or_else(|| "No trash item selected".to_string())?;
+        let name = entry.name().to_string();
+
+        match entry.item {
+            TrashedItem::Snippet(snippet) => {
+                if !self
+                    .snippet_database
+                    .notebooks
+                    .contains_key(&snippet.notebook_id)
+                {
+                    return Err(format!(
+                        "Notebook '{}' is gone, restore it first",
+                        entry.original_location
+                    ));
+                }
 
-    pub fn get_hovered_item(&self) -> Option<&TreeItem> {
+                if let Some(ref storage) = self.storage_manager {
+                    storage
+                        .restore_snippet_from_trash(&snippet)
+                        .map_err(|e| format!("Failed to restore snippet file: {}", e))?;
+                }
+
+                for tag in &snippet.tags {
+                    self.tag_manager.add_tag_to_snippet(snippet.id, tag.clone());
+                }
+                if let Some(notebook) = self
+                    .snippet_database
+                    .notebooks
+                    .get_mut(&snippet.notebook_id)
+                {
+                    notebook.snippet_count += 1;
+                    notebook.updated_at = Utc::now();
+                }
+                self.snippet_database.snippets.insert(snippet.id, snippet);
+            }
+            TrashedItem::Notebook(mut notebook) => {
+                // Nested notebooks stay in the trash until restored themselves
+                notebook
+                    .children
+                    .retain(|id| self.snippet_database.notebooks.contains_key(id));
+
+                // A parent that now sits below this notebook would close a cycle
+                let parent = notebook
+                    .parent_id
+                    .filter(|id| !self.is_descendant_of(id, &notebook.id))
+                    .and_then(|id| self.snippet_database.notebooks.get_mut(&id));
+                if let Some(parent) = parent {
+                    parent.add_child(notebook.id);
+                } else {
+                    notebook.parent_id = None;
+                    self.snippet_database.root_notebooks.push(notebook.id);
+                }
+                self.snippet_database
+                    .notebooks
+                    .insert(notebook.id, notebook);
+            }
+        }
+
+        self.trash.remove(index);
+        self.selected_trash_item = self
+            .selected_trash_item
+            .min(self.trash.len().saturating_sub(1));
+
+        self.save_trash()?;
+        self.save_database()?;
+        self.refresh_tree_items();
+        self.set_success_message(format!("'{}' restored", name));
+        Ok(())
+    }
+
+    /// Remembers the trash entries added since `trash_len` as the action to undo
+    fn record_delete_undo(&mut self, trash_len: usize, label: String) {
+        let entries = self.trash.get(trash_len..).unwrap_or_default().to_vec();
+        self.undo_action = Some(UndoAction::Delete { label, entries });
+    }
+
+    /// Where every notebook and snippet currently sits
+    pub fn tree_layout(&self) -> TreeLayout {
+        TreeLayout {
+            root_notebooks: self.snippet_database.root_notebooks.clone(),
+            notebooks: self
+                .snippet_database
+                .notebooks
+                .values()
+                .map(|notebook| (notebook.id, (notebook.parent_id, notebook.children.clone())))
+                .collect(),
+            snippets: self
+                .snippet_database
+                .snippets
+                .values()
+                .map(|snippet| (snippet.id, snippet.notebook_id))
+                .collect(),
+        }
+    }
+
+    /// Undo point for moving the selected tree item, stored once the move succeeds
+    pub fn move_undo_point(&self) -> UndoAction {
+        let name = match self.get_selected_item() {
+            Some(TreeItem::Notebook(id, _)) => self
+                .snippet_database
+                .notebooks
+                .get(id)
+                .map(|notebook| notebook.name.clone()),
+            Some(TreeItem::Snippet(id, _)) => self
+                .snippet_database
+                .snippets
+                .get(id)
+                .map(|snippet| snippet.title.clone()),
+            None => None,
+        };
+
+        UndoAction::Move {
+            label: format!("moved '{}'", name.unwrap_or_default()),
+            layout: self.tree_layout(),
+        }
+    }
+
+    /// Reverses the last delete or move, returning what was undone
+    pub fn undo_last_action(&mut self) -> Result<String, String> {
+        let action = self
+            .undo_action
+            .take()
+            .ok_or_else(|| "Nothing to undo".to_string())?;
+
+        let result = match &action {
+            UndoAction::Delete { entries, .. } => self.undo_delete(entries),
+            UndoAction::Move { layout, .. } => self.restore_tree_layout(layout),
+        };
+        if let Err(e) = result {
+            // Keep it so the undo can be retried once the problem is fixed
+            self.undo_action = Some(action);
+            return Err(e);
+        }
+
+        self.refresh_tree_items();
+        self.selected_tree_item = self
+            .selected_tree_item
+            .min(self.tree_items.len().saturating_sub(1));
+        Ok(action.label().to_string())
+    }
+
+    /// Restores trashed items newest first, so notebooks come back before their contents.
+    /// Entries purged from the trash since are put back from memory first.
+    fn undo_delete(&mut self, entries: &[TrashEntry]) -> Result<(), String> {
+        for entry in entries.iter().rev() {
+            let id = entry.item.id();
+            let restored = match &entry.item {
+                TrashedItem::Snippet(_) => self.snippet_database.snippets.contains_key(&id),
+                TrashedItem::Notebook(_) => self.snippet_database.notebooks.contains_key(&id),
+            };
+            if restored {
+                continue;
+            }
+
+            let index = match self.trash.iter().position(|e| e.item.id() == id) {
+                Some(index) => index,
+                None => {
+                    self.trash.push(entry.clone());
+                    self.trash.len() - 1
+                }
+            };
+            self.restore_trash_item(index)?;
+        }
+        Ok(())
+    }
+
+    /// Puts every notebook and snippet back where `layout` had it
+    fn restore_tree_layout(&mut self, layout: &TreeLayout) -> Result<(), String> {
+        let notebooks = &self.snippet_database.notebooks;
+        if notebooks.len() != layout.notebooks.len()
+            || notebooks
+                .keys()
+                .any(|id| !layout.notebooks.contains_key(id))
+        {
+            return Err("Notebooks changed since the move, it can no longer be undone".to_string());
+        }
+
+        for (snippet_id, notebook_id) in &layout.snippets {
+            if self.snippet_database.snippets.contains_key(snippet_id) {
+                self.move_snippet_to_notebook(*snippet_id, *notebook_id)?;
+            }
+        }
+        for (notebook_id, (parent_id, children)) in &layout.notebooks {
+            if let Some(notebook) = self.snippet_database.notebooks.get_mut(notebook_id) {
+                notebook.parent_id = *parent_id;
+                notebook.children = children.clone();
+            }
+        }
+        self.snippet_database.root_notebooks = layout.root_notebooks.clone();
+
+        self.save_database()
+    }
+
+    /// Permanently removes a trashed item and its files
+    pub fn purge_trash_item(&mut self, index: usize) -> Result<(), String> {
+        if index >= self.trash.len() {
+            return Err("No trash item selected".to_string());
+        }
+
+        let entry = self.trash.remove(index);
+        if let Some(ref storage) = self.storage_manager {
+            let result = match &entry.item {
+                TrashedItem::Snippet(snippet) => storage.delete_snippet_file(snippet),
+                TrashedItem::Notebook(notebook) => storage.delete_notebook_directory(notebook.id),
+            };
+
+            if let Err(e) = result {
+                self.trash.insert(index, entry);
+                return Err(format!("Failed to delete files: {}", e));
+            }
+        }
+
+        self.selected_trash_item = self
+            .selected_trash_item
+            .min(self.trash.len().saturating_sub(1));
+        self.save_trash()?;
+        self.set_success_message(format!("'{}' permanently deleted", entry.name()));
+        Ok(())
+    }
+
+    /// Permanently removes everything in the trash
+    pub fn empty_trash(&mut self) -> Result<(), String> {
+        let count = self.trash.len();
+        while !self.trash.is_empty() {
+            self.purge_trash_item(0)?;
+        }
+
+        self.set_success_message(format!("Emptied trash ({} items)", count));
+        Ok(())
+    }
+
+    /// Enters or leaves multi-select mode, dropping any marks on exit
+    pub fn toggle_multi_select_mode(&mut self) {
+        self.multi_select_mode = !self.multi_select_mode;
+        if !self.multi_select_mode {
+            self.marked_snippets.clear();
+        }
+    }
+
+    /// Marks or unmarks the selected snippet, or every snippet of the selected notebook
+    pub fn toggle_mark_selected(&mut self) -> Result<(), String> {
+        let snippet_ids: Vec<Uuid> = match self.get_selected_item().cloned() {
+            Some(TreeItem::Snippet(snippet_id, _)) => vec![snippet_id],
+            Some(TreeItem::Notebook(notebook_id, _)) => self
+                .snippet_database
+                .snippets
+                .values()
+                .filter(|s| s.notebook_id == notebook_id)
+                .map(|s| s.id)
+                .collect(),
+            None => return Err("Nothing selected".to_string()),
+        };
+
+        if snippet_ids.is_empty() {
+            return Err("Notebook has no snippets to mark".to_string());
+        }
+
+        if snippet_ids
+            .iter()
+            .all(|id| self.marked_snippets.contains(id))
+        {
+            for id in &snippet_ids {
+                self.marked_snippets.remove(id);
+            }
+        } else {
+            self.marked_snippets.extend(snippet_ids);
+        }
+
+        Ok(())
+    }
+
+    /// Marked snippets that still exist, in a stable order
+    fn marked_snippet_ids(&self) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = self
+            .marked_snippets
+            .iter()
+            .filter(|id| self.snippet_database.snippets.contains_key(id))
+            .copied()
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Moves every marked snippet to the trash
+    pub fn delete_marked_snippets(&mut self) -> Result<usize, String> {
+        let ids = self.marked_snippet_ids();
+        let trash_len = self.trash.len();
+        for snippet_id in &ids {
+            self.delete_snippet(*snippet_id)?;
+        }
+
+        self.record_delete_undo(trash_len, format!("deleted {} snippets", ids.len()));
+        self.marked_snippets.clear();
+        Ok(ids.len())
+    }
+
+    /// Moves every marked snippet into the target notebook
+    pub fn move_marked_snippets(&mut self, target_id: Uuid) -> Result<usize, String> {
+        if !self.snippet_database.notebooks.contains_key(&target_id) {
+            return Err("Target notebook not found".to_string());
+        }
+
+        let ids = self.marked_snippet_ids();
+        let layout = self.tree_layout();
+        for snippet_id in &ids {
+            self.move_snippet_to_notebook(*snippet_id, target_id)?;
+        }
+
+        self.undo_action = Some(UndoAction::Move {
+            label: format!("moved {} snippets", ids.len()),
+            layout,
+        });
+        self.save_database()?;
+        self.marked_snippets.clear();
+        self.refresh_tree_items();
+        Ok(ids.len())
+    }
+
+    /// Moves a snippet and its file into another notebook, keeping counts in sync
+    fn move_snippet_to_notebook(
+        &mut self,
+        snippet_id: Uuid,
+        target_id: Uuid,
+    ) -> Result<(), String> {
+        let old_notebook_id = match self.snippet_database.snippets.get(&snippet_id) {
+            Some(snippet) if snippet.notebook_id == target_id => return Ok(()),
+            Some(snippet) => snippet.notebook_id,
+            None => return Err("Snippet not found".to_string()),
+        };
+
+        if let (Some(storage), Some(snippet)) = (
+            self.storage_manager.as_ref(),
+            self.snippet_database.snippets.get(&snippet_id),
+        ) {
+            storage
+                .move_snippet_file(snippet, target_id)
+                .map_err(|e| format!("Failed to move snippet file: {}", e))?;
+        }
+
+        if let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) {
+            snippet.notebook_id = target_id;
+            snippet.updated_at = Utc::now();
+        }
+        if let Some(notebook) = self.snippet_database.notebooks.get_mut(&old_notebook_id) {
+            notebook.snippet_count = notebook.snippet_count.saturating_sub(1);
+        }
+        if let Some(notebook) = self.snippet_database.notebooks.get_mut(&target_id) {
+            notebook.snippet_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Favorites every marked snippet, or unfavorites them if all already are
+    pub fn toggle_favorite_marked(&mut self) -> Result<(usize, bool), String> {
+        let ids = self.marked_snippet_ids();
+        let make_favorite = ids
+            .iter()
+            .filter_map(|id| self.snippet_database.snippets.get(id))
+            .any(|s| !s.is_favorite);
+
+        for id in &ids {
+            let snippet = self.snippet_database.snippets.get_mut(id);
+            if let Some(snippet) = snippet.filter(|s| s.is_favorite != make_favorite) {
+                snippet.toggle_favorite();
+            }
+        }
+
+        self.save_database()?;
+        self.refresh_tree_items();
+        Ok((ids.len(), make_favorite))
+    }
+
+    /// Exports the marked snippets along with the notebooks that contain them
+    pub fn export_marked_snippets(&mut self, path: &std::path::Path) -> Result<usize, String> {
+        let ids = self.marked_snippet_ids();
+        if ids.is_empty() {
+            return Err("No snippets marked".to_string());
+        }
+
+        let options = ExportOptions {
+            _format: export_format_for_path(path),
+            snippet_ids: Some(ids.clone()),
+            ..ExportOptions::default()
+        };
+
+        export_database_with_tags(&self.snippet_database, &self.tag_manager, path, &options)
+            .map_err(|e| format!("Export failed: {}", e))?;
+
+        Ok(ids.len())
+    }
+
+    /// Exports every notebook and snippet, picking the format from the file extension
+    pub fn export_library(&self, path: &std::path::Path) -> Result<usize, String> {
+        let options = ExportOptions {
+            _format: export_format_for_path(path),
+            ..ExportOptions::default()
+        };
+
+        export_database_with_tags(&self.snippet_database, &self.tag_manager, path, &options)
+            .map_err(|e| format!("Export failed: {}", e))?;
+
+        Ok(self.snippet_database.snippets.len())
+    }
+
+    /// Writes a notebook and its descendants to a Markdown file, returning the snippet count
+    pub fn export_notebook_markdown(
+        &self,
+        notebook_id: Uuid,
+        path: &std::path::Path,
+    ) -> Result<usize, String> {
+        let (markdown, count) =
+            crate::models::export::notebook_to_markdown(&self.snippet_database, notebook_id)
+                .map_err(|e| format!("Export failed: {}", e))?;
+
+        std::fs::write(path, markdown).map_err(|e| format!("Export failed: {}", e))?;
+        Ok(count)
+    }
+
+    /// Placeholder values snix can fill in without asking
+    pub fn template_defaults(&self) -> std::collections::HashMap<String, String> {
+        let now = chrono::Local::now();
+        let mut values = std::collections::HashMap::from([
+            ("date".to_string(), now.format("%Y-%m-%d").to_string()),
+            ("time".to_string(), now.format("%H:%M").to_string()),
+            ("year".to_string(), now.format("%Y").to_string()),
+        ]);
+
+        let author = self.settings.author.trim();
+        if !author.is_empty() {
+            values.insert("author".to_string(), author.to_string());
+        }
+        values
+    }
+
+    pub fn known_template_values(
+        &self,
+        snippet: &CodeSnippet,
+    ) -> std::collections::HashMap<String, String> {
+        let mut values = self.template_defaults();
+        values.insert("title".to_string(), snippet.title.clone());
+        values
+    }
+
+    /// Re-reads the boilerplate folder, keeping the selection in range
+    pub fn reload_boilerplates(&mut self) {
+        match crate::models::boilerplate::load_boilerplates() {
+            Ok((boilerplates, invalid)) => {
+                self.boilerplates = boilerplates;
+                if let Some(first) = invalid.first() {
+                    self.set_error_message(format!("Skipped invalid boilerplate {}", first));
+                }
+            }
+            Err(e) => {
+                self.boilerplates.clear();
+                self.set_error_message(format!("Failed to load boilerplates: {}", e));
+            }
+        }
+
+        self.selected_boilerplate = self
+            .selected_boilerplate
+            .min(self.boilerplates.len().saturating_sub(1));
+    }
+
+    /// Recomputes the overview shown on the Statistics page
+    pub fn refresh_library_stats(&mut self) {
+        let data_dir = self
+            .storage_manager
+            .as_ref()
+            .map(|storage| storage.data_dir().to_path_buf())
+            .unwrap_or_default();
+        self.library_stats = Some(LibraryStats::compute(
+            &self.snippet_database,
+            &self.tag_manager,
+            &data_dir,
+        ));
+    }
+
+    /// All tags ordered by name, as listed on the tag management page
+    pub fn sorted_tags(&self) -> Vec<&Tag> {
+        let mut tags: Vec<&Tag> = self.tag_manager.tags.values().collect();
+        tags.sort_by_key(|tag| tag.name.to_lowercase());
+        tags
+    }
+
+    /// Snippets carrying a tag across all notebooks, ordered by title
+    pub fn snippets_with_tag(&self, tag_id: Uuid) -> Vec<&CodeSnippet> {
+        let mut snippets: Vec<&CodeSnippet> = self
+            .tag_manager
+            .get_snippets_with_tag(&tag_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.snippet_database.snippets.get(id))
+            .collect();
+        snippets.sort_by_key(|snippet| snippet.title.to_lowercase());
+        snippets
+    }
+
+    /// Existing tags matching the word being typed in the tag editor, most used first
+    pub fn tag_suggestions(&self) -> Vec<&Tag> {
+        let (typed, partial) = split_tag_input(&self.input_buffer);
+        let partial = partial.trim_start_matches('#').to_lowercase();
+        if partial.is_empty() {
+            return Vec::new();
+        }
+
+        let mut suggestions: Vec<&Tag> = self
+            .tag_manager
+            .tags
+            .values()
+            .filter(|tag| tag.name.to_lowercase().contains(&partial))
+            // Skip tags already entered earlier in the line
+            .filter(|tag| {
+                !typed
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .any(|word| word.trim_start_matches('#').eq_ignore_ascii_case(&tag.name))
+            })
+            .collect();
+        suggestions.sort_by(|a, b| {
+            b.usage_count
+                .cmp(&a.usage_count)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        suggestions.truncate(MAX_TAG_SUGGESTIONS);
+        suggestions
+    }
+
+    /// Replaces the word being typed with the selected suggestion's spelling
+    pub fn complete_tag_suggestion(&mut self) {
+        let Some(name) = self
+            .tag_suggestions()
+            .get(self.selected_tag_suggestion)
+            .map(|tag| tag.name.clone())
+        else {
+            return;
+        };
+
+        let typed_len = split_tag_input(&self.input_buffer).0.len();
+        self.input_buffer.truncate(typed_len);
+        self.input_buffer.push_str(&format!("#{} ", name));
+        self.input_cursor = 0;
+        self.selected_tag_suggestion = 0;
+    }
+
+    /// Replaces a snippet's tags with the `#tags` found in `text`
+    pub fn set_snippet_tags(&mut self, snippet_id: Uuid, text: &str) -> Result<(), String> {
+        let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) else {
+            return Err("Snippet not found".to_string());
+        };
+
+        snippet.tags.clear();
+        snippet.set_tags_from_text(text);
+        let tags = snippet.tags.clone();
+        for tag_name in tags {
+            self.tag_manager.add_tag_to_snippet(snippet_id, tag_name);
+        }
+
+        self.save_database()
+    }
+
+    /// Renames a tag on every snippet, merging it when the new name is already taken
+    pub fn rename_tag(&mut self, old_name: &str, new_name: &str) -> Result<(), String> {
+        let merging = self
+            .tag_manager
+            .find_tag(new_name)
+            .is_some_and(|id| Some(id) != self.tag_manager.find_tag(old_name));
+        let changed =
+            self.apply_tag_change(|tags, snippets| tags.rename_tag(old_name, new_name, snippets))?;
+
+        let new_name = new_name.trim().trim_start_matches('#');
+        self.set_success_message(format!(
+            "{} #{} {} #{} on {} snippets",
+            if merging { "Merged" } else { "Renamed" },
+            old_name,
+            if merging { "into" } else { "to" },
+            new_name,
+            changed
+        ));
+        Ok(())
+    }
+
+    /// Adds a tag to every snippet in a notebook and its nested notebooks, or removes it
+    /// when the input starts with `-`
+    pub fn bulk_tag_notebook(&mut self, notebook_id: Uuid, input: &str) -> Result<(), String> {
+        let input = input.trim();
+        let (removing, tag) = match input.strip_prefix('-') {
+            Some(tag) => (true, tag.trim().trim_start_matches('#')),
+            None => (false, input.trim_start_matches('#')),
+        };
+        let snippet_ids: Vec<Uuid> = self
+            .snippet_database
+            .snippets
+            .values()
+            .filter(|snippet| {
+                snippet.notebook_id == notebook_id
+                    || self.is_descendant_of(&snippet.notebook_id, &notebook_id)
+            })
+            .map(|snippet| snippet.id)
+            .collect();
+        if snippet_ids.is_empty() {
+            return Err("The notebook has no snippets".to_string());
+        }
+
+        let changed = self.apply_tag_change(|tags, snippets| {
+            if removing {
+                tags.remove_tag_from_snippets(tag, &snippet_ids, snippets)
+            } else {
+                tags.add_tag_to_snippets(tag, &snippet_ids, snippets)
+            }
+        })?;
+
+        let message = match (removing, changed) {
+            (true, 0) => format!("No snippet in the notebook is tagged #{}", tag),
+            (true, n) => format!("Removed #{} from {} snippets", tag, n),
+            (false, 0) => format!("Every snippet in the notebook is already tagged #{}", tag),
+            (false, n) => format!("Tagged {} snippets with #{}", n, tag),
+        };
+        self.set_success_message(message);
+        Ok(())
+    }
+
+    /// Moves every snippet of one tag onto another and drops the first tag
+    pub fn merge_tags(&mut self, from: &str, into: &str) -> Result<(), String> {
+        let changed =
+            self.apply_tag_change(|tags, snippets| tags.merge_tags(from, into, snippets))?;
+
+        self.set_success_message(format!(
+            "Merged #{} into #{} on {} snippets",
+            from,
+            into.trim().trim_start_matches('#'),
+            changed
+        ));
+        Ok(())
+    }
+
+    /// Applies a tag change and saves it, restoring the previous state if saving fails
+    fn apply_tag_change(
+        &mut self,
+        change: impl FnOnce(
+            &mut TagManager,
+            &mut std::collections::HashMap<Uuid, CodeSnippet>,
+        ) -> anyhow::Result<Vec<Uuid>>,
+    ) -> Result<usize, String> {
+        let previous_tags = self.tag_manager.clone();
+        let previous_snippets = self.snippet_database.snippets.clone();
+
+        let changed = change(&mut self.tag_manager, &mut self.snippet_database.snippets)
+            .map_err(|e| e.to_string())?;
+
+        if let Err(e) = self.save_database() {
+            self.tag_manager = previous_tags;
+            self.snippet_database.snippets = previous_snippets;
+            // The database file may already hold the new tags, so write the old state back
+            let _ = self.save_database();
+            return Err(e);
+        }
+
+        let tag_count = self.tag_manager.tags.len();
+        self.selected_tag = self.selected_tag.min(tag_count.saturating_sub(1));
+        self.tag_snippets_focused = false;
+        self.selected_tagged_snippet = 0;
+        self.refresh_tree_items();
+        Ok(changed.len())
+    }
+
+    /// Creates the selected boilerplate's files in `target`
+    pub fn scaffold_boilerplate(
+        &self,
+        target: &std::path::Path,
+        values: &std::collections::HashMap<String, String>,
+    ) -> Result<usize, String> {
+        let boilerplate = self
+            .boilerplates
+            .get(self.selected_boilerplate)
+            .ok_or_else(|| "No boilerplate selected".to_string())?;
+
+        boilerplate
+            .scaffold(target, values)
+            .map_err(|e| format!("Scaffolding failed: {}", e))
+    }
+
+    /// Switches the color theme and remembers the choice
+    pub fn apply_theme(&mut self, theme: Theme) -> Result<(), String> {
+        set_active_theme(theme);
+        self.settings.theme = theme.name().to_string();
+        self.needs_redraw = true;
+        self.save_settings()?;
+
+        self.set_success_message(format!("Theme set to {}", theme.name()));
+        Ok(())
+    }
+
+    /// Toggles soft word-wrap in the snippet preview
+    pub fn toggle_preview_wrap(&mut self) -> Result<(), String> {
+        self.settings.preview_wrap = !self.settings.preview_wrap;
+        // Visual rows change meaning when wrapping flips, so start from the top
+        self.content_scroll_position = 0;
+        self.horizontal_scroll = 0;
+        self.needs_redraw = true;
+        self.save_settings()?;
+
+        let state = if self.settings.preview_wrap {
+            "on"
+        } else {
+            "off"
+        };
+        self.set_success_message(format!("Word wrap {}", state));
+        Ok(())
+    }
+
+    /// Turns the backup written on exit on or off
+    pub fn toggle_backup_on_exit(&mut self) -> Result<(), String> {
+        self.settings.backup_on_exit = !self.settings.backup_on_exit;
+        self.save_settings()?;
+
+        let state = if self.settings.backup_on_exit {
+            "on"
+        } else {
+            "off"
+        };
+        self.set_success_message(format!("Backup on exit {}", state));
+        Ok(())
+    }
+
+    /// Compares the database with the snippet files on disk and keeps the report for Settings
+    pub fn verify_storage(&mut self) -> Result<(), String> {
+        let storage = self
+            .storage_manager
+            .as_ref()
+            .ok_or("Storage is not available")?;
+        let report = storage
+            .verify_integrity(&self.snippet_database)
+            .map_err(|e| format!("Storage check failed: {}", e))?;
+
+        if report.is_clean() {
+            self.set_success_message(format!(
+                "Checked {} snippets, storage is consistent",
+                self.snippet_database.snippets.len()
+            ));
+        } else {
+            self.set_error_message(format!(
+                "{} snippets without a file, {} files without a snippet",
+                report.missing_files.len(),
+                report.orphan_files.len()
+            ));
+        }
+        self.integrity_report = Some(report);
+        Ok(())
+    }
+
+    /// Rewrites the files the last storage check found missing from the database content
+    pub fn recreate_missing_files(&mut self) -> Result<(), String> {
+        let Some(report) = self.integrity_report.as_ref() else {
+            return Err("Press v to check the storage first".to_string());
+        };
+        let storage = self
+            .storage_manager
+            .as_ref()
+            .ok_or("Storage is not available")?;
+        let count = storage
+            .recreate_missing_files(&self.snippet_database, &report.missing_files)
+            .map_err(|e| format!("Failed to recreate files: {}", e))?;
+
+        self.verify_storage()?;
+        self.set_success_message(format!("Recreated {} snippet files", count));
+        Ok(())
+    }
+
+    /// Adds the files the last storage check found without a snippet to the library
+    pub fn import_orphan_files(&mut self) -> Result<(), String> {
+        let Some(report) = self.integrity_report.as_ref() else {
+            return Err("Press v to check the storage first".to_string());
+        };
+        let storage = self
+            .storage_manager
+            .as_ref()
+            .ok_or("Storage is not available")?;
+        let count = storage
+            .import_orphan_files(&mut self.snippet_database, &report.orphan_files)
+            .map_err(|e| format!("Failed to import orphan files: {}", e))?;
+
+        self.save_database()?;
+        self.refresh_tree_items();
+        self.verify_storage()?;
+        self.set_success_message(format!("Imported {} files as snippets", count));
+        Ok(())
+    }
+
+    /// Writes the exit backup if it is enabled, returning where it was saved
+    pub fn backup_on_exit(&self) -> Result<Option<std::path::PathBuf>, String> {
+        if !self.settings.backup_on_exit {
+            return Ok(None);
+        }
+
+        crate::models::backup::write_exit_backup(
+            &self.snippet_database,
+            &self.tag_manager,
+            &RetentionPolicy::from_settings(&self.settings),
+        )
+        .map(Some)
+        .map_err(|e| format!("Backup on exit failed: {}", e))
+    }
+
+    pub fn save_settings(&self) -> Result<(), String> {
+        let saved = match self.storage_manager {
+            Some(ref storage) => storage.save_settings(&self.settings),
+            None => Ok(()),
+        };
+        saved.map_err(|e| format!("Failed to save settings: {}", e))
+    }
+
+    pub fn save_trash(&self) -> Result<(), String> {
+        if let Some(ref storage) = self.storage_manager {
+            storage
+                .save_trash(&self.trash)
+                .map_err(|e| format!("Failed to save trash: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Size of a snippet's content file on disk, if it has been saved
+    pub fn snippet_file_size(&self, snippet: &CodeSnippet) -> Option<u64> {
+        let storage = self.storage_manager.as_ref()?;
+        std::fs::metadata(storage.get_snippet_file_path(snippet))
+            .ok()
+            .map(|metadata| metadata.len())
+    }
+
+    /// The `limit` most recently opened snippets, newest first
+    pub fn recent_snippets(&self, limit: usize) -> Vec<&CodeSnippet> {
+        let mut snippets: Vec<&CodeSnippet> = self.snippet_database.snippets.values().collect();
+        snippets.sort_by_key(|snippet| std::cmp::Reverse(snippet.accessed_at));
+        snippets.truncate(limit);
+        snippets
+    }
+
+    /// Human readable path of a notebook, e.g. "Work / Rust"
+    pub(crate) fn notebook_location(&self, notebook_id: Uuid) -> String {
+        self.notebook_names(notebook_id).join(" / ")
+    }
+
+    /// Breadcrumb of a notebook and its ancestors, e.g. "Work › Rust › Async"
+    pub fn notebook_path(&self, notebook_id: Uuid) -> String {
+        self.notebook_names(notebook_id).join(" › ")
+    }
+
+    /// Names along the `parent_id` chain, outermost notebook first
+    fn notebook_names(&self, notebook_id: Uuid) -> Vec<&str> {
+        let mut names = Vec::new();
+        let mut current = self.snippet_database.notebooks.get(&notebook_id);
+
+        while let Some(notebook) = current {
+            names.push(notebook.name.as_str());
+            current = notebook
+                .parent_id
+                .and_then(|id| self.snippet_database.notebooks.get(&id));
+        }
+
+        names.reverse();
+        names
+    }
+
+    pub fn get_selected_item(&self) -> Option<&TreeItem> {
+        self.tree_items.get(self.selected_tree_item)
+    }
+
+    pub fn get_hovered_item(&self) -> Option<&TreeItem> {
         if let Some(hovered_index) = self.hovered_tree_item {
             self.tree_items.get(hovered_index)
         } else {
@@ -618,28 +2285,133 @@ impl App {
         Ok(())
     }
 
+    /// Ids of every snippet, taken before an import so the snippets it adds can be told apart
+    pub fn snippet_ids(&self) -> std::collections::HashSet<Uuid> {
+        self.snippet_database.snippets.keys().copied().collect()
+    }
+
+    /// Saves the database after an import and writes files for the snippets not in `known`
+    pub fn save_imported_database(
+        &self,
+        known: &std::collections::HashSet<Uuid>,
+    ) -> Result<(), String> {
+        self.save_database()?;
+        if let Some(ref storage) = self.storage_manager {
+            storage
+                .write_new_snippet_files(&self.snippet_database, known)
+                .map_err(|e| format!("Failed to write snippet files: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Shows an error toast that expires on its own
     pub fn set_error_message(&mut self, message: String) {
-        self.error_message = Some(message);
-        self.success_message = None;
+        self.toasts.error(message);
     }
 
+    /// Shows a success toast that expires on its own
     pub fn set_success_message(&mut self, message: String) {
-        self.success_message = Some(message);
-        self.error_message = None;
+        self.toasts.success(message);
     }
 
+    /// Dismisses a pending question; toasts are left to expire
     pub fn clear_messages(&mut self) {
         self.error_message = None;
         self.success_message = None;
     }
 
-    /// Call this periodically to auto-clear messages after a timeout
+    /// Applies edits and deletions made to snippet files outside of snix
+    pub fn reload_changed_snippets(&mut self) {
+        let (Some(watcher), Some(storage)) = (&self.snippet_watcher, &self.storage_manager) else {
+            return;
+        };
+
+        let mut changed = Vec::new();
+        let mut deleted = Vec::new();
+        for path in watcher.changed_files() {
+            let Some(snippet_id) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| Uuid::parse_str(stem).ok())
+            else {
+                continue;
+            };
+            let Some(snippet) = self.snippet_database.snippets.get(&snippet_id) else {
+                continue;
+            };
+            // Old paths left behind by renames and moves are not this snippet's file anymore
+            if storage.get_snippet_file_path(snippet) != path {
+                continue;
+            }
+
+            if !path.exists() {
+                deleted.push(snippet_id);
+                continue;
+            }
+            // Our own writes land here too, and leave the content unchanged
+            match std::fs::read_to_string(&path) {
+                Ok(content) if content != snippet.content => changed.push((snippet_id, content)),
+                _ => {}
+            }
+        }
+
+        if changed.is_empty() && deleted.is_empty() {
+            return;
+        }
+
+        if !changed.is_empty() {
+            for (snippet_id, content) in changed.iter().cloned() {
+                if let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) {
+                    snippet.update_content(content);
+                }
+            }
+            if let Err(e) = self.save_database() {
+                self.set_error_message(e);
+            }
+        }
+
+        let mut removed = Vec::new();
+        for snippet_id in deleted {
+            let title = self.snippet_database.snippets[&snippet_id].title.clone();
+            match self.delete_snippet(snippet_id) {
+                Ok(()) => removed.push(title),
+                Err(e) => self.set_error_message(e),
+            }
+        }
+
+        if !removed.is_empty() {
+            self.set_error_message(format!(
+                "Removed '{}': file deleted outside snix (moved to trash)",
+                removed.join("', '")
+            ));
+        } else if let [(snippet_id, _)] = changed.as_slice() {
+            let title = self.snippet_database.snippets[snippet_id].title.clone();
+            self.set_success_message(format!("Reloaded '{}' from disk", title));
+        } else if !changed.is_empty() {
+            self.set_success_message(format!("Reloaded {} snippets from disk", changed.len()));
+        }
+
+        self.needs_redraw = true;
+    }
+
+    /// Whether something on screen changes without input, so the event loop should wake often.
+    /// Toasts have to expire on time; the Ollama state covers its spinners and copy feedback.
+    pub fn is_animating(&self) -> bool {
+        self.transfer_job.is_some()
+            || !self.toasts.is_empty()
+            || self
+                .ollama_state
+                .as_ref()
+                .is_some_and(|state| state.is_animating())
+    }
+
     pub fn _tick(&mut self) {
         // Update Ollama loading animation if active
         crate::handlers::ollama::update_loading_animation(self);
+        crate::handlers::transfer::poll_transfer(self);
 
-        // Messages will be cleared by user interaction or manual clearing
-        // This is a placeholder for future auto-clear functionality
+        self.reload_changed_snippets();
+        self.toasts.clean_expired();
     }
 
     /// Renders the current application state to the terminal frame
@@ -651,6 +2423,17 @@ impl App {
     /// and icons, maintaining consistent navigation while indicating that those
     /// features are under development.
     pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        self.terminal_too_small = area.width < components::MIN_TERMINAL_WIDTH
+            || area.height < components::MIN_TERMINAL_HEIGHT;
+        if self.terminal_too_small {
+            components::render_terminal_too_small(frame);
+            if self.show_quit_prompt {
+                components::render_quit_prompt(frame);
+            }
+            return;
+        }
+
         match self.state {
             AppState::StartPage => {
                 start_page::render(frame, self);
@@ -661,20 +2444,20 @@ impl App {
                     crate::ui::favorites::render_floating_favorites(frame, self);
                 }
             }
-            AppState::Boilerplates => {
-                components::render_wip_dialog(frame, frame.area(), "󰘦 Boilerplates", self)
-            }
+            AppState::Boilerplates => crate::ui::boilerplates::render(frame, self),
             AppState::Marketplace => {
                 components::render_wip_dialog(frame, frame.area(), "󰓜 Marketplace", self)
             }
             AppState::CodeSnippets => code_snippets::render(frame, self),
             AppState::ExportImport => export_import::render(frame, self),
+            AppState::Trash => crate::ui::trash::render(frame, self),
             AppState::InfoPage => {
                 components::render_wip_dialog(frame, frame.area(), "ⓘ Information", self)
             }
-            AppState::Settings => {
-                components::render_wip_dialog(frame, frame.area(), "⚙ Settings", self)
-            }
+            AppState::Settings => crate::ui::settings::render(frame, self),
+            AppState::Statistics => crate::ui::stats::render(frame, self),
+            AppState::Tags => crate::ui::tags::render(frame, self),
+            AppState::Recent => crate::ui::recent::render(frame, self),
         }
 
         if let Some(msg) = &self.error_message {
@@ -687,7 +2470,53 @@ impl App {
             crate::ui::about::render_about(frame, self);
         }
 
+        if self.input_mode == InputMode::HelpMenu {
+            crate::ui::help::render(frame, self);
+        }
+
         crate::ui::ollama::render_ollama_popup(frame, self, frame.area());
+
+        let ollama_toasts = self
+            .ollama_state
+            .iter()
+            .flat_map(|state| state.toasts.iter());
+        crate::ui::toast::render(frame, frame.area(), self.toasts.iter().chain(ollama_toasts));
+
+        if self.show_quit_prompt {
+            components::render_quit_prompt(frame);
+        }
+
+        if let Some(error) = &self.storage_error {
+            components::render_storage_error(frame, error);
+        }
+    }
+
+    /// Returns true when nothing would be lost by quitting; otherwise opens the quit prompt
+    pub fn confirm_quit(&mut self) -> bool {
+        let unsaved_chat = self
+            .ollama_state
+            .as_ref()
+            .is_some_and(|state| state.has_unsaved_conversation());
+        if unsaved_chat {
+            self.show_quit_prompt = true;
+        }
+        !unsaved_chat
+    }
+
+    /// Sets the command that opens this snippet, or clears it when `command` is blank
+    pub fn set_snippet_editor(&mut self, snippet_id: Uuid, command: &str) -> Result<(), String> {
+        let snippet = self
+            .snippet_database
+            .snippets
+            .get_mut(&snippet_id)
+            .ok_or_else(|| "Snippet not found".to_string())?;
+        let command = command.trim();
+        snippet.editor_override = (!command.is_empty()).then(|| command.to_string());
+
+        if let Err(e) = self.save_database() {
+            return Err(format!("Failed to save editor: {}", e));
+        }
+        Ok(())
     }
 
     pub fn update_snippet_description(
@@ -713,8 +2542,62 @@ impl App {
         }
     }
 
+    /// Renames a snippet and moves its backing file when the extension changes
+    pub fn rename_snippet(
+        &mut self,
+        snippet_id: Uuid,
+        title: String,
+        language: SnippetLanguage,
+    ) -> Result<(), String> {
+        if title.trim().is_empty() {
+            return Err("Snippet title cannot be empty".to_string());
+        }
+
+        let old_snippet = match self.snippet_database.snippets.get(&snippet_id) {
+            Some(snippet) => snippet.clone(),
+            None => return Err("Snippet not found".to_string()),
+        };
+
+        let new_extension = language.file_extension().to_string();
+
+        let collides = self.snippet_database.snippets.values().any(|s| {
+            s.id != snippet_id
+                && s.notebook_id == old_snippet.notebook_id
+                && s.title.eq_ignore_ascii_case(&title)
+                && s.file_extension.eq_ignore_ascii_case(&new_extension)
+        });
+        if collides {
+            return Err(format!(
+                "A snippet named '{}.{}' already exists in this notebook",
+                title, new_extension
+            ));
+        }
+
+        let extension_changed = new_extension != old_snippet.file_extension;
+        if let (true, Some(storage)) = (extension_changed, self.storage_manager.as_ref()) {
+            storage
+                .rename_snippet_file(&old_snippet, &new_extension)
+                .map_err(|e| format!("Failed to move snippet file: {}", e))?;
+        }
+
+        if let Some(snippet) = self.snippet_database.snippets.get_mut(&snippet_id) {
+            snippet.title = title;
+            snippet.language = language;
+            snippet.file_extension = new_extension;
+            snippet.updated_at = Utc::now();
+        }
+
+        if let Err(e) = self.save_database() {
+            return Err(format!("Failed to save snippet: {}", e));
+        }
+
+        self.refresh_tree_items();
+        Ok(())
+    }
+
     pub fn reset_scroll_position(&mut self) {
         self.content_scroll_position = 0;
+        self.horizontal_scroll = 0;
         self.needs_redraw = true;
     }
 
@@ -739,22 +2622,7 @@ impl App {
         color_index: usize,
     ) -> Result<(), String> {
         if let Some(notebook) = self.snippet_database.notebooks.get_mut(&notebook_id) {
-            // Store color index in a custom field or metadata
-            // For now, we'll use the description with a prefix to store the color
-            let desc = notebook.description.clone().unwrap_or_default();
-
-            // Extract description without color prefix if it exists
-            let desc_without_color = if desc.starts_with("[COLOR:") {
-                if let Some(end_idx) = desc.find(']') {
-                    desc[end_idx + 1..].trim().to_string()
-                } else {
-                    desc
-                }
-            } else {
-                desc
-            };
-
-            notebook.description = Some(format!("[COLOR:{}] {}", color_index, desc_without_color));
+            notebook.color_index = Some(color_index);
             notebook.updated_at = chrono::Utc::now();
             self.save_database()?;
             Ok(())
@@ -764,18 +2632,11 @@ impl App {
     }
 
     pub fn get_notebook_color(&self, notebook_id: &Uuid) -> usize {
-        if let Some(notebook) = self.snippet_database.notebooks.get(notebook_id) {
-            if let Some(desc) = &notebook.description {
-                if desc.starts_with("[COLOR:") {
-                    if let Some(end_idx) = desc.find(']') {
-                        if let Ok(color_idx) = desc[7..end_idx].parse::<usize>() {
-                            return color_idx;
-                        }
-                    }
-                }
-            }
-        }
-        0
+        self.snippet_database
+            .notebooks
+            .get(notebook_id)
+            .and_then(|notebook| notebook.color_index)
+            .unwrap_or(0)
     }
 
     pub fn toggle_notebook_collapse(&mut self) -> bool {
@@ -793,17 +2654,34 @@ impl App {
     }
 
     pub fn collapse_notebook(&mut self, notebook_id: Uuid) {
-        self.collapsed_notebooks.insert(notebook_id);
+        if self.collapsed_notebooks.insert(notebook_id) {
+            self.save_ui_state();
+        }
         self.refresh_tree_items();
         self.needs_redraw = true;
     }
 
     pub fn expand_notebook(&mut self, notebook_id: Uuid) {
-        self.collapsed_notebooks.remove(&notebook_id);
+        if self.collapsed_notebooks.remove(&notebook_id) {
+            self.save_ui_state();
+        }
         self.refresh_tree_items();
         self.needs_redraw = true;
     }
 
+    /// Remembers which notebooks are collapsed for the next session
+    fn save_ui_state(&mut self) {
+        let Some(ref storage) = self.storage_manager else {
+            return;
+        };
+        let ui_state = UiState {
+            collapsed_notebooks: self.collapsed_notebooks.clone(),
+        };
+        if let Err(e) = storage.save_ui_state(&ui_state) {
+            self.set_error_message(format!("Failed to save tree layout: {}", e));
+        }
+    }
+
     pub fn is_notebook_collapsed(&self, notebook_id: &Uuid) -> bool {
         self.collapsed_notebooks.contains(notebook_id)
     }
@@ -819,48 +2697,21 @@ impl App {
                 }
 
                 // Get parent notebook
-                if let Some(parent_id) = notebook.parent_id {
-                    if let Some(parent) = self.snippet_database.notebooks.get(&parent_id).cloned() {
-                        let grandparent_id = parent.parent_id;
-
-                        // Update notebook parent to grandparent (move up one level)
-                        if let Some(notebook_to_update) =
-                            self.snippet_database.notebooks.get_mut(&notebook_id)
-                        {
-                            notebook_to_update.parent_id = grandparent_id;
-                            notebook_to_update.updated_at = chrono::Utc::now();
-                        }
-
-                        // Remove this notebook from parent's children
-                        if let Some(parent_to_update) =
-                            self.snippet_database.notebooks.get_mut(&parent_id)
-                        {
-                            parent_to_update.children.retain(|id| *id != notebook_id);
-                            parent_to_update.updated_at = chrono::Utc::now();
-                        }
-
-                        // Add this notebook to grandparent's children if it exists
-                        if let Some(grandparent_id) = grandparent_id {
-                            if let Some(grandparent) =
-                                self.snippet_database.notebooks.get_mut(&grandparent_id)
-                            {
-                                grandparent.children.push(notebook_id);
-                                grandparent.updated_at = chrono::Utc::now();
-                            }
-                        } else {
-                            // No grandparent, add to root notebooks
-                            if !self.snippet_database.root_notebooks.contains(&notebook_id) {
-                                self.snippet_database.root_notebooks.push(notebook_id);
-                            }
-                        }
-
-                        // Save to make persistent
-                        let _ = self.save_database();
-                        self.refresh_tree_items();
-                        self.needs_redraw = true;
-                        self.set_success_message("Notebook moved up one level".to_string());
-                        return true;
+                if let Some(parent_id) = notebook.parent_id
+                    && let Some(parent) = self.snippet_database.notebooks.get(&parent_id).cloned()
+                {
+                    // Move up one level, to the grandparent or the root
+                    if let Err(e) = self.reparent_notebook(notebook_id, parent.parent_id) {
+                        self.set_error_message(e);
+                        return false;
                     }
+
+                    // Save to make persistent
+                    let _ = self.save_database();
+                    self.refresh_tree_items();
+                    self.needs_redraw = true;
+                    self.set_success_message("Notebook moved up one level".to_string());
+                    return true;
                 }
             }
         } else if let Some(TreeItem::Snippet(snippet_id, _)) = self.get_selected_item().cloned() {
@@ -905,64 +2756,18 @@ impl App {
             if let Some(hovered_item) = self.get_hovered_item().cloned() {
                 match hovered_item {
                     TreeItem::Notebook(target_id, _) => {
-                        // Can't move to itself
-                        if target_id == notebook_id {
-                            self.set_error_message("Cannot move notebook into itself".to_string());
-                            return false;
-                        }
-
-                        // Check if target is a descendant of the notebook - can't move to own child
-                        if self.is_descendant_of(&target_id, &notebook_id) {
-                            self.set_error_message(
-                                "Cannot move notebook into its own descendant".to_string(),
-                            );
+                        // Rejects moving into itself or one of its own descendants
+                        if let Err(e) = self.reparent_notebook(notebook_id, Some(target_id)) {
+                            self.set_error_message(e);
                             return false;
                         }
 
-                        // Get current notebook
-                        if let Some(notebook) =
-                            self.snippet_database.notebooks.get(&notebook_id).cloned()
-                        {
-                            // Remove from current parent's children list
-                            if let Some(parent_id) = notebook.parent_id {
-                                if let Some(parent) =
-                                    self.snippet_database.notebooks.get_mut(&parent_id)
-                                {
-                                    parent.children.retain(|id| *id != notebook_id);
-                                    parent.updated_at = chrono::Utc::now();
-                                }
-                            } else {
-                                // If it's a root notebook, remove from root list
-                                self.snippet_database
-                                    .root_notebooks
-                                    .retain(|id| *id != notebook_id);
-                            }
-
-                            // Update notebook's parent
-                            if let Some(notebook_to_update) =
-                                self.snippet_database.notebooks.get_mut(&notebook_id)
-                            {
-                                notebook_to_update.parent_id = Some(target_id);
-                                notebook_to_update.updated_at = chrono::Utc::now();
-                            }
-
-                            // Add to new parent's children list
-                            if let Some(new_parent) =
-                                self.snippet_database.notebooks.get_mut(&target_id)
-                            {
-                                if !new_parent.children.contains(&notebook_id) {
-                                    new_parent.children.push(notebook_id);
-                                    new_parent.updated_at = chrono::Utc::now();
-                                }
-                            }
-
-                            // Save to make persistent
-                            let _ = self.save_database();
-                            self.refresh_tree_items();
-                            self.needs_redraw = true;
-                            self.set_success_message("Notebook moved down one level".to_string());
-                            return true;
-                        }
+                        // Save to make persistent
+                        let _ = self.save_database();
+                        self.refresh_tree_items();
+                        self.needs_redraw = true;
+                        self.set_success_message("Notebook moved down one level".to_string());
+                        return true;
                     }
                     _ => {
                         self.set_error_message("Hover over a notebook to move into it".to_string());
@@ -1124,17 +2929,82 @@ impl App {
 
     // Helper to check if a notebook is a descendant of another
     fn is_descendant_of(&self, potential_descendant: &Uuid, ancestor: &Uuid) -> bool {
-        if let Some(notebook) = self.snippet_database.notebooks.get(potential_descendant) {
-            if let Some(parent_id) = notebook.parent_id {
-                if parent_id == *ancestor {
-                    return true;
-                }
-                return self.is_descendant_of(&parent_id, ancestor);
+        let mut current = self
+            .snippet_database
+            .notebooks
+            .get(potential_descendant)
+            .and_then(|notebook| notebook.parent_id);
+
+        // Bounded so a tree that is already broken cannot loop forever
+        for _ in 0..self.snippet_database.notebooks.len() {
+            let Some(parent_id) = current else {
+                return false;
+            };
+            if parent_id == *ancestor {
+                return true;
             }
+            current = self
+                .snippet_database
+                .notebooks
+                .get(&parent_id)
+                .and_then(|notebook| notebook.parent_id);
         }
         false
     }
 
+    /// Moves a notebook under `new_parent_id`, or to the root for `None`.
+    /// Every reparenting goes through here so the tree can never gain a cycle.
+    fn reparent_notebook(
+        &mut self,
+        notebook_id: Uuid,
+        new_parent_id: Option<Uuid>,
+    ) -> Result<(), String> {
+        let Some(old_parent_id) = self
+            .snippet_database
+            .notebooks
+            .get(&notebook_id)
+            .map(|notebook| notebook.parent_id)
+        else {
+            return Err("Notebook not found".to_string());
+        };
+
+        if let Some(target_id) = new_parent_id {
+            if target_id == notebook_id {
+                return Err("Cannot move notebook into itself".to_string());
+            }
+            if self.is_descendant_of(&target_id, &notebook_id) {
+                return Err("Cannot move notebook into its own descendant".to_string());
+            }
+            if !self.snippet_database.notebooks.contains_key(&target_id) {
+                return Err("Target notebook not found".to_string());
+            }
+        }
+
+        // Detach from the old parent or the root list
+        match old_parent_id.and_then(|id| self.snippet_database.notebooks.get_mut(&id)) {
+            Some(parent) => parent.remove_child(&notebook_id),
+            None => self
+                .snippet_database
+                .root_notebooks
+                .retain(|id| *id != notebook_id),
+        }
+
+        if let Some(notebook) = self.snippet_database.notebooks.get_mut(&notebook_id) {
+            notebook.parent_id = new_parent_id;
+            notebook.updated_at = chrono::Utc::now();
+        }
+
+        match new_parent_id.and_then(|id| self.snippet_database.notebooks.get_mut(&id)) {
+            Some(parent) => parent.add_child(notebook_id),
+            None => {
+                if !self.snippet_database.root_notebooks.contains(&notebook_id) {
+                    self.snippet_database.root_notebooks.push(notebook_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
     // Move an item to the next sibling notebook (right)
     pub fn move_item_to_next_sibling(&mut self) -> bool {
         if let Some(TreeItem::Snippet(snippet_id, _)) = self.get_selected_item().cloned() {
@@ -1546,6 +3416,41 @@ impl App {
         false
     }
 
+    /// Asks to trash the selected tree item; notebooks must be empty first
+    pub fn request_delete_selected(&mut self) {
+        self.clear_messages();
+        match self.get_selected_item().cloned() {
+            Some(TreeItem::Notebook(notebook_id, _)) => {
+                // Check if this notebook has snippets or nested notebooks
+                let has_snippets = self
+                    .snippet_database
+                    .snippets
+                    .values()
+                    .any(|s| s.notebook_id == notebook_id);
+
+                let has_children = self
+                    .snippet_database
+                    .notebooks
+                    .values()
+                    .any(|n| n.parent_id == Some(notebook_id));
+
+                if has_snippets || has_children {
+                    self.set_error_message(
+                        "Cannot delete a notebook that contains snippets or other notebooks"
+                            .to_string(),
+                    );
+                    return;
+                }
+
+                self.request_delete_confirmation(notebook_id, true);
+            }
+            Some(TreeItem::Snippet(snippet_id, _)) => {
+                self.request_delete_confirmation(snippet_id, false);
+            }
+            None => self.set_error_message("No item selected".to_string()),
+        }
+    }
+
     // Add these methods to handle confirmation states
     /// Request confirmation for deleting an item
     pub fn request_delete_confirmation(&mut self, item_id: Uuid, is_notebook: bool) {
@@ -1558,17 +3463,11 @@ impl App {
         // Set message based on item type
         if is_notebook {
             if let Some(notebook) = self.snippet_database.notebooks.get(&item_id) {
-                self.set_success_message(format!(
-                    "Are you sure you want to delete notebook '{}'?",
-                    notebook.name
-                ));
+                self.success_message = Some(format!("Move notebook '{}' to trash?", notebook.name));
             }
         } else {
             if let Some(snippet) = self.snippet_database.snippets.get(&item_id) {
-                self.set_success_message(format!(
-                    "Are you sure you want to delete snippet '{}'?",
-                    snippet.title
-                ));
+                self.success_message = Some(format!("Move snippet '{}' to trash?", snippet.title));
             }
         }
     }
@@ -1590,14 +3489,14 @@ impl App {
                     if let Err(e) = self.delete_notebook(item_id) {
                         self.set_error_message(e);
                     } else {
-                        self.set_success_message("Notebook deleted successfully".to_string());
+                        self.set_success_message("Notebook moved to trash".to_string());
                         self.code_snippets_state = CodeSnippetsState::NotebookList;
                     }
                 } else {
                     if let Err(e) = self.delete_snippet(item_id) {
                         self.set_error_message(e);
                     } else {
-                        self.set_success_message("Snippet deleted successfully".to_string());
+                        self.set_success_message("Snippet moved to trash".to_string());
                     }
                 }
 
@@ -1674,6 +3573,147 @@ impl App {
         Ok(())
     }
 
+    pub fn toggle_favorite_notebook(&mut self, notebook_id: Uuid) -> Result<(), String> {
+        let is_favorite = match self.snippet_database.notebooks.get_mut(&notebook_id) {
+            Some(notebook) => {
+                notebook.toggle_favorite();
+                notebook.is_favorite
+            }
+            None => return Err("Notebook not found".to_string()),
+        };
+
+        self.save_database()?;
+        if self.show_favorites_only {
+            self.refresh_tree_items();
+        }
+
+        self.set_success_message(format!(
+            "Notebook {} as favorite",
+            if is_favorite { "marked" } else { "unmarked" }
+        ));
+
+        Ok(())
+    }
+
+    /// Pins or unpins a snippet, keeping the cursor on the same tree entry
+    pub fn toggle_pinned_snippet(&mut self, snippet_id: Uuid) -> Result<(), String> {
+        let is_pinned = match self.snippet_database.snippets.get_mut(&snippet_id) {
+            Some(snippet) => {
+                snippet.toggle_pinned();
+                snippet.is_pinned
+            }
+            None => return Err("Snippet not found".to_string()),
+        };
+
+        self.save_database()?;
+
+        let selected = self.get_selected_item().cloned();
+        self.refresh_tree_items();
+        let index = selected
+            .and_then(|item| self.tree_items.iter().position(|i| *i == item))
+            .or_else(|| {
+                self.tree_items
+                    .iter()
+                    .position(|i| matches!(i, TreeItem::Snippet(id, _) if *id == snippet_id))
+            });
+        if let Some(index) = index {
+            self.selected_tree_item = index;
+        }
+        self.hovered_tree_item = None;
+        self.needs_redraw = true;
+
+        self.set_success_message(format!(
+            "Snippet {}",
+            if is_pinned { "pinned" } else { "unpinned" }
+        ));
+        Ok(())
+    }
+
+    /// Loads the archived versions of a snippet and switches to the history view
+    pub fn open_version_history(&mut self, snippet_id: Uuid) -> Result<(), String> {
+        let snippet = self
+            .snippet_database
+            .snippets
+            .get(&snippet_id)
+            .ok_or_else(|| "Snippet not found".to_string())?;
+        let storage = self
+            .storage_manager
+            .as_ref()
+            .ok_or_else(|| "Storage not available".to_string())?;
+
+        let versions = snippet.list_versions(storage);
+        if versions.is_empty() {
+            return Err(format!("No previous versions of '{}'", snippet.title));
+        }
+
+        self.snippet_versions = versions;
+        self.selected_version = 0;
+        self.content_scroll_position = 0;
+        self.code_snippets_state = CodeSnippetsState::VersionHistory { snippet_id };
+        Ok(())
+    }
+
+    /// Opens a diff of the two marked snippets, the older one as the base
+    pub fn open_snippet_diff(&mut self) -> Result<(), String> {
+        let mut marked: Vec<&CodeSnippet> = self
+            .marked_snippets
+            .iter()
+            .filter_map(|id| self.snippet_database.snippets.get(id))
+            .collect();
+        if marked.len() != 2 {
+            return Err("Mark exactly two snippets to compare".to_string());
+        }
+
+        marked.sort_by_key(|snippet| snippet.created_at);
+        let (left, right) = (marked[0].id, marked[1].id);
+        self.content_scroll_position = 0;
+        self.code_snippets_state = CodeSnippetsState::CompareSnippets { left, right };
+        Ok(())
+    }
+
+    /// Replaces the content of a snippet with an archived version.
+    /// The current content is archived first so a restore can be undone.
+    pub fn restore_snippet_version(
+        &mut self,
+        snippet_id: Uuid,
+        version_index: usize,
+    ) -> Result<(), String> {
+        let version = self
+            .snippet_versions
+            .get(version_index)
+            .cloned()
+            .ok_or_else(|| "No version selected".to_string())?;
+        let storage = self
+            .storage_manager
+            .as_ref()
+            .ok_or_else(|| "Storage not available".to_string())?;
+        let snippet = self
+            .snippet_database
+            .snippets
+            .get_mut(&snippet_id)
+            .ok_or_else(|| "Snippet not found".to_string())?;
+
+        let content = storage
+            .load_snippet_version(&version)
+            .map_err(|e| format!("Failed to load version: {}", e))?;
+
+        storage
+            .archive_snippet_version(snippet)
+            .map_err(|e| format!("Failed to archive current version: {}", e))?;
+        snippet.update_content(content);
+        storage
+            .save_snippet_content(snippet)
+            .map_err(|e| format!("Failed to save snippet: {}", e))?;
+
+        self.snippet_versions = snippet.list_versions(storage);
+        self.selected_version = 0;
+        self.content_scroll_position = 0;
+
+        self.save_database()?;
+        self.set_success_message(format!("Restored version {}", version.version));
+        Ok(())
+    }
+
     pub fn set_pending_action<F>(&mut self, message: String, action: Box<F>)
     where
         F: FnOnce(&mut App) + 'static,
@@ -1685,3 +3725,183 @@ impl App {
         self.confirmation_state = ConfirmationState::Custom { action };
     }
 }
+
+/// Splits tag editor input into the finished part and the word still being typed
+fn split_tag_input(input: &str) -> (&str, &str) {
+    let start = input
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace() || *c == ',')
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(0);
+    input.split_at(start)
+}
+
+/// Labels for `count` rows, all the same length so none is a prefix of another
+fn jump_labels(count: usize) -> Vec<String> {
+    let chars: Vec<char> = JUMP_LABEL_CHARS.chars().collect();
+    if count <= chars.len() {
+        return chars.iter().take(count).map(|c| c.to_string()).collect();
+    }
+
+    chars
+        .iter()
+        .flat_map(|first| {
+            chars
+                .iter()
+                .map(move |second| format!("{}{}", first, second))
+        })
+        .take(count)
+        .collect()
+}
+
+/// Export format implied by a file extension, defaulting to JSON
+fn export_format_for_path(path: &std::path::Path) -> ExportFormat {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("yaml") | Some("yml") => ExportFormat::YAML,
+        Some("toml") => ExportFormat::TOML,
+        Some("csv") => ExportFormat::CSV,
+        _ => ExportFormat::JSON,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::toast::NotificationType;
+
+    /// App holding only parent -> child -> grandchild, without any storage on disk
+    fn nested_app() -> (App, [Uuid; 3]) {
+        let mut app = App::with_storage(None, None);
+
+        let mut parent = Notebook::new("Parent".to_string());
+        let mut child = Notebook::new_with_parent("Child".to_string(), parent.id);
+        let grandchild = Notebook::new_with_parent("Grandchild".to_string(), child.id);
+        parent.add_child(child.id);
+        child.add_child(grandchild.id);
+        let ids = [parent.id, child.id, grandchild.id];
+
+        let database = &mut app.snippet_database;
+        database.root_notebooks.push(parent.id);
+        for notebook in [parent, child, grandchild] {
+            database.notebooks.insert(notebook.id, notebook);
+        }
+        app.refresh_tree_items();
+        (app, ids)
+    }
+
+    fn tree_index(app: &App, notebook_id: Uuid) -> usize {
+        app.tree_items
+            .iter()
+            .position(|item| matches!(item, TreeItem::Notebook(id, _) if *id == notebook_id))
+            .unwrap()
+    }
+
+    fn last_error(app: &App) -> Option<String> {
+        app.toasts
+            .iter()
+            .filter(|toast| toast.notification_type == NotificationType::Error)
+            .last()
+            .map(|toast| toast.message.clone())
+    }
+
+    fn assert_tree_unchanged(app: &App, [parent, child, grandchild]: [Uuid; 3]) {
+        let notebooks = &app.snippet_database.notebooks;
+        assert_eq!(notebooks[&parent].parent_id, None);
+        assert_eq!(notebooks[&child].parent_id, Some(parent));
+        assert_eq!(notebooks[&grandchild].parent_id, Some(child));
+        assert_eq!(notebooks[&parent].children, vec![child]);
+        assert_eq!(notebooks[&child].children, vec![grandchild]);
+        assert!(notebooks[&grandchild].children.is_empty());
+        assert_eq!(app.snippet_database.root_notebooks, vec![parent]);
+    }
+
+    #[test]
+    fn reparent_rejects_itself_and_descendants() {
+        let (mut app, ids @ [parent, child, grandchild]) = nested_app();
+
+        assert_eq!(
+            app.reparent_notebook(child, Some(child)),
+            Err("Cannot move notebook into itself".to_string())
+        );
+        assert_eq!(
+            app.reparent_notebook(parent, Some(grandchild)),
+            Err("Cannot move notebook into its own descendant".to_string())
+        );
+        assert_eq!(
+            app.reparent_notebook(parent, Some(child)),
+            Err("Cannot move notebook into its own descendant".to_string())
+        );
+        assert_tree_unchanged(&app, ids);
+    }
+
+    #[test]
+    fn move_notebook_down_rejects_descendant_target() {
+        let (mut app, ids @ [parent, _, grandchild]) = nested_app();
+        app.selected_tree_item = tree_index(&app, parent);
+        app.hovered_tree_item = Some(tree_index(&app, grandchild));
+
+        assert!(!app.move_notebook_down());
+        assert_eq!(
+            last_error(&app).as_deref(),
+            Some("Cannot move notebook into its own descendant")
+        );
+        assert_tree_unchanged(&app, ids);
+    }
+
+    #[test]
+    fn move_notebook_down_rejects_itself() {
+        let (mut app, ids @ [_, child, _]) = nested_app();
+        app.selected_tree_item = tree_index(&app, child);
+        app.hovered_tree_item = Some(tree_index(&app, child));
+
+        assert!(!app.move_notebook_down());
+        assert_eq!(
+            last_error(&app).as_deref(),
+            Some("Cannot move notebook into itself")
+        );
+        assert_tree_unchanged(&app, ids);
+    }
+
+    #[test]
+    fn sibling_moves_keep_an_only_child_in_place() {
+        let (mut app, ids @ [_, child, grandchild]) = nested_app();
+
+        for notebook_id in [child, grandchild] {
+            app.selected_tree_item = tree_index(&app, notebook_id);
+            assert!(!app.move_item_to_next_sibling());
+            assert_eq!(
+                last_error(&app).as_deref(),
+                Some("No other siblings available")
+            );
+            assert!(!app.move_item_to_prev_sibling());
+            assert_eq!(
+                last_error(&app).as_deref(),
+                Some("No other siblings available")
+            );
+        }
+        assert_tree_unchanged(&app, ids);
+    }
+
+    #[test]
+    fn fresh_snippet_is_not_reported_missing() {
+        let dir = std::env::temp_dir().join(format!("snix-test-{}", Uuid::new_v4()));
+        let storage = StorageManager::with_data_dir(dir.clone()).unwrap();
+        let mut app = App::with_storage(Some(storage), None);
+
+        let notebook_id = app.create_notebook("Notes".to_string()).unwrap();
+        let snippet_id = app
+            .create_snippet("Fresh".to_string(), SnippetLanguage::Rust, notebook_id)
+            .unwrap();
+        let report = app
+            .storage_manager
+            .as_ref()
+            .unwrap()
+            .verify_integrity(&app.snippet_database)
+            .unwrap();
+
+        drop(app);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(!report.missing_files.contains(&snippet_id));
+    }
+}
@@ -1,95 +1,377 @@
-use crate::models::StorageManager;
+use crate::cli::json::{FavoritesJson, NotebookJson, SnippetJson, notebook_path, print_json};
+use crate::cli::tree;
+use crate::handlers::keys::{ClipboardHandler, run_editor};
+use crate::models::stats::format_size;
+use crate::models::storage::SnippetDatabase;
+use crate::models::{
+    CodeSnippet, ExportFormat, ExportOptions, LibraryStats, StorageManager,
+    export_database_with_tags, import_database, merge_import_into_database_with_tags,
+};
+use crate::ui::code_snippets::syntect_syntax_name;
+use chrono::Utc;
 use colored::Colorize;
+use std::collections::HashSet;
 use std::error::Error;
+use std::io::IsTerminal;
+use std::path::Path;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::{LinesWithEndings, as_24_bit_terminal_escaped};
 use uuid::Uuid;
 
-/// Shows the content of a specific snippet by ID or name
-pub fn show_snippet(name_or_id: &str) -> Result<(), Box<dyn Error>> {
+/// Shows the content of a specific snippet by ID or name.
+/// Prints only the content when `raw` is set or stdout is not a terminal.
+pub fn show_snippet(name_or_id: &str, raw: bool) -> Result<(), Box<dyn Error>> {
     let storage = StorageManager::new()?;
     let database = storage.load_database()?;
 
-    // First try parsing as UUID
-    let snippet_id = match Uuid::parse_str(name_or_id) {
-        Ok(id) => Some(id),
-        Err(_) => {
-            // If not a valid UUID, try to find by name
-            let name = name_or_id.to_lowercase();
-
-            // Try exact match first
-            let exact_match = database
-                .snippets
-                .values()
-                .find(|s| s.title.to_lowercase() == name);
-
-            if let Some(snippet) = exact_match {
-                Some(snippet.id)
+    match find_snippets(&database, name_or_id).first() {
+        Some(snippet) => {
+            if raw || !std::io::stdout().is_terminal() {
+                print!("{}", snippet.content);
+                if !snippet.content.ends_with('\n') {
+                    println!();
+                }
             } else {
-                // Then try partial match
-                let partial_match = database
-                    .snippets
-                    .values()
-                    .find(|s| s.title.to_lowercase().contains(&name));
-
-                partial_match.map(|s| s.id)
+                display_snippet_content(snippet, &database);
             }
         }
-    };
+        None => print_snippet_not_found(&database, name_or_id),
+    }
 
-    match snippet_id {
-        Some(id) => {
-            if let Some(snippet) = database.snippets.get(&id) {
-                display_snippet_content(snippet, &database);
-            } else {
-                println!(
-                    "{}  Snippet not found with ID: {}",
-                    "┃".bright_magenta(),
-                    id
+    Ok(())
+}
+
+/// Copies a snippet's content to the system clipboard.
+/// Lists the candidates instead when a partial name matches more than one snippet.
+pub fn copy_snippet(name_or_id: &str) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let database = storage.load_database()?;
+
+    match find_snippets(&database, name_or_id).as_slice() {
+        [] => print_snippet_not_found(&database, name_or_id),
+        [snippet] => {
+            if !ClipboardHandler::copy_to_clipboard(&snippet.content) {
+                return Err(
+                    "No clipboard utility found (tried xclip, wl-copy, pbcopy and clip)".into(),
                 );
             }
+            println!(
+                "{}  Copied {} to the clipboard",
+                "┃".bright_magenta(),
+                snippet.title.bright_white()
+            );
+        }
+        candidates => print_snippet_candidates(candidates, name_or_id),
+    }
+
+    Ok(())
+}
+
+/// Opens a snippet in the external editor and saves the edited content.
+/// Lists the candidates instead when a partial name matches more than one snippet.
+pub fn edit_snippet(name_or_id: &str) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let mut database = storage.load_database()?;
+
+    let snippet_id = match find_snippets(&database, name_or_id).as_slice() {
+        [] => {
+            print_snippet_not_found(&database, name_or_id);
+            return Ok(());
         }
-        None => {
+        [snippet] => snippet.id,
+        candidates => {
+            print_snippet_candidates(candidates, name_or_id);
+            return Ok(());
+        }
+    };
+
+    let Some(snippet) = database.snippets.get_mut(&snippet_id) else {
+        return Ok(());
+    };
+
+    storage.save_snippet_content(snippet)?;
+    run_editor(
+        &storage.get_snippet_file_path(snippet),
+        snippet.editor_override.as_deref(),
+    )?;
+
+    let content =
+        storage.load_snippet_content(snippet.id, snippet.notebook_id, &snippet.file_extension)?;
+    let changed = snippet.content != content;
+    if changed {
+        // Keep the previous content around so it can be restored later
+        if let Err(e) = storage.archive_snippet_version(snippet) {
             println!(
-                "{}  No snippet found with name: {}",
+                "{}  Previous version was not archived: {}",
                 "┃".bright_magenta(),
-                name_or_id
+                e
             );
-            println!("{}  Available snippets:", "┃".bright_magenta());
-            println!("{}", "─".repeat(60).bright_magenta());
-
-            // List available snippets to help the user
-            for (idx, snippet) in database.snippets.values().enumerate().take(10) {
-                println!(
-                    "{}  {}. {}",
-                    "┃".bright_magenta(),
-                    (idx + 1).to_string().yellow(),
-                    snippet.title.bright_white()
-                );
-            }
+        }
+        snippet.update_content(content);
+        storage.save_snippet_content(snippet)?;
+    }
+    snippet.mark_accessed();
 
-            if database.snippets.len() > 10 {
-                println!(
-                    "{}  ... and {} more",
-                    "┃".bright_magenta(),
-                    database.snippets.len() - 10
-                );
-            }
+    let title = snippet.title.clone();
+    storage.save_database(&database)?;
+
+    if changed {
+        println!("{}  Saved {}", "┃".bright_magenta(), title.bright_white());
+    } else {
+        println!(
+            "{}  No changes to {}",
+            "┃".bright_magenta(),
+            title.bright_white()
+        );
+    }
+
+    Ok(())
+}
+
+/// Moves a snippet and its file into another notebook, both resolved by name or ID.
+/// Lists the notebooks instead when the target cannot be found.
+pub fn move_snippet(name_or_id: &str, notebook_name_or_id: &str) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let mut database = storage.load_database()?;
+
+    let snippet_id = match find_snippets(&database, name_or_id).as_slice() {
+        [] => {
+            print_snippet_not_found(&database, name_or_id);
+            return Ok(());
+        }
+        [snippet] => snippet.id,
+        candidates => {
+            print_snippet_candidates(candidates, name_or_id);
+            return Ok(());
+        }
+    };
+
+    let target_id = Uuid::parse_str(notebook_name_or_id)
+        .ok()
+        .filter(|id| database.notebooks.contains_key(id))
+        .or_else(|| tree::find_notebook_by_name(&database, notebook_name_or_id));
+    let Some(target_id) = target_id else {
+        println!(
+            "{}  No notebook found with name: {}",
+            "┃".bright_magenta(),
+            notebook_name_or_id
+        );
+        tree::list_all_notebooks(&database)?;
+        return Ok(());
+    };
+    let target_path = notebook_path(&database, target_id);
+
+    let Some(snippet) = database.snippets.get_mut(&snippet_id) else {
+        return Ok(());
+    };
+    let old_notebook_id = snippet.notebook_id;
+    if old_notebook_id == target_id {
+        println!(
+            "{}  {} is already in {}",
+            "┃".bright_magenta(),
+            snippet.title.bright_white(),
+            target_path.bright_white()
+        );
+        return Ok(());
+    }
+
+    storage.move_snippet_file(snippet, target_id)?;
+    snippet.notebook_id = target_id;
+    snippet.updated_at = Utc::now();
+    let title = snippet.title.clone();
+
+    if let Some(notebook) = database.notebooks.get_mut(&old_notebook_id) {
+        notebook.snippet_count = notebook.snippet_count.saturating_sub(1);
+    }
+    if let Some(notebook) = database.notebooks.get_mut(&target_id) {
+        notebook.snippet_count += 1;
+    }
+    storage.save_database(&database)?;
+
+    println!(
+        "{}  Moved {} to {}",
+        "┃".bright_magenta(),
+        title.bright_white(),
+        target_path.bright_white()
+    );
+    Ok(())
+}
+
+/// Checks that every snippet has its file and every file in the snippets folder has a snippet,
+/// optionally rewriting the missing files and importing the orphan ones
+pub fn run_doctor(recreate_missing: bool, import_orphans: bool) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let mut database = storage.load_database()?;
+    let report = storage.verify_integrity(&database)?;
+
+    if report.is_clean() {
+        println!(
+            "{}  Checked {} snippets, every file is where the database expects it",
+            "┃".bright_magenta(),
+            database.snippets.len()
+        );
+        return Ok(());
+    }
+
+    if !report.missing_files.is_empty() {
+        println!(
+            "{}  {} snippets have no file:",
+            "┃".bright_magenta(),
+            report.missing_files.len().to_string().yellow()
+        );
+        for snippet in report
+            .missing_files
+            .iter()
+            .filter_map(|id| database.snippets.get(id))
+        {
+            println!(
+                "{}    {} {}",
+                "┃".bright_magenta(),
+                snippet.title.bright_white(),
+                format!("({})", snippet.id).dimmed()
+            );
+        }
+    }
+
+    if !report.orphan_files.is_empty() {
+        println!(
+            "{}  {} files belong to no snippet:",
+            "┃".bright_magenta(),
+            report.orphan_files.len().to_string().yellow()
+        );
+        for path in &report.orphan_files {
+            println!("{}    {}", "┃".bright_magenta(), path.display());
         }
     }
 
+    if recreate_missing && !report.missing_files.is_empty() {
+        let count = storage.recreate_missing_files(&database, &report.missing_files)?;
+        println!(
+            "{}  Recreated {} files from the database",
+            "┃".bright_magenta(),
+            count
+        );
+    } else if !report.missing_files.is_empty() {
+        println!(
+            "{}  Run {} to write them again from the database",
+            "┃".bright_magenta(),
+            "snix doctor --recreate-missing".bright_white()
+        );
+    }
+
+    if import_orphans && !report.orphan_files.is_empty() {
+        let count = storage.import_orphan_files(&mut database, &report.orphan_files)?;
+        storage.save_database(&database)?;
+        println!(
+            "{}  Imported {} files as snippets",
+            "┃".bright_magenta(),
+            count
+        );
+    } else if !report.orphan_files.is_empty() {
+        println!(
+            "{}  Run {} to add them as snippets",
+            "┃".bright_magenta(),
+            "snix doctor --import-orphans".bright_white()
+        );
+    }
+
     Ok(())
 }
 
+/// Resolves a snippet by ID, then exact title, then partial title.
+/// An ID or exact match yields one snippet; partial matches are all returned sorted by title.
+fn find_snippets<'a>(database: &'a SnippetDatabase, name_or_id: &str) -> Vec<&'a CodeSnippet> {
+    if let Ok(id) = Uuid::parse_str(name_or_id) {
+        return database.snippets.get(&id).into_iter().collect();
+    }
+
+    let name = name_or_id.to_lowercase();
+    if let Some(snippet) = database
+        .snippets
+        .values()
+        .find(|s| s.title.to_lowercase() == name)
+    {
+        return vec![snippet];
+    }
+
+    let mut matches: Vec<&CodeSnippet> = database
+        .snippets
+        .values()
+        .filter(|s| s.title.to_lowercase().contains(&name))
+        .collect();
+    matches.sort_by_key(|s| s.title.to_lowercase());
+    matches
+}
+
+fn print_snippet_candidates(candidates: &[&CodeSnippet], name_or_id: &str) {
+    println!(
+        "{}  {} snippets match {}, be more specific:",
+        "┃".bright_magenta(),
+        candidates.len(),
+        name_or_id.bright_white()
+    );
+    println!("{}", "─".repeat(60).bright_magenta());
+    for (idx, snippet) in candidates.iter().enumerate() {
+        println!(
+            "{}  {}. {} {}",
+            "┃".bright_magenta(),
+            (idx + 1).to_string().yellow(),
+            snippet.title.bright_white(),
+            format!("({})", snippet.id).dimmed()
+        );
+    }
+}
+
+fn print_snippet_not_found(database: &SnippetDatabase, name_or_id: &str) {
+    println!(
+        "{}  No snippet found with name: {}",
+        "┃".bright_magenta(),
+        name_or_id
+    );
+    println!("{}  Available snippets:", "┃".bright_magenta());
+    println!("{}", "─".repeat(60).bright_magenta());
+
+    // List available snippets to help the user
+    for (idx, snippet) in database.snippets.values().enumerate().take(10) {
+        println!(
+            "{}  {}. {}",
+            "┃".bright_magenta(),
+            (idx + 1).to_string().yellow(),
+            snippet.title.bright_white()
+        );
+    }
+
+    if database.snippets.len() > 10 {
+        println!(
+            "{}  ... and {} more",
+            "┃".bright_magenta(),
+            database.snippets.len() - 10
+        );
+    }
+}
+
 /// Helper function to display snippet content
 fn display_snippet_content(
     snippet: &crate::models::CodeSnippet,
     database: &crate::models::storage::SnippetDatabase,
 ) {
-    // Find the notebook name
-    let notebook_name = database
-        .notebooks
-        .get(&snippet.notebook_id)
-        .map(|n| n.name.as_str())
-        .unwrap_or("Unknown");
+    // Find the notebook and its ancestors
+    let mut notebook_names = Vec::new();
+    let mut current = database.notebooks.get(&snippet.notebook_id);
+    while let Some(notebook) = current {
+        notebook_names.push(notebook.name.as_str());
+        current = notebook
+            .parent_id
+            .and_then(|parent_id| database.notebooks.get(&parent_id));
+    }
+    notebook_names.reverse();
+    let notebook_path = if notebook_names.is_empty() {
+        "Unknown".to_string()
+    } else {
+        notebook_names.join(" › ")
+    };
 
     // Find the full path
     let path = get_snippet_path(snippet, database);
@@ -106,7 +388,7 @@ fn display_snippet_content(
         "{}  {}: {}",
         "┃".bright_magenta(),
         "Notebook".bright_blue(),
-        notebook_name
+        notebook_path
     );
     println!(
         "{}  {}: {}",
@@ -136,13 +418,40 @@ fn display_snippet_content(
     );
     println!("{}", "─".repeat(60).bright_magenta());
 
-    // Content with basic formatting
-    // Split by lines and add the margin to each line
-    for line in snippet.content.lines() {
+    // Highlighting follows the same NO_COLOR / CLICOLOR rules as the rest of the output
+    let lines = if colored::control::SHOULD_COLORIZE.should_colorize() {
+        highlight_content(snippet)
+    } else {
+        snippet.content.lines().map(String::from).collect()
+    };
+    for line in lines {
         println!("{}  {}", "┃".bright_magenta(), line);
     }
 }
 
+/// Syntax-highlights snippet content with terminal escape codes, one entry per line
+fn highlight_content(snippet: &crate::models::CodeSnippet) -> Vec<String> {
+    let syntax_set = SyntaxSet::load_defaults_newlines();
+    let theme_set = ThemeSet::load_defaults();
+    let syntax = syntax_set
+        .find_syntax_by_name(syntect_syntax_name(&snippet.language))
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, &theme_set.themes["base16-mocha.dark"]);
+
+    LinesWithEndings::from(&snippet.content)
+        .map(|line| {
+            let ranges = highlighter
+                .highlight_line(line, &syntax_set)
+                .unwrap_or_default();
+            // Reset at the end so the colour does not leak into the next margin
+            format!(
+                "{}\x1b[0m",
+                as_24_bit_terminal_escaped(&ranges, false).trim_end_matches(['\n', '\r'])
+            )
+        })
+        .collect()
+}
+
 /// Get the full path of a snippet (notebook/subnotebook/snippet)
 fn get_snippet_path(
     snippet: &crate::models::CodeSnippet,
@@ -166,8 +475,9 @@ fn get_snippet_path(
     path_components.join("/")
 }
 
-/// Searches for snippets matching a query string
-pub fn search_snippets(query: &str) -> Result<(), Box<dyn Error>> {
+/// Searches for snippets matching a query string.
+/// Prints a JSON array instead of the decorated list when `json` is set.
+pub fn search_snippets(query: &str, json: bool) -> Result<(), Box<dyn Error>> {
     let storage = StorageManager::new()?;
     let database = storage.load_database()?;
 
@@ -198,6 +508,17 @@ pub fn search_snippets(query: &str) -> Result<(), Box<dyn Error>> {
         }
     }
 
+    if json {
+        let entries: Vec<SnippetJson> = results
+            .iter()
+            .map(|(_, match_type, snippet)| SnippetJson {
+                matched_in: Some(*match_type),
+                ..SnippetJson::new(snippet, &database)
+            })
+            .collect();
+        return print_json(&entries);
+    }
+
     println!(
         "{}  {} '{}'",
         "┃".bright_magenta(),
@@ -258,22 +579,77 @@ pub fn search_snippets(query: &str) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Lists all favorite snippets
-pub fn list_favorites() -> Result<(), Box<dyn Error>> {
+/// Lists all favorite notebooks and snippets.
+/// Prints a JSON object with `notebooks` and `snippets` arrays instead of the
+/// decorated list when `json` is set.
+pub fn list_favorites(json: bool) -> Result<(), Box<dyn Error>> {
     let storage = StorageManager::new()?;
     let database = storage.load_database()?;
 
+    let mut favorite_notebooks: Vec<NotebookJson> = database
+        .notebooks
+        .values()
+        .filter(|n| n.is_favorite)
+        .map(|notebook| NotebookJson::new(notebook, &database))
+        .collect();
+    favorite_notebooks.sort_by(|a, b| a.path.cmp(&b.path));
+
     let favorites: Vec<_> = database
         .snippets
         .values()
         .filter(|s| s.is_favorited())
         .collect();
 
-    if favorites.is_empty() {
-        println!("{}  No favorite snippets found.", "┃".bright_magenta());
+    if json {
+        let snippets: Vec<SnippetJson> = favorites
+            .iter()
+            .map(|snippet| SnippetJson::new(snippet, &database))
+            .collect();
+        return print_json(&FavoritesJson {
+            notebooks: favorite_notebooks,
+            snippets,
+        });
+    }
+
+    if favorite_notebooks.is_empty() && favorites.is_empty() {
+        println!("{}  No favorites found.", "┃".bright_magenta());
         return Ok(());
     }
 
+    if !favorite_notebooks.is_empty() {
+        println!(
+            "{}  {} favorite notebooks:",
+            "┃".bright_magenta(),
+            favorite_notebooks.len()
+        );
+
+        for (idx, notebook) in favorite_notebooks.iter().enumerate() {
+            println!(
+                "{}  {}. {}",
+                "┃".bright_magenta(),
+                (idx + 1).to_string().bright_yellow(),
+                notebook.path.bright_white().bold()
+            );
+            println!(
+                "{}     {}: {}",
+                "┃".bright_magenta(),
+                "Snippets".bright_green(),
+                notebook.snippet_count
+            );
+            println!(
+                "{}     {}: {}",
+                "┃".bright_magenta(),
+                "ID".bright_black(),
+                notebook.id
+            );
+        }
+
+        if favorites.is_empty() {
+            return Ok(());
+        }
+        println!("{}", "┃".bright_magenta());
+    }
+
     println!(
         "{}  {} favorite snippets:",
         "┃".bright_magenta(),
@@ -319,3 +695,134 @@ pub fn list_favorites() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+/// Prints an overview of the library: totals, languages, notebooks, tags and recency
+pub fn show_stats() -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let database = storage.load_database()?;
+    let tag_manager = storage.load_tag_manager()?;
+    let stats = LibraryStats::compute(&database, &tag_manager, storage.data_dir());
+
+    println!("{}  {}", "┃".bright_magenta(), "LIBRARY STATISTICS".bold());
+    print_stat_row("Snippets", stats.total_snippets.to_string());
+    print_stat_row("Notebooks", stats.total_notebooks.to_string());
+    print_stat_row("Favorites", stats.favorites.to_string());
+    print_stat_row("Lines of code", stats.total_lines.to_string());
+    print_stat_row("Storage", format_size(stats.storage_bytes));
+
+    print_stat_section("BY LANGUAGE", &stats.by_language, stats.total_snippets);
+    print_stat_section("BY NOTEBOOK", &stats.by_notebook, stats.total_snippets);
+    print_stat_section("TOP TAGS", &stats.top_tags, stats.total_snippets);
+
+    let recency: Vec<(String, usize)> = stats
+        .recency
+        .iter()
+        .map(|(label, count)| (label.to_string(), *count))
+        .collect();
+    print_stat_section("LAST ACCESSED", &recency, stats.total_snippets);
+
+    Ok(())
+}
+
+fn print_stat_row(label: &str, value: String) {
+    println!(
+        "{}  {:<20} {}",
+        "┃".bright_magenta(),
+        label.bright_blue(),
+        value.bright_white()
+    );
+}
+
+/// Prints one table of name/count rows with a share of the total
+fn print_stat_section(title: &str, rows: &[(String, usize)], total: usize) {
+    println!("{}", "┃".bright_magenta());
+    println!("{}  {}", "┃".bright_magenta(), title.bright_yellow());
+
+    if rows.is_empty() {
+        println!("{}  {}", "┃".bright_magenta(), "None".bright_black());
+        return;
+    }
+
+    for (name, count) in rows {
+        let share = if total > 0 {
+            *count as f64 * 100.0 / total as f64
+        } else {
+            0.0
+        };
+        println!(
+            "{}  {:<32} {:>5} {}",
+            "┃".bright_magenta(),
+            name,
+            count.to_string().bright_white(),
+            format!("{:>5.1}%", share).bright_black()
+        );
+    }
+}
+
+/// Exports the snippet database (including tags) to a file.
+/// A non-empty `tags` keeps only snippets carrying one of them.
+pub fn export_snippets(
+    path: &str,
+    format: ExportFormat,
+    favorites_only: bool,
+    tags: Vec<String>,
+    include_content: bool,
+) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let database = storage.load_database()?;
+    let tag_manager = storage.load_tag_manager()?;
+
+    let options = ExportOptions {
+        _format: format,
+        include_content,
+        notebook_ids: None,
+        snippet_ids: None,
+        include_favorites_only: favorites_only,
+        tags: (!tags.is_empty()).then_some(tags),
+        passphrase: None,
+    };
+
+    let (notebooks, snippets) =
+        export_database_with_tags(&database, &tag_manager, Path::new(path), &options)?;
+
+    println!(
+        "{}  {} {} notebooks and {} snippets to {}",
+        "┃".bright_magenta(),
+        "Successfully exported".bright_green(),
+        notebooks,
+        snippets,
+        path.bright_white()
+    );
+
+    Ok(())
+}
+
+/// Imports an export file and merges it into the snippet database
+pub fn import_snippets(path: &str, overwrite: bool) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let mut database = storage.load_database()?;
+    let mut tag_manager = storage.load_tag_manager()?;
+
+    let import_data = import_database(Path::new(path))?;
+    let known: HashSet<Uuid> = database.snippets.keys().copied().collect();
+    let (notebooks, snippets) = merge_import_into_database_with_tags(
+        &mut database,
+        &mut tag_manager,
+        import_data,
+        overwrite,
+    )?;
+
+    storage.save_database(&database)?;
+    storage.save_tag_manager(&tag_manager)?;
+    storage.write_new_snippet_files(&database, &known)?;
+
+    println!(
+        "{}  {} {} notebooks and {} snippets",
+        "┃".bright_magenta(),
+        "Successfully imported".bright_green(),
+        notebooks,
+        snippets
+    );
+
+    Ok(())
+}
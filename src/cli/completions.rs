@@ -0,0 +1,294 @@
+//! Shell completion scripts for the Snix CLI
+//! The CLI is hand-rolled, so the scripts are generated from the command
+//! table below. Notebook and snippet names are completed dynamically by
+//! calling back into `snix __complete`.
+
+use crate::models::StorageManager;
+use colored::Colorize;
+use std::error::Error;
+
+/// Top-level commands with a short description used by zsh and fish
+const COMMANDS: &[(&str, &str)] = &[
+    ("list", "List notebooks and snippets in tree format"),
+    ("ls", "Alias for list"),
+    ("notebooks", "List all notebooks with their IDs"),
+    ("show", "Display a snippet by name"),
+    ("view", "Alias for show"),
+    ("cat", "Alias for show"),
+    ("copy", "Copy a snippet to the clipboard"),
+    ("edit", "Open a snippet in the editor"),
+    ("move", "Move a snippet into another notebook"),
+    ("mv", "Alias for move"),
+    ("search", "Search for snippets matching a query"),
+    ("find", "Alias for search"),
+    ("favorites", "List all favorite snippets"),
+    ("fav", "Alias for favorites"),
+    ("stats", "Show library statistics"),
+    ("doctor", "Check snippet files against the database"),
+    ("export", "Export snippets to a file"),
+    ("import", "Import snippets from a file"),
+    ("completions", "Print a shell completion script"),
+    ("help", "Display the help message"),
+];
+
+const SHELLS: &[&str] = &["bash", "zsh", "fish"];
+
+/// Prints the completion script for the requested shell
+pub fn print_completions(shell: &str) -> Result<(), Box<dyn Error>> {
+    let script = match shell {
+        "bash" => bash_script(),
+        "zsh" => zsh_script(),
+        "fish" => fish_script(),
+        _ => {
+            println!("{}  Unsupported shell: {}", "┃".bright_magenta(), shell);
+            println!(
+                "{}  Supported shells: {}",
+                "┃".bright_magenta(),
+                SHELLS.join(", ")
+            );
+            return Ok(());
+        }
+    };
+
+    print!("{}", script);
+    Ok(())
+}
+
+/// Prints plain notebook, snippet or tag names, one per line, for completion scripts
+pub fn print_dynamic_candidates(kind: &str) -> Result<(), Box<dyn Error>> {
+    let storage = StorageManager::new()?;
+    let database = storage.load_database()?;
+
+    let mut names: Vec<&str> = match kind {
+        "notebooks" => database
+            .notebooks
+            .values()
+            .map(|n| n.name.as_str())
+            .collect(),
+        "snippets" => database
+            .snippets
+            .values()
+            .map(|s| s.title.as_str())
+            .collect(),
+        "tags" => database
+            .snippets
+            .values()
+            .flat_map(|s| s.tags.iter().map(String::as_str))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    names.sort_unstable();
+    names.dedup();
+
+    for name in names {
+        println!("{}", name);
+    }
+
+    Ok(())
+}
+
+fn command_names() -> String {
+    COMMANDS
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bash_script() -> String {
+    format!(
+        r#"# bash completion for snix
+_snix() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+
+    if [[ $COMP_CWORD -eq 1 ]]; then
+        COMPREPLY=($(compgen -W "{commands}" -- "$cur"))
+        return
+    fi
+
+    local IFS=$'\n'
+    case "${{COMP_WORDS[1]}}" in
+        list|ls)
+            if [[ "$cur" == -* ]]; then
+                COMPREPLY=($(compgen -W "--json --no-color --depth --counts --no-pager" -- "$cur"))
+            else
+                COMPREPLY=($(compgen -W "$(snix __complete notebooks 2>/dev/null)" -- "$cur"))
+            fi
+            ;;
+        notebooks|search|find|favorites|fav)
+            COMPREPLY=($(compgen -W "--json --no-color" -- "$cur"))
+            ;;
+        show|view|cat)
+            if [[ "$cur" == -* ]]; then
+                COMPREPLY=($(compgen -W "--raw" -- "$cur"))
+            else
+                COMPREPLY=($(compgen -W "$(snix __complete snippets 2>/dev/null)" -- "$cur"))
+            fi
+            ;;
+        copy|edit)
+            COMPREPLY=($(compgen -W "$(snix __complete snippets 2>/dev/null)" -- "$cur"))
+            ;;
+        move|mv)
+            if [[ $COMP_CWORD -eq 2 ]]; then
+                COMPREPLY=($(compgen -W "$(snix __complete snippets 2>/dev/null)" -- "$cur"))
+            elif [[ $COMP_CWORD -eq 3 ]]; then
+                COMPREPLY=($(compgen -W "$(snix __complete notebooks 2>/dev/null)" -- "$cur"))
+            fi
+            ;;
+        export)
+            if [[ "$prev" == "--format" ]]; then
+                COMPREPLY=($(compgen -W "json yaml toml csv dir" -- "$cur"))
+            elif [[ "$prev" == "--tag" || "$prev" == "-t" ]]; then
+                COMPREPLY=($(compgen -W "$(snix __complete tags 2>/dev/null)" -- "$cur"))
+            elif [[ "$cur" == -* ]]; then
+                COMPREPLY=($(compgen -W "--format --favorites-only --tag -t --no-content" -- "$cur"))
+            else
+                COMPREPLY=($(compgen -f -- "$cur"))
+            fi
+            ;;
+        doctor)
+            COMPREPLY=($(compgen -W "--fix --recreate-missing --import-orphans" -- "$cur"))
+            ;;
+        import)
+            if [[ "$cur" == -* ]]; then
+                COMPREPLY=($(compgen -W "--overwrite" -- "$cur"))
+            else
+                COMPREPLY=($(compgen -f -- "$cur"))
+            fi
+            ;;
+        completions)
+            COMPREPLY=($(compgen -W "{shells}" -- "$cur"))
+            ;;
+    esac
+}}
+complete -F _snix snix
+"#,
+        commands = command_names(),
+        shells = SHELLS.join(" ")
+    )
+}
+
+fn zsh_script() -> String {
+    let commands = COMMANDS
+        .iter()
+        .map(|(name, desc)| format!("        '{}:{}'", name, desc))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"#compdef snix
+# zsh completion for snix
+_snix() {{
+    local -a commands candidates
+    commands=(
+{commands}
+    )
+
+    if (( CURRENT == 2 )); then
+        _describe 'command' commands
+        return
+    fi
+
+    case "$words[2]" in
+        list|ls)
+            if [[ "$PREFIX" == -* ]]; then
+                compadd -- --json --no-color --depth --counts --no-pager
+            else
+                candidates=("${{(@f)$(snix __complete notebooks 2>/dev/null)}}")
+                compadd -a candidates
+            fi
+            ;;
+        notebooks|search|find|favorites|fav)
+            compadd -- --json --no-color
+            ;;
+        show|view|cat)
+            if [[ "$PREFIX" == -* ]]; then
+                compadd -- --raw
+            else
+                candidates=("${{(@f)$(snix __complete snippets 2>/dev/null)}}")
+                compadd -a candidates
+            fi
+            ;;
+        copy|edit)
+            candidates=("${{(@f)$(snix __complete snippets 2>/dev/null)}}")
+            compadd -a candidates
+            ;;
+        move|mv)
+            if (( CURRENT == 3 )); then
+                candidates=("${{(@f)$(snix __complete snippets 2>/dev/null)}}")
+            else
+                candidates=("${{(@f)$(snix __complete notebooks 2>/dev/null)}}")
+            fi
+            compadd -a candidates
+            ;;
+        export)
+            _arguments \
+                '2:path:_files' \
+                '--format[export format]:format:(json yaml toml csv dir)' \
+                '--favorites-only[only export favorite snippets]' \
+                '(--tag -t)'{{--tag,-t}}'[only export snippets with this tag]:tag:($(snix __complete tags 2>/dev/null))' \
+                '--no-content[strip snippet content]'
+            ;;
+        doctor)
+            compadd -- --fix --recreate-missing --import-orphans
+            ;;
+        import)
+            _arguments \
+                '2:path:_files' \
+                '--overwrite[overwrite existing items]'
+            ;;
+        completions)
+            compadd {shells}
+            ;;
+    esac
+}}
+
+compdef _snix snix
+"#,
+        commands = commands,
+        shells = SHELLS.join(" ")
+    )
+}
+
+fn fish_script() -> String {
+    let mut script = String::from("# fish completion for snix\ncomplete -c snix -f\n");
+
+    for (name, desc) in COMMANDS {
+        script.push_str(&format!(
+            "complete -c snix -n '__fish_use_subcommand' -a {} -d '{}'\n",
+            name, desc
+        ));
+    }
+
+    script.push_str(
+        r#"complete -c snix -n '__fish_seen_subcommand_from list ls' -a '(snix __complete notebooks 2>/dev/null)'
+complete -c snix -n '__fish_seen_subcommand_from show view cat copy edit' -a '(snix __complete snippets 2>/dev/null)'
+complete -c snix -n '__fish_seen_subcommand_from move mv; and test (count (commandline -opc)) -eq 2' -a '(snix __complete snippets 2>/dev/null)'
+complete -c snix -n '__fish_seen_subcommand_from move mv; and test (count (commandline -opc)) -eq 3' -a '(snix __complete notebooks 2>/dev/null)'
+complete -c snix -n '__fish_seen_subcommand_from list ls notebooks search find favorites fav' -l json -d 'Print JSON output'
+complete -c snix -l no-color -d 'Disable colored output'
+complete -c snix -n '__fish_seen_subcommand_from list ls' -l depth -x -d 'Only show notebooks up to N levels deep'
+complete -c snix -n '__fish_seen_subcommand_from list ls' -l counts -d 'Show snippet counts and languages per notebook'
+complete -c snix -n '__fish_seen_subcommand_from list ls' -l no-pager -d 'Do not page the output'
+complete -c snix -n '__fish_seen_subcommand_from show view cat' -l raw -d 'Print only the snippet content'
+complete -c snix -n '__fish_seen_subcommand_from export import' -F
+complete -c snix -n '__fish_seen_subcommand_from export' -l format -x -a 'json yaml toml csv dir'
+complete -c snix -n '__fish_seen_subcommand_from export' -l favorites-only -d 'Only export favorite snippets'
+complete -c snix -n '__fish_seen_subcommand_from export' -s t -l tag -x -a '(snix __complete tags 2>/dev/null)' -d 'Only export snippets with this tag'
+complete -c snix -n '__fish_seen_subcommand_from export' -l no-content -d 'Strip snippet content'
+complete -c snix -n '__fish_seen_subcommand_from import' -l overwrite -d 'Overwrite existing items'
+complete -c snix -n '__fish_seen_subcommand_from doctor' -l fix -d 'Recreate missing files and import orphans'
+complete -c snix -n '__fish_seen_subcommand_from doctor' -l recreate-missing -d 'Write missing files from the database'
+complete -c snix -n '__fish_seen_subcommand_from doctor' -l import-orphans -d 'Add orphan files as snippets'
+"#,
+    );
+
+    script.push_str(&format!(
+        "complete -c snix -n '__fish_seen_subcommand_from completions' -a '{}'\n",
+        SHELLS.join(" ")
+    ));
+
+    script
+}
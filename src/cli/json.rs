@@ -0,0 +1,148 @@
+//! Machine-readable output used when the `--json` flag is passed
+
+use crate::models::storage::SnippetDatabase;
+use crate::models::{CodeSnippet, Notebook};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::error::Error;
+use uuid::Uuid;
+
+/// A snippet as printed by `--json`
+#[derive(Serialize)]
+pub struct SnippetJson<'a> {
+    pub id: Uuid,
+    pub title: &'a str,
+    pub language: &'a str,
+    pub notebook_id: Uuid,
+    pub path: String,
+    pub tags: &'a [String],
+    pub favorite: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Which field matched, only set for search results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_in: Option<&'static str>,
+}
+
+impl<'a> SnippetJson<'a> {
+    pub fn new(snippet: &'a CodeSnippet, database: &SnippetDatabase) -> Self {
+        let notebook = notebook_path(database, snippet.notebook_id);
+        let path = if notebook.is_empty() {
+            snippet.title.clone()
+        } else {
+            format!("{}/{}", notebook, snippet.title)
+        };
+
+        Self {
+            id: snippet.id,
+            title: &snippet.title,
+            language: snippet.language.display_name(),
+            notebook_id: snippet.notebook_id,
+            path,
+            tags: &snippet.tags,
+            favorite: snippet.is_favorited(),
+            created_at: snippet.created_at,
+            updated_at: snippet.updated_at,
+            matched_in: None,
+        }
+    }
+}
+
+/// A notebook as printed by `--json`
+#[derive(Serialize)]
+pub struct NotebookJson<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub parent_id: Option<Uuid>,
+    pub path: String,
+    pub snippet_count: usize,
+    pub favorite: bool,
+}
+
+impl<'a> NotebookJson<'a> {
+    pub fn new(notebook: &'a Notebook, database: &SnippetDatabase) -> Self {
+        Self {
+            id: notebook.id,
+            name: &notebook.name,
+            parent_id: notebook.parent_id,
+            path: notebook_path(database, notebook.id),
+            snippet_count: database
+                .snippets
+                .values()
+                .filter(|snippet| snippet.notebook_id == notebook.id)
+                .count(),
+            favorite: notebook.is_favorite,
+        }
+    }
+}
+
+/// Favorite notebooks and snippets as printed by `favorites --json`
+#[derive(Serialize)]
+pub struct FavoritesJson<'a> {
+    pub notebooks: Vec<NotebookJson<'a>>,
+    pub snippets: Vec<SnippetJson<'a>>,
+}
+
+/// Prints any serializable value as pretty JSON on stdout
+pub fn print_json<T: Serialize>(value: &T) -> Result<(), Box<dyn Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Prints every snippet below `root_id` (or all snippets), ordered by path
+pub fn print_snippet_list(
+    database: &SnippetDatabase,
+    root_id: Option<Uuid>,
+) -> Result<(), Box<dyn Error>> {
+    let mut snippets: Vec<SnippetJson> = database
+        .snippets
+        .values()
+        .filter(|snippet| root_id.is_none_or(|root| is_within(database, snippet.notebook_id, root)))
+        .map(|snippet| SnippetJson::new(snippet, database))
+        .collect();
+    snippets.sort_by(|a, b| a.path.cmp(&b.path));
+
+    print_json(&snippets)
+}
+
+/// Prints every notebook with its path and direct snippet count, ordered by path
+pub fn print_notebook_list(database: &SnippetDatabase) -> Result<(), Box<dyn Error>> {
+    let mut notebooks: Vec<NotebookJson> = database
+        .notebooks
+        .values()
+        .map(|notebook| NotebookJson::new(notebook, database))
+        .collect();
+    notebooks.sort_by(|a, b| a.path.cmp(&b.path));
+
+    print_json(&notebooks)
+}
+
+/// Whether `notebook_id` is `root_id` or nested somewhere below it
+fn is_within(database: &SnippetDatabase, notebook_id: Uuid, root_id: Uuid) -> bool {
+    let mut current = Some(notebook_id);
+    while let Some(id) = current {
+        if id == root_id {
+            return true;
+        }
+        current = database
+            .notebooks
+            .get(&id)
+            .and_then(|notebook| notebook.parent_id);
+    }
+    false
+}
+
+/// Slash-separated names from the root notebook down to `notebook_id`
+pub fn notebook_path(database: &SnippetDatabase, notebook_id: Uuid) -> String {
+    let mut names = Vec::new();
+    let mut current = database.notebooks.get(&notebook_id);
+    while let Some(notebook) = current {
+        names.push(notebook.name.as_str());
+        current = notebook
+            .parent_id
+            .and_then(|parent_id| database.notebooks.get(&parent_id));
+    }
+
+    names.reverse();
+    names.join("/")
+}
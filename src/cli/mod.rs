@@ -4,14 +4,31 @@
 //! the terminal without launching the full TUI application.
 
 pub mod commands;
+pub mod completions;
+pub mod json;
+pub mod pager;
 pub mod tree;
 
-use crate::models::StorageManager;
+use crate::models::{ExportFormat, StorageManager};
 use colored::Colorize;
 use std::error::Error;
+use std::io::IsTerminal;
 
 /// Executes CLI commands based on the provided arguments
 pub fn execute_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
+    // `--json` and `--no-color` may appear anywhere, so pull them out before matching the command
+    let json = args.iter().any(|arg| arg == "--json");
+    let no_color = args.iter().any(|arg| arg == "--no-color");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|arg| *arg != "--json" && *arg != "--no-color")
+        .cloned()
+        .collect();
+
+    if no_color || !color_enabled() {
+        colored::control::set_override(false);
+    }
+
     if args.is_empty() {
         // No arguments provided, show help
         print_help();
@@ -20,26 +37,63 @@ pub fn execute_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
 
     match args[0].as_str() {
         "list" | "ls" => {
+            let mut notebook_name = None;
+            let mut tree_options = tree::TreeOptions::default();
+            let mut use_pager = true;
+
+            let mut options = args[1..].iter();
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--depth" | "-d" => {
+                        match options.next().and_then(|value| value.parse::<usize>().ok()) {
+                            Some(depth) if depth > 0 => tree_options.max_depth = Some(depth),
+                            _ => {
+                                println!(
+                                    "{}  Error: --depth needs a positive number",
+                                    "┃".bright_magenta()
+                                );
+                                return Ok(());
+                            }
+                        }
+                    }
+                    "--counts" | "-c" => tree_options.counts = true,
+                    "--no-pager" => use_pager = false,
+                    other if other.starts_with('-') => {
+                        println!("{}  Unknown option: {}", "┃".bright_magenta(), other);
+                        return Ok(());
+                    }
+                    name => notebook_name = Some(name),
+                }
+            }
+
             let storage = StorageManager::new()?;
             let database = storage.load_database()?;
 
-            if args.len() == 1 {
-                tree::display_tree(&database, None)?;
+            let Some(name) = notebook_name else {
+                if json {
+                    json::print_snippet_list(&database, None)?;
+                } else {
+                    let output = tree::render_tree(&database, None, tree_options)?;
+                    print_paged(&output, use_pager)?;
+                }
                 return Ok(());
-            }
+            };
 
             // Try to find notebook by name or ID
-            let notebook_id = if let Ok(id) = uuid::Uuid::parse_str(&args[1]) {
+            let notebook_id = if let Ok(id) = uuid::Uuid::parse_str(name) {
                 // Valid UUID format, use directly
                 Some(id)
             } else {
-                match tree::find_notebook_by_name(&database, &args[1]) {
+                match tree::find_notebook_by_name(&database, name) {
                     Some(id) => Some(id),
+                    None if json => {
+                        return Err(format!("No notebook found with name: {}", name).into());
+                    }
                     None => {
                         println!(
                             "{}  No notebook found with name: {}",
                             "┃".bright_magenta(),
-                            args[1]
+                            name
                         );
 
                         tree::list_all_notebooks(&database)?;
@@ -48,16 +102,34 @@ pub fn execute_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
                 }
             };
 
-            tree::display_tree(&database, notebook_id)?;
+            match notebook_id {
+                Some(id) if json => {
+                    if !database.notebooks.contains_key(&id) {
+                        return Err(format!("Notebook with ID {} not found", id).into());
+                    }
+                    json::print_snippet_list(&database, Some(id))?;
+                }
+                _ => {
+                    let output = tree::render_tree(&database, notebook_id, tree_options)?;
+                    print_paged(&output, use_pager)?;
+                }
+            }
         }
         "notebooks" => {
             // List all available notebooks with their IDs
             let storage = StorageManager::new()?;
             let database = storage.load_database()?;
-            tree::list_all_notebooks(&database)?;
+            if json {
+                json::print_notebook_list(&database)?;
+            } else {
+                tree::list_all_notebooks(&database)?;
+            }
         }
         "favorites" | "fav" => {
-            commands::list_favorites()?;
+            commands::list_favorites(json)?;
+        }
+        "stats" => {
+            commands::show_stats()?;
         }
         "show" | "view" | "cat" => {
             if args.len() < 2 {
@@ -66,13 +138,89 @@ pub fn execute_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
                     "┃".bright_magenta()
                 );
                 println!(
-                    "{}  Usage: snix show <SNIPPET_NAME_OR_ID>",
+                    "{}  Usage: snix show <SNIPPET_NAME_OR_ID> [--raw]",
+                    "┃".bright_magenta()
+                );
+                return Ok(());
+            }
+
+            let mut raw = false;
+            for option in &args[2..] {
+                match option.as_str() {
+                    "--raw" => raw = true,
+                    other => {
+                        println!("{}  Unknown option: {}", "┃".bright_magenta(), other);
+                        return Ok(());
+                    }
+                }
+            }
+
+            commands::show_snippet(&args[1], raw)?;
+        }
+        "copy" => {
+            if args.len() < 2 {
+                println!(
+                    "{}  Error: Missing snippet name or ID",
+                    "┃".bright_magenta()
+                );
+                println!(
+                    "{}  Usage: snix copy <SNIPPET_NAME_OR_ID>",
+                    "┃".bright_magenta()
+                );
+                return Ok(());
+            }
+
+            commands::copy_snippet(&args[1])?;
+        }
+        "edit" => {
+            if args.len() < 2 {
+                println!(
+                    "{}  Error: Missing snippet name or ID",
+                    "┃".bright_magenta()
+                );
+                println!(
+                    "{}  Usage: snix edit <SNIPPET_NAME_OR_ID>",
                     "┃".bright_magenta()
                 );
                 return Ok(());
             }
 
-            commands::show_snippet(&args[1])?;
+            commands::edit_snippet(&args[1])?;
+        }
+        "doctor" => {
+            let mut recreate_missing = false;
+            let mut import_orphans = false;
+            for option in &args[1..] {
+                match option.as_str() {
+                    "--recreate-missing" => recreate_missing = true,
+                    "--import-orphans" => import_orphans = true,
+                    "--fix" => {
+                        recreate_missing = true;
+                        import_orphans = true;
+                    }
+                    other => {
+                        println!("{}  Unknown option: {}", "┃".bright_magenta(), other);
+                        return Ok(());
+                    }
+                }
+            }
+
+            commands::run_doctor(recreate_missing, import_orphans)?;
+        }
+        "move" | "mv" => {
+            if args.len() < 3 {
+                println!(
+                    "{}  Error: Missing snippet or target notebook",
+                    "┃".bright_magenta()
+                );
+                println!(
+                    "{}  Usage: snix move <SNIPPET_NAME_OR_ID> <NOTEBOOK_NAME_OR_ID>",
+                    "┃".bright_magenta()
+                );
+                return Ok(());
+            }
+
+            commands::move_snippet(&args[1], &args[2])?;
         }
         "search" | "find" => {
             if args.len() < 2 {
@@ -81,7 +229,105 @@ pub fn execute_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
                 return Ok(());
             }
 
-            commands::search_snippets(&args[1])?;
+            commands::search_snippets(&args[1], json)?;
+        }
+        "export" => {
+            if args.len() < 2 {
+                println!("{}  Error: Missing export path", "┃".bright_magenta());
+                println!(
+                    "{}  Usage: snix export <PATH> [--format json|yaml|toml|csv|dir] [--favorites-only] [--tag <TAG>]... [--no-content]",
+                    "┃".bright_magenta()
+                );
+                return Ok(());
+            }
+
+            let path = &args[1];
+            let mut format = format_from_path(path);
+            let mut favorites_only = false;
+            let mut tags = Vec::new();
+            let mut include_content = true;
+
+            let mut options = args[2..].iter();
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--format" | "-f" => {
+                        let value = options.next().map(String::as_str).unwrap_or("");
+                        match parse_export_format(value) {
+                            Some(parsed) => format = parsed,
+                            None => {
+                                println!(
+                                    "{}  Error: Unknown export format '{}' (expected json, yaml, toml, csv or dir)",
+                                    "┃".bright_magenta(),
+                                    value
+                                );
+                                return Ok(());
+                            }
+                        }
+                    }
+                    "--favorites-only" => favorites_only = true,
+                    "--tag" | "-t" => {
+                        let Some(value) = options.next() else {
+                            println!("{}  Error: --tag needs a tag name", "┃".bright_magenta());
+                            return Ok(());
+                        };
+                        tags.extend(
+                            value
+                                .split(',')
+                                .map(|tag| tag.trim().trim_start_matches('#'))
+                                .filter(|tag| !tag.is_empty())
+                                .map(str::to_string),
+                        );
+                    }
+                    "--no-content" => include_content = false,
+                    other => {
+                        println!("{}  Unknown option: {}", "┃".bright_magenta(), other);
+                        return Ok(());
+                    }
+                }
+            }
+
+            commands::export_snippets(path, format, favorites_only, tags, include_content)?;
+        }
+        "import" => {
+            if args.len() < 2 {
+                println!("{}  Error: Missing import path", "┃".bright_magenta());
+                println!(
+                    "{}  Usage: snix import <PATH> [--overwrite]",
+                    "┃".bright_magenta()
+                );
+                return Ok(());
+            }
+
+            let mut overwrite = false;
+            for option in &args[2..] {
+                match option.as_str() {
+                    "--overwrite" => overwrite = true,
+                    other => {
+                        println!("{}  Unknown option: {}", "┃".bright_magenta(), other);
+                        return Ok(());
+                    }
+                }
+            }
+
+            commands::import_snippets(&args[1], overwrite)?;
+        }
+        "completions" => {
+            if args.len() < 2 {
+                println!("{}  Error: Missing shell name", "┃".bright_magenta());
+                println!(
+                    "{}  Usage: snix completions <bash|zsh|fish>",
+                    "┃".bright_magenta()
+                );
+                return Ok(());
+            }
+
+            completions::print_completions(&args[1])?;
+        }
+        "__complete" => {
+            // Used by the completion scripts to fetch notebook and snippet names
+            if let Some(kind) = args.get(1) {
+                completions::print_dynamic_candidates(kind)?;
+            }
         }
         "help" => {
             print_help();
@@ -96,6 +342,27 @@ pub fn execute_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Parses an export format name given on the command line
+fn parse_export_format(value: &str) -> Option<ExportFormat> {
+    match value.to_lowercase().as_str() {
+        "json" => Some(ExportFormat::JSON),
+        "yaml" | "yml" => Some(ExportFormat::YAML),
+        "toml" => Some(ExportFormat::TOML),
+        "csv" => Some(ExportFormat::CSV),
+        "dir" | "directory" => Some(ExportFormat::Directory),
+        _ => None,
+    }
+}
+
+/// Picks an export format from the file extension, defaulting to JSON
+fn format_from_path(path: &str) -> ExportFormat {
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(parse_export_format)
+        .unwrap_or(ExportFormat::JSON)
+}
+
 /// Prints the help message with available commands
 fn print_help() {
     println!(
@@ -119,6 +386,16 @@ fn print_help() {
         "list <NOTEBOOK_NAME>".bright_white(),
         "List snippets in the specified notebook"
     );
+    println!(
+        "{}  {:<27} Only show notebooks up to N levels deep",
+        "┃".bright_magenta(),
+        "list --depth <N>".bright_white()
+    );
+    println!(
+        "{}  {:<27} Show snippet counts and languages per notebook",
+        "┃".bright_magenta(),
+        "list --counts".bright_white()
+    );
     println!(
         "{}  {:<27} {}",
         "┃".bright_magenta(),
@@ -131,6 +408,26 @@ fn print_help() {
         "show, view <NAME>".bright_white(),
         "Display a snippet by name (partial name works)"
     );
+    println!(
+        "{}  {:<27} Print only the snippet content, for piping",
+        "┃".bright_magenta(),
+        "show <NAME> --raw".bright_white()
+    );
+    println!(
+        "{}  {:<27} Copy a snippet to the clipboard",
+        "┃".bright_magenta(),
+        "copy <NAME>".bright_white()
+    );
+    println!(
+        "{}  {:<27} Open a snippet in $EDITOR and save the changes",
+        "┃".bright_magenta(),
+        "edit <NAME>".bright_white()
+    );
+    println!(
+        "{}  {:<27} Move a snippet into another notebook",
+        "┃".bright_magenta(),
+        "move, mv <NAME> <NOTEBOOK>".bright_white()
+    );
     println!(
         "{}  {:<27} {}",
         "┃".bright_magenta(),
@@ -138,10 +435,39 @@ fn print_help() {
         "Search for snippets matching the query"
     );
     println!(
-        "{}  {:<27} {}",
+        "{}  {:<27} List favorite notebooks and snippets",
+        "┃".bright_magenta(),
+        "favorites, fav".bright_white()
+    );
+    println!(
+        "{}  {:<27} Show an overview of the snippet library",
         "┃".bright_magenta(),
-        "favorites, fav".bright_white(),
-        "List all favorite snippets"
+        "stats".bright_white()
+    );
+    println!(
+        "{}  {:<27} Find snippets without files and files without snippets",
+        "┃".bright_magenta(),
+        "doctor".bright_white()
+    );
+    println!(
+        "{}  {:<27} Recreate missing files and import orphan files",
+        "┃".bright_magenta(),
+        "doctor --fix".bright_white()
+    );
+    println!(
+        "{}  {:<27} Export snippets (--format, --favorites-only, --tag, --no-content)",
+        "┃".bright_magenta(),
+        "export <PATH> [OPTIONS]".bright_white()
+    );
+    println!(
+        "{}  {:<27} Import snippets from a JSON, YAML or TOML export",
+        "┃".bright_magenta(),
+        "import <PATH> [--overwrite]".bright_white()
+    );
+    println!(
+        "{}  {:<27} Print a completion script (bash, zsh, fish)",
+        "┃".bright_magenta(),
+        "completions <SHELL>".bright_white()
     );
     println!(
         "{}  {:<27} {}",
@@ -150,9 +476,42 @@ fn print_help() {
         "Display this help message"
     );
 
+    println!("{}  {}", "┃".bright_magenta(), "OPTIONS:".bright_yellow());
+    println!(
+        "{}  {:<27} Print JSON from list, notebooks, search and favorites",
+        "┃".bright_magenta(),
+        "--json".bright_white()
+    );
+    println!(
+        "{}  {:<27} Disable colored output (also off with NO_COLOR or when piped)",
+        "┃".bright_magenta(),
+        "--no-color".bright_white()
+    );
+    println!(
+        "{}  {:<27} Print list output directly instead of through $PAGER",
+        "┃".bright_magenta(),
+        "--no-pager".bright_white()
+    );
+
     println!("{}  {}", "┃".bright_magenta(), "TIP:".bright_green());
     println!(
         "{}  Run with no arguments to launch the full TUI (Terminal User Interface) mode",
         "┃".bright_magenta()
     );
 }
+
+/// Prints `output` through the pager unless paging was turned off
+fn print_paged(output: &str, use_pager: bool) -> Result<(), Box<dyn Error>> {
+    if use_pager {
+        pager::page(output)
+    } else {
+        print!("{}", output);
+        Ok(())
+    }
+}
+
+/// Whether colors should be used: stdout is a terminal and `NO_COLOR` is unset or empty
+fn color_enabled() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    !no_color && std::io::stdout().is_terminal()
+}
@@ -0,0 +1,40 @@
+//! Sends long CLI output through `$PAGER`, the way git does
+
+use std::error::Error;
+use std::io::{IsTerminal, Write};
+use std::process::{Child, Command, Stdio};
+
+/// Prints `output`, through `$PAGER` (or `less`) when stdout is a terminal
+pub fn page(output: &str) -> Result<(), Box<dyn Error>> {
+    let pager = if std::io::stdout().is_terminal() {
+        spawn_pager()
+    } else {
+        None
+    };
+    let Some(mut child) = pager else {
+        print!("{}", output);
+        return Ok(());
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Quitting the pager early closes the pipe, which is not an error
+        let _ = stdin.write_all(output.as_bytes());
+    }
+    child.wait()?;
+    Ok(())
+}
+
+/// Starts the pager with a piped stdin; `None` when `PAGER` is empty or cannot be run
+fn spawn_pager() -> Option<Child> {
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| "less".to_string());
+    let mut parts = pager.split_whitespace();
+    let program = parts.next()?;
+
+    let mut command = Command::new(program);
+    command.args(parts).stdin(Stdio::piped());
+    // Same defaults as git: exit if it fits on one screen, keep colors, don't clear
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+    command.spawn().ok()
+}
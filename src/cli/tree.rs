@@ -1,34 +1,45 @@
 use crate::models::storage::SnippetDatabase;
 use colored::Colorize;
+use std::collections::{BTreeMap, HashSet};
 use std::error::Error;
+use std::fmt::{self, Write};
 use uuid::Uuid;
 
-/// Displays the database content in a tree-like structure
-pub fn display_tree(
+/// Languages named in a notebook's count summary before the rest are elided
+const SUMMARY_LANGUAGES: usize = 3;
+
+/// How much `render_tree` shows
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TreeOptions {
+    /// Only show notebooks up to this many levels deep
+    pub max_depth: Option<usize>,
+    /// Follow each notebook name with its snippet count and main languages
+    pub counts: bool,
+}
+
+/// Renders the database content in a tree-like structure
+pub fn render_tree(
     database: &SnippetDatabase,
     root_id: Option<Uuid>,
-) -> Result<(), Box<dyn Error>> {
+    options: TreeOptions,
+) -> Result<String, Box<dyn Error>> {
+    let mut out = String::new();
     if database.notebooks.is_empty() {
-        println!("No notebooks found in database.");
-        return Ok(());
+        writeln!(out, "No notebooks found in database.")?;
+        return Ok(out);
     }
 
     match root_id {
         Some(id) => {
             if let Some(notebook) = database.notebooks.get(&id) {
-                println!(
-                    "{}  {} {}",
-                    "┃".bright_magenta(),
-                    "󰠮".bright_blue(),
-                    notebook.name.bold()
-                );
-                print_notebook_contents(database, id, 1, &notebook.name, vec![]);
+                write_root_notebook(&mut out, database, id, &notebook.name, options, vec![])?;
             } else {
-                println!(
+                writeln!(
+                    out,
                     "{}  Notebook with ID {} not found",
                     "┃".bright_magenta(),
                     id
-                );
+                )?;
             }
         }
         None => {
@@ -37,12 +48,6 @@ pub fn display_tree(
             for (idx, notebook_id) in database.root_notebooks.iter().enumerate() {
                 if let Some(notebook) = database.notebooks.get(notebook_id) {
                     let is_last = idx == count - 1;
-                    println!(
-                        "{}  {} {}",
-                        "┃".bright_magenta(),
-                        "󰠮".bright_blue(),
-                        notebook.name.bold()
-                    );
 
                     // Create guide vector - true means draw line, false means space
                     let mut guides = Vec::new();
@@ -54,22 +59,68 @@ pub fn display_tree(
                         guides.push(true); // Not last, so draw line for following siblings
                     }
 
-                    print_notebook_contents(database, *notebook_id, 1, &notebook.name, guides);
+                    write_root_notebook(
+                        &mut out,
+                        database,
+                        *notebook_id,
+                        &notebook.name,
+                        options,
+                        guides,
+                    )?;
                 }
             }
         }
     }
-    Ok(())
+    Ok(out)
+}
+
+/// Writes a top-level notebook line followed by its contents
+fn write_root_notebook(
+    out: &mut String,
+    database: &SnippetDatabase,
+    notebook_id: Uuid,
+    name: &str,
+    options: TreeOptions,
+    guides: Vec<bool>,
+) -> fmt::Result {
+    let summary = if options.counts {
+        count_summary(database, notebook_id)
+    } else {
+        String::new()
+    };
+    if options.max_depth.is_some_and(|max| max <= 1) {
+        return writeln!(
+            out,
+            "{}  {} {}{}{}",
+            "┃".bright_magenta(),
+            "󰠮".bright_blue(),
+            name.bold(),
+            summary,
+            hidden_hint(database, notebook_id)
+        );
+    }
+
+    writeln!(
+        out,
+        "{}  {} {}{}",
+        "┃".bright_magenta(),
+        "󰠮".bright_blue(),
+        name.bold(),
+        summary
+    )?;
+    write_notebook_contents(out, database, notebook_id, 1, options, name, guides)
 }
 
-/// Recursively prints the contents of a notebook
-fn print_notebook_contents(
+/// Recursively writes the contents of a notebook
+fn write_notebook_contents(
+    out: &mut String,
     database: &SnippetDatabase,
     notebook_id: Uuid,
     depth: usize,
+    options: TreeOptions,
     path: &str,
     guides: Vec<bool>,
-) {
+) -> fmt::Result {
     // Get all snippets in this notebook
     let snippets: Vec<_> = database
         .snippets
@@ -95,66 +146,59 @@ fn print_notebook_contents(
 
         let language_icon = snippet.language.icon();
         let star = if snippet.is_favorited() {
-            " ".yellow()
+            " ".yellow()
         } else {
             "".normal()
         };
 
         let full_path = format!("{}/{}", path, snippet.title);
 
-        print!("{}  ", "┃".bright_magenta());
-        for guide in &guides {
-            if *guide {
-                print!("┃  ");
-            } else {
-                print!("   ");
-            }
-        }
-
-        if is_last_item {
-            print!("└── ");
-        } else {
-            print!("├── ");
-        }
+        write_guides(out, &guides, is_last_item)?;
 
-        // Print the actual snippet content
-        println!(
+        // Write the actual snippet content
+        writeln!(
+            out,
             "{}{} {} [{}] {}",
             star,
             language_icon,
             snippet.title.bright_white(),
             snippet.language.short_name().bright_black(),
             full_path.bright_black().italic()
-        );
+        )?;
     }
 
     // Display child notebooks
     for (i, (child_id, child)) in children.iter().enumerate() {
         let is_last = i == children.len() - 1;
         let child_path = format!("{}/{}", path, child.name);
+        // Children of a notebook at `depth` sit one level further down
+        let expand = options.max_depth.is_none_or(|max| depth + 1 < max);
 
-        print!("{}  ", "┃".bright_magenta());
+        write_guides(out, &guides, is_last)?;
 
-        for guide in &guides {
-            if *guide {
-                print!("┃  ");
-            } else {
-                print!("   ");
-            }
-        }
-
-        if is_last {
-            print!("└── ");
+        let hint = if expand {
+            String::new()
         } else {
-            print!("├── ");
-        }
-
-        println!(
-            "{} {} {}",
+            hidden_hint(database, *child_id)
+        };
+        let summary = if options.counts {
+            count_summary(database, *child_id)
+        } else {
+            String::new()
+        };
+        writeln!(
+            out,
+            "{} {}{} {}{}",
             "󰠮".bright_blue(),
             child.name.bold(),
-            child_path.bright_black().italic()
-        );
+            summary,
+            child_path.bright_black().italic(),
+            hint
+        )?;
+
+        if !expand {
+            continue;
+        }
 
         // Create guide vector for the next level
         let mut next_guides = guides.clone();
@@ -166,9 +210,116 @@ fn print_notebook_contents(
             next_guides.push(true); // Not last, so draw line for following siblings
         }
 
-        // Recursively print children
-        print_notebook_contents(database, *child_id, depth + 1, &child_path, next_guides);
+        // Recursively write children
+        write_notebook_contents(
+            out,
+            database,
+            *child_id,
+            depth + 1,
+            options,
+            &child_path,
+            next_guides,
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the tree guides and branch that start every nested line
+fn write_guides(out: &mut String, guides: &[bool], is_last: bool) -> fmt::Result {
+    write!(out, "{}  ", "┃".bright_magenta())?;
+    for guide in guides {
+        if *guide {
+            write!(out, "┃  ")?;
+        } else {
+            write!(out, "   ")?;
+        }
+    }
+
+    if is_last {
+        write!(out, "└── ")
+    } else {
+        write!(out, "├── ")
+    }
+}
+
+/// " (… N more)" for a notebook cut off by the depth limit, empty when it has nothing inside
+fn hidden_hint(database: &SnippetDatabase, notebook_id: Uuid) -> String {
+    let hidden = count_nested_items(database, notebook_id);
+    if hidden == 0 {
+        String::new()
+    } else {
+        format!(" (… {} more)", hidden).bright_black().to_string()
+    }
+}
+
+/// " [12 · rs,py]": the snippets anywhere in a notebook and their most common languages
+fn count_summary(database: &SnippetDatabase, notebook_id: Uuid) -> String {
+    let notebooks = notebook_and_descendants(database, notebook_id);
+    let mut languages: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut count = 0;
+    for snippet in database
+        .snippets
+        .values()
+        .filter(|snippet| notebooks.contains(&snippet.notebook_id))
+    {
+        count += 1;
+        *languages
+            .entry(snippet.language.file_extension())
+            .or_default() += 1;
+    }
+
+    if count == 0 {
+        return " [0]".bright_black().to_string();
+    }
+
+    let mut languages: Vec<(&str, usize)> = languages.into_iter().collect();
+    // Most used first; the map already ordered ties by name
+    languages.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    let mut names: Vec<&str> = languages
+        .iter()
+        .take(SUMMARY_LANGUAGES)
+        .map(|(name, _)| *name)
+        .collect();
+    if languages.len() > SUMMARY_LANGUAGES {
+        names.push("…");
+    }
+    format!(" [{} · {}]", count, names.join(","))
+        .bright_black()
+        .to_string()
+}
+
+/// Snippets and notebooks anywhere below `notebook_id`
+fn count_nested_items(database: &SnippetDatabase, notebook_id: Uuid) -> usize {
+    notebook_and_descendants(database, notebook_id)
+        .iter()
+        .map(|id| {
+            let snippets = database
+                .snippets
+                .values()
+                .filter(|snippet| snippet.notebook_id == *id)
+                .count();
+            let children = database
+                .notebooks
+                .get(id)
+                .map_or(0, |notebook| notebook.children.len());
+            snippets + children
+        })
+        .sum()
+}
+
+/// `notebook_id` and every notebook nested inside it
+fn notebook_and_descendants(database: &SnippetDatabase, notebook_id: Uuid) -> HashSet<Uuid> {
+    let mut seen = HashSet::new();
+    let mut pending = vec![notebook_id];
+    while let Some(id) = pending.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        if let Some(notebook) = database.notebooks.get(&id) {
+            pending.extend(notebook.children.iter().copied());
+        }
     }
+    seen
 }
 
 /// Find notebook ID by name (case insensitive, partial match)
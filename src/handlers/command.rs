@@ -0,0 +1,243 @@
+//! Vim-style `:` command line for the snippet tree
+
+use crate::app::{App, CodeSnippetsState, InputMode, TreeItem};
+use crate::handlers::keys::{LanguageDetector, get_current_notebook_id};
+use crate::handlers::text_input;
+use crate::ui::colors::{TAG_COLOR_NAMES, palette};
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use std::path::PathBuf;
+
+/// How many past commands Up/Down can recall
+const MAX_COMMAND_HISTORY: usize = 50;
+
+/// Commands understood by the `:` prompt, shown by `:help`
+const COMMANDS: &[&str] = &[
+    "new <title.ext>",
+    "delete",
+    "export <path>",
+    "search <query>",
+    "tag <tags>",
+    "tagcolor <tag> [color]",
+    "q",
+];
+
+/// Opens the command line with an empty buffer
+pub fn enter_command_mode(app: &mut App) {
+    app.clear_messages();
+    app.input_buffer.clear();
+    app.command_history_index = None;
+    app.input_mode = InputMode::Command;
+}
+
+/// Handles keys while the command line is open; returns true when a command asks to quit
+pub fn handle_command_mode_keys(key: KeyEvent, app: &mut App) -> bool {
+    match key.code {
+        KeyCode::Esc => {
+            app.input_mode = InputMode::Normal;
+            app.input_buffer.clear();
+            false
+        }
+        KeyCode::Enter => {
+            let line = std::mem::take(&mut app.input_buffer);
+            app.input_mode = InputMode::Normal;
+            remember_command(app, line.trim());
+            execute_command(app, line.trim())
+        }
+        KeyCode::Backspace => {
+            // Deleting past the start of an empty prompt closes it, as in vim
+            if !text_input::backspace(&mut app.input_buffer, &mut app.input_cursor)
+                && app.input_buffer.is_empty()
+            {
+                app.input_mode = InputMode::Normal;
+            }
+            app.command_history_index = None;
+            false
+        }
+        KeyCode::Up => {
+            if !app.command_history.is_empty() {
+                let index = app
+                    .command_history_index
+                    .map_or(app.command_history.len() - 1, |i| i.saturating_sub(1));
+                app.command_history_index = Some(index);
+                app.input_buffer = app.command_history[index].clone();
+                app.input_cursor = 0;
+            }
+            false
+        }
+        KeyCode::Down => {
+            match app.command_history_index {
+                Some(i) if i + 1 < app.command_history.len() => {
+                    app.command_history_index = Some(i + 1);
+                    app.input_buffer = app.command_history[i + 1].clone();
+                    app.input_cursor = 0;
+                }
+                Some(_) => {
+                    app.command_history_index = None;
+                    app.input_buffer.clear();
+                }
+                None => {}
+            }
+            false
+        }
+        KeyCode::Char(c) => {
+            text_input::insert(&mut app.input_buffer, &mut app.input_cursor, &c.to_string());
+            app.command_history_index = None;
+            false
+        }
+        _ => {
+            text_input::handle_editing_key(key, &mut app.input_buffer, &mut app.input_cursor);
+            false
+        }
+    }
+}
+
+fn remember_command(app: &mut App, line: &str) {
+    app.command_history_index = None;
+    if line.is_empty() || app.command_history.last().is_some_and(|last| last == line) {
+        return;
+    }
+
+    app.command_history.push(line.to_string());
+    if app.command_history.len() > MAX_COMMAND_HISTORY {
+        app.command_history.remove(0);
+    }
+}
+
+/// Runs one command line; returns true when the app should quit
+fn execute_command(app: &mut App, line: &str) -> bool {
+    let (name, args) = match line.split_once(char::is_whitespace) {
+        Some((name, args)) => (name, args.trim()),
+        None => (line, ""),
+    };
+
+    match name {
+        "" => {}
+        "q" | "quit" => return true,
+        "new" => create_snippet(app, args),
+        "delete" | "del" => app.request_delete_selected(),
+        "export" => export_library(app, args),
+        "search" => search(app, args),
+        "tag" | "tags" => tag_selected_snippet(app, args),
+        "tagcolor" => set_tag_color(app, args),
+        "help" | "h" => app.set_success_message(format!("Commands: {}", COMMANDS.join(" · "))),
+        other => app.set_error_message(format!("Unknown command: {} (try :help)", other)),
+    }
+    false
+}
+
+fn create_snippet(app: &mut App, args: &str) {
+    if args.is_empty() {
+        app.set_error_message("Usage: :new <title.ext>".to_string());
+        return;
+    }
+    let Some(notebook_id) = get_current_notebook_id(app) else {
+        app.set_error_message("No notebook selected".to_string());
+        return;
+    };
+
+    let (title, language) = LanguageDetector::parse_title_and_language(args);
+    match app.create_snippet(title, language, notebook_id) {
+        Ok(_) => {
+            app.set_success_message("Snippet created successfully!".to_string());
+            app.code_snippets_state = CodeSnippetsState::NotebookList;
+            app.refresh_tree_items();
+        }
+        Err(e) => app.set_error_message(e),
+    }
+}
+
+fn export_library(app: &mut App, args: &str) {
+    if args.is_empty() {
+        app.set_error_message("Usage: :export <path>".to_string());
+        return;
+    }
+
+    let path = match args.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(args),
+    };
+    match app.export_library(&path) {
+        Ok(n) => app.set_success_message(format!("Exported {} snippets to {}", n, path.display())),
+        Err(e) => app.set_error_message(e),
+    }
+}
+
+fn search(app: &mut App, args: &str) {
+    if args.is_empty() {
+        app.set_error_message("Usage: :search <query>".to_string());
+        return;
+    }
+
+    app.input_mode = InputMode::Search;
+    app.search_query = args.to_string();
+    app.search_results.clear();
+    app.selected_search_result = 0;
+    app.selected_recent_search = 0;
+    let checkpoint = app.toasts.checkpoint();
+    let count = app.perform_search(args);
+    if !app.toasts.error_since(checkpoint) {
+        app.set_success_message(format!("Found {} results for '{}'", count, args));
+    }
+}
+
+fn tag_selected_snippet(app: &mut App, args: &str) {
+    let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item().cloned() else {
+        app.set_error_message("Select a snippet first".to_string());
+        return;
+    };
+
+    // Accept both `:tag rust cli` and `:tag #rust #cli`
+    let tags = args
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+        .map(|word| format!("#{}", word.trim_start_matches('#')))
+        .collect::<Vec<_>>()
+        .join(" ");
+    match app.set_snippet_tags(snippet_id, &tags) {
+        Ok(()) if tags.is_empty() => app.set_success_message("Tags cleared".to_string()),
+        Ok(()) => app.set_success_message(format!("Tags set to {}", tags)),
+        Err(e) => app.set_error_message(e),
+    }
+}
+
+/// `:tagcolor wip gold` colors snippets tagged #wip in the tree; without a color it clears it
+fn set_tag_color(app: &mut App, args: &str) {
+    let mut words = args.split_whitespace();
+    let Some(tag) = words.next() else {
+        app.set_error_message(format!(
+            "Usage: :tagcolor <tag> [{}]",
+            TAG_COLOR_NAMES.join("|")
+        ));
+        return;
+    };
+    let tag = tag.trim_start_matches('#').to_lowercase();
+
+    let message = match words.next() {
+        Some(color) if palette().named(color).is_some() => {
+            let color = color.to_lowercase();
+            let message = format!("Snippets tagged #{} are now shown in {}", tag, color);
+            app.settings.tag_colors.insert(tag, color);
+            message
+        }
+        Some(color) => {
+            app.set_error_message(format!(
+                "Unknown color '{}', pick one of {}",
+                color,
+                TAG_COLOR_NAMES.join(", ")
+            ));
+            return;
+        }
+        None => {
+            if app.settings.tag_colors.remove(&tag).is_none() {
+                app.set_error_message(format!("#{} has no color", tag));
+                return;
+            }
+            format!("Removed the color of #{}", tag)
+        }
+    };
+
+    match app.save_settings() {
+        Ok(()) => app.set_success_message(message),
+        Err(e) => app.set_error_message(e),
+    }
+}
@@ -0,0 +1,280 @@
+//! Shortcut table behind the `?` help menu, kept next to the handlers it describes
+//!
+//! Each section mirrors one `handle_*_keys` function; update it together with the handler.
+
+use crate::app::{AppState, CodeSnippetsState};
+
+/// One row of the help menu: the keys as printed and what they do
+#[derive(Debug, Clone, Copy)]
+pub struct KeyBinding {
+    pub keys: &'static str,
+    pub action: &'static str,
+}
+
+/// A titled group of bindings
+#[derive(Debug, Clone, Copy)]
+pub struct KeySection {
+    pub title: &'static str,
+    pub bindings: &'static [KeyBinding],
+}
+
+const fn bind(keys: &'static str, action: &'static str) -> KeyBinding {
+    KeyBinding { keys, action }
+}
+
+/// `dispatch_key_event`
+const GLOBAL: KeySection = KeySection {
+    title: "General",
+    bindings: &[
+        bind("?", "Toggle this help menu"),
+        bind("Ctrl+N", "New quick snippet in Inbox"),
+        bind("Backspace", "Go back"),
+        bind("q", "Quit (outside the snippets screen)"),
+    ],
+};
+
+/// `handle_other_page_keys`, the fallback of most pages
+const PAGE: KeySection = KeySection {
+    title: "Page",
+    bindings: &[bind("Esc", "Go back"), bind("h", "Go to home page")],
+};
+
+/// `handle_start_page_keys`
+const START_PAGE: KeySection = KeySection {
+    title: "Start Page",
+    bindings: &[
+        bind("↑/k ↓/j", "Move through the menu"),
+        bind("⏎", "Open the selected entry"),
+        bind("b m s e", "Boilerplates, market, snippets, I/O"),
+        bind("n", "New quick snippet in Inbox"),
+        bind("u t d c", "Backups, trash, stats, settings"),
+        bind("r", "Recently opened snippets"),
+        bind("1-9 0", "Open a recent snippet"),
+        bind("/", "Search snippets"),
+        bind("a i", "About, info"),
+    ],
+};
+
+/// `handle_notebook_list_keys`
+const TREE_NAVIGATION: KeySection = KeySection {
+    title: "Navigation",
+    bindings: &[
+        bind("↑/k ↓/j", "Move up and down"),
+        bind("⏎", "Notebook details / edit snippet"),
+        bind("Shift+⏎", "Open classic notebook view"),
+        bind("'", "Jump to a visible row by its label"),
+        bind("PgUp PgDn", "Scroll the preview"),
+        bind("Esc", "Clear filter, then go back"),
+        bind("h", "Go to home page"),
+    ],
+};
+
+/// `handle_notebook_list_keys`
+const TREE_NOTEBOOKS: KeySection = KeySection {
+    title: "Notebooks",
+    bindings: &[
+        bind("n", "Create root notebook"),
+        bind("b", "Create nested notebook"),
+        bind("Space", "Collapse/expand notebook"),
+        bind("v", "View notebook details"),
+        bind("x", "Move notebook/snippet to trash"),
+        bind("u", "Undo last delete or move"),
+        bind("Shift+↑ ↓", "Move item up/down a level"),
+        bind("Shift+← →", "Move item between siblings"),
+    ],
+};
+
+/// `handle_notebook_list_keys`
+const TREE_SNIPPETS: KeySection = KeySection {
+    title: "Snippets",
+    bindings: &[
+        bind("s", "Create snippet in current notebook"),
+        bind("P", "Create snippet from clipboard"),
+        bind("Ctrl+P", "Quick open a snippet by title"),
+        bind("e", "Rename snippet (title.ext)"),
+        bind("d", "Edit snippet description"),
+        bind("t", "Edit tags"),
+        bind("Ctrl+D", "Duplicate snippet"),
+        bind("y", "Copy, filling {{placeholders}}"),
+        bind("f", "Toggle favorite"),
+        bind("p", "Pin snippet to the top of the tree"),
+        bind("o", "Restore a previous version"),
+        bind("i", "Snippet info (dates, size, tags)"),
+        bind("g", "Upload to a secret GitHub Gist"),
+        bind("M", "Export notebook as Markdown"),
+        bind("l", "Ollama chat for selected snippet"),
+        bind("V", "Multi-select, Space marks"),
+    ],
+};
+
+/// `handle_notebook_list_keys`
+const TREE_VIEW: KeySection = KeySection {
+    title: "View",
+    bindings: &[
+        bind("/", "Search snippets"),
+        bind(":", "Command line (:new, :tag, :q…)"),
+        bind("a", "Cycle tree sort order"),
+        bind("L", "Filter tree by language"),
+        bind("w", "Toggle word wrap in preview"),
+        bind("r", "Refresh tree view"),
+        bind("Shift+F", "Show favorites popup"),
+        bind("T", "Browse snippets by tag"),
+        bind("A", "Recently opened snippets"),
+        bind(",", "Settings"),
+        bind("Ctrl+C", "Clear messages"),
+    ],
+};
+
+/// `handle_notebook_details_keys`
+const NOTEBOOK_DETAILS: KeySection = KeySection {
+    title: "Notebook Details",
+    bindings: &[
+        bind("s", "Create snippet in this notebook"),
+        bind("e", "Rename notebook"),
+        bind("d", "Edit notebook description"),
+        bind("c", "Change notebook color"),
+        bind("x", "Move notebook to trash"),
+        bind("/", "Search snippets"),
+        bind("Esc", "Return to notebook list"),
+    ],
+};
+
+/// `handle_notebook_view_keys`
+const NOTEBOOK_VIEW: KeySection = KeySection {
+    title: "Notebook View",
+    bindings: &[
+        bind("/", "Search snippets"),
+        bind("l", "Ollama chat for selected snippet"),
+        bind("Esc", "Return to notebook list"),
+    ],
+};
+
+/// `handle_version_history_keys`
+const VERSION_HISTORY: KeySection = KeySection {
+    title: "Version History",
+    bindings: &[
+        bind("↑/k ↓/j", "Select a version"),
+        bind("PgUp PgDn", "Scroll the preview"),
+        bind("⏎", "Restore the selected version"),
+        bind("Esc", "Return to notebook list"),
+    ],
+};
+
+/// `handle_compare_snippets_keys`
+const COMPARE_SNIPPETS: KeySection = KeySection {
+    title: "Compare Snippets",
+    bindings: &[
+        bind("↑/k ↓/j", "Scroll"),
+        bind("PgUp PgDn", "Scroll a page"),
+        bind("s", "Swap sides"),
+        bind("Esc", "Return to notebook list"),
+    ],
+};
+
+/// `handle_settings_keys`
+const SETTINGS: KeySection = KeySection {
+    title: "Settings",
+    bindings: &[
+        bind("↑/k ↓/j", "Choose a theme"),
+        bind("⏎", "Apply the theme"),
+        bind("b", "Toggle backup on exit"),
+        bind("v", "Verify storage"),
+        bind("r", "Recreate missing snippet files"),
+        bind("o", "Import orphan files"),
+    ],
+};
+
+/// `handle_trash_keys`
+const TRASH: KeySection = KeySection {
+    title: "Trash",
+    bindings: &[
+        bind("↑/k ↓/j", "Move through the trash"),
+        bind("r", "Restore the selected item"),
+        bind("x / Del", "Delete the selected item for good"),
+        bind("X", "Empty the trash"),
+    ],
+};
+
+/// `handle_recent_keys`
+const RECENT: KeySection = KeySection {
+    title: "Recent Snippets",
+    bindings: &[
+        bind("↑/k ↓/j", "Move through the list"),
+        bind("⏎", "Edit the selected snippet"),
+    ],
+};
+
+/// `handle_tags_keys`
+const TAGS: KeySection = KeySection {
+    title: "Tags",
+    bindings: &[
+        bind("↑/k ↓/j", "Move through the list"),
+        bind("⏎ / → / l", "Show the snippets with the tag"),
+        bind("← / Esc", "Back to the tag list"),
+        bind("r", "Rename the tag"),
+        bind("m", "Merge into another tag"),
+    ],
+};
+
+/// `handle_statistics_keys`
+const STATISTICS: KeySection = KeySection {
+    title: "Statistics",
+    bindings: &[bind("r", "Recount the library")],
+};
+
+/// `handle_boilerplates_keys`
+const BOILERPLATES: KeySection = KeySection {
+    title: "Boilerplates",
+    bindings: &[
+        bind("↑/k ↓/j", "Move through the list"),
+        bind("⏎", "Scaffold the selected boilerplate"),
+        bind("r", "Reload the boilerplate folder"),
+    ],
+};
+
+/// `handle_export_import_keys`
+const EXPORT_IMPORT: KeySection = KeySection {
+    title: "Export / Import",
+    bindings: &[
+        bind("↑/k ↓/j", "Move through the options"),
+        bind("⏎", "Select"),
+        bind("e", "Export"),
+        bind("f", "Import from a file"),
+        bind("c", "Import from the clipboard"),
+        bind("g", "Import from a GitHub Gist"),
+        bind("Esc", "Go back"),
+    ],
+};
+
+/// Sections for the screen being shown, most specific first and the global keys last
+pub fn sections_for(
+    state: &AppState,
+    code_snippets_state: &CodeSnippetsState,
+) -> Vec<&'static KeySection> {
+    let mut sections = match state {
+        AppState::StartPage => vec![&START_PAGE],
+        AppState::CodeSnippets => match code_snippets_state {
+            CodeSnippetsState::NotebookDetails { .. } => vec![&NOTEBOOK_DETAILS],
+            CodeSnippetsState::NotebookView { .. } => vec![&NOTEBOOK_VIEW],
+            CodeSnippetsState::VersionHistory { .. } => vec![&VERSION_HISTORY],
+            CodeSnippetsState::CompareSnippets { .. } => vec![&COMPARE_SNIPPETS],
+            CodeSnippetsState::Settings => vec![&SETTINGS],
+            _ => vec![
+                &TREE_NAVIGATION,
+                &TREE_SNIPPETS,
+                &TREE_NOTEBOOKS,
+                &TREE_VIEW,
+            ],
+        },
+        AppState::Settings => vec![&SETTINGS, &PAGE],
+        AppState::Trash => vec![&TRASH, &PAGE],
+        AppState::Recent => vec![&RECENT, &PAGE],
+        AppState::Tags => vec![&TAGS, &PAGE],
+        AppState::Statistics => vec![&STATISTICS, &PAGE],
+        AppState::Boilerplates => vec![&BOILERPLATES, &PAGE],
+        AppState::ExportImport => vec![&EXPORT_IMPORT],
+        _ => vec![&PAGE],
+    };
+    sections.push(&GLOBAL);
+    sections
+}
@@ -1,14 +1,20 @@
-use crate::app::{App, AppState, CodeSnippetsState, InputMode, RecentSearchEntry, TreeItem};
-use crate::handlers::ollama;
+use crate::app::{
+    App, AppState, BoilerplatePrompt, CodeSnippetsState, InputMode, PendingTemplate,
+    RECENT_SNIPPET_LIMIT, RecentSearchEntry, TagPrompt, TreeItem,
+};
+use crate::handlers::{command, ollama, quick_open, text_input, transfer};
 use crate::models::SnippetLanguage;
 use crate::models::export::ExportFormat;
 use crate::ui::backup_restore;
-use crate::ui::colors::RosePine;
+use crate::ui::colors::{Theme, palette};
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
+/// Columns the preview moves per Left/Right press while word wrap is off
+const HORIZONTAL_SCROLL_STEP: usize = 8;
+
 struct NavigationHandler;
 
 impl NavigationHandler {
@@ -91,34 +97,76 @@ impl SearchHandler {
     /// Handle search query input and execution
     fn handle_search_input(key: KeyEvent, app: &mut App) -> bool {
         match key.code {
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Self::toggle_regex_mode(app);
+                true
+            }
             KeyCode::Char(c) => {
-                app.search_query.push(c);
-                let query = app.search_query.clone();
-                let count = app.perform_search(&query);
-                app.set_success_message(format!("Found {} results for '{}'", count, query));
-                app.needs_redraw = true;
+                text_input::insert(&mut app.search_query, &mut app.input_cursor, &c.to_string());
+                Self::rerun_search(app);
                 true
             }
             KeyCode::Backspace => {
-                if !app.search_query.is_empty() {
-                    app.search_query.pop();
-                    if app.search_query.is_empty() {
-                        app.search_results.clear();
-                        app.selected_search_result = 0;
-                        app.set_success_message("Type to search".to_string());
-                    } else {
-                        let query = app.search_query.clone();
-                        let count = app.perform_search(&query);
-                        app.set_success_message(format!("Found {} results for '{}'", count, query));
-                    }
+                if text_input::backspace(&mut app.search_query, &mut app.input_cursor) {
+                    Self::rerun_search(app);
+                }
+                true
+            }
+            _ => {
+                let before = app.search_query.len();
+                if !text_input::handle_editing_key(
+                    key,
+                    &mut app.search_query,
+                    &mut app.input_cursor,
+                ) {
+                    return false;
+                }
+                if app.search_query.len() != before {
+                    Self::rerun_search(app);
                 }
                 app.needs_redraw = true;
                 true
             }
-            _ => false,
         }
     }
 
+    /// Searches again after the query was edited
+    fn rerun_search(app: &mut App) {
+        if app.search_query.is_empty() {
+            app.search_results.clear();
+            app.selected_search_result = 0;
+            app.set_success_message("Type to search".to_string());
+        } else {
+            app.clear_messages();
+            // The result count is in the results title, so only errors get a toast
+            let query = app.search_query.clone();
+            app.perform_search(&query);
+        }
+        app.needs_redraw = true;
+    }
+
+    /// Switch between fuzzy and regex search, re-running the current query
+    fn toggle_regex_mode(app: &mut App) {
+        app.regex_search = !app.regex_search;
+        let mode = if app.regex_search { "Regex" } else { "Fuzzy" };
+
+        if app.search_query.is_empty() {
+            app.set_success_message(format!("{} search enabled", mode));
+        } else {
+            app.clear_messages();
+            let query = app.search_query.clone();
+            let checkpoint = app.toasts.checkpoint();
+            let count = app.perform_search(&query);
+            if !app.toasts.error_since(checkpoint) {
+                app.set_success_message(format!(
+                    "{} search enabled - found {} results for '{}'",
+                    mode, count, query
+                ));
+            }
+        }
+        app.needs_redraw = true;
+    }
+
     /// Close search mode and save recent search if applicable
     fn close_search_mode(app: &mut App) {
         app.input_mode = InputMode::Normal;
@@ -152,40 +200,54 @@ impl OllamaHandler {
     /// Initialize and open Ollama chat for a snippet
     fn open_snippet_chat(app: &mut App, snippet_id: uuid::Uuid) -> bool {
         if let Some(snippet) = app.snippet_database.snippets.get(&snippet_id) {
+            let database = &app.snippet_database;
             // Initialize Ollama state if needed
             if app.ollama_state.is_none() {
-                app.ollama_state = Some(crate::ui::ollama::OllamaState::new());
+                app.ollama_state =
+                    Some(crate::ui::ollama::OllamaState::from_settings(&app.settings));
             }
 
             if let Some(ollama_state) = &mut app.ollama_state {
-                // Generate snippet hash to check for existing sessions
-                use std::collections::hash_map::DefaultHasher;
-                use std::hash::{Hash, Hasher};
-
-                let mut hasher = DefaultHasher::new();
-                snippet.content.hash(&mut hasher);
-                let snippet_hash = format!("{:x}", hasher.finish());
+                ollama_state.remember_scroll_position();
+                let snippet_hash = crate::ui::ollama::snippet_content_hash(&snippet.content);
 
-                // Check for existing sessions with this snippet
+                // Check for existing sessions this snippet was attached to
                 let mut existing_sessions: Vec<_> = ollama_state
                     .saved_sessions
                     .iter()
-                    .filter(|session| session.snippet_hash.as_ref() == Some(&snippet_hash))
+                    .filter(|session| session.involves_snippet(&snippet_hash))
                     .collect();
 
                 // Sort by updated_at (most recent first)
                 existing_sessions.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
 
-                // Set current snippet for context
-                ollama_state.current_snippet = Some(snippet.content.clone());
+                // Attach this snippet for context, first so it leads the panel and prompt
+                ollama_state.attached_snippets =
+                    vec![crate::ui::ollama::AttachedSnippet::from_snippet(snippet)];
+                ollama_state.snippet_picker = None;
+                ollama_state.snippet_panel_scroll = 0;
 
                 if let Some(most_recent_session) = existing_sessions.first() {
                     let session_to_load = (*most_recent_session).clone();
 
+                    // Re-attach the other snippets the chat was about
+                    ollama_state.attached_snippets.extend(
+                        database
+                            .snippets
+                            .values()
+                            .filter(|other| other.id != snippet_id)
+                            .filter(|other| {
+                                let hash = crate::ui::ollama::snippet_content_hash(&other.content);
+                                session_to_load.snippet_hashes.contains(&hash)
+                            })
+                            .map(crate::ui::ollama::AttachedSnippet::from_snippet),
+                    );
+
                     ollama_state.conversation = session_to_load.conversation.clone();
                     ollama_state.current_session = Some(session_to_load.clone());
                     ollama_state.system_prompt = session_to_load.system_prompt.clone();
                     ollama_state.system_prompt_buffer = session_to_load.system_prompt.clone();
+                    ollama_state.params = session_to_load.params.clone();
                     ollama_state.unsaved_changes = false;
 
                     // Set the last assistant response for copy functionality
@@ -196,9 +258,9 @@ impl OllamaHandler {
                         .find(|msg| msg.role == crate::ui::ollama::ChatRole::Assistant)
                         .map(|msg| msg.content.clone());
 
-                    // Switch to chat panel and scroll to bottom
+                    // Switch to chat panel where the chat was left
                     ollama_state.active_panel = crate::ui::ollama::ActivePanel::CurrentChat;
-                    ollama_state.scroll_to_bottom();
+                    ollama_state.restore_scroll_position();
 
                     ollama_state.add_success_toast(format!(
                         "Loaded existing chat: {} ({} messages)",
@@ -209,17 +271,15 @@ impl OllamaHandler {
                     // No existing sessions - create new chat
                     ollama_state.conversation.clear();
                     ollama_state.scroll_position = 0;
+                    ollama_state.follow_output = true;
                     ollama_state.unsaved_changes = false;
                     ollama_state.last_assistant_response = None;
 
                     // Create enhanced system prompt with snippet context
-                    let enhanced_system_prompt = ollama::create_snippet_system_prompt(
-                        &snippet.language.to_string(),
-                        &snippet.title,
-                        &snippet.content,
-                    );
-
-                    ollama_state.system_prompt = enhanced_system_prompt;
+                    if !ollama_state.apply_prompt_template() {
+                        ollama_state.system_prompt =
+                            ollama::create_snippet_system_prompt(&ollama_state.attached_snippets);
+                    }
 
                     let snippet_info = format!(
                         " Working with {} snippet: '{}'",
@@ -274,6 +334,8 @@ impl InputHandler {
     /// Handle common escape behavior across input modes
     fn handle_escape(app: &mut App, clear_input: bool) {
         app.input_mode = InputMode::Normal;
+        app.pending_template = None;
+        app.pending_paste = None;
         if clear_input {
             app.input_buffer.clear();
             app.pending_snippet_title.clear();
@@ -302,18 +364,58 @@ impl InputHandler {
 }
 
 /// Common clipboard operations
-struct ClipboardHandler;
+pub(crate) struct ClipboardHandler;
 
 impl ClipboardHandler {
-    /// Copy text to clipboard using available utilities
-    fn copy_to_clipboard(content: &str) -> bool {
-        let commands = [
+    /// Clipboard utilities to try, ordered so the most likely tool for the
+    /// current platform comes first
+    fn clipboard_commands() -> Vec<(&'static str, Vec<&'static str>)> {
+        let linux = vec![
             ("xclip", vec!["-selection", "clipboard"]),
             ("wl-copy", vec![]),
             ("termux-clipboard-set", vec![]),
         ];
+        let macos = vec![("pbcopy", vec![])];
+        let windows = vec![
+            ("clip", vec![]),
+            (
+                "powershell",
+                vec!["-NoProfile", "-Command", "$input | Set-Clipboard"],
+            ),
+        ];
+
+        Self::for_platform(linux, macos, windows)
+    }
 
-        for (cmd, args) in &commands {
+    /// Utilities that print the clipboard, in the same platform order
+    fn paste_commands() -> Vec<(&'static str, Vec<&'static str>)> {
+        let linux = vec![
+            ("xclip", vec!["-selection", "clipboard", "-o"]),
+            ("wl-paste", vec!["--no-newline"]),
+            ("termux-clipboard-get", vec![]),
+        ];
+        let macos = vec![("pbpaste", vec![])];
+        let windows = vec![(
+            "powershell",
+            vec!["-NoProfile", "-Command", "Get-Clipboard -Raw"],
+        )];
+
+        Self::for_platform(linux, macos, windows)
+    }
+
+    fn for_platform<T: Clone>(linux: Vec<T>, macos: Vec<T>, windows: Vec<T>) -> Vec<T> {
+        if cfg!(target_os = "macos") {
+            [macos, linux, windows].concat()
+        } else if cfg!(target_os = "windows") {
+            [windows, macos, linux].concat()
+        } else {
+            [linux, macos, windows].concat()
+        }
+    }
+
+    /// Copy text to clipboard using available utilities
+    pub(crate) fn copy_to_clipboard(content: &str) -> bool {
+        for (cmd, args) in Self::clipboard_commands() {
             if let Ok(mut process) = Command::new(cmd).args(args).stdin(Stdio::piped()).spawn() {
                 if let Some(stdin) = process.stdin.as_mut() {
                     if stdin.write_all(content.as_bytes()).is_ok() {
@@ -324,14 +426,30 @@ impl ClipboardHandler {
         }
         false
     }
+
+    /// Read text from the clipboard, `None` when no utility could read it
+    pub(crate) fn read_from_clipboard() -> Option<String> {
+        Self::paste_commands().into_iter().find_map(|(cmd, args)| {
+            let output = Command::new(cmd)
+                .args(args)
+                .stdin(Stdio::null())
+                .stderr(Stdio::null())
+                .output()
+                .ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            String::from_utf8(output.stdout).ok()
+        })
+    }
 }
 
 /// Language detection and parsing utilities
-struct LanguageDetector;
+pub(crate) struct LanguageDetector;
 
 impl LanguageDetector {
     /// Parse title and language from input string with file extension
-    fn parse_title_and_language(input: &str) -> (String, SnippetLanguage) {
+    pub(crate) fn parse_title_and_language(input: &str) -> (String, SnippetLanguage) {
         if input.contains('.') {
             let parts: Vec<&str> = input.rsplitn(2, '.').collect();
             let extension = parts[0].to_lowercase();
@@ -374,13 +492,152 @@ impl LanguageDetector {
             "toml" => SnippetLanguage::Toml,
             "ini" => SnippetLanguage::Ini,
             "conf" | "config" => SnippetLanguage::Config,
+            "lua" => SnippetLanguage::Lua,
+            "zig" => SnippetLanguage::Zig,
+            "ex" | "exs" => SnippetLanguage::Elixir,
+            "hs" | "lhs" => SnippetLanguage::Haskell,
+            "scala" | "sc" => SnippetLanguage::Scala,
+            "r" => SnippetLanguage::R,
+            "pl" | "pm" => SnippetLanguage::Perl,
+            "nix" => SnippetLanguage::Nix,
+            "graphql" | "gql" => SnippetLanguage::GraphQL,
+            "vue" => SnippetLanguage::Vue,
+            "svelte" => SnippetLanguage::Svelte,
             _ => SnippetLanguage::Text,
         }
     }
 }
 
-/// Main keyboard event handler and dispatcher
+/// Main keyboard event handler; returns true once the app should exit
 pub fn handle_key_events(key: KeyEvent, app: &mut App) -> bool {
+    if app.storage_error.is_some() {
+        return handle_storage_error_keys(key, app);
+    }
+
+    if app.show_quit_prompt {
+        return handle_quit_prompt_keys(key, app);
+    }
+
+    // Only the resize hint is on screen, so other keys would act on a page nobody sees
+    if app.terminal_too_small {
+        return key.code == KeyCode::Char('q') && app.confirm_quit();
+    }
+
+    // A field that opens or closes starts with the cursor at the end of its text
+    let focus = text_input::focused_field(app);
+    let quit = dispatch_key_event(key, app) && app.confirm_quit();
+    if text_input::focused_field(app) != focus {
+        app.input_cursor = 0;
+    }
+    quit
+}
+
+/// Adds bracketed-paste text to the focused text field in one go
+///
+/// Text fields are single-line and submit on Enter, so line breaks become spaces.
+pub fn handle_paste_event(text: &str, app: &mut App) {
+    use crate::ui::export_import::ExportImportState;
+
+    if app.storage_error.is_some()
+        || app.show_quit_prompt
+        || app.terminal_too_small
+        || app.transfer_job.is_some()
+    {
+        return;
+    }
+    let text = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if text.is_empty() {
+        return;
+    }
+
+    if app
+        .ollama_state
+        .as_ref()
+        .is_some_and(|state| state.show_popup)
+    {
+        ollama::handle_ollama_paste(app, &text);
+        return;
+    }
+
+    let takes_text = match (&app.state, &app.input_mode) {
+        (AppState::CodeSnippets, InputMode::Search) => {
+            text_input::insert(&mut app.search_query, &mut app.input_cursor, &text);
+            SearchHandler::rerun_search(app);
+            return;
+        }
+        (
+            AppState::CodeSnippets,
+            InputMode::Normal
+            | InputMode::HelpMenu
+            | InputMode::SelectLanguage
+            | InputMode::SelectNotebookColor,
+        ) => false,
+        (AppState::CodeSnippets, _) => true,
+        (AppState::Boilerplates, _) => app.boilerplate_prompt.is_some(),
+        (AppState::Tags, _) => app.tag_prompt.is_some(),
+        (AppState::ExportImport, _) => app
+            .export_import_state
+            .as_ref()
+            .is_some_and(ExportImportState::takes_text),
+        _ => false,
+    };
+    if takes_text {
+        text_input::insert(&mut app.input_buffer, &mut app.input_cursor, &text);
+        app.selected_tag_suggestion = 0;
+        app.quick_open_selected = 0;
+        app.command_history_index = None;
+    }
+}
+
+/// Handles the save/discard/cancel choice shown when quitting with unsaved work
+fn handle_quit_prompt_keys(key: KeyEvent, app: &mut App) -> bool {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.show_quit_prompt = false;
+            let saved = app
+                .ollama_state
+                .as_mut()
+                .map(ollama::save_current_session)
+                .unwrap_or(Ok(()));
+            match saved {
+                Ok(()) => true,
+                Err(e) => {
+                    app.set_error_message(format!("Failed to save chat session: {}", e));
+                    false
+                }
+            }
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') => true,
+        KeyCode::Esc => {
+            app.show_quit_prompt = false;
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Handles the quit/continue choice shown when the storage folder cannot be used
+fn handle_storage_error_keys(key: KeyEvent, app: &mut App) -> bool {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => true,
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            app.storage_error = None;
+            app.set_error_message(
+                "Running without storage: nothing will be saved when snix exits".to_string(),
+            );
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Routes a key to the popup, input mode or page that should receive it
+fn dispatch_key_event(key: KeyEvent, app: &mut App) -> bool {
     // Handle Ollama popup if it's active
     if let Some(ollama_state) = &app.ollama_state {
         if ollama_state.show_popup {
@@ -391,6 +648,29 @@ pub fn handle_key_events(key: KeyEvent, app: &mut App) -> bool {
         }
     }
 
+    // Other keys wait for the transfer; leaving or quitting cancels it first
+    if app.transfer_job.is_some() {
+        return match key.code {
+            KeyCode::Esc => {
+                transfer::cancel_transfer(app);
+                false
+            }
+            KeyCode::Char('q') => {
+                transfer::cancel_transfer(app);
+                true
+            }
+            _ => false,
+        };
+    }
+
+    // The help menu swallows keys until it is closed
+    if app.input_mode == InputMode::HelpMenu {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('?')) {
+            app.input_mode = InputMode::Normal;
+        }
+        return false;
+    }
+
     // Handle special input modes first
     if app.input_mode == InputMode::SelectNotebookColor {
         return handle_notebook_color_selection(key, app);
@@ -401,7 +681,31 @@ pub fn handle_key_events(key: KeyEvent, app: &mut App) -> bool {
         return handle_input_mode_keys(key, app);
     }
 
+    // The scaffold prompt takes typed text, so global shortcuts must not fire
+    if app.state == AppState::Boilerplates && app.boilerplate_prompt.is_some() {
+        return handle_boilerplates_keys(key, app);
+    }
+
+    if app.state == AppState::Tags && app.tag_prompt.is_some() {
+        return handle_tags_keys(key, app);
+    }
+
+    if app.state == AppState::ExportImport
+        && app
+            .export_import_state
+            .as_ref()
+            .is_some_and(|state| state.takes_text())
+    {
+        return handle_export_import_keys(key, app);
+    }
+
     match key.code {
+        // Quick snippet - works from any page
+        KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            start_quick_snippet(app);
+            false
+        }
+
         // Global quit command - works from any page
         KeyCode::Char('q') | KeyCode::Char('Q') => {
             if app.state == AppState::StartPage || app.state != AppState::CodeSnippets {
@@ -423,16 +727,6 @@ pub fn handle_key_events(key: KeyEvent, app: &mut App) -> bool {
 
         // Global back navigation
         KeyCode::Backspace => {
-            // Don't trigger back navigation when in import path popup
-            if let (AppState::ExportImport, Some(export_state)) =
-                (&app.state, &app.export_import_state)
-            {
-                if export_state.mode == crate::ui::export_import::ExportImportMode::ImportPathPopup
-                {
-                    return handle_export_import_keys(key, app);
-                }
-            }
-
             if app.can_go_back() {
                 app.go_back();
             }
@@ -444,11 +738,405 @@ pub fn handle_key_events(key: KeyEvent, app: &mut App) -> bool {
             AppState::StartPage => handle_start_page_keys(key, app),
             AppState::CodeSnippets => handle_code_snippets_keys(key, app),
             AppState::ExportImport => handle_export_import_keys(key, app),
+            AppState::Trash => handle_trash_keys(key, app),
+            AppState::Settings => handle_settings_keys(key, app),
+            AppState::Boilerplates => handle_boilerplates_keys(key, app),
+            AppState::Statistics => handle_statistics_keys(key, app),
+            AppState::Tags => handle_tags_keys(key, app),
+            AppState::Recent => handle_recent_keys(key, app),
             _ => handle_other_page_keys(key, app),
         },
     }
 }
 
+/// Handles keys for the statistics page
+fn handle_statistics_keys(key: KeyEvent, app: &mut App) -> bool {
+    match key.code {
+        KeyCode::Char('r') => {
+            app.refresh_library_stats();
+            false
+        }
+        _ => handle_other_page_keys(key, app),
+    }
+}
+
+/// Handles keys for the tag management page
+fn handle_tags_keys(key: KeyEvent, app: &mut App) -> bool {
+    if let Some(prompt) = app.tag_prompt {
+        match key.code {
+            KeyCode::Esc => {
+                app.tag_prompt = None;
+                app.input_buffer.clear();
+            }
+            KeyCode::Enter => {
+                let input = app.input_buffer.trim().to_string();
+                if input.is_empty() {
+                    return false;
+                }
+                app.tag_prompt = None;
+                app.input_buffer.clear();
+
+                let (TagPrompt::Rename(tag_id) | TagPrompt::Merge(tag_id)) = prompt;
+                let Some(name) = app.tag_manager.tags.get(&tag_id).map(|t| t.name.clone()) else {
+                    return false;
+                };
+                let result = match prompt {
+                    TagPrompt::Rename(_) => app.rename_tag(&name, &input),
+                    TagPrompt::Merge(_) => app.merge_tags(&name, &input),
+                };
+                if let Err(e) = result {
+                    app.set_error_message(e);
+                }
+            }
+            _ => {
+                text_input::edit_input_buffer(key, app);
+            }
+        }
+        return false;
+    }
+
+    let selected = app.sorted_tags().get(app.selected_tag).map(|tag| tag.id);
+    let tagged: Vec<uuid::Uuid> = selected
+        .map(|tag_id| {
+            app.snippets_with_tag(tag_id)
+                .iter()
+                .map(|snippet| snippet.id)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if app.tag_snippets_focused {
+        return match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                app.clear_messages();
+                app.selected_tagged_snippet = app.selected_tagged_snippet.saturating_sub(1);
+                false
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                app.clear_messages();
+                if app.selected_tagged_snippet + 1 < tagged.len() {
+                    app.selected_tagged_snippet += 1;
+                }
+                false
+            }
+            KeyCode::Enter if app.error_message.is_none() && app.success_message.is_none() => {
+                if let Some(snippet_id) = tagged.get(app.selected_tagged_snippet).copied() {
+                    launch_external_editor(app, snippet_id);
+                }
+                false
+            }
+            KeyCode::Left | KeyCode::Esc => {
+                app.clear_messages();
+                app.tag_snippets_focused = false;
+                false
+            }
+            _ => handle_other_page_keys(key, app),
+        };
+    }
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.clear_messages();
+            app.selected_tag = app.selected_tag.saturating_sub(1);
+            app.selected_tagged_snippet = 0;
+            false
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.clear_messages();
+            if app.selected_tag + 1 < app.tag_manager.tags.len() {
+                app.selected_tag += 1;
+                app.selected_tagged_snippet = 0;
+            }
+            false
+        }
+
+        // Move into the snippets carrying the selected tag
+        KeyCode::Enter | KeyCode::Right | KeyCode::Char('l')
+            if app.error_message.is_none() && app.success_message.is_none() =>
+        {
+            if !tagged.is_empty() {
+                app.tag_snippets_focused = true;
+            }
+            false
+        }
+
+        // Typing the name of another tag merges the two
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.clear_messages();
+            if let Some(tag_id) = selected {
+                app.input_buffer = app.tag_manager.tags[&tag_id].name.clone();
+                app.tag_prompt = Some(TagPrompt::Rename(tag_id));
+            }
+            false
+        }
+
+        KeyCode::Char('m') | KeyCode::Char('M') => {
+            app.clear_messages();
+            if let Some(tag_id) = selected {
+                app.input_buffer.clear();
+                app.tag_prompt = Some(TagPrompt::Merge(tag_id));
+            }
+            false
+        }
+
+        _ => handle_other_page_keys(key, app),
+    }
+}
+
+/// Handles keys for the settings page
+fn handle_settings_keys(key: KeyEvent, app: &mut App) -> bool {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.selected_theme = app.selected_theme.saturating_sub(1);
+            false
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_theme + 1 < Theme::ALL.len() {
+                app.selected_theme += 1;
+            }
+            false
+        }
+
+        // Apply the highlighted theme
+        KeyCode::Enter if app.error_message.is_none() && app.success_message.is_none() => {
+            let theme = Theme::ALL[app.selected_theme.min(Theme::ALL.len() - 1)];
+            if let Err(e) = app.apply_theme(theme) {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        KeyCode::Char('b') => {
+            if let Err(e) = app.toggle_backup_on_exit() {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        KeyCode::Char('v') => {
+            if let Err(e) = app.verify_storage() {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        KeyCode::Char('r') => {
+            if let Err(e) = app.recreate_missing_files() {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        KeyCode::Char('o') => {
+            if let Err(e) = app.import_orphan_files() {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        _ => handle_other_page_keys(key, app),
+    }
+}
+
+/// Handles keys for the boilerplates page and its scaffold prompt
+fn handle_boilerplates_keys(key: KeyEvent, app: &mut App) -> bool {
+    if app.boilerplate_prompt.is_some() {
+        match key.code {
+            KeyCode::Esc => {
+                app.boilerplate_prompt = None;
+                app.input_buffer.clear();
+            }
+            KeyCode::Enter => {
+                let input = app.input_buffer.trim().to_string();
+                app.input_buffer.clear();
+                answer_boilerplate_prompt(app, input);
+            }
+            _ => {
+                text_input::edit_input_buffer(key, app);
+            }
+        }
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.clear_messages();
+            app.selected_boilerplate = app.selected_boilerplate.saturating_sub(1);
+            false
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.clear_messages();
+            if app.selected_boilerplate + 1 < app.boilerplates.len() {
+                app.selected_boilerplate += 1;
+            }
+            false
+        }
+
+        // Ask where to scaffold the selected boilerplate
+        KeyCode::Enter if app.error_message.is_none() && app.success_message.is_none() => {
+            if let Some(boilerplate) = app.boilerplates.get(app.selected_boilerplate) {
+                let folder: String = boilerplate
+                    .name
+                    .to_lowercase()
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '-' })
+                    .collect();
+                app.input_buffer = format!("./{}", folder);
+                app.boilerplate_prompt = Some(BoilerplatePrompt::default());
+            }
+            false
+        }
+
+        // Re-read the boilerplate folder
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.clear_messages();
+            let checkpoint = app.toasts.checkpoint();
+            app.reload_boilerplates();
+            if !app.toasts.error_since(checkpoint) {
+                app.set_success_message(format!("Loaded {} boilerplates", app.boilerplates.len()));
+            }
+            false
+        }
+
+        _ => handle_other_page_keys(key, app),
+    }
+}
+
+/// Records one prompt answer, scaffolding once the directory and every variable are known
+fn answer_boilerplate_prompt(app: &mut App, input: String) {
+    let Some(mut prompt) = app.boilerplate_prompt.take() else {
+        return;
+    };
+
+    match prompt.target {
+        None => {
+            if input.is_empty() {
+                app.boilerplate_prompt = Some(prompt);
+                return;
+            }
+            let target = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+                Some((rest, home)) => home.join(rest),
+                None => PathBuf::from(&input),
+            };
+
+            prompt.values = app.template_defaults();
+            if let Some(project) = target.file_name().and_then(|name| name.to_str()) {
+                prompt
+                    .values
+                    .insert("project".to_string(), project.to_string());
+            }
+            prompt.missing = app
+                .boilerplates
+                .get(app.selected_boilerplate)
+                .map(|boilerplate| boilerplate.template_variables())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|name| !prompt.values.contains_key(name))
+                .collect();
+            prompt.target = Some(target);
+        }
+        Some(_) => {
+            if !prompt.missing.is_empty() {
+                let name = prompt.missing.remove(0);
+                prompt.values.insert(name, input);
+            }
+        }
+    }
+
+    if !prompt.missing.is_empty() {
+        app.boilerplate_prompt = Some(prompt);
+        return;
+    }
+
+    if let Some(target) = &prompt.target {
+        match app.scaffold_boilerplate(target, &prompt.values) {
+            Ok(count) => {
+                app.set_success_message(format!("Created {} files in {}", count, target.display()));
+            }
+            Err(e) => {
+                app.set_error_message(e);
+            }
+        }
+    }
+}
+
+/// Handles keys for the trash page
+fn handle_trash_keys(key: KeyEvent, app: &mut App) -> bool {
+    if app.has_pending_action() {
+        match key.code {
+            KeyCode::Enter => {
+                app.confirm_pending_action();
+            }
+            KeyCode::Esc => {
+                app.cancel_pending_action();
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.clear_messages();
+            app.selected_trash_item = app.selected_trash_item.saturating_sub(1);
+            false
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.clear_messages();
+            if app.selected_trash_item + 1 < app.trash.len() {
+                app.selected_trash_item += 1;
+            }
+            false
+        }
+
+        // Restore the selected item
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            let index = app.selected_trash_item;
+            if let Err(e) = app.restore_trash_item(index) {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        // Permanently delete the selected item
+        KeyCode::Char('x') | KeyCode::Delete => {
+            if let Some(entry) = app.trash.get(app.selected_trash_item) {
+                let index = app.selected_trash_item;
+                app.set_pending_action(
+                    format!("Permanently delete '{}'?", entry.name()),
+                    Box::new(move |app: &mut App| {
+                        if let Err(e) = app.purge_trash_item(index) {
+                            app.set_error_message(e);
+                        }
+                    }),
+                );
+            }
+            false
+        }
+
+        // Empty the whole trash
+        KeyCode::Char('X') => {
+            if !app.trash.is_empty() {
+                app.set_pending_action(
+                    format!("Permanently delete all {} items in trash?", app.trash.len()),
+                    Box::new(|app: &mut App| {
+                        if let Err(e) = app.empty_trash() {
+                            app.set_error_message(e);
+                        }
+                    }),
+                );
+            }
+            false
+        }
+
+        _ => handle_other_page_keys(key, app),
+    }
+}
+
 /// Handle notebook color selection input mode
 fn handle_notebook_color_selection(key: KeyEvent, app: &mut App) -> bool {
     match key.code {
@@ -490,6 +1178,14 @@ fn handle_notebook_color_selection(key: KeyEvent, app: &mut App) -> bool {
 
 /// Handles keyboard input for input mode in code snippets
 fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
+    if app.input_mode == InputMode::Command {
+        return command::handle_command_mode_keys(key, app);
+    }
+
+    if app.input_mode == InputMode::QuickOpen {
+        return quick_open::handle_quick_open_keys(key, app);
+    }
+
     // Special case for search mode - direct character input to search query
     if app.input_mode == InputMode::Search {
         match key.code {
@@ -504,6 +1200,8 @@ fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
                     app.open_selected_search_result();
                     app.input_mode = InputMode::Normal;
                     app.search_query.clear();
+                } else if !app.search_query.trim().is_empty() {
+                    create_snippet_from_search(app);
                 } else {
                     app.set_success_message("No results to select".to_string());
                 }
@@ -594,12 +1292,25 @@ fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
                             let (title, language) =
                                 LanguageDetector::parse_title_and_language(&input);
 
+                            let pasted = app.pending_paste.take();
                             if let Some(notebook_id) = get_current_notebook_id(app) {
-                                match app.create_snippet(title, language, notebook_id) {
+                                let is_paste = pasted.is_some();
+                                let result = match pasted {
+                                    Some(content) => app.create_snippet_with_content(
+                                        title,
+                                        language,
+                                        notebook_id,
+                                        content,
+                                    ),
+                                    None => app.create_snippet(title, language, notebook_id),
+                                };
+                                match result {
                                     Ok(_snippet_id) => {
-                                        app.set_success_message(
-                                            "Snippet created successfully!".to_string(),
-                                        );
+                                        app.set_success_message(if is_paste {
+                                            "Snippet created from clipboard".to_string()
+                                        } else {
+                                            "Snippet created successfully!".to_string()
+                                        });
                                         app.code_snippets_state = CodeSnippetsState::NotebookList;
                                         app.refresh_tree_items();
                                     }
@@ -614,17 +1325,24 @@ fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
                             app.input_mode = InputMode::Normal;
                         } else {
                             app.input_mode = InputMode::Normal;
+                            app.pending_paste = None;
                             app.code_snippets_state = CodeSnippetsState::NotebookList;
                             app.clear_messages();
                         }
                     }
                     InputMode::SelectLanguage => {
-                        // This shouldn't happen with Enter, language selection uses different keys
                         app.input_mode = InputMode::Normal;
-                        app.pending_snippet_title.clear();
                         app.clear_messages();
 
-                        app.code_snippets_state = CodeSnippetsState::NotebookList;
+                        let language = app.filter_languages().get(app.selected_language).cloned();
+                        if let Some(language) = language {
+                            let name = language.display_name().to_string();
+                            app.set_language_filter(Some(language));
+                            app.set_success_message(format!(
+                                "Language filter: {} (Esc clears)",
+                                name
+                            ));
+                        }
                     }
                     InputMode::Search => {
                         // When Enter is pressed in search input mode, treat it as confirmation
@@ -678,6 +1396,108 @@ fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
                         app.input_mode = InputMode::Normal;
                         app.pending_snippet_title.clear();
                     }
+                    InputMode::EditSnippetEditor => {
+                        app.input_mode = InputMode::Normal;
+                        if let Some(TreeItem::Snippet(snippet_id, _)) =
+                            app.get_selected_item().cloned()
+                        {
+                            match app.set_snippet_editor(snippet_id, &input) {
+                                Ok(()) if input.is_empty() => app.set_success_message(
+                                    "Snippet opens in the default editor again".to_string(),
+                                ),
+                                Ok(()) => {
+                                    app.set_success_message(format!("Snippet opens with {}", input))
+                                }
+                                Err(e) => app.set_error_message(e),
+                            }
+                        } else {
+                            app.set_error_message("Snippet selection lost".to_string());
+                        }
+                    }
+                    InputMode::RenameSnippet => {
+                        if let Some(TreeItem::Snippet(snippet_id, _)) =
+                            app.get_selected_item().cloned()
+                        {
+                            if input.is_empty() {
+                                app.clear_messages();
+                            } else {
+                                // Keep the current language unless a new extension was typed
+                                let (title, language) = if input.contains('.') {
+                                    LanguageDetector::parse_title_and_language(&input)
+                                } else {
+                                    let language = app
+                                        .snippet_database
+                                        .snippets
+                                        .get(&snippet_id)
+                                        .map(|s| s.language.clone())
+                                        .unwrap_or(SnippetLanguage::Text);
+                                    (input, language)
+                                };
+
+                                match app.rename_snippet(snippet_id, title, language) {
+                                    Ok(_) => {
+                                        app.set_success_message(
+                                            "Snippet renamed successfully".to_string(),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        app.set_error_message(e);
+                                    }
+                                }
+                            }
+                        } else {
+                            app.set_error_message("Snippet selection lost".to_string());
+                        }
+                        app.input_mode = InputMode::Normal;
+                    }
+                    InputMode::ExportSelection => {
+                        app.input_mode = InputMode::Normal;
+                        if !input.is_empty() {
+                            let path = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+                                Some((rest, home)) => home.join(rest),
+                                None => PathBuf::from(&input),
+                            };
+                            match app.export_marked_snippets(&path) {
+                                Ok(n) => {
+                                    app.set_success_message(format!(
+                                        "Exported {} snippets to {}",
+                                        n,
+                                        path.display()
+                                    ));
+                                }
+                                Err(e) => {
+                                    app.set_error_message(e);
+                                }
+                            }
+                        }
+                    }
+                    InputMode::ExportNotebookMarkdown => {
+                        app.input_mode = InputMode::Normal;
+                        if let Some(TreeItem::Notebook(notebook_id, _)) =
+                            app.get_selected_item().cloned()
+                        {
+                            if !input.is_empty() {
+                                let path = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+                                    Some((rest, home)) => home.join(rest),
+                                    None => PathBuf::from(&input),
+                                };
+                                match app.export_notebook_markdown(notebook_id, &path) {
+                                    Ok(n) => {
+                                        app.set_success_message(format!(
+                                            "Exported {} snippets to {}",
+                                            n,
+                                            path.display()
+                                        ));
+                                    }
+                                    Err(e) => {
+                                        app.set_error_message(e);
+                                    }
+                                }
+                            }
+                        } else {
+                            app.set_error_message("Notebook selection lost".to_string());
+                        }
+                    }
                     InputMode::EditNotebookDescription => {
                         if let Some(notebook_id) = app.current_notebook_id {
                             match app.update_notebook_description(notebook_id, input) {
@@ -698,45 +1518,70 @@ fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
                     InputMode::SelectNotebookColor => {
                         app.input_mode = InputMode::Normal;
                     }
-                    InputMode::EditTags => {
-                        // Handle Enter to save tags
-                        let input_text = input.clone();
-
-                        // First get the snippet ID without borrowing
-                        let snippet_id_opt =
-                            if let Some(TreeItem::Snippet(id, _)) = app.get_selected_item() {
-                                Some(*id)
-                            } else {
-                                None
-                            };
-
-                        // Now process with the ID without holding a borrow across function calls
-                        if let Some(snippet_id) = snippet_id_opt {
-                            // First update the snippet
-                            if let Some(snippet) =
-                                app.snippet_database.snippets.get_mut(&snippet_id)
-                            {
-                                // Clear existing tags before setting new ones
-                                snippet.tags.clear();
-                                snippet.set_tags_from_text(&input_text);
-
-                                // Get a copy of the tags to avoid borrowing issues
-                                let tags: Vec<String> = snippet.tags.clone();
-
-                                // Now update the tag manager with the cloned tags
-                                for tag_name in tags {
-                                    app.tag_manager.add_tag_to_snippet(snippet_id, tag_name);
+                    InputMode::CloneNotebook => {
+                        app.input_mode = InputMode::Normal;
+                        let with_snippets = app.clone_with_snippets;
+                        match app.current_notebook_id {
+                            Some(notebook_id) => {
+                                match app.clone_notebook(notebook_id, input, with_snippets) {
+                                    Ok(clone_id) => {
+                                        app.set_success_message(if with_snippets {
+                                            "Notebook cloned with its snippets".to_string()
+                                        } else {
+                                            "Notebook structure cloned".to_string()
+                                        });
+                                        app.code_snippets_state =
+                                            CodeSnippetsState::NotebookDetails {
+                                                notebook_id: clone_id,
+                                            };
+                                    }
+                                    Err(e) => app.set_error_message(e),
                                 }
+                            }
+                            None => app.set_error_message("No notebook selected".to_string()),
+                        }
+                    }
+                    InputMode::FillTemplate => {
+                        app.input_mode = InputMode::Normal;
+                        if let Some(mut pending) = app.pending_template.take() {
+                            if !pending.missing.is_empty() {
+                                let name = pending.missing.remove(0);
+                                pending.values.insert(name, input);
+                            }
 
-                                app.set_success_message("Tags updated".to_string());
+                            if pending.missing.is_empty() {
+                                copy_filled_template(app, &pending);
                             } else {
-                                app.set_error_message("Snippet not found".to_string());
+                                app.pending_template = Some(pending);
+                                app.input_mode = InputMode::FillTemplate;
+                            }
+                        }
+                    }
+                    InputMode::EditTags => {
+                        if let Some(TreeItem::Snippet(snippet_id, _)) =
+                            app.get_selected_item().cloned()
+                        {
+                            match app.set_snippet_tags(snippet_id, &input) {
+                                Ok(()) => app.set_success_message("Tags updated".to_string()),
+                                Err(e) => app.set_error_message(e),
                             }
                         }
 
                         // Always return to normal mode even if no snippet was found
                         app.input_mode = InputMode::Normal;
                     }
+                    InputMode::BulkTagNotebook => {
+                        app.input_mode = InputMode::Normal;
+                        match app.get_selected_item().cloned() {
+                            _ if input.trim().is_empty() => {}
+                            Some(TreeItem::Notebook(notebook_id, _)) => {
+                                if let Err(e) = app.bulk_tag_notebook(notebook_id, &input) {
+                                    app.set_error_message(e);
+                                }
+                            }
+                            _ => app.set_error_message("Notebook selection lost".to_string()),
+                        }
+                    }
                     _ => {
                         app.input_mode = InputMode::Normal;
                         app.clear_messages();
@@ -745,71 +1590,76 @@ fn handle_input_mode_keys(key: KeyEvent, app: &mut App) -> bool {
                 false
             }
             KeyCode::Backspace => {
-                if !app.input_buffer.is_empty() {
-                    app.input_buffer.pop();
+                text_input::backspace(&mut app.input_buffer, &mut app.input_cursor);
+                app.selected_tag_suggestion = 0;
+                false
+            }
+            KeyCode::Tab if app.input_mode == InputMode::EditTags => {
+                app.complete_tag_suggestion();
+                false
+            }
+            KeyCode::Tab if app.input_mode == InputMode::CloneNotebook => {
+                app.clone_with_snippets = !app.clone_with_snippets;
+                false
+            }
+            KeyCode::Up | KeyCode::Down if app.input_mode == InputMode::EditTags => {
+                let count = app.tag_suggestions().len();
+                if count > 0 {
+                    InputHandler::handle_selection_navigation(
+                        key,
+                        &mut app.selected_tag_suggestion,
+                        count,
+                    );
                 }
                 false
             }
             KeyCode::Up | KeyCode::Down | KeyCode::Char('k') | KeyCode::Char('j')
                 if app.input_mode == InputMode::SelectLanguage =>
             {
-                let languages = get_available_languages();
-                InputHandler::handle_selection_navigation(
-                    key,
-                    &mut app.selected_language,
-                    languages.len(),
-                );
+                let count = app.filter_languages().len();
+                if count > 0 {
+                    InputHandler::handle_selection_navigation(
+                        key,
+                        &mut app.selected_language,
+                        count,
+                    );
+                }
                 false
             }
             KeyCode::Char(c) => {
                 if app.input_mode != InputMode::SelectLanguage
                     && app.input_mode != InputMode::SelectNotebookColor
                 {
-                    app.input_buffer.push(c);
+                    text_input::insert(
+                        &mut app.input_buffer,
+                        &mut app.input_cursor,
+                        &c.to_string(),
+                    );
+                    app.selected_tag_suggestion = 0;
+                }
+                false
+            }
+            _ => {
+                if text_input::handle_editing_key(key, &mut app.input_buffer, &mut app.input_cursor)
+                {
+                    app.selected_tag_suggestion = 0;
                 }
                 false
             }
-            _ => false,
         }
     }
 }
 
-fn get_available_languages() -> Vec<SnippetLanguage> {
-    vec![
-        SnippetLanguage::Rust,
-        SnippetLanguage::JavaScript,
-        SnippetLanguage::TypeScript,
-        SnippetLanguage::Python,
-        SnippetLanguage::Go,
-        SnippetLanguage::Java,
-        SnippetLanguage::C,
-        SnippetLanguage::Cpp,
-        SnippetLanguage::CSharp,
-        SnippetLanguage::PHP,
-        SnippetLanguage::Ruby,
-        SnippetLanguage::Swift,
-        SnippetLanguage::Kotlin,
-        SnippetLanguage::Dart,
-        SnippetLanguage::HTML,
-        SnippetLanguage::CSS,
-        SnippetLanguage::SCSS,
-        SnippetLanguage::SQL,
-        SnippetLanguage::Bash,
-        SnippetLanguage::PowerShell,
-        SnippetLanguage::Yaml,
-        SnippetLanguage::Json,
-        SnippetLanguage::Xml,
-        SnippetLanguage::Markdown,
-        SnippetLanguage::Dockerfile,
-        SnippetLanguage::Toml,
-        SnippetLanguage::Ini,
-        SnippetLanguage::Config,
-        SnippetLanguage::Text,
-    ]
-}
-
 // Handles keyboard input specifically for the code snippets page
 fn handle_code_snippets_keys(key: KeyEvent, app: &mut App) -> bool {
+    if key.code == KeyCode::Char('p')
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+        && !app.has_pending_action()
+    {
+        quick_open::enter_quick_open(app);
+        return false;
+    }
+
     match app.code_snippets_state {
         CodeSnippetsState::NotebookList => handle_notebook_list_keys(key, app),
         CodeSnippetsState::NotebookView { notebook_id } => {
@@ -822,11 +1672,42 @@ fn handle_code_snippets_keys(key: KeyEvent, app: &mut App) -> bool {
             handle_snippet_editor_keys(key, app, snippet_id)
         }
         CodeSnippetsState::SearchSnippets => handle_search_keys(key, app),
+        CodeSnippetsState::Settings if key.code == KeyCode::Esc => {
+            app.clear_messages();
+            app.code_snippets_state = CodeSnippetsState::NotebookList;
+            false
+        }
+        CodeSnippetsState::Settings => handle_settings_keys(key, app),
+        CodeSnippetsState::VersionHistory { snippet_id } => {
+            handle_version_history_keys(key, app, snippet_id)
+        }
+        CodeSnippetsState::CompareSnippets { left, right } => {
+            handle_compare_snippets_keys(key, app, left, right)
+        }
         _ => handle_other_snippets_keys(key, app),
     }
 }
 
 // Handles keys for the main notebook list view
+/// Collects label keys while jump labels are shown; any key that matches no label hides them
+fn handle_jump_label_keys(key: KeyEvent, app: &mut App) {
+    let Some(mut typed) = app.jump_input.take() else {
+        return;
+    };
+    app.needs_redraw = true;
+    let KeyCode::Char(c) = key.code else {
+        return;
+    };
+
+    typed.push(c);
+    let targets = app.jump_targets();
+    if let Some((_, index)) = targets.iter().find(|(label, _)| *label == typed) {
+        app.select_tree_item(*index);
+    } else if targets.iter().any(|(label, _)| label.starts_with(&typed)) {
+        app.jump_input = Some(typed);
+    }
+}
+
 fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
     if app.input_mode == InputMode::Search {
         return handle_search_keys(key, app);
@@ -837,6 +1718,12 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         return false;
     }
 
+    // The info overlay follows the selection, so only the closing keys are captured
+    if app.show_snippet_info && matches!(key.code, KeyCode::Esc | KeyCode::Char('i')) {
+        app.show_snippet_info = false;
+        return false;
+    }
+
     if app.has_pending_action() {
         match key.code {
             KeyCode::Enter => {
@@ -851,6 +1738,11 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         }
     }
 
+    if app.jump_input.is_some() {
+        handle_jump_label_keys(key, app);
+        return false;
+    }
+
     if key.code == KeyCode::Enter && (app.error_message.is_some() || app.success_message.is_some())
     {
         app.clear_messages();
@@ -872,11 +1764,29 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         }
     }
 
+    if key.code == KeyCode::Char('V') {
+        app.clear_messages();
+        app.toggle_multi_select_mode();
+        return false;
+    }
+
+    if key.code == KeyCode::Char('\'') && !app.tree_items.is_empty() {
+        app.clear_messages();
+        app.jump_input = Some(String::new());
+        return false;
+    }
+
+    if app.multi_select_mode && handle_multi_select_keys(key, app) {
+        return false;
+    }
+
     match key.code {
         // Handle Shift + Up for moving notebook up in hierarchy
         KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
             app.clear_messages();
+            let undo = app.move_undo_point();
             if app.move_notebook_up() {
+                app.undo_action = Some(undo);
                 app.needs_redraw = true;
             }
             false
@@ -885,7 +1795,9 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         // Handle Shift + Down for moving notebook down in hierarchy
         KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
             app.clear_messages();
+            let undo = app.move_undo_point();
             if app.move_notebook_down() {
+                app.undo_action = Some(undo);
                 app.needs_redraw = true;
             }
             false
@@ -912,7 +1824,11 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         }
 
         KeyCode::PageDown => {
-            app.content_scroll_position = app.content_scroll_position.saturating_add(5);
+            // Stop at the end of the preview so PageUp moves back straight away
+            app.content_scroll_position = app
+                .content_scroll_position
+                .saturating_add(5)
+                .min(app.preview_scroll_limit.get());
             app.needs_redraw = true;
             false
         }
@@ -1014,6 +1930,34 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
+        // Create a snippet from the clipboard, asking only for its title
+        KeyCode::Char('P') => {
+            app.clear_messages();
+            if app.snippet_database.notebooks.is_empty() {
+                app.set_error_message("Create a notebook first".to_string());
+                return false;
+            }
+
+            match ClipboardHandler::read_from_clipboard() {
+                None => app.set_error_message(
+                    "Could not read the clipboard (install xclip, wl-clipboard or pbpaste)"
+                        .to_string(),
+                ),
+                Some(content) if content.trim().is_empty() => {
+                    app.set_error_message("Clipboard is empty".to_string())
+                }
+                Some(content) => {
+                    let notebook_id = get_current_notebook_id(app)
+                        .unwrap_or_else(|| app.snippet_database.root_notebooks[0]);
+                    app.pending_paste = Some(content);
+                    app.input_mode = InputMode::CreateSnippet;
+                    app.input_buffer.clear();
+                    app.code_snippets_state = CodeSnippetsState::CreateSnippet { notebook_id };
+                }
+            }
+            false
+        }
+
         // Create new snippet (in current notebook or first available)
         KeyCode::Char('s') | KeyCode::Char('S') => {
             app.clear_messages();
@@ -1035,42 +1979,7 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
 
         // Delete selected item (notebook or snippet)
         KeyCode::Char('x') | KeyCode::Char('X') => {
-            app.clear_messages();
-            if let Some(selected_item) = app.get_selected_item().cloned() {
-                match selected_item {
-                    TreeItem::Notebook(notebook_id, _) => {
-                        // Check if this notebook has snippets or nested notebooks
-                        let has_snippets = app
-                            .snippet_database
-                            .snippets
-                            .values()
-                            .any(|s| s.notebook_id == notebook_id);
-
-                        let has_children = app
-                            .snippet_database
-                            .notebooks
-                            .values()
-                            .any(|n| n.parent_id == Some(notebook_id));
-
-                        if has_snippets || has_children {
-                            app.set_error_message(
-                                "Cannot delete a notebook that contains snippets or other notebooks"
-                                    .to_string(),
-                            );
-                            return false;
-                        }
-
-                        // Request confirmation for deletion
-                        app.request_delete_confirmation(notebook_id, true);
-                    }
-                    TreeItem::Snippet(snippet_id, _) => {
-                        // Request confirmation for deletion
-                        app.request_delete_confirmation(snippet_id, false);
-                    }
-                }
-            } else {
-                app.set_error_message("No item selected".to_string());
-            }
+            app.request_delete_selected();
             false
         }
 
@@ -1096,6 +2005,29 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
+        // Vim-style command line
+        KeyCode::Char(':') => {
+            command::enter_command_mode(app);
+            false
+        }
+
+        // Pick a language to narrow the tree to
+        KeyCode::Char('L') => {
+            app.clear_messages();
+            let languages = app.filter_languages();
+            if languages.is_empty() {
+                app.set_error_message("No snippets to filter yet".to_string());
+            } else {
+                app.selected_language = app
+                    .language_filter
+                    .as_ref()
+                    .and_then(|current| languages.iter().position(|l| l == current))
+                    .unwrap_or(0);
+                app.input_mode = InputMode::SelectLanguage;
+            }
+            false
+        }
+
         // Show floating favorites window with Shift+F
         KeyCode::Char('F') | KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::SHIFT) => {
             app.show_favorites_popup = !app.show_favorites_popup;
@@ -1109,6 +2041,14 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
+        // Clear the language filter before leaving the page
+        KeyCode::Esc if app.language_filter.is_some() => {
+            app.clear_messages();
+            app.set_language_filter(None);
+            app.set_success_message("Language filter cleared".to_string());
+            false
+        }
+
         // Back/Escape
         KeyCode::Esc => {
             app.clear_messages();
@@ -1129,22 +2069,97 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         // Clear messages manually
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.clear_messages();
+            app.toasts.clear();
             false
         }
 
         // Copy snippet to clipboard
         KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item().cloned() {
+                start_template_copy(app, snippet_id);
+            } else {
+                app.set_error_message("No snippet selected".to_string());
+            }
+            false
+        }
+
+        // Export the selected notebook and its descendants as Markdown
+        KeyCode::Char('M') => {
+            app.clear_messages();
+            if let Some(TreeItem::Notebook(notebook_id, _)) = app.get_selected_item().cloned() {
+                if let Some(notebook) = app.snippet_database.notebooks.get(&notebook_id) {
+                    app.input_buffer = format!("{}.md", notebook.name.replace(['/', '\\'], "_"));
+                    app.input_mode = InputMode::ExportNotebookMarkdown;
+                }
+            } else {
+                app.set_error_message("Select a notebook first".to_string());
+            }
+            false
+        }
+
+        // Upload the selected snippet to a new secret Gist
+        KeyCode::Char('g') => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item().cloned() {
+                match app.push_snippet_to_gist(snippet_id) {
+                    Ok(url) => {
+                        if ClipboardHandler::copy_to_clipboard(&url) {
+                            app.set_success_message(format!("Gist created: {} (link copied)", url));
+                        } else {
+                            app.set_success_message(format!("Gist created: {}", url));
+                        }
+                    }
+                    Err(e) => app.set_error_message(e),
+                }
+            } else {
+                app.set_error_message("Select a snippet first".to_string());
+            }
+            false
+        }
+
+        // Set the editor command used for this snippet only
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.clear_messages();
             if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item() {
                 if let Some(snippet) = app.snippet_database.snippets.get(snippet_id) {
-                    if ClipboardHandler::copy_to_clipboard(&snippet.content) {
-                        app.set_success_message(format!("'{}' copied to clipboard", snippet.title));
-                    } else {
-                        app.set_error_message("Failed to copy to clipboard (xclip, wl-copy, or termux-clipboard-set required)".to_string());
-                    }
+                    app.input_mode = InputMode::EditSnippetEditor;
+                    app.input_buffer = snippet.editor_override.clone().unwrap_or_default();
+                } else {
+                    app.set_error_message("Snippet not found".to_string());
                 }
             } else {
-                app.set_error_message("No snippet selected".to_string());
+                app.set_error_message("Select a snippet first".to_string());
+            }
+            false
+        }
+
+        // Rename snippet (title and extension)
+        KeyCode::Char('e') | KeyCode::Char('E') => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item() {
+                if let Some(snippet) = app.snippet_database.snippets.get(snippet_id) {
+                    app.input_mode = InputMode::RenameSnippet;
+                    app.input_buffer = format!("{}.{}", snippet.title, snippet.file_extension);
+                } else {
+                    app.set_error_message("Snippet not found".to_string());
+                }
+            } else {
+                app.set_error_message("Select a snippet first".to_string());
+            }
+            false
+        }
+
+        // Duplicate the selected snippet
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item().cloned() {
+                match app.duplicate_snippet(snippet_id) {
+                    Ok(_) => app.set_success_message("Snippet duplicated".to_string()),
+                    Err(e) => app.set_error_message(e),
+                }
+            } else {
+                app.set_error_message("Select a snippet first".to_string());
             }
             false
         }
@@ -1167,8 +2182,19 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
+        // Show created/updated/accessed times and size of the selected snippet
+        KeyCode::Char('i') => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(_, _)) = app.get_selected_item() {
+                app.show_snippet_info = true;
+            } else {
+                app.set_error_message("Select a snippet first".to_string());
+            }
+            false
+        }
+
         // View notebook details with 'v' key
-        KeyCode::Char('v') | KeyCode::Char('V') => {
+        KeyCode::Char('v') => {
             app.clear_messages();
             if let Some(TreeItem::Notebook(notebook_id, _)) = app.get_selected_item().cloned() {
                 app.current_notebook_id = Some(notebook_id);
@@ -1180,11 +2206,23 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
-        // Move item to next sibling (Shift+Right)
-        KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+        // Move item to next sibling (Shift+Right)
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            app.clear_messages();
+            let undo = app.move_undo_point();
+            if app.move_item_to_next_sibling() {
+                app.undo_action = Some(undo);
+                app.needs_redraw = true;
+            }
+            false
+        }
+
+        // Undo the last delete or move
+        KeyCode::Char('u') => {
             app.clear_messages();
-            if app.move_item_to_next_sibling() {
-                app.needs_redraw = true;
+            match app.undo_last_action() {
+                Ok(label) => app.set_success_message(format!("Undid: {}", label)),
+                Err(e) => app.set_error_message(e),
             }
             false
         }
@@ -1192,12 +2230,31 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         // Move item to previous sibling (Shift+Left)
         KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
             app.clear_messages();
+            let undo = app.move_undo_point();
             if app.move_item_to_prev_sibling() {
+                app.undo_action = Some(undo);
                 app.needs_redraw = true;
             }
             false
         }
 
+        // Scroll the preview sideways while word wrap is off
+        KeyCode::Left if !app.settings.preview_wrap => {
+            app.horizontal_scroll = app
+                .horizontal_scroll
+                .min(app.preview_column_limit.get())
+                .saturating_sub(HORIZONTAL_SCROLL_STEP);
+            app.needs_redraw = true;
+            false
+        }
+
+        KeyCode::Right if !app.settings.preview_wrap => {
+            app.horizontal_scroll = (app.horizontal_scroll + HORIZONTAL_SCROLL_STEP)
+                .min(app.preview_column_limit.get());
+            app.needs_redraw = true;
+            false
+        }
+
         // Set tag editing mode
         KeyCode::Char('t') => {
             app.clear_messages();
@@ -1207,11 +2264,16 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
                     // Set input buffer to current tags
                     app.input_buffer = snippet.get_tags_display_string();
                     app.input_mode = InputMode::EditTags;
+                    app.selected_tag_suggestion = 0;
                     // Clear any messages to ensure the full tag editing UI is visible
                     app.clear_messages();
                 } else {
                     app.set_error_message("Snippet not found".to_string());
                 }
+            } else if let Some(TreeItem::Notebook(_, _)) = app.get_selected_item() {
+                // On a notebook, tag every snippet inside it at once
+                app.input_buffer.clear();
+                app.input_mode = InputMode::BulkTagNotebook;
             } else {
                 app.set_error_message("Select a snippet first".to_string());
             }
@@ -1221,13 +2283,76 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
         // Toggle favorite status for the current snippet
         KeyCode::Char('f') => {
             if app.input_mode == InputMode::Normal {
-                if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item() {
-                    if let Err(e) = app.toggle_favorite_snippet(*snippet_id) {
-                        app.set_error_message(e);
+                let result = match app.get_selected_item().cloned() {
+                    Some(TreeItem::Snippet(snippet_id, _)) => {
+                        app.toggle_favorite_snippet(snippet_id)
                     }
-                } else {
-                    app.set_error_message("Select a snippet to mark as favorite".to_string());
+                    Some(TreeItem::Notebook(notebook_id, _)) => {
+                        app.toggle_favorite_notebook(notebook_id)
+                    }
+                    None => Err("Select a snippet or notebook to mark as favorite".to_string()),
+                };
+                if let Err(e) = result {
+                    app.set_error_message(e);
+                }
+            }
+            false
+        }
+
+        // Browse previous versions of the selected snippet
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item().cloned() {
+                if let Err(e) = app.open_version_history(snippet_id) {
+                    app.set_error_message(e);
+                }
+            } else {
+                app.set_error_message("Select a snippet first".to_string());
+            }
+            false
+        }
+
+        // Pin the selected snippet to the top of the tree
+        KeyCode::Char('p') => {
+            app.clear_messages();
+            if let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item().cloned() {
+                if let Err(e) = app.toggle_pinned_snippet(snippet_id) {
+                    app.set_error_message(e);
                 }
+            } else {
+                app.set_error_message("Select a snippet to pin".to_string());
+            }
+            false
+        }
+
+        // Rename and merge tags across all snippets
+        KeyCode::Char('T') => {
+            app.clear_messages();
+            app.navigate_to(AppState::Tags);
+            false
+        }
+
+        // Recently opened snippets
+        KeyCode::Char('A') => {
+            app.clear_messages();
+            app.navigate_to(AppState::Recent);
+            false
+        }
+
+        // Cycle the order of notebooks and snippets in the tree
+        KeyCode::Char('a') => {
+            app.clear_messages();
+            if let Err(e) = app.cycle_tree_sort() {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        // Toggle soft word-wrap in the preview
+        KeyCode::Char('w') => {
+            app.clear_messages();
+            if let Err(e) = app.toggle_preview_wrap() {
+                app.set_error_message(e);
             }
             false
         }
@@ -1247,6 +2372,110 @@ fn handle_notebook_list_keys(key: KeyEvent, app: &mut App) -> bool {
     }
 }
 
+/// Handles bulk actions while multi-select mode is active.
+/// Returns true when the key was consumed.
+fn handle_multi_select_keys(key: KeyEvent, app: &mut App) -> bool {
+    let count = app.marked_snippets.len();
+
+    match key.code {
+        KeyCode::Esc => {
+            app.clear_messages();
+            app.toggle_multi_select_mode();
+        }
+
+        // Mark or unmark the item under the cursor
+        KeyCode::Char(' ') => {
+            app.clear_messages();
+            if let Err(e) = app.toggle_mark_selected() {
+                app.set_error_message(e);
+            }
+        }
+
+        KeyCode::Char('x') | KeyCode::Char('X') if count > 0 => {
+            app.set_pending_action(
+                format!("Move {} snippets to trash?", count),
+                Box::new(|app: &mut App| match app.delete_marked_snippets() {
+                    Ok(n) => app.set_success_message(format!("Moved {} snippets to trash", n)),
+                    Err(e) => app.set_error_message(e),
+                }),
+            );
+        }
+
+        // Move the marked snippets into the notebook under the cursor
+        KeyCode::Char('m') | KeyCode::Char('M') if count > 0 => {
+            let target_id = match app.get_selected_item() {
+                Some(TreeItem::Notebook(id, _)) => Some(*id),
+                Some(TreeItem::Snippet(id, _)) => {
+                    app.snippet_database.snippets.get(id).map(|s| s.notebook_id)
+                }
+                None => None,
+            };
+
+            if let Some(target_id) = target_id {
+                let target_name = app
+                    .snippet_database
+                    .notebooks
+                    .get(&target_id)
+                    .map(|n| n.name.clone())
+                    .unwrap_or_default();
+
+                app.set_pending_action(
+                    format!("Move {} snippets to '{}'?", count, target_name),
+                    Box::new(
+                        move |app: &mut App| match app.move_marked_snippets(target_id) {
+                            Ok(n) => app.set_success_message(format!(
+                                "Moved {} snippets to '{}'",
+                                n, target_name
+                            )),
+                            Err(e) => app.set_error_message(e),
+                        },
+                    ),
+                );
+            } else {
+                app.set_error_message("Select a target notebook first".to_string());
+            }
+        }
+
+        KeyCode::Char('f') | KeyCode::Char('F') if count > 0 => {
+            app.clear_messages();
+            match app.toggle_favorite_marked() {
+                Ok((n, true)) => app.set_success_message(format!("Favorited {} snippets", n)),
+                Ok((n, false)) => app.set_success_message(format!("Unfavorited {} snippets", n)),
+                Err(e) => app.set_error_message(e),
+            }
+        }
+
+        KeyCode::Char('e') | KeyCode::Char('E') if count > 0 => {
+            app.clear_messages();
+            app.input_mode = InputMode::ExportSelection;
+            app.input_buffer = "snippets_selection.json".to_string();
+        }
+
+        // Diff the two marked snippets
+        KeyCode::Char('d') | KeyCode::Char('D') => {
+            app.clear_messages();
+            if let Err(e) = app.open_snippet_diff() {
+                app.set_error_message(e);
+            }
+        }
+
+        KeyCode::Char('x')
+        | KeyCode::Char('X')
+        | KeyCode::Char('m')
+        | KeyCode::Char('M')
+        | KeyCode::Char('f')
+        | KeyCode::Char('F')
+        | KeyCode::Char('e')
+        | KeyCode::Char('E') => {
+            app.set_error_message("No snippets marked (press Space to mark)".to_string());
+        }
+
+        _ => return false,
+    }
+
+    true
+}
+
 /// Handles keys for notebook view
 fn handle_notebook_view_keys(key: KeyEvent, app: &mut App, _notebook_id: uuid::Uuid) -> bool {
     // If search mode is active, handle search keys
@@ -1349,6 +2578,100 @@ fn handle_search_keys(key: KeyEvent, app: &mut App) -> bool {
     }
 }
 
+/// Handles keys for the diff of two snippets
+fn handle_compare_snippets_keys(
+    key: KeyEvent,
+    app: &mut App,
+    left: uuid::Uuid,
+    right: uuid::Uuid,
+) -> bool {
+    match key.code {
+        KeyCode::Esc => {
+            app.clear_messages();
+            app.content_scroll_position = 0;
+            app.code_snippets_state = CodeSnippetsState::NotebookList;
+        }
+
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.content_scroll_position = app.content_scroll_position.saturating_sub(1);
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.content_scroll_position = app.content_scroll_position.saturating_add(1);
+        }
+
+        KeyCode::PageUp => {
+            app.content_scroll_position = app.content_scroll_position.saturating_sub(5);
+        }
+
+        KeyCode::PageDown => {
+            app.content_scroll_position = app.content_scroll_position.saturating_add(5);
+        }
+
+        // Use the other snippet as the base
+        KeyCode::Char('s') => {
+            app.content_scroll_position = 0;
+            app.code_snippets_state = CodeSnippetsState::CompareSnippets {
+                left: right,
+                right: left,
+            };
+        }
+
+        _ => {}
+    }
+    false
+}
+
+/// Handles keys for the snippet version history view
+fn handle_version_history_keys(key: KeyEvent, app: &mut App, snippet_id: uuid::Uuid) -> bool {
+    match key.code {
+        KeyCode::Esc => {
+            app.clear_messages();
+            app.snippet_versions.clear();
+            app.content_scroll_position = 0;
+            app.code_snippets_state = CodeSnippetsState::NotebookList;
+            false
+        }
+
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_version > 0 {
+                app.selected_version -= 1;
+                app.content_scroll_position = 0;
+            }
+            false
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_version + 1 < app.snippet_versions.len() {
+                app.selected_version += 1;
+                app.content_scroll_position = 0;
+            }
+            false
+        }
+
+        KeyCode::PageUp => {
+            app.content_scroll_position = app.content_scroll_position.saturating_sub(5);
+            false
+        }
+
+        KeyCode::PageDown => {
+            app.content_scroll_position = app.content_scroll_position.saturating_add(5);
+            false
+        }
+
+        // Restore the selected version
+        KeyCode::Enter => {
+            let selected = app.selected_version;
+            if let Err(e) = app.restore_snippet_version(snippet_id, selected) {
+                app.set_error_message(e);
+            }
+            false
+        }
+
+        _ => false,
+    }
+}
+
 /// Handles keys for other snippet states
 fn handle_other_snippets_keys(key: KeyEvent, app: &mut App) -> bool {
     match key.code {
@@ -1361,7 +2684,7 @@ fn handle_other_snippets_keys(key: KeyEvent, app: &mut App) -> bool {
 }
 
 /// Get the current notebook ID for creating snippets
-fn get_current_notebook_id(app: &App) -> Option<uuid::Uuid> {
+pub(crate) fn get_current_notebook_id(app: &App) -> Option<uuid::Uuid> {
     // If we have a current notebook selected, use that
     if let Some(id) = app.current_notebook_id {
         return Some(id);
@@ -1383,62 +2706,160 @@ fn get_current_notebook_id(app: &App) -> Option<uuid::Uuid> {
     app.snippet_database.root_notebooks.first().copied()
 }
 
+/// Turns a search without results into the new snippet prompt, titled with the query
+fn create_snippet_from_search(app: &mut App) {
+    let title = app.search_query.trim().to_string();
+    SearchHandler::close_search_mode(app);
+
+    let Some(notebook_id) = get_current_notebook_id(app) else {
+        app.set_error_message("Create a notebook first".to_string());
+        return;
+    };
+
+    app.pending_paste = None;
+    app.input_buffer = title;
+    app.input_mode = InputMode::CreateSnippet;
+    app.code_snippets_state = CodeSnippetsState::CreateSnippet { notebook_id };
+}
+
 /// Launch external editor for snippet editing
+/// Copies a snippet, first asking for any template variables snix cannot fill itself
+fn start_template_copy(app: &mut App, snippet_id: uuid::Uuid) {
+    let Some(snippet) = app.snippet_database.snippets.get(&snippet_id) else {
+        return;
+    };
+
+    let values = app.known_template_values(snippet);
+    let missing: Vec<String> = snippet
+        .template_variables()
+        .into_iter()
+        .filter(|name| !values.contains_key(name))
+        .collect();
+
+    let pending = PendingTemplate {
+        snippet_id,
+        values,
+        missing,
+    };
+    if pending.missing.is_empty() {
+        copy_filled_template(app, &pending);
+    } else {
+        app.pending_template = Some(pending);
+        app.input_mode = InputMode::FillTemplate;
+    }
+}
+
+fn copy_filled_template(app: &mut App, pending: &PendingTemplate) {
+    let Some(snippet) = app.snippet_database.snippets.get(&pending.snippet_id) else {
+        app.set_error_message("Snippet not found".to_string());
+        return;
+    };
+
+    if ClipboardHandler::copy_to_clipboard(&snippet.fill_template(&pending.values)) {
+        app.set_success_message(format!("'{}' copied to clipboard", snippet.title));
+    } else {
+        app.set_error_message(
+            "Failed to copy to clipboard (no clipboard utility found)".to_string(),
+        );
+    }
+}
+
 pub fn launch_external_editor(app: &mut App, snippet_id: uuid::Uuid) {
     // Set flag to indicate a full UI redraw will be needed after editor use
     app.needs_redraw = true;
 
-    if let Some(snippet) = app.snippet_database.snippets.get(&snippet_id) {
-        if let Some(ref storage) = app.storage_manager {
-            let file_path = storage.get_snippet_file_path(snippet);
+    if let Some(snippet) = app.snippet_database.snippets.get(&snippet_id)
+        && let Some(ref storage) = app.storage_manager
+    {
+        let file_path = storage.get_snippet_file_path(snippet);
+        let breadcrumb = format!(
+            "{} › {}",
+            app.notebook_path(snippet.notebook_id),
+            snippet.title
+        );
+
+        if let Err(e) = storage.save_snippet_content(snippet) {
+            app.set_error_message(format!("Failed to prepare file for editing: {}", e));
+            return;
+        }
 
-            if let Err(e) = storage.save_snippet_content(snippet) {
-                app.set_error_message(format!("Failed to prepare file for editing: {}", e));
-                return;
-            }
+        let editor_override = snippet.editor_override.as_deref();
+        if let Err(e) = suspend_tui_for_editor(&file_path, &breadcrumb, editor_override) {
+            app.set_error_message(format!("Failed to launch editor: {}", e));
+            return;
+        }
 
-            if let Err(e) = suspend_tui_for_editor(&file_path) {
-                app.set_error_message(format!("Failed to launch editor: {}", e));
-                return;
-            }
+        if let Ok(content) =
+            storage.load_snippet_content(snippet.id, snippet.notebook_id, &snippet.file_extension)
+            && let Some(snippet) = app.snippet_database.snippets.get_mut(&snippet_id)
+        {
+            // A snippet created without an extension gets a language once it has content
+            let detected =
+                if snippet.language == SnippetLanguage::Text && snippet.content.trim().is_empty() {
+                    SnippetLanguage::detect_from_content(&content)
+                        .map(|language| (snippet.title.clone(), language))
+                } else {
+                    None
+                };
 
-            if let Ok(content) = storage.load_snippet_content(
-                snippet.id,
-                snippet.notebook_id,
-                &snippet.file_extension,
-            ) {
-                if let Some(snippet) = app.snippet_database.snippets.get_mut(&snippet_id) {
-                    snippet.update_content(content);
+            // Keep the previous content around so it can be restored later
+            let archive_error = if snippet.content != content {
+                storage.archive_snippet_version(snippet).err()
+            } else {
+                None
+            };
+
+            snippet.update_content(content);
 
-                    if let Err(e) = storage.save_snippet_content(snippet) {
-                        app.set_error_message(format!("Failed to save snippet: {}", e));
+            if let Err(e) = storage.save_snippet_content(snippet) {
+                app.set_error_message(format!("Failed to save snippet: {}", e));
+            } else {
+                if let Err(e) = app.save_database() {
+                    app.set_error_message(format!("Failed to save database: {}", e));
+                } else {
+                    if let Some(e) = archive_error {
+                        app.set_error_message(format!(
+                            "Snippet saved, but the previous version was not archived: {}",
+                            e
+                        ));
                     } else {
-                        if let Err(e) = app.save_database() {
-                            app.set_error_message(format!("Failed to save database: {}", e));
-                        } else {
-                            app.set_success_message("Snippet saved successfully!".to_string());
+                        app.set_success_message("Snippet saved successfully!".to_string());
+                    }
 
-                            app.code_snippets_state = CodeSnippetsState::NotebookList;
-                            app.refresh_tree_items();
+                    if let Some((title, language)) = detected {
+                        let name = language.display_name().to_string();
+                        match app.rename_snippet(snippet_id, title, language) {
+                            Ok(()) => app.set_success_message(format!(
+                                "Snippet saved and detected as {}",
+                                name
+                            )),
+                            Err(e) => app.set_error_message(e),
                         }
                     }
+
+                    app.code_snippets_state = CodeSnippetsState::NotebookList;
+                    app.refresh_tree_items();
                 }
             }
         }
     }
 }
 
-/// Properly suspend TUI and launch external editor
-fn suspend_tui_for_editor(file_path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+/// Properly suspend TUI and launch external editor, naming the snippet being edited
+fn suspend_tui_for_editor(
+    file_path: &std::path::Path,
+    breadcrumb: &str,
+    editor_override: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
     use ratatui::crossterm::{
+        event::{DisableBracketedPaste, EnableBracketedPaste},
         execute,
         terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
     };
     use std::io::{Write, stdout};
-    use std::process::Command;
 
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen)?;
+    execute!(stdout(), LeaveAlternateScreen, DisableBracketedPaste)?;
 
     // Clear the terminal completely using the most thorough approach
     print!("\x1B[!p"); // Soft reset (DEC)
@@ -1446,27 +2867,15 @@ fn suspend_tui_for_editor(file_path: &std::path::Path) -> Result<(), Box<dyn std
     print!("\x1B[2J"); // Clear entire screen
     print!("\x1B[H"); // Move cursor to home position
     print!("\x1B[?25h"); // Show cursor
+    println!("Editing {}", breadcrumb);
     stdout().flush()?;
 
-    // Try to launch editors in order of preference
-    let editors = ["nvim", "vim", "nano"];
-    let mut editor_launched = false;
-
-    for editor in &editors {
-        if let Ok(mut child) = Command::new(editor).arg(file_path).spawn() {
-            if let Ok(_) = child.wait() {
-                editor_launched = true;
-                break;
-            }
-        }
-    }
-
-    if !editor_launched {
-        println!("Could not launch any editor (nvim, vim, nano)");
+    if let Err(e) = run_editor(file_path, editor_override) {
+        println!("{}", e);
         println!("Press Enter to continue...");
         let mut buffer = String::new();
         std::io::stdin().read_line(&mut buffer)?;
-        return Err("Could not launch any editor".into());
+        return Err(e);
     }
 
     println!("\nReturning to snix...");
@@ -1481,9 +2890,9 @@ fn suspend_tui_for_editor(file_path: &std::path::Path) -> Result<(), Box<dyn std
     print!("\x1B[H"); // Move cursor to home position
     stdout().flush()?;
 
-    // Restore the terminal UI state
+    // Restore the terminal UI state; the reset above also turned bracketed paste off
     enable_raw_mode()?;
-    execute!(stdout(), EnterAlternateScreen)?;
+    execute!(stdout(), EnterAlternateScreen, EnableBracketedPaste)?;
 
     // Final screen initialization
     print!("\x1B[?1049h"); // Ensure alternate screen buffer is active
@@ -1495,6 +2904,69 @@ fn suspend_tui_for_editor(file_path: &std::path::Path) -> Result<(), Box<dyn std
     Ok(())
 }
 
+/// Opens the file in the first editor that launches and waits for it to exit.
+/// A snippet's own editor command, if it has one, is tried before the others.
+pub(crate) fn run_editor(
+    file_path: &std::path::Path,
+    editor_override: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = file_path.to_string_lossy();
+    for editor in editor_candidates(editor_override) {
+        let Some((program, args)) = editor.split_first() else {
+            continue;
+        };
+
+        // `{file}` puts the path inside the arguments, otherwise it goes last
+        let mut command = Command::new(program);
+        if args.iter().any(|arg| arg.contains(FILE_PLACEHOLDER)) {
+            command.args(args.iter().map(|arg| arg.replace(FILE_PLACEHOLDER, &path)));
+        } else {
+            command.args(args).arg(file_path);
+        }
+
+        let finished = command.spawn().and_then(|mut child| child.wait());
+        if finished.is_ok() {
+            return Ok(());
+        }
+    }
+
+    Err("Could not launch any editor ($VISUAL, $EDITOR, nvim, vim, nano)".into())
+}
+
+/// Placeholder in an editor command that is replaced by the snippet's file path
+const FILE_PLACEHOLDER: &str = "{file}";
+
+/// Builds the list of editor commands to try, honouring `$VISUAL` and `$EDITOR`
+/// before falling back to the built-in defaults
+fn editor_candidates(editor_override: Option<&str>) -> Vec<Vec<String>> {
+    let mut candidates = Vec::new();
+
+    if let Some(command) = editor_override {
+        let parts: Vec<String> = command.split_whitespace().map(String::from).collect();
+        if !parts.is_empty() {
+            candidates.push(parts);
+        }
+    }
+
+    for var in ["VISUAL", "EDITOR"] {
+        if let Ok(value) = std::env::var(var) {
+            let parts: Vec<String> = value.split_whitespace().map(String::from).collect();
+            if !parts.is_empty() && !candidates.contains(&parts) {
+                candidates.push(parts);
+            }
+        }
+    }
+
+    for fallback in ["nvim", "vim", "nano"] {
+        let parts = vec![fallback.to_string()];
+        if !candidates.contains(&parts) {
+            candidates.push(parts);
+        }
+    }
+
+    candidates
+}
+
 /// Handles keyboard input specifically for the start page (main menu)
 fn handle_start_page_keys(key: KeyEvent, app: &mut App) -> bool {
     // If backup/restore overlay is open, handle its keys first
@@ -1531,21 +3003,26 @@ fn handle_start_page_keys(key: KeyEvent, app: &mut App) -> bool {
                 0 => app.navigate_to(AppState::Boilerplates),
                 1 => app.navigate_to(AppState::Marketplace),
                 2 => app.navigate_to(AppState::CodeSnippets),
-                3 => {
+                3 => start_quick_snippet(app),
+                4 => {
                     app.navigate_to(AppState::ExportImport);
                     app.export_import_state =
                         Some(crate::ui::export_import::ExportImportState::default());
                 }
-                4 => {
+                5 => {
                     app.show_backup_restore_overlay = true;
                     if app.backup_restore_state.is_none() {
                         app.backup_restore_state =
                             Some(crate::ui::backup_restore::BackupRestoreState::default());
                     }
                 }
-                5 => app.navigate_to(AppState::InfoPage),
-                6 => app.navigate_to(AppState::Settings),
-                7 => return true, // Exit
+                6 => {
+                    app.selected_trash_item = 0;
+                    app.navigate_to(AppState::Trash);
+                }
+                7 => app.navigate_to(AppState::Statistics),
+                8 => app.navigate_to(AppState::Settings),
+                9 => return true, // Exit
                 _ => {}
             }
             false
@@ -1595,6 +3072,11 @@ fn handle_start_page_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
+        KeyCode::Char('n') => {
+            start_quick_snippet(app);
+            false
+        }
+
         KeyCode::Char('e') => {
             app.navigate_to(AppState::ExportImport);
             app.export_import_state = Some(crate::ui::export_import::ExportImportState::default());
@@ -1611,6 +3093,22 @@ fn handle_start_page_keys(key: KeyEvent, app: &mut App) -> bool {
             false
         }
 
+        KeyCode::Char('d') => {
+            app.navigate_to(AppState::Statistics);
+            false
+        }
+
+        KeyCode::Char('t') => {
+            app.selected_trash_item = 0;
+            app.navigate_to(AppState::Trash);
+            false
+        }
+
+        KeyCode::Char('r') => {
+            app.navigate_to(AppState::Recent);
+            false
+        }
+
         // Quick search functionality from start page
         KeyCode::Char('/') => {
             app.navigate_to(AppState::CodeSnippets);
@@ -1646,25 +3144,106 @@ fn handle_start_page_keys(key: KeyEvent, app: &mut App) -> bool {
                 _ => unreachable!(),
             };
 
-            let mut recent_snippets: Vec<_> = app.snippet_database.snippets.values().collect();
-            recent_snippets.sort_by(|a, b| b.accessed_at.cmp(&a.accessed_at));
+            let recent = app.recent_snippets(10);
+            if let Some(snippet_id) = recent.get(index).map(|snippet| snippet.id) {
+                app.navigate_to(AppState::CodeSnippets);
+                open_recent_snippet(app, snippet_id);
+            }
+
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Handles keys for the list of recently opened snippets
+fn handle_recent_keys(key: KeyEvent, app: &mut App) -> bool {
+    let count = app.recent_snippets(RECENT_SNIPPET_LIMIT).len();
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.clear_messages();
+            app.selected_recent_snippet = app.selected_recent_snippet.saturating_sub(1);
+            false
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.clear_messages();
+            if app.selected_recent_snippet + 1 < count {
+                app.selected_recent_snippet += 1;
+            }
+            false
+        }
+        KeyCode::Enter if app.error_message.is_none() && app.success_message.is_none() => {
+            let snippet_id = app
+                .recent_snippets(RECENT_SNIPPET_LIMIT)
+                .get(app.selected_recent_snippet)
+                .map(|snippet| snippet.id);
+            if let Some(snippet_id) = snippet_id {
+                open_recent_snippet(app, snippet_id);
+                // The opened snippet moves to the top of the list
+                app.selected_recent_snippet = 0;
+            }
+            false
+        }
+        _ => handle_other_page_keys(key, app),
+    }
+}
+
+/// Marks a snippet as opened and edits it in the external editor
+fn open_recent_snippet(app: &mut App, snippet_id: uuid::Uuid) {
+    if let Some(snippet) = app.snippet_database.snippets.get_mut(&snippet_id) {
+        snippet.mark_accessed();
+    }
+    let _ = app.save_database();
+    launch_external_editor(app, snippet_id);
+}
 
-            if index < recent_snippets.len() {
-                let snippet = &recent_snippets[index];
-                let snippet_id = snippet.id;
+/// Files a new snippet into the Inbox notebook, selects it in the tree and opens the editor
+fn start_quick_snippet(app: &mut App) {
+    let snippet_id = match app.create_quick_snippet() {
+        Ok(id) => id,
+        Err(e) => {
+            app.set_error_message(e);
+            return;
+        }
+    };
 
-                app.navigate_to(AppState::CodeSnippets);
-                if let Some(snippet) = app.snippet_database.snippets.get_mut(&snippet_id) {
-                    snippet.mark_accessed();
-                }
-                let _ = app.save_database();
-                launch_external_editor(app, snippet_id);
-            }
+    reveal_and_edit_snippet(app, snippet_id);
+}
 
-            false
+/// Shows a snippet selected in the tree, expanding the notebooks above it, and opens the editor
+pub fn reveal_and_edit_snippet(app: &mut App, snippet_id: uuid::Uuid) {
+    app.input_mode = InputMode::Normal;
+    app.navigate_to(AppState::CodeSnippets);
+    app.code_snippets_state = CodeSnippetsState::NotebookList;
+
+    let mut current = app
+        .snippet_database
+        .snippets
+        .get(&snippet_id)
+        .map(|snippet| snippet.notebook_id);
+    // Bounded so a corrupt parent cycle cannot hang the UI
+    for _ in 0..app.snippet_database.notebooks.len() {
+        let Some(notebook_id) = current else {
+            break;
+        };
+        if app.collapsed_notebooks.contains(&notebook_id) {
+            app.expand_notebook(notebook_id);
         }
-        _ => false,
+        current = app
+            .snippet_database
+            .notebooks
+            .get(&notebook_id)
+            .and_then(|notebook| notebook.parent_id);
+    }
+    if let Some(index) = app
+        .tree_items
+        .iter()
+        .position(|item| matches!(item, TreeItem::Snippet(id, _) if *id == snippet_id))
+    {
+        app.select_tree_item(index);
     }
+
+    open_recent_snippet(app, snippet_id);
 }
 
 /// Handle keyboard input for the About popup
@@ -1775,6 +3354,17 @@ fn handle_notebook_details_keys(key: KeyEvent, app: &mut App, notebook_id: uuid:
             false
         }
 
+        KeyCode::Char('k') | KeyCode::Char('K') => {
+            // Clone the notebook and its nested notebooks under a new name
+            if let Some(notebook) = app.snippet_database.notebooks.get(&notebook_id) {
+                app.input_buffer = format!("{} copy", notebook.name);
+                app.input_mode = InputMode::CloneNotebook;
+                app.current_notebook_id = Some(notebook_id);
+                app.clone_with_snippets = false;
+            }
+            false
+        }
+
         KeyCode::Char('s') | KeyCode::Char('S') => {
             // Create snippet in this notebook
             app.code_snippets_state = CodeSnippetsState::CreateSnippet { notebook_id };
@@ -1794,14 +3384,17 @@ fn handle_notebook_details_keys(key: KeyEvent, app: &mut App, notebook_id: uuid:
 
             app.set_pending_action(
                 format!(
-                    "Delete notebook \"{}\" and all its snippets?",
+                    "Move notebook \"{}\" and all its snippets to trash?",
                     notebook_name
                 ),
                 Box::new(move |app: &mut App| {
                     if let Err(e) = app.delete_notebook(notebook_id) {
                         app.set_error_message(e);
                     } else {
-                        app.set_success_message(format!("Notebook \"{}\" deleted", notebook_name));
+                        app.set_success_message(format!(
+                            "Notebook \"{}\" moved to trash",
+                            notebook_name
+                        ));
                         app.code_snippets_state = CodeSnippetsState::NotebookList;
                     }
                 }),
@@ -1832,24 +3425,24 @@ fn handle_notebook_details_keys(key: KeyEvent, app: &mut App, notebook_id: uuid:
 #[allow(dead_code)]
 fn get_available_colors() -> Vec<(&'static str, ratatui::style::Color)> {
     vec![
-        ("Default", RosePine::TEXT),
-        ("Red", RosePine::LOVE),
-        ("Orange", RosePine::GOLD),
-        ("Green", RosePine::FOAM),
-        ("Blue", RosePine::IRIS),
-        ("Purple", RosePine::IRIS),
-        ("Pink", RosePine::ROSE),
+        ("Default", palette().text),
+        ("Red", palette().love),
+        ("Orange", palette().gold),
+        ("Green", palette().foam),
+        ("Blue", palette().iris),
+        ("Purple", palette().iris),
+        ("Pink", palette().rose),
         ("White", ratatui::style::Color::White),
     ]
 }
 
 /// Handles keyboard input for the export/import page
 fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
+    use crate::models::export::ConflictResolution;
     use crate::models::{
-        import_database, import_from_clipboard, merge_import_into_database_with_tags,
+        import_from_clipboard, is_encrypted_export, merge_import_into_database_with_tags,
     };
-    use crate::ui::export_import::{ExportImportMode, ExportImportState};
-    use std::path::Path;
+    use crate::ui::export_import::{ExportImportMode, ExportImportState, ImportSource};
 
     // Get mutable reference to export/import state
     if app.export_import_state.is_none() {
@@ -1872,7 +3465,7 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     false
                 }
                 KeyCode::Down | KeyCode::Char('j') => {
-                    state.selected_option = (state.selected_option + 1).min(2);
+                    state.selected_option = (state.selected_option + 1).min(3);
                     false
                 }
                 KeyCode::Char('e') | KeyCode::Char('E') => {
@@ -1892,6 +3485,12 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     state.mode = ExportImportMode::ImportClipboard;
                     false
                 }
+                KeyCode::Char('g') | KeyCode::Char('G') => {
+                    // Import from a GitHub Gist
+                    state.mode = ExportImportMode::ImportGist;
+                    app.input_buffer.clear();
+                    false
+                }
                 KeyCode::Enter => {
                     match state.selected_option {
                         0 => {
@@ -1911,6 +3510,12 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                             state.mode = ExportImportMode::ImportClipboard;
                             false
                         }
+                        3 => {
+                            // Import from a GitHub Gist
+                            state.mode = ExportImportMode::ImportGist;
+                            app.input_buffer.clear();
+                            false
+                        }
                         _ => false,
                     }
                 }
@@ -1930,7 +3535,7 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     false
                 }
                 KeyCode::Down | KeyCode::Char('j') => {
-                    state.selected_option = (state.selected_option + 1).min(3);
+                    state.selected_option = (state.selected_option + 1).min(5);
                     false
                 }
                 KeyCode::Enter => {
@@ -1946,15 +3551,28 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                             false
                         }
                         2 => {
+                            // Toggle passphrase encryption
+                            state.encrypt = !state.encrypt;
+                            false
+                        }
+                        3 => {
+                            // Pick the tags to export
+                            state.mode = ExportImportMode::ExportTags;
+                            state.tag_cursor = 0;
+                            false
+                        }
+                        4 => {
                             // Cycle through formats
                             state.export_format = match state.export_format {
                                 ExportFormat::JSON => ExportFormat::YAML,
                                 ExportFormat::YAML => ExportFormat::TOML,
-                                ExportFormat::TOML => ExportFormat::JSON,
+                                ExportFormat::TOML => ExportFormat::CSV,
+                                ExportFormat::CSV => ExportFormat::Directory,
+                                ExportFormat::Directory => ExportFormat::JSON,
                             };
                             false
                         }
-                        3 => {
+                        5 => {
                             // Continue to path selection
                             state.mode = ExportImportMode::ExportPath;
 
@@ -1963,20 +3581,17 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                                 ExportFormat::JSON => "json",
                                 ExportFormat::YAML => "yaml",
                                 ExportFormat::TOML => "toml",
+                                ExportFormat::CSV => "csv",
+                                // Directory exports are a folder without an extension
+                                ExportFormat::Directory => "",
                             };
 
                             // Update the export path with the correct extension
-                            let path = state.export_path.clone();
-                            if let Some(file_stem) = path.file_stem() {
-                                let file_stem_str = file_stem.to_string_lossy();
-                                if let Some(parent) = path.parent() {
-                                    state.export_path =
-                                        parent.join(format!("{}.{}", file_stem_str, extension));
-                                } else {
-                                    state.export_path =
-                                        PathBuf::from(format!("{}.{}", file_stem_str, extension));
-                                }
-                            }
+                            state.export_path = export_path_with_extension(
+                                &state.export_path,
+                                extension,
+                                state.encrypt,
+                            );
 
                             app.input_buffer = state.export_path.to_string_lossy().to_string();
                             false
@@ -1992,6 +3607,33 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                 _ => false,
             }
         }
+        ExportImportMode::ExportTags => {
+            let tags = crate::ui::export_import::export_tag_names(&app.tag_manager);
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.tag_cursor = state.tag_cursor.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.tag_cursor = (state.tag_cursor + 1).min(tags.len().saturating_sub(1));
+                }
+                KeyCode::Char(' ') | KeyCode::Enter => {
+                    if let Some(tag) = tags.get(state.tag_cursor) {
+                        let before = state.export_tags.len();
+                        state.export_tags.retain(|t| !t.eq_ignore_ascii_case(tag));
+                        if state.export_tags.len() == before {
+                            state.export_tags.push(tag.clone());
+                        }
+                    }
+                }
+                KeyCode::Char('c') => state.export_tags.clear(),
+                KeyCode::Esc => {
+                    state.mode = ExportImportMode::ExportOptions;
+                    state.selected_option = 3;
+                }
+                _ => {}
+            }
+            false
+        }
         ExportImportMode::ExportPath => {
             match key.code {
                 KeyCode::Enter => {
@@ -1999,34 +3641,14 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     state.export_path = path.to_path_buf();
                     app.input_buffer.clear();
 
-                    state.mode = ExportImportMode::Exporting;
-
-                    // Create options
-                    let options = crate::models::export::ExportOptions {
-                        _format: state.export_format,
-                        include_content: state.include_content,
-                        notebook_ids: None,
-                        include_favorites_only: state.favorites_only,
-                    };
-
-                    // Export
-                    if let Err(e) = crate::models::export::export_database_with_tags(
-                        &app.snippet_database,
-                        &app.tag_manager,
-                        &state.export_path,
-                        &options,
-                    ) {
-                        state.status_message = Some(format!("Export failed: {}", e));
-                        state.is_error = true;
-                    } else {
-                        state.status_message = Some(format!(
-                            "Export successful! Saved to {}",
-                            state.export_path.display()
-                        ));
-                        state.is_error = false;
+                    // Encrypted exports ask for the passphrase before writing anything
+                    if state.encrypt {
+                        state.passphrase_for_import = false;
+                        state.mode = ExportImportMode::Passphrase;
+                        return false;
                     }
 
-                    state.mode = ExportImportMode::MainMenu;
+                    run_export(app, None);
                     false
                 }
                 KeyCode::Esc => {
@@ -2035,11 +3657,13 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     false
                 }
                 KeyCode::Tab => {
-                    // Cycle through formats: JSON -> YAML -> TOML -> JSON
+                    // Cycle through formats: JSON -> YAML -> TOML -> CSV -> Directory -> JSON
                     state.export_format = match state.export_format {
                         ExportFormat::JSON => ExportFormat::YAML,
                         ExportFormat::YAML => ExportFormat::TOML,
-                        ExportFormat::TOML => ExportFormat::JSON,
+                        ExportFormat::TOML => ExportFormat::CSV,
+                        ExportFormat::CSV => ExportFormat::Directory,
+                        ExportFormat::Directory => ExportFormat::JSON,
                     };
 
                     // Update file extension based on format
@@ -2055,39 +3679,35 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                             ExportFormat::JSON => "json",
                             ExportFormat::YAML => "yaml",
                             ExportFormat::TOML => "toml",
+                            ExportFormat::CSV => "csv",
+                            ExportFormat::Directory => "",
                         };
 
-                        app.input_buffer = if let Some(parent) = path.parent() {
-                            if parent.as_os_str().is_empty() {
-                                format!("{}.{}", file_stem, extension)
-                            } else {
-                                format!("{}/{}.{}", parent.display(), file_stem, extension)
-                            }
-                        } else {
-                            format!("{}.{}", file_stem, extension)
-                        };
+                        app.input_buffer = export_path_with_extension(
+                            &path.with_file_name(file_stem),
+                            extension,
+                            state.encrypt,
+                        )
+                        .to_string_lossy()
+                        .to_string();
                     } else {
                         // If input buffer is empty, create a default filename with correct extension
                         let filename = match state.export_format {
                             ExportFormat::JSON => "snippets_export.json",
                             ExportFormat::YAML => "snippets_export.yaml",
                             ExportFormat::TOML => "snippets_export.toml",
+                            ExportFormat::CSV => "snippets_export.csv",
+                            ExportFormat::Directory => "snippets_export",
                         };
                         app.input_buffer = filename.to_string();
+                        app.input_cursor = 0;
                     }
                     false
                 }
-                KeyCode::Char(c) => {
-                    app.input_buffer.push(c);
-                    false
-                }
-                KeyCode::Backspace => {
-                    if !app.input_buffer.is_empty() {
-                        app.input_buffer.pop();
-                    }
+                _ => {
+                    text_input::edit_input_buffer(key, app);
                     false
                 }
-                _ => false,
             }
         }
         ExportImportMode::ImportOptions => {
@@ -2097,7 +3717,7 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     false
                 }
                 KeyCode::Down | KeyCode::Char('j') => {
-                    state.selected_option = (state.selected_option + 1).min(1);
+                    state.selected_option = (state.selected_option + 1).min(3);
                     false
                 }
                 KeyCode::Enter => {
@@ -2107,8 +3727,13 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                             state.overwrite_existing = !state.overwrite_existing;
                             false
                         }
-                        1 => {
-                            // Continue to file selection
+                        1..=3 => {
+                            // Continue to file or folder selection
+                            state.import_source = match state.selected_option {
+                                1 => ImportSource::ExportFile,
+                                2 => ImportSource::SourceFolder,
+                                _ => ImportSource::VsCodeSnippets,
+                            };
                             state.mode = ExportImportMode::ImportPathPopup;
                             app.input_buffer.clear();
                             false
@@ -2148,59 +3773,30 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                             .import_path
                             .clone();
 
-                        // Take ownership of the tag manager to avoid borrow issues
-                        let mut tag_manager_clone = app.tag_manager.clone();
-
-                        match import_database(&import_path) {
-                            Ok(import_data) => {
-                                // Use the function that handles tags
-                                match merge_import_into_database_with_tags(
-                                    &mut app.snippet_database,
-                                    &mut tag_manager_clone,
-                                    import_data,
-                                    overwrite,
-                                ) {
-                                    Ok((notebooks, snippets)) => {
-                                        // Update the app's tag manager with the merged one
-                                        app.tag_manager = tag_manager_clone;
-                                        app.refresh_tree_items();
-
-                                        let save_result = app.save_database();
-
-                                        // Update the status message and mode
-                                        let state = app.export_import_state.as_mut().unwrap();
-                                        if let Err(e) = save_result {
-                                            state.status_message = Some(format!(
-                                                "Import succeeded but failed to save database: {}",
-                                                e
-                                            ));
-                                            state.is_error = true;
-                                        } else {
-                                            state.status_message = Some(format!(
-                                                "Successfully imported {} notebooks and {} snippets",
-                                                notebooks, snippets
-                                            ));
-                                            state.is_error = false;
-                                        }
-
-                                        state.mode = ExportImportMode::MainMenu;
-                                    }
-                                    Err(e) => {
-                                        let state = app.export_import_state.as_mut().unwrap();
-                                        state.status_message =
-                                            Some(format!("Failed to merge import data: {}", e));
-                                        state.is_error = true;
-                                        state.mode = ExportImportMode::MainMenu;
-                                    }
-                                }
+                        let state = app.export_import_state.as_mut().unwrap();
+                        match state.import_source {
+                            ImportSource::ExportFile => {}
+                            ImportSource::SourceFolder => {
+                                transfer::start_source_import(app, import_path);
+                                return false;
                             }
-                            Err(e) => {
-                                let state = app.export_import_state.as_mut().unwrap();
-                                state.status_message = Some(format!("Import failed: {}", e));
-                                state.is_error = true;
-                                state.mode = ExportImportMode::MainMenu;
+                            ImportSource::VsCodeSnippets => {
+                                state.notebook_cursor = 0;
+                                state.mode = ExportImportMode::ImportNotebook;
+                                app.input_buffer.clear();
+                                return false;
                             }
                         }
+
+                        if is_encrypted_export(&import_path) {
+                            let state = app.export_import_state.as_mut().unwrap();
+                            state.passphrase_for_import = true;
+                            state.mode = ExportImportMode::Passphrase;
+                            app.input_buffer.clear();
+                            return false;
+                        }
+
+                        transfer::start_file_import(app, import_path, None, overwrite);
                     }
                     false
                 }
@@ -2209,26 +3805,16 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     app.input_buffer.clear();
                     false
                 }
-                KeyCode::Char(c) => {
-                    app.input_buffer.push(c);
-                    false
-                }
-                KeyCode::Backspace => {
-                    // Delete character if the buffer is not empty
-                    // If empty, do nothing but don't exit the popup
-                    if !app.input_buffer.is_empty() {
-                        app.input_buffer.pop();
-                    }
-                    // Always return false to prevent the backspace from
-                    // propagating and potentially triggering another handler
-                    false
-                }
                 KeyCode::Tab => {
                     // Implement Tab completion
                     complete_path(&mut app.input_buffer);
+                    app.input_cursor = 0;
+                    false
+                }
+                _ => {
+                    text_input::edit_input_buffer(key, app);
                     false
                 }
-                _ => false,
             }
         }
         ExportImportMode::ImportClipboard => {
@@ -2246,7 +3832,14 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                     let mut tag_manager_clone = app.tag_manager.clone();
 
                     match import_from_clipboard() {
+                        Ok(Some(import_data))
+                            if !overwrite && start_conflict_resolution(app, &import_data) =>
+                        {
+                            app.export_import_state.as_mut().unwrap().pending_import =
+                                Some(import_data);
+                        }
                         Ok(Some(import_data)) => {
+                            let known = app.snippet_ids();
                             // Use the function that handles tags
                             match merge_import_into_database_with_tags(
                                 &mut app.snippet_database,
@@ -2258,7 +3851,7 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                                     // Update the app's tag manager with the merged one
                                     app.tag_manager = tag_manager_clone;
                                     app.refresh_tree_items();
-                                    let save_result = app.save_database();
+                                    let save_result = app.save_imported_database(&known);
 
                                     // Update the status message and mode
                                     let state = app.export_import_state.as_mut().unwrap();
@@ -2312,10 +3905,150 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
                 _ => false,
             }
         }
+        ExportImportMode::ResolveConflicts => {
+            let resolution = match key.code {
+                KeyCode::Char('k') | KeyCode::Char('K') => ConflictResolution::KeepExisting,
+                KeyCode::Char('o') | KeyCode::Char('O') => ConflictResolution::Overwrite,
+                KeyCode::Char('b') | KeyCode::Char('B') => ConflictResolution::KeepBoth,
+                KeyCode::Esc => {
+                    state.pending_import = None;
+                    state.conflicts.clear();
+                    state.resolutions.clear();
+                    state.status_message =
+                        Some("Import cancelled, nothing was changed".to_string());
+                    state.is_error = false;
+                    state.mode = ExportImportMode::MainMenu;
+                    return false;
+                }
+                _ => return false,
+            };
+
+            // Upper case applies the choice to this and every remaining conflict
+            let apply_to_all = matches!(key.code, KeyCode::Char(c) if c.is_ascii_uppercase());
+            let remaining = if apply_to_all {
+                &state.conflicts[state.conflict_index..]
+            } else {
+                &state.conflicts[state.conflict_index..=state.conflict_index]
+            };
+            for conflict in remaining {
+                state.resolutions.insert(conflict.id, resolution);
+            }
+            state.conflict_index += remaining.len();
+
+            if state.conflict_index >= state.conflicts.len() {
+                finish_conflict_resolution(app);
+            }
+            false
+        }
+        ExportImportMode::Passphrase => match key.code {
+            KeyCode::Enter => {
+                if !app.input_buffer.is_empty() {
+                    let passphrase = std::mem::take(&mut app.input_buffer);
+                    if state.passphrase_for_import {
+                        let import_path = state.import_path.clone();
+                        let overwrite = state.overwrite_existing;
+                        state.mode = ExportImportMode::Importing;
+                        transfer::start_file_import(app, import_path, Some(passphrase), overwrite);
+                    } else {
+                        run_export(app, Some(passphrase));
+                    }
+                }
+                false
+            }
+            KeyCode::Esc => {
+                app.input_buffer.clear();
+                state.mode = if state.passphrase_for_import {
+                    ExportImportMode::ImportPathPopup
+                } else {
+                    ExportImportMode::ExportOptions
+                };
+                false
+            }
+            _ => {
+                text_input::edit_input_buffer(key, app);
+                false
+            }
+        },
+        ExportImportMode::ImportNotebook => {
+            let choices = crate::ui::export_import::import_notebook_choices(app);
+            let state = app.export_import_state.as_mut().unwrap();
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.notebook_cursor = state.notebook_cursor.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.notebook_cursor =
+                        (state.notebook_cursor + 1).min(choices.len().saturating_sub(1));
+                }
+                KeyCode::Enter => {
+                    let notebook_id = choices.get(state.notebook_cursor).and_then(|(id, _)| *id);
+                    let import_path = state.import_path.clone();
+                    let result = app.import_vscode_snippets(&import_path, notebook_id);
+
+                    let state = app.export_import_state.as_mut().unwrap();
+                    match result {
+                        Ok(count) => {
+                            state.status_message = Some(format!(
+                                "Imported {} snippets from {}",
+                                count,
+                                import_path.display()
+                            ));
+                            state.is_error = false;
+                        }
+                        Err(e) => {
+                            state.status_message = Some(e);
+                            state.is_error = true;
+                        }
+                    }
+                    state.mode = ExportImportMode::MainMenu;
+                }
+                KeyCode::Esc => {
+                    app.input_buffer = state.import_path.display().to_string();
+                    state.mode = ExportImportMode::ImportPathPopup;
+                }
+                _ => {}
+            }
+            false
+        }
+        ExportImportMode::ImportGist => match key.code {
+            KeyCode::Enter => {
+                if !app.input_buffer.trim().is_empty() {
+                    let input = app.input_buffer.trim().to_string();
+                    let result = app.pull_gist_into_notebook(&input);
+
+                    let state = app.export_import_state.as_mut().unwrap();
+                    match result {
+                        Ok(count) => {
+                            state.status_message =
+                                Some(format!("Imported {} files from the Gist", count));
+                            state.is_error = false;
+                        }
+                        Err(e) => {
+                            state.status_message = Some(e);
+                            state.is_error = true;
+                        }
+                    }
+                    state.mode = ExportImportMode::MainMenu;
+                    app.input_buffer.clear();
+                }
+                false
+            }
+            KeyCode::Esc => {
+                state.mode = ExportImportMode::MainMenu;
+                state.selected_option = 3;
+                app.input_buffer.clear();
+                false
+            }
+            _ => {
+                text_input::edit_input_buffer(key, app);
+                false
+            }
+        },
         ExportImportMode::Exporting | ExportImportMode::Importing => {
-            // We shouldn't normally reach here as these are transitional states
-            // But if we do, just go back to the main menu
-            state.mode = ExportImportMode::MainMenu;
+            // A background transfer reports back through `transfer::poll_transfer`
+            if app.transfer_job.is_none() {
+                state.mode = ExportImportMode::MainMenu;
+            }
             false
         }
         // This mode is deprecated in favor of ImportPathPopup, but we need to handle it
@@ -2327,6 +4060,231 @@ fn handle_export_import_keys(key: KeyEvent, app: &mut App) -> bool {
     }
 }
 
+/// Starts the export configured on the export/import page in the background
+fn run_export(app: &mut App, passphrase: Option<String>) {
+    use crate::models::export::ExportOptions;
+    use crate::ui::export_import::ExportImportMode;
+
+    let Some(state) = app.export_import_state.as_mut() else {
+        return;
+    };
+    state.mode = ExportImportMode::Exporting;
+
+    let options = ExportOptions {
+        _format: state.export_format,
+        include_content: state.include_content,
+        notebook_ids: None,
+        snippet_ids: None,
+        include_favorites_only: state.favorites_only,
+        tags: (!state.export_tags.is_empty()).then(|| state.export_tags.clone()),
+        passphrase,
+    };
+    let path = state.export_path.clone();
+
+    transfer::start_export(app, path, options);
+}
+
+/// Swaps the extension of an export path, using ".snix.enc" for encrypted exports
+fn export_path_with_extension(path: &Path, extension: &str, encrypt: bool) -> PathBuf {
+    use crate::models::export::ENCRYPTED_EXPORT_EXTENSION;
+
+    let suffix = format!(".{}", ENCRYPTED_EXPORT_EXTENSION);
+    let path = match path.to_str().and_then(|p| p.strip_suffix(suffix.as_str())) {
+        Some(stripped) => PathBuf::from(stripped),
+        None => path.to_path_buf(),
+    };
+
+    if encrypt {
+        path.with_extension(ENCRYPTED_EXPORT_EXTENSION)
+    } else {
+        path.with_extension(extension)
+    }
+}
+
+/// Switches to the conflict prompt when the import collides with existing items.
+/// Returns false when there is nothing to ask about.
+fn start_conflict_resolution(
+    app: &mut App,
+    import_data: &crate::models::export::ExportData,
+) -> bool {
+    use crate::models::export::find_import_conflicts;
+    use crate::ui::export_import::ExportImportMode;
+
+    let conflicts = find_import_conflicts(&app.snippet_database, import_data);
+    if conflicts.is_empty() {
+        return false;
+    }
+
+    let state = app.export_import_state.as_mut().unwrap();
+    state.conflicts = conflicts;
+    state.conflict_index = 0;
+    state.resolutions.clear();
+    state.mode = ExportImportMode::ResolveConflicts;
+    true
+}
+
+/// Merges the pending import once every conflict has an answer
+fn finish_conflict_resolution(app: &mut App) {
+    use crate::models::export::merge_import_with_resolutions;
+    use crate::ui::export_import::ExportImportMode;
+
+    let state = app.export_import_state.as_mut().unwrap();
+    let resolutions = std::mem::take(&mut state.resolutions);
+    state.conflicts.clear();
+    state.mode = ExportImportMode::MainMenu;
+    let Some(import_data) = state.pending_import.take() else {
+        return;
+    };
+
+    let known = app.snippet_ids();
+    // Merge into copies so a failure halfway leaves the library untouched
+    let mut database_clone = app.snippet_database.clone();
+    let mut tag_manager_clone = app.tag_manager.clone();
+    let mut overwritten = Vec::new();
+    let result = merge_import_with_resolutions(
+        &mut database_clone,
+        &mut tag_manager_clone,
+        import_data,
+        &resolutions,
+        &mut overwritten,
+    );
+
+    match result {
+        Ok((notebooks, snippets)) => {
+            app.snippet_database = database_clone;
+            app.tag_manager = tag_manager_clone;
+            app.refresh_tree_items();
+            let save_result = app.save_imported_database(&known);
+            // Stale files only go once the database no longer points at them
+            let files_result = if save_result.is_ok() {
+                settle_overwritten_snippets(app, &overwritten)
+            } else {
+                Ok(())
+            };
+
+            let state = app.export_import_state.as_mut().unwrap();
+            if let Err(e) = save_result {
+                state.status_message = Some(format!(
+                    "Import succeeded but failed to save database: {}",
+                    e
+                ));
+                state.is_error = true;
+            } else if let Err(e) = files_result {
+                state.status_message = Some(format!(
+                    "Imported {} notebooks and {} snippets, but replaced snippet files were not updated: {}",
+                    notebooks, snippets, e
+                ));
+                state.is_error = true;
+            } else {
+                state.status_message = Some(format!(
+                    "Successfully imported {} notebooks and {} snippets",
+                    notebooks, snippets
+                ));
+                state.is_error = false;
+            }
+        }
+        Err(e) => {
+            let state = app.export_import_state.as_mut().unwrap();
+            state.status_message = Some(format!("Failed to merge import data: {}", e));
+            state.is_error = true;
+        }
+    }
+}
+
+/// Archives the old content of snippets replaced by an import and rewrites their files,
+/// removing the old file when it sat in another notebook or used another extension
+fn settle_overwritten_snippets(
+    app: &App,
+    overwritten: &[crate::models::CodeSnippet],
+) -> anyhow::Result<()> {
+    let Some(ref storage) = app.storage_manager else {
+        return Ok(());
+    };
+
+    for old in overwritten {
+        let Some(snippet) = app.snippet_database.snippets.get(&old.id) else {
+            continue;
+        };
+        if old.content != snippet.content {
+            storage.archive_snippet_version(old)?;
+        }
+        if storage.get_snippet_file_path(old) != storage.get_snippet_file_path(snippet) {
+            storage.delete_snippet_content(old)?;
+        }
+        storage.save_snippet_content(snippet)?;
+    }
+    Ok(())
+}
+
+/// Merges the result of reading an export file into the database and reports the outcome
+pub(crate) fn merge_file_import(
+    app: &mut App,
+    result: anyhow::Result<crate::models::export::ExportData>,
+    overwrite: bool,
+) {
+    use crate::models::merge_import_into_database_with_tags;
+    use crate::ui::export_import::ExportImportMode;
+
+    // Take ownership of the tag manager to avoid borrow issues
+    let mut tag_manager_clone = app.tag_manager.clone();
+
+    match result {
+        Ok(import_data) => {
+            if !overwrite && start_conflict_resolution(app, &import_data) {
+                app.export_import_state.as_mut().unwrap().pending_import = Some(import_data);
+                return;
+            }
+
+            let known = app.snippet_ids();
+            // Use the function that handles tags
+            match merge_import_into_database_with_tags(
+                &mut app.snippet_database,
+                &mut tag_manager_clone,
+                import_data,
+                overwrite,
+            ) {
+                Ok((notebooks, snippets)) => {
+                    // Update the app's tag manager with the merged one
+                    app.tag_manager = tag_manager_clone;
+                    app.refresh_tree_items();
+
+                    let save_result = app.save_imported_database(&known);
+
+                    // Update the status message and mode
+                    let state = app.export_import_state.as_mut().unwrap();
+                    if let Err(e) = save_result {
+                        state.status_message = Some(format!(
+                            "Import succeeded but failed to save database: {}",
+                            e
+                        ));
+                        state.is_error = true;
+                    } else {
+                        state.status_message = Some(format!(
+                            "Successfully imported {} notebooks and {} snippets",
+                            notebooks, snippets
+                        ));
+                        state.is_error = false;
+                    }
+
+                    state.mode = ExportImportMode::MainMenu;
+                }
+                Err(e) => {
+                    let state = app.export_import_state.as_mut().unwrap();
+                    state.status_message = Some(format!("Failed to merge import data: {}", e));
+                    state.is_error = true;
+                    state.mode = ExportImportMode::MainMenu;
+                }
+            }
+        }
+        Err(e) => {
+            let state = app.export_import_state.as_mut().unwrap();
+            state.status_message = Some(format!("Import failed: {}", e));
+            state.is_error = true;
+            state.mode = ExportImportMode::MainMenu;
+        }
+    }
+}
+
 /// Function to handle path autocompletion
 fn complete_path(input_buffer: &mut String) {
     let path_str = input_buffer.trim();
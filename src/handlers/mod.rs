@@ -1,2 +1,7 @@
+pub mod command;
+pub mod keymap;
 pub mod keys;
 pub mod ollama;
+pub mod quick_open;
+pub mod text_input;
+pub mod transfer;
@@ -1,26 +1,32 @@
 use crate::app::App;
+use crate::models::{Settings, SnippetLanguage, StorageManager};
 use anyhow::{Result, anyhow};
 use flume;
-use ollama_rs::Ollama;
 use once_cell::sync::Lazy;
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use reqwest;
 use serde_json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use tokio::runtime::Runtime;
 
 use crate::ui::ollama::{
-    ActivePanel, ChatMessage, ChatRole, HistoryFilter, MessageMetrics, OllamaMessage, OllamaState,
+    ActivePanel, AttachedSnippet, ChatMessage, ChatRole, CodeBlock, CodeBlockAction,
+    DEFAULT_SYSTEM_PROMPT, EvalStats, GenerationParams, HistoryFilter, MessageMetrics,
+    MessageSearch, ModelInfo, OllamaMessage, OllamaState, SnippetPicker,
 };
 
-const OLLAMA_HOST: &str = "http://localhost";
-const OLLAMA_PORT: u16 = 11434;
-const OLLAMA_TEMPERATURE: f32 = 0.7;
+const DEFAULT_OLLAMA_PORT: u16 = 11434;
 const OLLAMA_NUM_PREDICT: i32 = 2048;
 const OLLAMA_TOP_K: u32 = 40;
-const OLLAMA_TOP_P: f32 = 0.9;
+/// How often the response stream checks whether it was cancelled
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Auto-save intervals the Settings panel steps through, in seconds
+const AUTOSAVE_STEPS: [u64; 6] = [0, 10, 30, 60, 120, 300];
 
-const ERROR_CONNECTION_REFUSED: &str = "Cannot connect to Ollama. Please ensure Ollama is running:\n1. Install Ollama from https://ollama.ai\n2. Run 'ollama serve' in terminal\n3. Install a model: 'ollama pull llama2'";
+const ERROR_CONNECTION_HELP: &str = "Please ensure Ollama is running:\n1. Install Ollama from https://ollama.ai\n2. Run 'ollama serve' in terminal\n3. Install a model: 'ollama pull llama2'";
 const ERROR_NO_MODELS: &str = "No models found. Please install models using 'ollama pull <model_name>'. Example: 'ollama pull llama2'";
 
 // Global static channel for communication using once_cell for thread safety
@@ -41,9 +47,28 @@ pub fn get_ollama_receiver() -> flume::Receiver<OllamaMessage> {
     OLLAMA_CHANNEL.1.clone()
 }
 
-/// Creates a new Ollama client with default configuration
-fn create_ollama_client() -> Ollama {
-    Ollama::new(OLLAMA_HOST.to_string(), OLLAMA_PORT)
+/// Splits an Ollama URL such as `http://gpu-box.lan:11434` into base URL and port
+pub fn parse_ollama_host(host: &str) -> Result<(String, u16)> {
+    let url = reqwest::Url::parse(host.trim()).map_err(|e| anyhow!("Invalid URL: {}", e))?;
+
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(anyhow!("URL must start with http:// or https://"));
+    }
+    let hostname = url
+        .host_str()
+        .ok_or_else(|| anyhow!("URL is missing a host name"))?;
+
+    // Ollama listens on 11434 unless told otherwise
+    let port = url.port().unwrap_or(DEFAULT_OLLAMA_PORT);
+    Ok((format!("{}://{}", url.scheme(), hostname), port))
+}
+
+/// Creates a new Ollama client for the configured host
+fn connection_error_message(host: &str) -> String {
+    format!(
+        "Cannot connect to Ollama at {}. {}",
+        host, ERROR_CONNECTION_HELP
+    )
 }
 
 /// Determines if an error is a connection-related error
@@ -52,13 +77,11 @@ fn is_connection_error(error: &str) -> bool {
 }
 
 /// Creates enhanced system prompt for snippet analysis
-pub fn create_snippet_system_prompt(
-    snippet_language: &str,
-    snippet_title: &str,
-    snippet_content: &str,
-) -> String {
-    format!(
-        "You are a helpful AI assistant specializing in code analysis and development. \
+pub fn create_snippet_system_prompt(snippets: &[AttachedSnippet]) -> String {
+    match snippets {
+        [] => DEFAULT_SYSTEM_PROMPT.to_string(),
+        [snippet] => format!(
+            "You are a helpful AI assistant specializing in code analysis and development. \
 You are currently working with a {} code snippet titled '{}'. \
 Here is the code snippet:
 ```{}
@@ -67,8 +90,24 @@ Here is the code snippet:
 
 Please provide helpful analysis, suggestions, explanations, or answer questions about this code. \
 When discussing the code, be specific and reference particular parts when relevant.",
-        snippet_language, snippet_title, snippet_language, snippet_content
-    )
+            snippet.language, snippet.title, snippet.language, snippet.content
+        ),
+        snippets => {
+            let listing = crate::ui::ollama::snippet_listing(snippets);
+            format!(
+                "You are a helpful AI assistant specializing in code analysis and development. \
+You are currently working with {} related code snippets. \
+Here are the code snippets:
+
+{}
+
+Please provide helpful analysis, suggestions, explanations, or answer questions about this code. \
+When discussing the code, name the snippet you refer to and reference particular parts when relevant.",
+                snippets.len(),
+                listing
+            )
+        }
+    }
 }
 
 pub fn fetch_ollama_models(app: &mut App) -> Result<()> {
@@ -79,64 +118,93 @@ pub fn fetch_ollama_models(app: &mut App) -> Result<()> {
         // Reset to safe index when clearing models
         ollama_state.selected_model_index = 0;
 
-        let sender = get_ollama_sender();
+        spawn_model_fetch(ollama_state.host.clone());
 
-        // Use the global runtime to spawn the async task
-        GLOBAL_RUNTIME.spawn(async move {
-            let result = async {
-                let ollama = create_ollama_client();
+        Ok(())
+    } else {
+        Err(anyhow!("Ollama state not initialized"))
+    }
+}
 
-                // Test connection first to avoid any issue (spoiler alert it did!)
-                match ollama.list_local_models().await {
-                    Ok(models_list) => {
-                        let model_names: Vec<String> =
-                            models_list.iter().map(|model| model.name.clone()).collect();
+/// Body of Ollama's `/api/tags` response
+#[derive(serde::Deserialize)]
+struct ModelList {
+    models: Vec<ModelInfo>,
+}
 
-                        if model_names.is_empty() {
-                            Err(anyhow!(ERROR_NO_MODELS))
-                        } else {
-                            Ok(model_names)
-                        }
+/// Loads the model list from the given host in the background
+fn spawn_model_fetch(host: String) {
+    let sender = get_ollama_sender();
+
+    // Use the global runtime to spawn the async task
+    GLOBAL_RUNTIME.spawn(async move {
+        let result = async {
+            let (base_url, port) = parse_ollama_host(&host)?;
+
+            // `/api/tags` also reports each model's size and parameter count
+            let response = reqwest::Client::new()
+                .get(format!("{}:{}/api/tags", base_url, port))
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            match response {
+                Ok(response) => {
+                    let models = response.json::<ModelList>().await?.models;
+
+                    if models.is_empty() {
+                        Err(anyhow!(ERROR_NO_MODELS))
+                    } else {
+                        Ok(models)
                     }
-                    Err(e) => {
-                        if is_connection_error(&e.to_string()) {
-                            Err(anyhow!(ERROR_CONNECTION_REFUSED))
-                        } else {
-                            Err(anyhow!("Failed to load models: {}", e))
-                        }
+                }
+                Err(e) => {
+                    if is_connection_error(&e.to_string()) {
+                        Err(anyhow!(connection_error_message(&host)))
+                    } else {
+                        Err(anyhow!("Failed to load models from {}: {}", host, e))
                     }
                 }
             }
-            .await;
+        }
+        .await;
 
-            match result {
-                Ok(model_names) => {
-                    let _ = sender.send(OllamaMessage::ModelsLoaded {
-                        models: model_names,
-                    });
-                }
-                Err(e) => {
-                    let _ = sender.send(OllamaMessage::Error {
-                        request_id: 0,
-                        message: e.to_string(),
-                    });
-                }
+        match result {
+            Ok(models) => {
+                let _ = sender.send(OllamaMessage::ModelsLoaded { models });
+            }
+            Err(e) => {
+                let _ = sender.send(OllamaMessage::Error {
+                    request_id: 0,
+                    message: e.to_string(),
+                });
             }
-        });
+        }
+    });
+}
 
-        Ok(())
-    } else {
-        Err(anyhow!("Ollama state not initialized"))
-    }
+/// Everything needed to generate one assistant reply
+pub struct ChatRequest {
+    pub host: String,
+    pub model: String,
+    pub message: String,
+    pub system_prompt: String,
+    pub conversation_history: Vec<ChatMessage>,
+    pub params: GenerationParams,
 }
 
 pub async fn send_message_to_ollama(
-    model: String,
-    message: String,
-    system_prompt: String,
-    conversation_history: Vec<ChatMessage>,
+    request: ChatRequest,
     request_id: u64,
+    cancel_token: Arc<AtomicBool>,
 ) -> Result<()> {
+    let ChatRequest {
+        host,
+        model,
+        message,
+        system_prompt,
+        conversation_history,
+        params,
+    } = request;
     let sender = get_ollama_sender();
 
     // Build conversation context
@@ -161,23 +229,31 @@ pub async fn send_message_to_ollama(
     // Use direct HTTP streaming for real-time responses
     let client = reqwest::Client::new();
 
+    // Unset sampling options are left out so Ollama applies its own defaults
+    let mut options = params.to_options();
+    options.insert("num_predict".to_string(), OLLAMA_NUM_PREDICT.into());
+    options.insert("top_k".to_string(), OLLAMA_TOP_K.into());
+
     let request_body = serde_json::json!({
         "model": model,
         "prompt": full_prompt,
         "stream": true,
-        "options": {
-            "temperature": OLLAMA_TEMPERATURE,
-            "num_predict": OLLAMA_NUM_PREDICT,
-            "top_k": OLLAMA_TOP_K,
-            "top_p": OLLAMA_TOP_P,
-        }
+        "options": options,
     });
 
+    let (base_url, port) = match parse_ollama_host(&host) {
+        Ok(parts) => parts,
+        Err(e) => {
+            let _ = sender.send(OllamaMessage::Error {
+                request_id,
+                message: format!("Invalid Ollama host '{}': {}", host, e),
+            });
+            return Err(e);
+        }
+    };
+
     match client
-        .post(&format!(
-            "{}:{}{}",
-            OLLAMA_HOST, OLLAMA_PORT, "/api/generate"
-        ))
+        .post(format!("{}:{}{}", base_url, port, "/api/generate"))
         .json(&request_body)
         .send()
         .await
@@ -189,7 +265,19 @@ pub async fn send_message_to_ollama(
                 let mut stream = response.bytes_stream();
                 let mut buffer = String::new();
 
-                while let Some(chunk_result) = stream.next().await {
+                loop {
+                    if cancel_token.load(Ordering::Relaxed) {
+                        // Dropping the stream closes the connection and stops generation
+                        return Ok(());
+                    }
+
+                    let chunk_result =
+                        match tokio::time::timeout(CANCEL_POLL_INTERVAL, stream.next()).await {
+                            Ok(Some(chunk_result)) => chunk_result,
+                            Ok(None) => break,
+                            Err(_) => continue,
+                        };
+
                     match chunk_result {
                         Ok(chunk) => {
                             if let Ok(text) = std::str::from_utf8(&chunk) {
@@ -201,28 +289,27 @@ pub async fn send_message_to_ollama(
 
                                     if let Ok(json_response) =
                                         serde_json::from_str::<serde_json::Value>(line)
-                                    {
-                                        if let Some(response_text) =
+                                        && let Some(response_text) =
                                             json_response.get("response").and_then(|r| r.as_str())
-                                        {
-                                            let is_done = json_response
-                                                .get("done")
-                                                .and_then(|d| d.as_bool())
-                                                .unwrap_or(false);
-
-                                            // Send each chunk immediately for real-time display
-                                            let chunk = OllamaMessage::ResponseChunk {
-                                                request_id,
-                                                content: response_text.to_string(),
-                                                done: is_done,
-                                            };
-                                            let _ = sender.send(chunk);
-
-                                            buffer.push_str(response_text);
-
-                                            if is_done {
-                                                return Ok(());
-                                            }
+                                    {
+                                        let is_done = json_response
+                                            .get("done")
+                                            .and_then(|d| d.as_bool())
+                                            .unwrap_or(false);
+
+                                        // Send each chunk immediately for real-time display
+                                        let chunk = OllamaMessage::ResponseChunk {
+                                            request_id,
+                                            content: response_text.to_string(),
+                                            done: is_done,
+                                            eval: EvalStats::from_response(&json_response),
+                                        };
+                                        let _ = sender.send(chunk);
+
+                                        buffer.push_str(response_text);
+
+                                        if is_done {
+                                            return Ok(());
                                         }
                                     }
                                 }
@@ -245,6 +332,7 @@ pub async fn send_message_to_ollama(
                     request_id,
                     content: String::new(),
                     done: true,
+                    eval: None,
                 };
                 let _ = sender.send(final_chunk);
             } else {
@@ -258,8 +346,10 @@ pub async fn send_message_to_ollama(
         }
         Err(e) => {
             let error_msg = if is_connection_error(&e.to_string()) {
-                "Cannot connect to Ollama. Please ensure Ollama is running with 'ollama serve'"
-                    .to_string()
+                format!(
+                    "Cannot connect to Ollama at {}. Please ensure Ollama is running with 'ollama serve'",
+                    host
+                )
             } else if e.to_string().contains("model") && e.to_string().contains("not found") {
                 format!(
                     "Model '{}' not found. Please install it with 'ollama pull {}'",
@@ -291,11 +381,23 @@ pub fn update_loading_animation(app: &mut App) {
                 ollama_state.loading_animation_frame.wrapping_add(1);
         }
 
+        // Long replies are saved as they stream, so a crash loses little of them
+        if ollama_state.autosave_due() {
+            autosave_session(ollama_state);
+        }
+
         ollama_state.clean_expired_toasts();
         ollama_state.update_copy_button_feedback();
     }
 }
 
+/// Saves the chat without asking, creating its session the first time
+fn autosave_session(ollama_state: &mut OllamaState) {
+    if let Err(e) = save_current_session(ollama_state) {
+        ollama_state.add_error_toast(format!("Failed to save chat: {}", e));
+    }
+}
+
 pub fn process_ollama_messages(app: &mut App) {
     let receiver = &get_ollama_receiver();
 
@@ -309,6 +411,8 @@ pub fn process_ollama_messages(app: &mut App) {
                         ollama_state.error_message = Some("󰅙 No models found!\n\nTo fix this:\n1. Install Ollama from https://ollama.ai\n2. Run 'ollama serve' in terminal\n3. Install a model: 'ollama pull llama2'\n4. Restart this application".to_string());
                     } else {
                         ollama_state.models = models;
+                        // Smallest first, so it is easy to see what fits in memory
+                        ollama_state.models.sort_by_key(|model| model.size);
                         // Auto-select first model if none selected
                         if ollama_state.selected_model_index >= ollama_state.models.len() {
                             ollama_state.selected_model_index = 0;
@@ -328,6 +432,7 @@ pub fn process_ollama_messages(app: &mut App) {
                     request_id,
                     content,
                     done,
+                    eval,
                 } => {
                     // Only process if this matches the current pending request
                     if ollama_state.pending_response_id == Some(request_id) {
@@ -341,7 +446,7 @@ pub fn process_ollama_messages(app: &mut App) {
                             // Track performance metrics
                             ollama_state.add_response_chunk(&content);
                             ollama_state.typing_indicator = format!(
-                                " 󱍢 Receiving... ({} tokens)",
+                                " 󱍢 Receiving... (~{} tokens)",
                                 ollama_state.current_message_token_count
                             );
 
@@ -356,6 +461,7 @@ pub fn process_ollama_messages(app: &mut App) {
                                         content: content.clone(),
                                         metrics: MessageMetrics::default(),
                                         context_length: 0,
+                                        bookmarked: false,
                                     });
                                 }
                             } else {
@@ -365,6 +471,7 @@ pub fn process_ollama_messages(app: &mut App) {
                                     content: content.clone(),
                                     metrics: MessageMetrics::default(),
                                     context_length: 0,
+                                    bookmarked: false,
                                 });
                             }
 
@@ -384,7 +491,7 @@ pub fn process_ollama_messages(app: &mut App) {
 
                         if done {
                             // Finalize performance metrics
-                            let metrics = ollama_state.finish_message_timing();
+                            let metrics = ollama_state.finish_message_timing(eval);
                             let context_length = ollama_state.conversation.len() as u32;
 
                             // Update the last assistant message with final metrics and track for copying
@@ -398,106 +505,25 @@ pub fn process_ollama_messages(app: &mut App) {
                                 }
                             }
 
-                            // Update current session with metrics
+                            // Update current session with metrics and recompute its stats
                             if let Some(session) = &mut ollama_state.current_session {
-                                if let Some(last_msg) = session.conversation.last_mut() {
-                                    if last_msg.role == ChatRole::Assistant {
-                                        last_msg.metrics = metrics.clone();
-                                        last_msg.context_length = context_length;
-                                    }
-                                }
-                                // Update session stats
-                                session.total_context_tokens = context_length;
+                                session.set_last_response_metrics(metrics, context_length);
                             }
 
                             ollama_state.is_sending = false;
                             ollama_state.pending_response_id = None;
+                            ollama_state.cancel_token = None;
                             ollama_state.typing_indicator.clear();
                             ollama_state.mark_unsaved_changes();
 
                             // Auto-save if enabled and we have content
                             if ollama_state.auto_save_enabled {
-                                if let Some(current_session) = &mut ollama_state.current_session {
-                                    if ollama_state.chat_storage.is_some() {
-                                        let save_result = {
-                                            let storage =
-                                                ollama_state.chat_storage.as_ref().unwrap();
-                                            storage.save_session(current_session)
-                                        };
-
-                                        match save_result {
-                                            Ok(_) => {
-                                                ollama_state
-                                                    .add_success_toast("Chat saved! 󰭻".to_string());
-                                                // Refresh the sessions list to show the updated session
-                                                if let Some(storage) = &ollama_state.chat_storage {
-                                                    if let Ok(sessions) =
-                                                        storage.load_all_sessions()
-                                                    {
-                                                        ollama_state.saved_sessions = sessions;
-                                                    }
-                                                }
-                                            }
-                                            Err(_) => {
-                                                ollama_state.add_error_toast(
-                                                    "Failed to save chat".to_string(),
-                                                );
-                                            }
-                                        }
-                                    }
-                                } else if let Some(model) = ollama_state.get_selected_model() {
-                                    // Create a new session if none exists
-                                    let mut new_session = crate::ui::ollama::ChatSession::new(
-                                        model.clone(),
-                                        ollama_state.system_prompt.clone(),
-                                    );
-
-                                    // Associate with current snippet if available
-                                    if let Some(snippet) = &ollama_state.current_snippet {
-                                        new_session = new_session
-                                            .with_snippet(snippet, "Current Snippet".to_string());
-                                    }
-
-                                    // Copy conversation
-                                    new_session.conversation = ollama_state.conversation.clone();
-
-                                    // Save the new session
-                                    if ollama_state.chat_storage.is_some() {
-                                        let save_result = {
-                                            let storage =
-                                                ollama_state.chat_storage.as_ref().unwrap();
-                                            storage.save_session(&new_session)
-                                        };
-
-                                        match save_result {
-                                            Ok(_) => {
-                                                ollama_state.add_success_toast(
-                                                    "New chat saved! 󰭻".to_string(),
-                                                );
-                                                // Refresh the sessions list to show the new session
-                                                if let Some(storage) = &ollama_state.chat_storage {
-                                                    if let Ok(sessions) =
-                                                        storage.load_all_sessions()
-                                                    {
-                                                        ollama_state.saved_sessions = sessions;
-                                                    }
-                                                }
-                                            }
-                                            Err(_) => {
-                                                ollama_state.add_error_toast(
-                                                    "Failed to save new chat".to_string(),
-                                                );
-                                            }
-                                        }
-                                    }
-
-                                    ollama_state.current_session = Some(new_session);
-                                }
+                                autosave_session(ollama_state);
                             }
                         }
 
-                        // Auto-scroll to bottom on any content update
-                        ollama_state.scroll_to_bottom();
+                        // Keep up with the reply unless the reader scrolled up
+                        ollama_state.follow_new_output();
                     }
                 }
                 OllamaMessage::Error {
@@ -513,10 +539,12 @@ pub fn process_ollama_messages(app: &mut App) {
                                 content: format!("󰅙 Error: {}", message),
                                 metrics: MessageMetrics::default(),
                                 context_length: 0,
+                                bookmarked: false,
                             });
                         }
                         ollama_state.is_sending = false;
                         ollama_state.pending_response_id = None;
+                        ollama_state.cancel_token = None;
                         ollama_state.typing_indicator.clear();
                         ollama_state.error_message = Some(message);
                         ollama_state.scroll_to_bottom();
@@ -527,7 +555,70 @@ pub fn process_ollama_messages(app: &mut App) {
     }
 }
 
+/// Adds pasted text to the field that typed characters would go to
+pub fn handle_ollama_paste(app: &mut App, text: &str) {
+    let Some(ollama_state) = app.ollama_state.as_mut() else {
+        return;
+    };
+    if ollama_state.code_block_picker.is_some() || ollama_state.snippet_picker.is_some() {
+        return;
+    }
+
+    if let Some(search) = &mut ollama_state.message_search {
+        search.query.push_str(text);
+        ollama_state.update_message_search();
+    } else if ollama_state.editing_host {
+        ollama_state.host_buffer.push_str(text);
+    } else if ollama_state.editing_system_prompt {
+        ollama_state.system_prompt_buffer.push_str(text);
+    } else if ollama_state.active_panel == ActivePanel::ChatHistory {
+        ollama_state.search_query.push_str(text);
+    } else if ollama_state.active_panel == ActivePanel::CurrentChat && !ollama_state.is_sending {
+        ollama_state.input_buffer.push_str(text);
+    }
+}
+
 pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Saving code from a response needs the whole app, so handle it before borrowing the chat state
+    let picking_code_block = app
+        .ollama_state
+        .as_ref()
+        .is_some_and(|state| state.code_block_picker.is_some());
+    if picking_code_block {
+        handle_code_block_picker(app, key);
+        return Ok(());
+    }
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        let action = match key.code {
+            KeyCode::Char('b') => Some(CodeBlockAction::Save),
+            KeyCode::Char('k') => Some(CodeBlockAction::Copy),
+            _ => None,
+        };
+        if let Some(action) = action {
+            start_code_block_pick(app, action);
+            return Ok(());
+        }
+    }
+    // Picking snippets to attach reads the snippet database, so it also needs the whole app
+    let picking_snippets = app
+        .ollama_state
+        .as_ref()
+        .is_some_and(|state| state.snippet_picker.is_some());
+    if picking_snippets {
+        handle_snippet_picker(app, key);
+        return Ok(());
+    }
+    if key.code == KeyCode::Char('a') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        if let Some(ollama_state) = app
+            .ollama_state
+            .as_mut()
+            .filter(|state| !state.editing_system_prompt)
+        {
+            ollama_state.snippet_picker = Some(SnippetPicker::default());
+        }
+        return Ok(());
+    }
+
     if let Some(ollama_state) = &mut app.ollama_state {
         // Handle save prompt first if it's shqwing
         if ollama_state.show_save_prompt {
@@ -559,23 +650,31 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
             }
         }
 
+        if ollama_state.export_path_input.is_some() {
+            handle_export_path_input(ollama_state, key);
+            return Ok(());
+        }
+
+        if ollama_state.message_search.is_some() {
+            handle_message_search(ollama_state, key);
+            return Ok(());
+        }
+
         match key.code {
             KeyCode::Esc => {
-                if ollama_state.editing_system_prompt {
+                if ollama_state.is_sending {
+                    // Stop the running response instead of leaving the chat
+                    ollama_state.cancel_generation();
+                } else if ollama_state.editing_host {
+                    ollama_state.editing_host = false;
+                    ollama_state.host_buffer.clear();
+                } else if ollama_state.editing_system_prompt {
                     // Cancel editing system prompt
                     ollama_state.editing_system_prompt = false;
                     ollama_state.system_prompt_buffer.clear();
                 } else {
                     // Check for unsaved changes before exiting
-                    let has_actual_unsaved_changes = ollama_state.has_unsaved_session();
-                    let has_unsaved_conversation = !ollama_state.conversation.is_empty()
-                        && ollama_state.current_session.is_none()
-                        && ollama_state
-                            .conversation
-                            .iter()
-                            .any(|msg| msg.role == ChatRole::User);
-
-                    if has_actual_unsaved_changes || has_unsaved_conversation {
+                    if ollama_state.has_unsaved_conversation() {
                         ollama_state.show_save_prompt = true;
                     } else {
                         // Hide Ollama interface but preserve state for associated chats
@@ -601,11 +700,24 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     ActivePanel::ChatHistory => {
                         load_selected_session(ollama_state)?;
                     }
+                    ActivePanel::Settings if ollama_state.editing_host => {
+                        save_ollama_host(
+                            ollama_state,
+                            &mut app.settings,
+                            app.storage_manager.as_ref(),
+                        );
+                    }
                     ActivePanel::Settings => {
-                        // Edit system prompt
+                        // Edit the active template, or the system prompt when none is active
                         if !ollama_state.editing_system_prompt {
                             ollama_state.editing_system_prompt = true;
-                            ollama_state.system_prompt_buffer = ollama_state.system_prompt.clone();
+                            ollama_state.system_prompt_buffer =
+                                match ollama_state.active_prompt_template() {
+                                    Some(template) => template.body.clone(),
+                                    None => ollama_state.system_prompt.clone(),
+                                };
+                        } else if ollama_state.active_template.is_some() {
+                            save_prompt_template_edit(ollama_state);
                         } else {
                             // Save system prompt
                             ollama_state.system_prompt = ollama_state.system_prompt_buffer.clone();
@@ -615,6 +727,50 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     }
                 }
             }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if !ollama_state.attached_snippets.is_empty() {
+                    ollama_state.show_snippet_panel = !ollama_state.show_snippet_panel;
+                } else {
+                    ollama_state
+                        .add_info_toast("No snippet attached • Ctrl+A to attach one".to_string());
+                }
+            }
+            // Scroll the snippet panel independently of the conversation
+            KeyCode::Up
+                if key.modifiers.contains(KeyModifiers::SHIFT)
+                    && ollama_state.show_snippet_panel =>
+            {
+                ollama_state.snippet_panel_scroll =
+                    ollama_state.snippet_panel_scroll.saturating_sub(1);
+            }
+            KeyCode::Down
+                if key.modifiers.contains(KeyModifiers::SHIFT)
+                    && ollama_state.show_snippet_panel =>
+            {
+                let last_line = ollama_state.snippet_panel_line_count().saturating_sub(1);
+                ollama_state.snippet_panel_scroll =
+                    (ollama_state.snippet_panel_scroll + 1).min(last_line);
+            }
+            KeyCode::Up | KeyCode::Down if key.modifiers.contains(KeyModifiers::ALT) => {
+                ollama_state.move_message_focus(key.code == KeyCode::Down);
+            }
+            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::ALT) => {
+                match ollama_state.toggle_message_bookmark() {
+                    Some(true) => {
+                        ollama_state.add_success_toast("Message bookmarked 󰃀".to_string())
+                    }
+                    Some(false) => ollama_state.add_info_toast("Bookmark removed".to_string()),
+                    None => ollama_state.add_info_toast("No message to bookmark yet".to_string()),
+                }
+            }
+            KeyCode::Char(c @ ('n' | 'p')) if key.modifiers.contains(KeyModifiers::ALT) => {
+                let jumped = ollama_state.jump_to_bookmark(c == 'n');
+                if !jumped {
+                    ollama_state.add_info_toast(
+                        "No bookmarked messages • Alt+B bookmarks the focused one".to_string(),
+                    );
+                }
+            }
             KeyCode::Up => {
                 if key.modifiers.contains(KeyModifiers::CONTROL) {
                     // Navigate models up (Ctrl+Up) - works from any panel
@@ -710,18 +866,26 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     }
                 }
             }
-            KeyCode::Char('m') | KeyCode::Char('M') => {
-                if key.modifiers.contains(KeyModifiers::CONTROL) {
-                    // Show current model and model list
-                    if !ollama_state.models.is_empty() {
-                        ollama_state.add_info_toast(format!(
-                            "Models ({}): {}",
-                            ollama_state.models.len(),
-                            ollama_state.models.join(", ")
-                        ));
-                    } else {
-                        ollama_state.add_error_toast("No models available! Ensure Ollama is running and models are installed.".to_string());
-                    }
+            KeyCode::Char('m') | KeyCode::Char('M')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                // Show current model and model list
+                if !ollama_state.models.is_empty() {
+                    ollama_state.add_info_toast(format!(
+                        "Models ({}): {}",
+                        ollama_state.models.len(),
+                        ollama_state
+                            .models
+                            .iter()
+                            .map(|model| model.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                } else {
+                    ollama_state.add_error_toast(
+                        "No models available! Ensure Ollama is running and models are installed."
+                            .to_string(),
+                    );
                 }
             }
             KeyCode::Left => {
@@ -748,6 +912,18 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     ollama_state.selected_session_index = 0;
                 }
             }
+            KeyCode::Char('/')
+                if ollama_state.active_panel == ActivePanel::CurrentChat
+                    && ollama_state.input_buffer.is_empty() =>
+            {
+                ollama_state.message_search = Some(MessageSearch::default());
+            }
+            KeyCode::Char('f')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && ollama_state.active_panel == ActivePanel::CurrentChat =>
+            {
+                ollama_state.message_search = Some(MessageSearch::default());
+            }
             KeyCode::Char('f') | KeyCode::Char('F') => {
                 if ollama_state.active_panel == ActivePanel::ChatHistory {
                     // Toggle favorite for selected session
@@ -765,13 +941,67 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     }
                 }
             }
-            KeyCode::Delete => {
-                if ollama_state.active_panel == ActivePanel::ChatHistory {
-                    // Delete selected session
-                    delete_selected_session(ollama_state)?;
+            KeyCode::Char('e')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && ollama_state.active_panel != ActivePanel::CurrentChat =>
+            {
+                if ollama_state.get_filtered_sessions().is_empty() {
+                    ollama_state.add_info_toast("No chat sessions to export".to_string());
+                } else {
+                    ollama_state.export_path_input = Some(format!(
+                        "~/snix-chats-{}.md",
+                        chrono::Local::now().format("%Y%m%d")
+                    ));
                 }
             }
-            KeyCode::Char(' ') => {
+            KeyCode::Delete if ollama_state.active_panel == ActivePanel::ChatHistory => {
+                // Delete selected session
+                if let Err(e) = delete_selected_session(ollama_state) {
+                    ollama_state.add_error_toast(format!("Failed to delete session: {}", e));
+                }
+            }
+            KeyCode::Char('h')
+                if ollama_state.active_panel == ActivePanel::Settings
+                    && !ollama_state.editing_host
+                    && !ollama_state.editing_system_prompt =>
+            {
+                // Edit the Ollama server address
+                ollama_state.editing_host = true;
+                ollama_state.host_buffer = ollama_state.host.clone();
+            }
+            KeyCode::Char(c @ ('t' | 'T' | 'o' | 'O' | 'c' | 'C' | 'x'))
+                if ollama_state.active_panel == ActivePanel::Settings
+                    && !ollama_state.editing_host
+                    && !ollama_state.editing_system_prompt
+                    && !key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                adjust_generation_params(ollama_state, c);
+            }
+            KeyCode::Char(c @ ('i' | 'I'))
+                if ollama_state.active_panel == ActivePanel::Settings
+                    && !ollama_state.editing_host
+                    && !ollama_state.editing_system_prompt =>
+            {
+                step_autosave_interval(
+                    ollama_state,
+                    c == 'I',
+                    &mut app.settings,
+                    app.storage_manager.as_ref(),
+                );
+            }
+            KeyCode::Char(c @ ('[' | ']'))
+                if ollama_state.active_panel == ActivePanel::Settings
+                    && !ollama_state.editing_host
+                    && !ollama_state.editing_system_prompt =>
+            {
+                select_prompt_template(
+                    ollama_state,
+                    c == ']',
+                    &mut app.settings,
+                    app.storage_manager.as_ref(),
+                );
+            }
+            KeyCode::Char(' ') if !ollama_state.editing_host => {
                 if ollama_state.active_panel == ActivePanel::Settings {
                     // Toggle auto-save
                     ollama_state.auto_save_enabled = !ollama_state.auto_save_enabled;
@@ -788,6 +1018,7 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
             KeyCode::Home => {
                 // Scroll to top of chat - works from any panel
                 ollama_state.scroll_position = 0;
+                ollama_state.follow_output = false;
             }
             KeyCode::End => {
                 // Scroll to bottom of chat - works from any panel
@@ -818,6 +1049,9 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     }
                 }
             }
+            KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                regenerate_last_response(ollama_state);
+            }
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 // Copy last assistant response to clipboard
                 if ollama_state.copy_last_response() {
@@ -837,50 +1071,7 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     ollama_state.loading_models = true;
                     ollama_state.error_message = Some(" Refreshing models...".to_string());
 
-                    // Trigger model refresh by directly using the global runtime
-                    let sender = get_ollama_sender();
-                    GLOBAL_RUNTIME.spawn(async move {
-                        let result = async {
-                            let ollama = create_ollama_client();
-
-                            match ollama.list_local_models().await {
-                                Ok(models_list) => {
-                                    let model_names: Vec<String> = models_list
-                                        .iter()
-                                        .map(|model| model.name.clone())
-                                        .collect();
-
-                                    if model_names.is_empty() {
-                                        Err(anyhow!(ERROR_NO_MODELS))
-                                    } else {
-                                        Ok(model_names)
-                                    }
-                                }
-                                Err(e) => {
-                                    if is_connection_error(&e.to_string()) {
-                                        Err(anyhow!(ERROR_CONNECTION_REFUSED))
-                                    } else {
-                                        Err(anyhow!("Failed to load models: {}", e))
-                                    }
-                                }
-                            }
-                        }
-                        .await;
-
-                        match result {
-                            Ok(model_names) => {
-                                let _ = sender.send(OllamaMessage::ModelsLoaded {
-                                    models: model_names,
-                                });
-                            }
-                            Err(e) => {
-                                let _ = sender.send(OllamaMessage::Error {
-                                    request_id: 0,
-                                    message: e.to_string(),
-                                });
-                            }
-                        }
-                    });
+                    spawn_model_fetch(ollama_state.host.clone());
                 } else {
                     if let Err(e) = refresh_sessions(ollama_state) {
                         ollama_state.add_error_toast(format!("Failed to refresh sessions: {}", e));
@@ -890,7 +1081,9 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 }
             }
             KeyCode::Char(c) => {
-                if ollama_state.editing_system_prompt {
+                if ollama_state.editing_host {
+                    ollama_state.host_buffer.push(c);
+                } else if ollama_state.editing_system_prompt {
                     // Edit system prompt
                     ollama_state.system_prompt_buffer.push(c);
                 } else if ollama_state.active_panel == ActivePanel::ChatHistory {
@@ -904,7 +1097,9 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 }
             }
             KeyCode::Backspace => {
-                if ollama_state.editing_system_prompt {
+                if ollama_state.editing_host {
+                    ollama_state.host_buffer.pop();
+                } else if ollama_state.editing_system_prompt {
                     // Edit system prompt
                     ollama_state.system_prompt_buffer.pop();
                 } else if ollama_state.active_panel == ActivePanel::ChatHistory {
@@ -923,6 +1118,335 @@ pub fn handle_ollama_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+/// Steps a sampling option (lowercase lowers, uppercase raises) or resets them all with `x`
+fn adjust_generation_params(ollama_state: &mut OllamaState, key: char) {
+    let params = &mut ollama_state.params;
+    match key {
+        't' => params.adjust_temperature(-0.1),
+        'T' => params.adjust_temperature(0.1),
+        'o' => params.adjust_top_p(-0.05),
+        'O' => params.adjust_top_p(0.05),
+        'c' => params.scale_num_ctx(false),
+        'C' => params.scale_num_ctx(true),
+        'x' => {
+            *params = GenerationParams::default();
+            ollama_state.add_info_toast("Generation options reset to Ollama defaults".to_string());
+        }
+        _ => return,
+    }
+
+    let params = ollama_state.params.clone();
+    if let Some(session) = &mut ollama_state.current_session {
+        session.params = params;
+        ollama_state.unsaved_changes = true;
+    }
+}
+
+/// Saves or copies the only code block of the last response, or opens a picker when there are several
+fn start_code_block_pick(app: &mut App, action: CodeBlockAction) {
+    let Some(ollama_state) = &mut app.ollama_state else {
+        return;
+    };
+    if ollama_state.is_sending {
+        ollama_state.add_info_toast("Wait for the response to finish first".to_string());
+        return;
+    }
+
+    let mut blocks = ollama_state.last_response_code_blocks();
+    match blocks.len() {
+        0 => ollama_state.add_info_toast("No code block in the last response".to_string()),
+        1 => apply_code_block_action(app, action, blocks.remove(0), 0, 1),
+        _ => {
+            ollama_state.code_block_action = action;
+            ollama_state.code_block_picker = Some(0);
+        }
+    }
+}
+
+fn apply_code_block_action(
+    app: &mut App,
+    action: CodeBlockAction,
+    block: CodeBlock,
+    index: usize,
+    count: usize,
+) {
+    match action {
+        CodeBlockAction::Save => save_code_block_as_snippet(app, block),
+        CodeBlockAction::Copy => {
+            let Some(ollama_state) = &mut app.ollama_state else {
+                return;
+            };
+            copy_code_block(ollama_state, &block, index, count);
+        }
+    }
+}
+
+/// Copies one fenced block of the last response, naming it in the toast
+fn copy_code_block(ollama_state: &mut OllamaState, block: &CodeBlock, index: usize, count: usize) {
+    let language = match block.info.trim() {
+        "" => "plain text",
+        info => info,
+    };
+    let name = if count > 1 {
+        format!("block {}/{} ({})", index + 1, count, language)
+    } else {
+        format!("{} block", language)
+    };
+
+    if crate::handlers::keys::ClipboardHandler::copy_to_clipboard(&block.code) {
+        ollama_state.add_success_toast(format!(
+            "Copied {} • {} lines",
+            name,
+            block.code.lines().count()
+        ));
+    } else {
+        ollama_state
+            .add_error_toast("Failed to copy to clipboard (clipboard tools required)".to_string());
+    }
+}
+
+fn handle_code_block_picker(app: &mut App, key: KeyEvent) {
+    let Some(ollama_state) = &mut app.ollama_state else {
+        return;
+    };
+    let Some(index) = ollama_state.code_block_picker else {
+        return;
+    };
+    let mut blocks = ollama_state.last_response_code_blocks();
+    let count = blocks.len().max(1);
+
+    match key.code {
+        KeyCode::Esc => ollama_state.code_block_picker = None,
+        KeyCode::Right | KeyCode::Down | KeyCode::Tab | KeyCode::Char('b' | 'k') => {
+            ollama_state.code_block_picker = Some((index + 1) % count);
+        }
+        KeyCode::Left | KeyCode::Up | KeyCode::BackTab => {
+            ollama_state.code_block_picker = Some((index + count - 1) % count);
+        }
+        KeyCode::Enter => {
+            ollama_state.code_block_picker = None;
+            let action = ollama_state.code_block_action;
+            if index < blocks.len() {
+                let count = blocks.len();
+                apply_code_block_action(app, action, blocks.swap_remove(index), index, count);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Keys of the overlay that attaches snippets to the chat; typing filters the list
+fn handle_snippet_picker(app: &mut App, key: KeyEvent) {
+    let Some(picker) = app
+        .ollama_state
+        .as_ref()
+        .and_then(|state| state.snippet_picker.clone())
+    else {
+        return;
+    };
+    let candidates: Vec<AttachedSnippet> =
+        crate::ui::ollama::snippet_picker_candidates(app, &picker.filter)
+            .into_iter()
+            .map(AttachedSnippet::from_snippet)
+            .collect();
+    let Some(ollama_state) = &mut app.ollama_state else {
+        return;
+    };
+    let Some(picker) = &mut ollama_state.snippet_picker else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Esc => ollama_state.snippet_picker = None,
+        KeyCode::Down => {
+            picker.selected = (picker.selected + 1).min(candidates.len().saturating_sub(1));
+        }
+        KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+        KeyCode::Backspace => {
+            picker.filter.pop();
+            picker.selected = 0;
+        }
+        KeyCode::Char(c) => {
+            picker.filter.push(c);
+            picker.selected = 0;
+        }
+        KeyCode::Enter => {
+            let Some(snippet) = candidates.into_iter().nth(picker.selected) else {
+                return;
+            };
+            let title = snippet.title.clone();
+            let attached = ollama_state.toggle_attached_snippet(snippet);
+            if !ollama_state.apply_prompt_template() {
+                ollama_state.system_prompt =
+                    create_snippet_system_prompt(&ollama_state.attached_snippets);
+            }
+            if let Some(session) = &mut ollama_state.current_session {
+                session.system_prompt = ollama_state.system_prompt.clone();
+            }
+            if attached {
+                ollama_state.add_success_toast(format!("Attached {}", title));
+            } else {
+                ollama_state.add_info_toast(format!("Detached {}", title));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Creates a snippet from a code block in the notebook of the snippet the chat belongs to
+fn save_code_block_as_snippet(app: &mut App, block: CodeBlock) {
+    let source_snippet = app
+        .ollama_state
+        .as_ref()
+        .and_then(|state| state.attached_snippets.first())
+        .and_then(|snippet| app.snippet_database.snippets.get(&snippet.id));
+
+    let notebook_id = source_snippet
+        .map(|snippet| snippet.notebook_id)
+        .or(app.current_notebook_id);
+    let title = match source_snippet {
+        Some(snippet) => format!("{} (Ollama)", snippet.title),
+        None => "Ollama snippet".to_string(),
+    };
+
+    let result = match notebook_id {
+        Some(notebook_id) => app.create_snippet_with_content(
+            title.clone(),
+            SnippetLanguage::from_fence_info(&block.info),
+            notebook_id,
+            block.code,
+        ),
+        None => Err("Open the chat from a snippet to choose a notebook".to_string()),
+    };
+
+    if let Some(ollama_state) = &mut app.ollama_state {
+        match result {
+            Ok(_) => ollama_state.add_success_toast(format!("Saved snippet '{}'", title)),
+            Err(e) => ollama_state.add_error_toast(format!("Failed to save snippet: {}", e)),
+        }
+    }
+}
+
+/// Validates the edited host, remembers it in settings and reloads the model list
+/// Makes the next (or previous) prompt template active and remembers the choice
+fn select_prompt_template(
+    ollama_state: &mut OllamaState,
+    forward: bool,
+    settings: &mut Settings,
+    storage: Option<&StorageManager>,
+) {
+    if ollama_state.prompt_templates.is_empty() {
+        ollama_state.add_info_toast("No prompt templates in ~/.snix/prompts".to_string());
+        return;
+    }
+
+    ollama_state.cycle_prompt_template(forward);
+    let name = ollama_state
+        .active_prompt_template()
+        .map(|template| template.name.clone());
+    if !ollama_state.apply_prompt_template() {
+        ollama_state.system_prompt = create_snippet_system_prompt(&ollama_state.attached_snippets);
+    }
+
+    settings.ollama_prompt_template = name.clone().unwrap_or_default();
+    if let Some(Err(e)) = storage.map(|storage| storage.save_settings(settings)) {
+        ollama_state.add_error_toast(format!("Failed to save settings: {}", e));
+    } else {
+        match name {
+            Some(name) => ollama_state.add_info_toast(format!("Prompt template: {}", name)),
+            None => ollama_state.add_info_toast("No prompt template".to_string()),
+        }
+    }
+}
+
+/// Saves the edited body of the active template to its file and applies it
+fn save_prompt_template_edit(ollama_state: &mut OllamaState) {
+    let Some(template) = ollama_state
+        .active_template
+        .and_then(|index| ollama_state.prompt_templates.get_mut(index))
+    else {
+        return;
+    };
+    template.body = ollama_state.system_prompt_buffer.trim_end().to_string();
+    let result = crate::models::prompts::save_prompt_template(template);
+    let name = template.name.clone();
+
+    ollama_state.editing_system_prompt = false;
+    ollama_state.system_prompt_buffer.clear();
+    ollama_state.apply_prompt_template();
+    match result {
+        Ok(()) => ollama_state.add_success_toast(format!("Saved prompt template {}", name)),
+        Err(e) => ollama_state.add_error_toast(format!("Failed to save template: {}", e)),
+    }
+}
+
+/// Moves the auto-save interval to the next longer or shorter step and remembers it
+fn step_autosave_interval(
+    ollama_state: &mut OllamaState,
+    longer: bool,
+    settings: &mut Settings,
+    storage: Option<&StorageManager>,
+) {
+    let current = ollama_state.autosave_seconds;
+    let next = if longer {
+        AUTOSAVE_STEPS.iter().copied().find(|&secs| secs > current)
+    } else {
+        AUTOSAVE_STEPS
+            .iter()
+            .rev()
+            .copied()
+            .find(|&secs| secs < current)
+    };
+    let Some(next) = next else {
+        return;
+    };
+
+    ollama_state.autosave_seconds = next;
+    settings.ollama_autosave_seconds = next;
+    if let Some(Err(e)) = storage.map(|storage| storage.save_settings(settings)) {
+        ollama_state.add_error_toast(format!("Failed to save settings: {}", e));
+    } else {
+        ollama_state.add_info_toast(format!(
+            "Auto-save {}",
+            crate::ui::ollama::autosave_interval_label(next)
+        ));
+    }
+}
+
+fn save_ollama_host(
+    ollama_state: &mut OllamaState,
+    settings: &mut Settings,
+    storage: Option<&StorageManager>,
+) {
+    let host = ollama_state
+        .host_buffer
+        .trim()
+        .trim_end_matches('/')
+        .to_string();
+    if let Err(e) = parse_ollama_host(&host) {
+        ollama_state.add_error_toast(format!("Invalid Ollama host: {}", e));
+        return;
+    }
+
+    ollama_state.host = host.clone();
+    ollama_state.editing_host = false;
+    ollama_state.host_buffer.clear();
+
+    settings.ollama_host = host.clone();
+    if let Some(Err(e)) = storage.map(|storage| storage.save_settings(settings)) {
+        ollama_state.add_error_toast(format!("Failed to save settings: {}", e));
+    } else {
+        ollama_state.add_success_toast(format!("Ollama host set to {}", host));
+    }
+
+    // Models differ per server, so reload them from the new host
+    ollama_state.models.clear();
+    ollama_state.selected_model_index = 0;
+    ollama_state.loading_models = true;
+    ollama_state.error_message = None;
+    spawn_model_fetch(host);
+}
+
 fn send_chat_message(ollama_state: &mut OllamaState) -> Result<()> {
     if ollama_state.input_buffer.trim().is_empty() || ollama_state.is_sending {
         return Ok(());
@@ -942,29 +1466,16 @@ fn send_chat_message(ollama_state: &mut OllamaState) -> Result<()> {
     let system_prompt = ollama_state.system_prompt.clone();
     let conversation_history = ollama_state.conversation.clone();
 
-    // Generate unique request ID - simplified to avoid potential issues
-    let request_id = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(0);
-
     // Add user message to conversation
     ollama_state.conversation.push(ChatMessage {
         role: ChatRole::User,
         content: message.clone(),
         metrics: MessageMetrics::default(),
         context_length: ollama_state.conversation.len() as u32,
+        bookmarked: false,
     });
 
-    // Clear input and set sending state
     ollama_state.input_buffer.clear();
-    ollama_state.is_sending = true;
-    ollama_state.pending_response_id = Some(request_id);
-    ollama_state.typing_indicator = "Assistant is thinking...".to_string();
-
-    // Start performance tracking for the response
-    ollama_state.start_message_timing();
-    ollama_state.mark_unsaved_changes();
 
     // Update current session or create one if it doesn't exist
     if let Some(session) = &mut ollama_state.current_session {
@@ -974,65 +1485,146 @@ fn send_chat_message(ollama_state: &mut OllamaState) -> Result<()> {
         let model_name = model.clone();
         let mut new_session =
             crate::ui::ollama::ChatSession::new(model_name, system_prompt.clone());
+        new_session.params = ollama_state.params.clone();
 
-        // Associate with current snippet if available
-        if let Some(snippet_content) = &ollama_state.current_snippet {
-            new_session = new_session.with_snippet(snippet_content, "Current Snippet".to_string());
+        // Associate with the attached snippets if any
+        if !ollama_state.attached_snippets.is_empty() {
+            new_session = new_session.with_snippets(&ollama_state.attached_snippets);
         }
 
         new_session.add_message(ChatRole::User, message.clone());
         ollama_state.current_session = Some(new_session);
     }
 
-    // Use the global runtime to spawn the async task instead of tokio::spawn
-    GLOBAL_RUNTIME.spawn(async move {
-        let _ = send_message_to_ollama(
-            model,
-            message,
-            system_prompt,
-            conversation_history,
-            request_id,
-        )
-        .await;
+    spawn_response(ollama_state, model, message, conversation_history);
+    Ok(())
+}
+
+/// Drops the last assistant reply and streams a fresh one for the same user turn
+fn regenerate_last_response(ollama_state: &mut OllamaState) {
+    if ollama_state.is_sending {
+        ollama_state.add_info_toast("Wait for the response to finish first".to_string());
+        return;
+    }
+
+    let last_is_reply = ollama_state
+        .conversation
+        .last()
+        .is_some_and(|msg| msg.role == ChatRole::Assistant);
+    if !last_is_reply {
+        ollama_state.add_info_toast("No assistant response to regenerate".to_string());
+        return;
+    }
+
+    let Some(user_index) = ollama_state
+        .conversation
+        .iter()
+        .rposition(|msg| msg.role == ChatRole::User)
+    else {
+        ollama_state.add_info_toast("No user message to answer again".to_string());
+        return;
+    };
+
+    let Some(model) = ollama_state.get_selected_model().cloned() else {
+        ollama_state.error_message =
+            Some("No model selected. Please select a model first.".to_string());
+        return;
+    };
+
+    ollama_state.conversation.pop();
+    let session_reply = ollama_state.current_session.as_mut().filter(|session| {
+        session
+            .conversation
+            .last()
+            .is_some_and(|msg| msg.role == ChatRole::Assistant)
     });
+    if let Some(session) = session_reply {
+        session.conversation.pop();
+    }
+    ollama_state.last_assistant_response = None;
 
-    Ok(())
+    let message = ollama_state.conversation[user_index].content.clone();
+    let conversation_history = ollama_state.conversation[..user_index].to_vec();
+
+    spawn_response(ollama_state, model, message, conversation_history);
+    ollama_state.add_info_toast("Regenerating response...".to_string());
 }
 
-fn load_selected_session(ollama_state: &mut OllamaState) -> Result<()> {
-    let filtered_sessions = ollama_state.get_filtered_sessions();
-    if ollama_state.selected_session_index < filtered_sessions.len() {
-        if let Some(selected_session) = filtered_sessions.get(ollama_state.selected_session_index) {
-            let selected_session = (*selected_session).clone();
+/// Marks the chat as busy and streams a reply to `message` in the background
+fn spawn_response(
+    ollama_state: &mut OllamaState,
+    model: String,
+    message: String,
+    conversation_history: Vec<ChatMessage>,
+) {
+    // Generate unique request ID - simplified to avoid potential issues
+    let request_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+
+    ollama_state.is_sending = true;
+    ollama_state.pending_response_id = Some(request_id);
+    ollama_state.typing_indicator = "Assistant is thinking...".to_string();
+    let cancel_token = Arc::new(AtomicBool::new(false));
+    ollama_state.cancel_token = Some(cancel_token.clone());
 
-            // Load conversation
-            ollama_state.conversation = selected_session.conversation.clone();
-            ollama_state.current_session = Some(selected_session.clone());
-            ollama_state.system_prompt = selected_session.system_prompt.clone();
-            ollama_state.system_prompt_buffer = selected_session.system_prompt.clone();
+    // Start performance tracking for the response
+    ollama_state.start_message_timing();
+    ollama_state.mark_unsaved_changes();
 
-            // Reset unsaved changes flag since we just loaded a saved session
-            ollama_state.unsaved_changes = false;
+    let request = ChatRequest {
+        host: ollama_state.host.clone(),
+        model,
+        message,
+        system_prompt: ollama_state.system_prompt.clone(),
+        conversation_history,
+        params: ollama_state.params.clone(),
+    };
 
-            // Set the last assistant response for copy functionality
-            ollama_state.last_assistant_response = ollama_state
-                .conversation
-                .iter()
-                .rev()
-                .find(|msg| msg.role == crate::ui::ollama::ChatRole::Assistant)
-                .map(|msg| msg.content.clone());
-
-            // Switch to chat panel
-            ollama_state.active_panel = ActivePanel::CurrentChat;
-
-            // Scroll to bottom to show the most recent messages
-            ollama_state.scroll_to_bottom();
-            ollama_state.add_success_toast(format!(
-                "Loaded session: {} ({} messages)",
-                selected_session.title,
-                selected_session.get_message_count()
-            ));
-        }
+    // Use the global runtime to spawn the async task instead of tokio::spawn
+    GLOBAL_RUNTIME.spawn(async move {
+        let _ = send_message_to_ollama(request, request_id, cancel_token).await;
+    });
+}
+
+fn load_selected_session(ollama_state: &mut OllamaState) -> Result<()> {
+    let filtered_sessions = ollama_state.get_filtered_sessions();
+    if ollama_state.selected_session_index < filtered_sessions.len()
+        && let Some(selected_session) = filtered_sessions.get(ollama_state.selected_session_index)
+    {
+        let selected_session = (*selected_session).clone();
+        ollama_state.remember_scroll_position();
+
+        // Load conversation
+        ollama_state.conversation = selected_session.conversation.clone();
+        ollama_state.focused_message = None;
+        ollama_state.current_session = Some(selected_session.clone());
+        ollama_state.system_prompt = selected_session.system_prompt.clone();
+        ollama_state.system_prompt_buffer = selected_session.system_prompt.clone();
+        ollama_state.params = selected_session.params.clone();
+
+        // Reset unsaved changes flag since we just loaded a saved session
+        ollama_state.unsaved_changes = false;
+
+        // Set the last assistant response for copy functionality
+        ollama_state.last_assistant_response = ollama_state
+            .conversation
+            .iter()
+            .rev()
+            .find(|msg| msg.role == crate::ui::ollama::ChatRole::Assistant)
+            .map(|msg| msg.content.clone());
+
+        // Switch to chat panel
+        ollama_state.active_panel = ActivePanel::CurrentChat;
+
+        // Back to where this chat was left, or its most recent messages
+        ollama_state.restore_scroll_position();
+        ollama_state.add_success_toast(format!(
+            "Loaded session: {} ({} messages)",
+            selected_session.title,
+            selected_session.get_message_count()
+        ));
     }
     Ok(())
 }
@@ -1059,33 +1651,127 @@ fn toggle_session_favorite(ollama_state: &mut OllamaState) -> Result<()> {
     Ok(())
 }
 
+/// Keys of the find prompt; the chat scrolls to the match being looked at
+fn handle_message_search(ollama_state: &mut OllamaState, key: KeyEvent) {
+    let Some(search) = &mut ollama_state.message_search else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc => ollama_state.message_search = None,
+        KeyCode::Enter | KeyCode::Up => ollama_state.step_message_search(true),
+        KeyCode::Down => ollama_state.step_message_search(false),
+        KeyCode::Backspace => {
+            search.query.pop();
+            ollama_state.update_message_search();
+        }
+        KeyCode::Char(c) => {
+            search.query.push(c);
+            ollama_state.update_message_search();
+        }
+        _ => {}
+    }
+}
+
+/// Keys of the export path prompt; Enter writes the listed sessions to the typed path
+fn handle_export_path_input(ollama_state: &mut OllamaState, key: KeyEvent) {
+    let Some(input) = &mut ollama_state.export_path_input else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Esc => ollama_state.export_path_input = None,
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Enter => {
+            let input = input.trim().to_string();
+            ollama_state.export_path_input = None;
+            if input.is_empty() {
+                return;
+            }
+            match export_sessions_markdown(ollama_state, &input) {
+                Ok((count, path)) => ollama_state.add_success_toast(format!(
+                    "Exported {} chats to {}",
+                    count,
+                    path.display()
+                )),
+                Err(e) => ollama_state.add_error_toast(format!("Failed to export chats: {}", e)),
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Writes the sessions shown by the current history filter to one markdown file
+fn export_sessions_markdown(
+    ollama_state: &OllamaState,
+    input: &str,
+) -> Result<(usize, std::path::PathBuf)> {
+    let storage = ollama_state
+        .chat_storage
+        .as_ref()
+        .ok_or_else(|| anyhow!("Chat storage is not available"))?;
+    let sessions = ollama_state.get_filtered_sessions();
+
+    let path = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => std::path::PathBuf::from(input),
+    };
+    std::fs::write(&path, storage.export_all_sessions(&sessions))?;
+    Ok((sessions.len(), path))
+}
+
 fn delete_selected_session(ollama_state: &mut OllamaState) -> Result<()> {
     let filtered_sessions = ollama_state.get_filtered_sessions();
-    if let Some(selected_session) = filtered_sessions.get(ollama_state.selected_session_index) {
-        let session_id = selected_session.id;
+    let Some(selected_session) = filtered_sessions.get(ollama_state.selected_session_index) else {
+        ollama_state.add_info_toast("No chat session selected".to_string());
+        return Ok(());
+    };
+    let session_id = selected_session.id;
+    let title = selected_session.title.clone();
 
-        // Remove from main sessions list
-        ollama_state.saved_sessions.retain(|s| s.id != session_id);
+    // Delete from storage first so a failure leaves the list untouched
+    if let Some(storage) = &ollama_state.chat_storage {
+        storage.delete_session(session_id)?;
+    }
 
-        // Delete from storage if available
-        if let Some(storage) = &ollama_state.chat_storage {
-            let _ = storage.delete_session(session_id);
-        }
+    // Remove from main sessions list
+    ollama_state.saved_sessions.retain(|s| s.id != session_id);
+    ollama_state.session_scroll_positions.remove(&session_id);
+
+    // The loaded chat would otherwise be saved again under the deleted id
+    let is_current = ollama_state
+        .current_session
+        .as_ref()
+        .is_some_and(|session| session.id == session_id);
+    if is_current {
+        ollama_state.current_session = None;
+        ollama_state.conversation.clear();
+        ollama_state.focused_message = None;
+        ollama_state.scroll_position = 0;
+        ollama_state.follow_output = true;
+        ollama_state.last_assistant_response = None;
+        ollama_state.unsaved_changes = false;
+    }
 
-        // Adjust selection index safely
-        let filtered_count = ollama_state.get_filtered_sessions().len();
-        if filtered_count > 0 && ollama_state.selected_session_index >= filtered_count {
-            ollama_state.selected_session_index = filtered_count.saturating_sub(1);
-        } else if filtered_count == 0 {
-            ollama_state.selected_session_index = 0;
-        }
+    // Adjust selection index safely
+    let filtered_count = ollama_state.get_filtered_sessions().len();
+    if filtered_count > 0 && ollama_state.selected_session_index >= filtered_count {
+        ollama_state.selected_session_index = filtered_count.saturating_sub(1);
+    } else if filtered_count == 0 {
+        ollama_state.selected_session_index = 0;
     }
+
+    ollama_state.add_success_toast(format!("Deleted chat: {}", title));
     Ok(())
 }
 
 fn clear_conversation(ollama_state: &mut OllamaState) -> Result<()> {
     ollama_state.conversation.clear();
+    ollama_state.focused_message = None;
     ollama_state.scroll_position = 0;
+    ollama_state.follow_output = true;
     ollama_state.last_assistant_response = None; // Clear copy functionality state
 
     if let Some(session) = &mut ollama_state.current_session {
@@ -1095,7 +1781,7 @@ fn clear_conversation(ollama_state: &mut OllamaState) -> Result<()> {
     Ok(())
 }
 
-fn save_current_session(ollama_state: &mut OllamaState) -> Result<()> {
+pub(crate) fn save_current_session(ollama_state: &mut OllamaState) -> Result<()> {
     if let Some(session) = &ollama_state.current_session {
         if let Some(storage) = &ollama_state.chat_storage {
             storage.save_session(session)?;
@@ -1112,6 +1798,7 @@ fn save_current_session(ollama_state: &mut OllamaState) -> Result<()> {
             }
 
             ollama_state.unsaved_changes = false;
+            ollama_state.last_saved_at = Some(std::time::Instant::now());
         }
     } else if !ollama_state.conversation.is_empty() {
         // Create a new session from current conversation
@@ -1120,10 +1807,11 @@ fn save_current_session(ollama_state: &mut OllamaState) -> Result<()> {
                 model.clone(),
                 ollama_state.system_prompt.clone(),
             );
+            new_session.params = ollama_state.params.clone();
 
-            // Associate with current snippet if available
-            if let Some(snippet) = &ollama_state.current_snippet {
-                new_session = new_session.with_snippet(snippet, "Current Snippet".to_string());
+            // Associate with the attached snippets if any
+            if !ollama_state.attached_snippets.is_empty() {
+                new_session = new_session.with_snippets(&ollama_state.attached_snippets);
             }
 
             // Copy conversation
@@ -1135,6 +1823,7 @@ fn save_current_session(ollama_state: &mut OllamaState) -> Result<()> {
                 ollama_state.saved_sessions.push(new_session.clone());
                 ollama_state.current_session = Some(new_session);
                 ollama_state.unsaved_changes = false;
+                ollama_state.last_saved_at = Some(std::time::Instant::now());
             }
         }
     }
@@ -1152,6 +1841,7 @@ fn refresh_sessions(ollama_state: &mut OllamaState) -> Result<()> {
 fn scroll_chat_up(ollama_state: &mut OllamaState) {
     let scroll_amount = ollama_state.scroll_speed.max(1);
     ollama_state.scroll_position = ollama_state.scroll_position.saturating_sub(scroll_amount);
+    ollama_state.follow_output = false;
 }
 
 /// Safely scroll chat down with overflow protection
@@ -1164,6 +1854,7 @@ fn scroll_chat_down(ollama_state: &mut OllamaState) {
     ollama_state.scroll_position = (ollama_state.scroll_position + scroll_amount)
         .min(max_scroll)
         .min(2000);
+    ollama_state.follow_output = ollama_state.scroll_position >= max_scroll;
 }
 
 /// Safely fast scroll chat up
@@ -1172,6 +1863,7 @@ fn fast_scroll_chat_up(ollama_state: &mut OllamaState) {
     ollama_state.scroll_position = ollama_state
         .scroll_position
         .saturating_sub(fast_scroll_amount);
+    ollama_state.follow_output = false;
 }
 
 /// Safely fast scroll chat down with overflow protection
@@ -1184,6 +1876,7 @@ fn fast_scroll_chat_down(ollama_state: &mut OllamaState) {
     ollama_state.scroll_position = (ollama_state.scroll_position + fast_scroll_amount)
         .min(max_scroll)
         .min(2000);
+    ollama_state.follow_output = ollama_state.scroll_position >= max_scroll;
 }
 
 /// Calculate maximum scroll position based on chat content
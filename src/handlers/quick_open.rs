@@ -0,0 +1,74 @@
+//! Ctrl+P picker that opens a snippet by fuzzy matching its title
+
+use crate::app::{App, InputMode};
+use crate::handlers::keys::reveal_and_edit_snippet;
+use crate::handlers::text_input;
+use crate::search::quick_open_matches;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Opens the picker with an empty query
+pub fn enter_quick_open(app: &mut App) {
+    if app.snippet_database.snippets.is_empty() {
+        app.set_error_message("No snippets to open yet".to_string());
+        return;
+    }
+
+    app.clear_messages();
+    app.input_buffer.clear();
+    app.quick_open_selected = 0;
+    app.input_mode = InputMode::QuickOpen;
+}
+
+/// Handles keys while the picker is open
+pub fn handle_quick_open_keys(key: KeyEvent, app: &mut App) -> bool {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => close(app),
+        KeyCode::Enter => {
+            let matches = quick_open_matches(app, &app.input_buffer);
+            match matches.get(app.quick_open_selected) {
+                Some((snippet_id, _)) => {
+                    let snippet_id = *snippet_id;
+                    close(app);
+                    reveal_and_edit_snippet(app, snippet_id);
+                }
+                None => app.set_error_message("No snippet matches".to_string()),
+            }
+        }
+        KeyCode::Up => select_previous(app),
+        KeyCode::Char('p') | KeyCode::Char('k') if ctrl => select_previous(app),
+        KeyCode::Down => select_next(app),
+        KeyCode::Char('n') | KeyCode::Char('j') if ctrl => select_next(app),
+        KeyCode::Backspace => {
+            text_input::backspace(&mut app.input_buffer, &mut app.input_cursor);
+            app.quick_open_selected = 0;
+        }
+        KeyCode::Char(c) if !ctrl => {
+            text_input::insert(&mut app.input_buffer, &mut app.input_cursor, &c.to_string());
+            app.quick_open_selected = 0;
+        }
+        _ => {
+            if text_input::handle_editing_key(key, &mut app.input_buffer, &mut app.input_cursor) {
+                app.quick_open_selected = 0;
+            }
+        }
+    }
+    false
+}
+
+fn close(app: &mut App) {
+    app.input_mode = InputMode::Normal;
+    app.input_buffer.clear();
+    app.quick_open_selected = 0;
+}
+
+fn select_previous(app: &mut App) {
+    app.quick_open_selected = app.quick_open_selected.saturating_sub(1);
+}
+
+fn select_next(app: &mut App) {
+    let count = quick_open_matches(app, &app.input_buffer).len();
+    if app.quick_open_selected + 1 < count {
+        app.quick_open_selected += 1;
+    }
+}
@@ -0,0 +1,112 @@
+//! Cursor movement and mid-line editing for the single-line text fields
+//!
+//! The cursor is counted in characters back from the end of the text, so text that other
+//! code sets or appends keeps the cursor at its end as before.
+
+use crate::app::{App, AppState, InputMode};
+use crate::ui::export_import::ExportImportMode;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// The field keys go to, used to put the cursor back at the end when another field opens
+pub fn focused_field(app: &App) -> (AppState, InputMode, Option<ExportImportMode>, bool) {
+    (
+        app.state.clone(),
+        app.input_mode.clone(),
+        app.export_import_state
+            .as_ref()
+            .map(|state| state.mode.clone()),
+        app.boilerplate_prompt.is_some() || app.tag_prompt.is_some(),
+    )
+}
+
+/// Characters before the cursor, after pulling a stale cursor back inside the text
+pub fn cursor_position(text: &str, cursor: &mut usize) -> usize {
+    let len = text.chars().count();
+    *cursor = (*cursor).min(len);
+    len - *cursor
+}
+
+/// Byte offset of the character at `position`
+fn byte_index(text: &str, position: usize) -> usize {
+    text.char_indices()
+        .nth(position)
+        .map_or(text.len(), |(index, _)| index)
+}
+
+/// Inserts text at the cursor, leaving the cursor after it
+pub fn insert(text: &mut String, cursor: &mut usize, inserted: &str) {
+    let position = cursor_position(text, cursor);
+    text.insert_str(byte_index(text, position), inserted);
+}
+
+/// Removes the character before the cursor; false when the cursor is at the start
+pub fn backspace(text: &mut String, cursor: &mut usize) -> bool {
+    let position = cursor_position(text, cursor);
+    if position == 0 {
+        return false;
+    }
+    text.remove(byte_index(text, position - 1));
+    true
+}
+
+/// Types, deletes and moves the cursor in `App::input_buffer`; false for keys it ignores
+pub fn edit_input_buffer(key: KeyEvent, app: &mut App) -> bool {
+    match key.code {
+        KeyCode::Char(c) => insert(&mut app.input_buffer, &mut app.input_cursor, &c.to_string()),
+        KeyCode::Backspace => {
+            backspace(&mut app.input_buffer, &mut app.input_cursor);
+        }
+        _ => return handle_editing_key(key, &mut app.input_buffer, &mut app.input_cursor),
+    }
+    true
+}
+
+/// Handles Left/Right/Home/End, Ctrl+Left/Right by word and Delete; false for other keys
+pub fn handle_editing_key(key: KeyEvent, text: &mut String, cursor: &mut usize) -> bool {
+    let position = cursor_position(text, cursor);
+    let len = text.chars().count();
+    let by_word = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    let new_position = match key.code {
+        KeyCode::Left if by_word => previous_word_start(text, position),
+        KeyCode::Right if by_word => next_word_end(text, position),
+        KeyCode::Left => position.saturating_sub(1),
+        KeyCode::Right => (position + 1).min(len),
+        KeyCode::Home => 0,
+        KeyCode::End => len,
+        KeyCode::Delete => {
+            if position < len {
+                text.remove(byte_index(text, position));
+                *cursor -= 1;
+            }
+            return true;
+        }
+        _ => return false,
+    };
+    *cursor = len - new_position;
+    true
+}
+
+fn previous_word_start(text: &str, position: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut index = position;
+    while index > 0 && !chars[index - 1].is_alphanumeric() {
+        index -= 1;
+    }
+    while index > 0 && chars[index - 1].is_alphanumeric() {
+        index -= 1;
+    }
+    index
+}
+
+fn next_word_end(text: &str, position: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut index = position;
+    while index < chars.len() && !chars[index].is_alphanumeric() {
+        index += 1;
+    }
+    while index < chars.len() && chars[index].is_alphanumeric() {
+        index += 1;
+    }
+    index
+}
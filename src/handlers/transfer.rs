@@ -0,0 +1,198 @@
+//! Exports and imports that run on a background thread so the UI keeps drawing
+
+use crate::app::App;
+use crate::handlers::keys::merge_file_import;
+use crate::models::export::{ExportData, SourceImportSummary};
+use crate::models::storage::SnippetDatabase;
+use crate::models::{
+    ExportOptions, export_database_with_progress, import_database, import_encrypted_database,
+    import_source_directory,
+};
+use crate::ui::export_import::ExportImportMode;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+use std::thread;
+use std::time::Instant;
+
+/// What the background thread hands back once it is done
+#[derive(Debug)]
+enum TransferOutcome {
+    Exported(anyhow::Result<PathBuf>),
+    Loaded(anyhow::Result<ExportData>),
+    SourceFolder(anyhow::Result<(SnippetDatabase, SourceImportSummary)>),
+}
+
+/// An export or import running off the UI thread
+#[derive(Debug)]
+pub struct TransferJob {
+    /// Notebooks, snippets or files handled so far
+    processed: Arc<AtomicUsize>,
+    /// Set by `cancel_transfer`; the thread stops before writing anything
+    cancelled: Arc<AtomicBool>,
+    started_at: Instant,
+    overwrite: bool,
+    receiver: Receiver<TransferOutcome>,
+    pub animation_frame: usize,
+}
+
+impl TransferJob {
+    fn spawn(
+        overwrite: bool,
+        work: impl FnOnce(&AtomicUsize, &AtomicBool) -> TransferOutcome + Send + 'static,
+    ) -> Self {
+        let processed = Arc::new(AtomicUsize::new(0));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let counter = Arc::clone(&processed);
+        let stop = Arc::clone(&cancelled);
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            let _ = sender.send(work(&counter, &stop));
+        });
+
+        Self {
+            processed,
+            cancelled,
+            started_at: Instant::now(),
+            overwrite,
+            receiver,
+            animation_frame: 0,
+        }
+    }
+
+    pub fn processed(&self) -> usize {
+        self.processed.load(Ordering::Relaxed)
+    }
+
+    pub fn elapsed_secs(&self) -> u64 {
+        self.started_at.elapsed().as_secs()
+    }
+}
+
+/// Writes the export in the background
+pub fn start_export(app: &mut App, path: PathBuf, options: ExportOptions) {
+    let database = app.snippet_database.clone();
+    let tag_manager = app.tag_manager.clone();
+
+    app.transfer_job = Some(TransferJob::spawn(false, move |progress, cancelled| {
+        let result = export_database_with_progress(
+            &database,
+            &tag_manager,
+            &path,
+            &options,
+            progress,
+            cancelled,
+        );
+        TransferOutcome::Exported(result.map(|_| path))
+    }));
+}
+
+/// Reads (and decrypts) an export file in the background, merging it once loaded
+pub fn start_file_import(
+    app: &mut App,
+    path: PathBuf,
+    passphrase: Option<String>,
+    overwrite: bool,
+) {
+    app.transfer_job = Some(TransferJob::spawn(overwrite, move |progress, _| {
+        let result = match passphrase {
+            Some(passphrase) => import_encrypted_database(&path, &passphrase),
+            None => import_database(&path),
+        };
+        if let Ok(data) = &result {
+            progress.store(
+                data.notebooks.len() + data.snippets.len(),
+                Ordering::Relaxed,
+            );
+        }
+        TransferOutcome::Loaded(result)
+    }));
+}
+
+/// Reads a folder of source files in the background into a fresh database
+pub fn start_source_import(app: &mut App, path: PathBuf) {
+    app.transfer_job = Some(TransferJob::spawn(false, move |progress, cancelled| {
+        let mut database = SnippetDatabase::default();
+        let result = import_source_directory(&mut database, &path, progress, cancelled);
+        TransferOutcome::SourceFolder(result.map(|summary| (database, summary)))
+    }));
+}
+
+/// Stops the running transfer; whatever it finishes afterwards is thrown away
+pub fn cancel_transfer(app: &mut App) {
+    if let Some(job) = app.transfer_job.take() {
+        job.cancelled.store(true, Ordering::Relaxed);
+        set_status(app, "Transfer cancelled".to_string(), false);
+    }
+}
+
+/// Advances the spinner and applies the result once the background thread is done
+pub fn poll_transfer(app: &mut App) {
+    let Some(job) = app.transfer_job.as_mut() else {
+        return;
+    };
+    job.animation_frame = job.animation_frame.wrapping_add(1);
+
+    let outcome = match job.receiver.try_recv() {
+        Ok(outcome) => outcome,
+        Err(TryRecvError::Empty) => return,
+        Err(TryRecvError::Disconnected) => {
+            app.transfer_job = None;
+            set_status(app, "The transfer stopped unexpectedly".to_string(), true);
+            return;
+        }
+    };
+    let overwrite = job.overwrite;
+    app.transfer_job = None;
+
+    match outcome {
+        TransferOutcome::Exported(Ok(path)) => set_status(
+            app,
+            format!("Export successful! Saved to {}", path.display()),
+            false,
+        ),
+        TransferOutcome::Exported(Err(e)) => set_status(app, format!("Export failed: {}", e), true),
+        TransferOutcome::Loaded(result) => merge_file_import(app, result, overwrite),
+        TransferOutcome::SourceFolder(Ok((imported, summary))) => {
+            let known = app.snippet_ids();
+            app.snippet_database.notebooks.extend(imported.notebooks);
+            app.snippet_database.snippets.extend(imported.snippets);
+            app.snippet_database
+                .root_notebooks
+                .extend(imported.root_notebooks);
+            app.refresh_tree_items();
+
+            match app.save_imported_database(&known) {
+                Ok(()) => set_status(
+                    app,
+                    format!(
+                        "Imported {} snippets into {} notebooks, skipped {} files",
+                        summary.imported, summary.notebooks, summary.skipped
+                    ),
+                    false,
+                ),
+                Err(e) => set_status(
+                    app,
+                    format!("Import succeeded but failed to save database: {}", e),
+                    true,
+                ),
+            }
+        }
+        TransferOutcome::SourceFolder(Err(e)) => {
+            set_status(app, format!("Import failed: {}", e), true)
+        }
+    }
+}
+
+fn set_status(app: &mut App, message: String, is_error: bool) {
+    match app.export_import_state.as_mut() {
+        Some(state) => {
+            state.status_message = Some(message);
+            state.is_error = is_error;
+            state.mode = ExportImportMode::MainMenu;
+        }
+        None if is_error => app.set_error_message(message),
+        None => app.set_success_message(message),
+    }
+}
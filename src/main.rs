@@ -7,14 +7,16 @@ use ratatui::{
     crossterm::{
         cursor::Show,
         event::{self, Event},
-        event::{DisableMouseCapture, EnableMouseCapture},
+        event::{
+            DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        },
         execute,
         terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
     },
 };
 use std::error::Error;
 use std::io::{self};
-use std::panic;
+use std::panic::{self, AssertUnwindSafe};
 use std::time::Duration;
 
 mod app;
@@ -24,7 +26,7 @@ mod models;
 mod search;
 mod ui;
 
-use handlers::keys::handle_key_events;
+use handlers::keys::{handle_key_events, handle_paste_event};
 
 /// Main entry point for the application
 fn main() -> Result<(), Box<dyn Error>> {
@@ -40,10 +42,24 @@ fn main() -> Result<(), Box<dyn Error>> {
     }));
 
     let mut terminal = setup_terminal()?;
+    let mut app = App::new();
 
-    // Run the application
-    let result = run_app(&mut terminal);
-    cleanup_terminal()?;
+    // Run the application, catching a panic so the exit backup still gets written
+    let result = panic::catch_unwind(AssertUnwindSafe(|| run_app(&mut terminal, &mut app)));
+    let backup = app.backup_on_exit();
+
+    let result = match result {
+        Ok(result) => {
+            cleanup_terminal()?;
+            result
+        }
+        Err(payload) => {
+            // The panic hook has already restored the terminal
+            report_backup(backup);
+            panic::resume_unwind(payload);
+        }
+    };
+    report_backup(backup);
 
     if let Err(err) = result {
         eprintln!("Error: {}", err);
@@ -53,6 +69,15 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Prints where the exit backup went, if one was written
+fn report_backup(backup: Result<Option<std::path::PathBuf>, String>) {
+    match backup {
+        Ok(Some(path)) => println!("Backup saved to {}", path.display()),
+        Ok(None) => {}
+        Err(e) => eprintln!("{}", e),
+    }
+}
+
 /// Sets up the terminal for the TUI application
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, Box<dyn Error>> {
     println!("Starting snix - Template & Boilerplate Manager");
@@ -60,33 +85,57 @@ fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, Box<dyn Er
 
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
 
     Ok(terminal)
 }
 
+/// Event poll timeout while a spinner or timed feedback is on screen
+const ACTIVE_POLL_INTERVAL: Duration = Duration::from_millis(80);
+/// Event poll timeout when nothing animates; input still wakes the loop right away
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
 /// Runs the main application loop
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<(), Box<dyn Error>> {
-    let mut app = App::new();
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+) -> Result<(), Box<dyn Error>> {
     let mut should_quit = false;
 
     while !should_quit {
         if app.needs_redraw {
-            force_redraw(terminal, &mut app)?;
+            force_redraw(terminal, app)?;
             app.needs_redraw = false;
         } else {
             terminal.draw(|frame| app.render(frame))?;
         }
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                should_quit = handle_key_events(key, &mut app);
-
-                if app.needs_redraw {
-                    force_redraw(terminal, &mut app)?;
-                    app.needs_redraw = false;
+        let poll_interval = if app.is_animating() {
+            ACTIVE_POLL_INTERVAL
+        } else {
+            IDLE_POLL_INTERVAL
+        };
+        if event::poll(poll_interval)? {
+            match event::read()? {
+                Event::Key(key) => {
+                    should_quit = handle_key_events(key, app);
+
+                    if app.needs_redraw {
+                        force_redraw(terminal, app)?;
+                        app.needs_redraw = false;
+                    }
                 }
+                // A paste arrives whole instead of as one key event per character
+                Event::Paste(text) => handle_paste_event(&text, app),
+                // Start from a clean screen; `App::render` checks the new size
+                Event::Resize(_, _) => app.needs_redraw = true,
+                _ => {}
             }
         }
         app._tick();
@@ -119,7 +168,12 @@ fn force_redraw<B: ratatui::backend::Backend>(
 fn cleanup_terminal() -> Result<(), Box<dyn Error>> {
     disable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, LeaveAlternateScreen, DisableMouseCapture)?;
+    execute!(
+        stdout,
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
     execute!(stdout, Show)?;
 
     println!("Thanks for using snix! Goodbye!");
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, Utc};
+
+use crate::models::export::{ExportOptions, export_database_with_tags};
+use crate::models::settings::Settings;
+use crate::models::storage::{SnippetDatabase, snix_dir};
+use crate::models::tags::TagManager;
+
+/// File name prefix of the backups written when the app exits
+const EXIT_BACKUP_PREFIX: &str = "backup-exit-";
+
+/// Timestamp format every backup file name ends with
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Which backups survive a prune; a backup kept by any rule is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// The newest backups, whatever their age
+    pub keep_last: usize,
+    /// The newest backup of each of the most recent days
+    pub keep_daily: usize,
+    /// The newest backup of each of the most recent weeks
+    pub keep_weekly: usize,
+}
+
+impl RetentionPolicy {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            keep_last: settings.backup_keep_last,
+            keep_daily: settings.backup_keep_daily,
+            keep_weekly: settings.backup_keep_weekly,
+        }
+    }
+
+    /// Short summary such as "last 5, 7 daily, 4 weekly"
+    pub fn describe(&self) -> String {
+        format!(
+            "last {}, {} daily, {} weekly",
+            self.keep_last, self.keep_daily, self.keep_weekly
+        )
+    }
+
+    /// Backups that no rule keeps, given each backup with its creation time
+    pub fn expired(&self, backups: &[(PathBuf, DateTime<Utc>)]) -> Vec<PathBuf> {
+        let mut newest_first: Vec<&(PathBuf, DateTime<Utc>)> = backups.iter().collect();
+        newest_first.sort_by_key(|(_, created_at)| std::cmp::Reverse(*created_at));
+
+        let mut kept: HashSet<&Path> = newest_first
+            .iter()
+            .take(self.keep_last.max(1))
+            .map(|(path, _)| path.as_path())
+            .collect();
+
+        let mut days = HashSet::new();
+        let mut weeks = HashSet::new();
+        for (path, created_at) in &newest_first {
+            let local = created_at.with_timezone(&Local);
+            if days.len() < self.keep_daily && days.insert(local.date_naive()) {
+                kept.insert(path.as_path());
+            }
+            let week = local.iso_week();
+            if weeks.len() < self.keep_weekly && weeks.insert((week.year(), week.week())) {
+                kept.insert(path.as_path());
+            }
+        }
+
+        newest_first
+            .into_iter()
+            .filter(|(path, _)| !kept.contains(path.as_path()))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// Folder holding the backups, `~/.snix/backups`
+pub fn backups_dir() -> Option<PathBuf> {
+    snix_dir().map(|dir| dir.join("backups"))
+}
+
+/// Path for a new backup named `<prefix><timestamp>.json`, creating the folder if needed
+pub fn new_backup_path(prefix: &str) -> Result<PathBuf> {
+    let dir = backups_dir().context("Failed to get home directory")?;
+    fs::create_dir_all(&dir).context("Failed to create backups directory")?;
+
+    Ok(dir.join(format!(
+        "{}{}.json",
+        prefix,
+        Utc::now().format(BACKUP_TIMESTAMP_FORMAT)
+    )))
+}
+
+/// Writes a timestamped backup of every snippet with its content and tags,
+/// then prunes the backups folder with `policy`
+pub fn write_exit_backup(
+    database: &SnippetDatabase,
+    tag_manager: &TagManager,
+    policy: &RetentionPolicy,
+) -> Result<PathBuf> {
+    let path = new_backup_path(EXIT_BACKUP_PREFIX)?;
+    export_database_with_tags(database, tag_manager, &path, &ExportOptions::default())?;
+
+    apply_retention(policy)?;
+    Ok(path)
+}
+
+/// Deletes the backups in `~/.snix/backups` that `policy` does not keep,
+/// returning how many were removed. Files without a timestamp in their name are left alone.
+pub fn apply_retention(policy: &RetentionPolicy) -> Result<usize> {
+    let Some(dir) = backups_dir().filter(|dir| dir.is_dir()) else {
+        return Ok(0);
+    };
+
+    let backups: Vec<(PathBuf, DateTime<Utc>)> = fs::read_dir(&dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter_map(|path| backup_timestamp(&path).map(|created_at| (path, created_at)))
+        .collect();
+
+    let expired = policy.expired(&backups);
+    for path in &expired {
+        fs::remove_file(path)
+            .with_context(|| format!("Failed to remove old backup {}", path.display()))?;
+    }
+    Ok(expired.len())
+}
+
+/// Creation time encoded at the end of a backup file name
+fn backup_timestamp(path: &Path) -> Option<DateTime<Utc>> {
+    if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+        return None;
+    }
+
+    let stem = path.file_stem()?.to_str()?;
+    let timestamp = stem.get(stem.len().checked_sub(15)?..)?;
+    NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT)
+        .ok()
+        .map(|naive| naive.and_utc())
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::models::storage::snix_dir;
+use crate::models::template;
+
+/// A multi-file project template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Boilerplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub files: Vec<BoilerplateFile>,
+}
+
+/// One file of a boilerplate; both the path and the content may hold placeholders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoilerplateFile {
+    pub path: String,
+    #[serde(default)]
+    pub content: String,
+}
+
+impl BoilerplateFile {
+    fn new(path: &str, content: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            content: content.to_string(),
+        }
+    }
+}
+
+impl Boilerplate {
+    /// Placeholders used anywhere in the boilerplate, in order of first use
+    pub fn template_variables(&self) -> Vec<String> {
+        template::template_variables(
+            self.files
+                .iter()
+                .flat_map(|file| [file.path.as_str(), file.content.as_str()]),
+        )
+    }
+
+    /// Writes every file below `target`, refusing to overwrite anything already there
+    pub fn scaffold(&self, target: &Path, values: &HashMap<String, String>) -> Result<usize> {
+        let mut planned = Vec::with_capacity(self.files.len());
+        for file in &self.files {
+            let relative = PathBuf::from(template::fill_template(&file.path, values));
+            // Keep every file inside the target directory
+            if relative
+                .components()
+                .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+            {
+                return Err(anyhow!("Invalid file path in boilerplate: {}", file.path));
+            }
+
+            let path = target.join(relative);
+            if path.exists() {
+                return Err(anyhow!("{} already exists", path.display()));
+            }
+            planned.push((path, template::fill_template(&file.content, values)));
+        }
+
+        for (path, content) in &planned {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            fs::write(path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+
+        Ok(planned.len())
+    }
+}
+
+/// Folder holding one `.toml` file per boilerplate
+pub fn boilerplates_dir() -> Option<PathBuf> {
+    snix_dir().map(|dir| dir.join("boilerplates"))
+}
+
+/// Loads every boilerplate, seeding the folder with the built-in set on first use.
+/// Files that fail to parse are returned separately so one typo does not hide the rest.
+pub fn load_boilerplates() -> Result<(Vec<Boilerplate>, Vec<String>)> {
+    let dir = boilerplates_dir().context("Failed to get home directory")?;
+    if !dir.exists() {
+        seed_boilerplates(&dir)?;
+    }
+
+    let mut boilerplates = Vec::new();
+    let mut invalid = Vec::new();
+    let entries =
+        fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+            continue;
+        }
+
+        let parsed = fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| toml::from_str::<Boilerplate>(&content).map_err(Into::into));
+        match parsed {
+            Ok(boilerplate) => boilerplates.push(boilerplate),
+            Err(e) => invalid.push(format!(
+                "{}: {}",
+                path.file_name().unwrap_or_default().to_string_lossy(),
+                e
+            )),
+        }
+    }
+
+    boilerplates.sort_by_key(|boilerplate| boilerplate.name.to_lowercase());
+    Ok((boilerplates, invalid))
+}
+
+fn seed_boilerplates(dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    for (file_name, boilerplate) in default_boilerplates() {
+        let content = toml::to_string_pretty(&boilerplate)
+            .with_context(|| format!("Failed to serialize {}", boilerplate.name))?;
+        fs::write(dir.join(file_name), content)
+            .with_context(|| format!("Failed to write {}", file_name))?;
+    }
+    Ok(())
+}
+
+fn default_boilerplates() -> Vec<(&'static str, Boilerplate)> {
+    vec![
+        (
+            "rust-cli.toml",
+            Boilerplate {
+                name: "Rust CLI".to_string(),
+                description: "A binary crate with a main function and a .gitignore".to_string(),
+                files: vec![
+                    BoilerplateFile::new(
+                        "Cargo.toml",
+                        r#"[package]
+name = "{{project}}"
+version = "0.1.0"
+edition = "2024"
+authors = ["{{author}}"]
+
+[dependencies]
+"#,
+                    ),
+                    BoilerplateFile::new(
+                        "src/main.rs",
+                        r#"fn main() {
+    println!("Hello from {{project}}!");{{cursor}}
+}
+"#,
+                    ),
+                    BoilerplateFile::new(".gitignore", "/target\n"),
+                ],
+            },
+        ),
+        (
+            "python-package.toml",
+            Boilerplate {
+                name: "Python package".to_string(),
+                description: "A src-layout package with a pyproject.toml".to_string(),
+                files: vec![
+                    BoilerplateFile::new(
+                        "pyproject.toml",
+                        r#"[project]
+name = "{{project}}"
+version = "0.1.0"
+description = "{{description}}"
+authors = [{ name = "{{author}}" }]
+requires-python = ">=3.9"
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+"#,
+                    ),
+                    BoilerplateFile::new(
+                        "src/{{module}}/__init__.py",
+                        "\"\"\"{{description}}\"\"\"\n\n__version__ = \"0.1.0\"\n",
+                    ),
+                    BoilerplateFile::new("README.md", "# {{project}}\n\n{{description}}\n"),
+                    BoilerplateFile::new(".gitignore", "__pycache__/\n*.egg-info/\n.venv/\n"),
+                ],
+            },
+        ),
+        (
+            "node-script.toml",
+            Boilerplate {
+                name: "Node script".to_string(),
+                description: "An ES module entry point with a package.json".to_string(),
+                files: vec![
+                    BoilerplateFile::new(
+                        "package.json",
+                        r#"{
+  "name": "{{project}}",
+  "version": "0.1.0",
+  "type": "module",
+  "main": "index.js",
+  "author": "{{author}}",
+  "scripts": {
+    "start": "node index.js"
+  }
+}
+"#,
+                    ),
+                    BoilerplateFile::new(
+                        "index.js",
+                        "// {{project}}, created {{date}}\n\nconsole.log(\"Hello from {{project}}\");\n",
+                    ),
+                    BoilerplateFile::new(".gitignore", "node_modules/\n"),
+                ],
+            },
+        ),
+    ]
+}
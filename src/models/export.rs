@@ -1,22 +1,46 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, rand_core::RngCore};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
 use anyhow::{Context, Result};
+use argon2::Argon2;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::models::storage::SnippetDatabase;
-use crate::models::{CodeSnippet, Notebook, TagManager};
+use crate::models::{CodeSnippet, Notebook, SnippetLanguage, TagManager};
+
+/// Sidecar file written next to the snippet files of a directory export
+pub const DIRECTORY_MANIFEST_FILE: &str = "snix-export.json";
+
+/// Header that marks a passphrase-encrypted export
+pub const ENCRYPTED_EXPORT_MAGIC: &[u8] = b"SNIXENC1";
+
+/// Extension given to passphrase-encrypted exports
+pub const ENCRYPTED_EXPORT_EXTENSION: &str = "snix.enc";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Source files larger than this are skipped when importing a folder
+pub const MAX_SOURCE_FILE_SIZE: u64 = 512 * 1024;
 
 /// Export format options
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ExportFormat {
     JSON,
     YAML,
     TOML,
+    /// One row per snippet, for spreadsheets (export only)
+    CSV,
+    /// Folders per notebook with one file per snippet, plus a metadata sidecar
+    Directory,
 }
 
 /// Export options for customizing what to export
@@ -25,7 +49,12 @@ pub struct ExportOptions {
     pub _format: ExportFormat,
     pub include_content: bool,
     pub notebook_ids: Option<Vec<Uuid>>,
+    pub snippet_ids: Option<Vec<Uuid>>,
     pub include_favorites_only: bool,
+    /// Only export snippets carrying at least one of these tags
+    pub tags: Option<Vec<String>>,
+    /// Encrypt the written file with this passphrase
+    pub passphrase: Option<String>,
 }
 
 impl Default for ExportOptions {
@@ -34,11 +63,48 @@ impl Default for ExportOptions {
             _format: ExportFormat::JSON,
             include_content: true,
             notebook_ids: None,
+            snippet_ids: None,
             include_favorites_only: false,
+            tags: None,
+            passphrase: None,
         }
     }
 }
 
+/// Metadata sidecar of a directory export, keeping what plain files cannot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryManifest {
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    pub notebooks: Vec<ManifestNotebook>,
+    pub snippets: Vec<ManifestSnippet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestNotebook {
+    pub id: Uuid,
+    pub name: String,
+    pub parent_id: Option<Uuid>,
+    /// Folder relative to the export root
+    pub path: PathBuf,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestSnippet {
+    pub id: Uuid,
+    pub notebook_id: Uuid,
+    /// File relative to the export root
+    pub path: PathBuf,
+    pub title: String,
+    pub language: SnippetLanguage,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub is_favorite: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 /// Export file structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportData {
@@ -73,11 +139,21 @@ impl ExportData {
                 include = notebook_ids.contains(&snippet.notebook_id);
             }
 
+            // Filter by an explicit snippet selection if needed
+            if let Some(snippet_ids) = &options.snippet_ids {
+                include = include && snippet_ids.contains(id);
+            }
+
             // Filter by favorites if needed
             if options.include_favorites_only {
                 include = include && snippet.is_favorite;
             }
 
+            // Filter by tags if needed
+            if let Some(tags) = &options.tags {
+                include = include && tags.iter().any(|tag| snippet.has_tag(tag));
+            }
+
             if include {
                 let mut snippet_clone = snippet.clone();
 
@@ -90,6 +166,27 @@ impl ExportData {
             }
         }
 
+        // Only keep the notebooks (and their ancestors) that hold selected snippets
+        if options.snippet_ids.is_some() || options.tags.is_some() || options.include_favorites_only
+        {
+            let mut needed = std::collections::HashSet::new();
+            for snippet in snippets.values() {
+                let mut current = Some(snippet.notebook_id);
+                while let Some(id) = current {
+                    if !needed.insert(id) {
+                        break;
+                    }
+                    current = notebooks.get(&id).and_then(|n| n.parent_id);
+                }
+            }
+
+            notebooks.retain(|id, _| needed.contains(id));
+            root_notebooks.retain(|id| needed.contains(id));
+            for notebook in notebooks.values_mut() {
+                notebook.children.retain(|id| needed.contains(id));
+            }
+        }
+
         Self {
             version: env!("CARGO_PKG_VERSION").to_string(),
             created_at: Utc::now(),
@@ -132,74 +229,413 @@ impl ExportData {
     }
 }
 
-/// Export database to a file including tag information
+/// Export database to a file including tag information.
+/// Returns the number of notebooks and snippets written.
 pub fn export_database_with_tags(
     db: &SnippetDatabase,
     tag_manager: &TagManager,
     path: &Path,
     options: &ExportOptions,
-) -> Result<()> {
+) -> Result<(usize, usize)> {
+    export_database_with_progress(
+        db,
+        tag_manager,
+        path,
+        options,
+        &AtomicUsize::new(0),
+        &AtomicBool::new(false),
+    )
+}
+
+/// Same as `export_database_with_tags`, storing the number of exported items in `progress`
+///
+/// Nothing is written once `cancelled` is set.
+pub fn export_database_with_progress(
+    db: &SnippetDatabase,
+    tag_manager: &TagManager,
+    path: &Path,
+    options: &ExportOptions,
+    progress: &AtomicUsize,
+    cancelled: &AtomicBool,
+) -> Result<(usize, usize)> {
     let export_data = ExportData::from_database_with_tags(db, tag_manager, options);
+    let counts = (export_data.notebooks.len(), export_data.snippets.len());
+    progress.store(counts.0 + counts.1, Ordering::Relaxed);
 
     // Export based on format
-    match options._format {
-        ExportFormat::JSON => {
-            let json = serde_json::to_string_pretty(&export_data)
-                .context("Failed to serialize database to JSON")?;
-            fs::write(path, json).context("Failed to write JSON export file")?;
-        }
-        ExportFormat::YAML => {
-            let yaml = serde_yaml::to_string(&export_data)
-                .context("Failed to serialize database to YAML")?;
-            fs::write(path, yaml).context("Failed to write YAML export file")?;
+    let contents =
+        match options._format {
+            ExportFormat::JSON => serde_json::to_string_pretty(&export_data)
+                .context("Failed to serialize database to JSON")?,
+            ExportFormat::YAML => serde_yaml::to_string(&export_data)
+                .context("Failed to serialize database to YAML")?,
+            ExportFormat::TOML => toml::to_string_pretty(&export_data)
+                .context("Failed to serialize database to TOML")?,
+            ExportFormat::CSV => export_data.to_csv(options.include_content),
+            ExportFormat::Directory => {
+                if options.passphrase.is_some() {
+                    return Err(anyhow::anyhow!("Folder exports cannot be encrypted"));
+                }
+                if cancelled.load(Ordering::Relaxed) {
+                    return Err(anyhow::anyhow!("Export cancelled"));
+                }
+                return export_to_directory(&export_data, path).map(|_| counts);
+            }
+        };
+
+    if cancelled.load(Ordering::Relaxed) {
+        return Err(anyhow::anyhow!("Export cancelled"));
+    }
+    match &options.passphrase {
+        Some(passphrase) => {
+            let encrypted = encrypt_export(contents.as_bytes(), passphrase)?;
+            fs::write(path, encrypted).context("Failed to write encrypted export file")?;
         }
-        ExportFormat::TOML => {
-            let toml = toml::to_string_pretty(&export_data)
-                .context("Failed to serialize database to TOML")?;
-            fs::write(path, toml).context("Failed to write TOML export file")?;
+        None => fs::write(path, contents).context("Failed to write export file")?,
+    }
+
+    Ok(counts)
+}
+
+/// Derive a 256-bit key from a passphrase with Argon2id
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow::anyhow!("Failed to derive encryption key: {}", e))?;
+    Ok(key)
+}
+
+/// Wrap serialized export bytes as magic | salt | nonce | AES-256-GCM ciphertext
+fn encrypt_export(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    if passphrase.is_empty() {
+        return Err(anyhow::anyhow!("Passphrase cannot be empty"));
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(passphrase, &salt)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| anyhow::anyhow!("Failed to encrypt export"))?;
+
+    let mut output =
+        Vec::with_capacity(ENCRYPTED_EXPORT_MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    output.extend_from_slice(ENCRYPTED_EXPORT_MAGIC);
+    output.extend_from_slice(&salt);
+    output.extend_from_slice(&nonce);
+    output.extend_from_slice(&ciphertext);
+    Ok(output)
+}
+
+fn decrypt_export(bytes: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let body = bytes
+        .strip_prefix(ENCRYPTED_EXPORT_MAGIC)
+        .context("File is not an encrypted snix export")?;
+    if body.len() < SALT_LEN + NONCE_LEN {
+        return Err(anyhow::anyhow!("Encrypted export is truncated"));
+    }
+
+    let (salt, rest) = body.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let key = derive_key(passphrase, salt)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow::anyhow!("Wrong passphrase or the file is corrupted"))
+}
+
+/// Write one folder per notebook and one file per snippet below `root`
+fn export_to_directory(data: &ExportData, root: &Path) -> Result<()> {
+    if root.is_file() {
+        return Err(anyhow::anyhow!(
+            "{} is a file, not a directory",
+            root.display()
+        ));
+    }
+    if root.exists() {
+        let mut entries = fs::read_dir(root).context("Failed to read export directory")?;
+        if entries.next().is_some() {
+            return Err(anyhow::anyhow!(
+                "Export directory {} is not empty",
+                root.display()
+            ));
         }
     }
+    fs::create_dir_all(root).context("Failed to create export directory")?;
+
+    // Names already taken in each folder, compared case-insensitively
+    let mut used_names: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+    let mut folders: HashMap<Uuid, PathBuf> = HashMap::new();
+    let mut manifest = DirectoryManifest {
+        version: data.version.clone(),
+        created_at: Utc::now(),
+        notebooks: Vec::new(),
+        snippets: Vec::new(),
+    };
+
+    // Walk from the top-level notebooks down so parents get their folder first
+    let mut top_level: Vec<&Notebook> = data
+        .notebooks
+        .values()
+        .filter(|notebook| {
+            notebook
+                .parent_id
+                .is_none_or(|parent| !data.notebooks.contains_key(&parent))
+        })
+        .collect();
+    top_level.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut pending: Vec<(&Notebook, PathBuf)> = top_level
+        .into_iter()
+        .rev()
+        .map(|notebook| (notebook, PathBuf::new()))
+        .collect();
+
+    while let Some((notebook, parent_dir)) = pending.pop() {
+        let taken = used_names.entry(parent_dir.clone()).or_default();
+        let folder = parent_dir.join(unique_file_name(taken, &notebook.name, None));
+        fs::create_dir_all(root.join(&folder))
+            .with_context(|| format!("Failed to create folder for notebook '{}'", notebook.name))?;
+
+        let mut children: Vec<&Notebook> = notebook
+            .children
+            .iter()
+            .filter_map(|id| data.notebooks.get(id))
+            .collect();
+        children.sort_by(|a, b| b.name.cmp(&a.name));
+        pending.extend(children.into_iter().map(|child| (child, folder.clone())));
+
+        manifest.notebooks.push(ManifestNotebook {
+            id: notebook.id,
+            name: notebook.name.clone(),
+            parent_id: notebook.parent_id,
+            path: folder.clone(),
+            description: notebook.description.clone(),
+        });
+        folders.insert(notebook.id, folder);
+    }
+
+    let mut snippets: Vec<&CodeSnippet> = data.snippets.values().collect();
+    snippets.sort_by(|a, b| a.title.cmp(&b.title));
+
+    for snippet in snippets {
+        let folder = folders
+            .get(&snippet.notebook_id)
+            .cloned()
+            .unwrap_or_default();
+        let taken = used_names.entry(folder.clone()).or_default();
+        let extension = if snippet.file_extension.is_empty() {
+            snippet.language.file_extension().to_string()
+        } else {
+            snippet.file_extension.clone()
+        };
+        let file = folder.join(unique_file_name(taken, &snippet.title, Some(&extension)));
+
+        fs::write(root.join(&file), &snippet.content)
+            .with_context(|| format!("Failed to write snippet '{}'", snippet.title))?;
+
+        manifest.snippets.push(ManifestSnippet {
+            id: snippet.id,
+            notebook_id: snippet.notebook_id,
+            path: file,
+            title: snippet.title.clone(),
+            language: snippet.language.clone(),
+            description: snippet.description.clone(),
+            tags: snippet.tags.clone(),
+            is_favorite: snippet.is_favorite,
+            created_at: snippet.created_at,
+            updated_at: snippet.updated_at,
+        });
+    }
+
+    let json =
+        serde_json::to_string_pretty(&manifest).context("Failed to serialize export metadata")?;
+    fs::write(root.join(DIRECTORY_MANIFEST_FILE), json)
+        .context("Failed to write export metadata")?;
 
     Ok(())
 }
 
+/// A file system safe name that is not yet in `taken`, adding " (2)", " (3)", ... on collisions
+fn unique_file_name(taken: &mut HashSet<String>, name: &str, extension: Option<&str>) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let cleaned = cleaned.trim().trim_matches('.');
+    let stem = if cleaned.is_empty() {
+        "untitled"
+    } else {
+        cleaned
+    };
+
+    let with_extension = |stem: String| match extension {
+        Some(ext) => format!("{}.{}", stem, ext),
+        None => stem,
+    };
+
+    let mut candidate = with_extension(stem.to_string());
+    let mut counter = 2;
+    while taken.contains(&candidate.to_lowercase()) || candidate == DIRECTORY_MANIFEST_FILE {
+        candidate = with_extension(format!("{} ({})", stem, counter));
+        counter += 1;
+    }
+
+    taken.insert(candidate.to_lowercase());
+    candidate
+}
+
+impl ExportData {
+    /// Flatten the snippets to CSV, one row per snippet
+    pub fn to_csv(&self, include_content: bool) -> String {
+        let mut header = vec!["id", "notebook", "title", "language", "tags", "created_at"];
+        if include_content {
+            header.push("content");
+        }
+
+        let mut rows: Vec<Vec<String>> = self
+            .snippets
+            .values()
+            .map(|snippet| {
+                let mut row = vec![
+                    snippet.id.to_string(),
+                    self.notebook_path(snippet.notebook_id),
+                    snippet.title.clone(),
+                    snippet.language.display_name().to_string(),
+                    snippet.tags.join(";"),
+                    snippet.created_at.to_rfc3339(),
+                ];
+                if include_content {
+                    row.push(snippet.content.clone());
+                }
+                row
+            })
+            .collect();
+        rows.sort_by(|a, b| (&a[1], &a[2]).cmp(&(&b[1], &b[2])));
+
+        let mut csv = csv_record(header.into_iter().map(String::from));
+        for row in rows {
+            csv.push_str(&csv_record(row.into_iter()));
+        }
+        csv
+    }
+
+    /// Notebook names from the root down, e.g. "Work / Rust"
+    fn notebook_path(&self, notebook_id: Uuid) -> String {
+        let mut names = Vec::new();
+        let mut current = self.notebooks.get(&notebook_id);
+
+        while let Some(notebook) = current {
+            names.push(notebook.name.as_str());
+            current = notebook.parent_id.and_then(|id| self.notebooks.get(&id));
+        }
+
+        names.reverse();
+        names.join(" / ")
+    }
+}
+
+/// One CSV line, quoting fields that contain separators, quotes or line breaks
+fn csv_record(fields: impl Iterator<Item = String>) -> String {
+    let fields: Vec<String> = fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect();
+
+    format!("{}\r\n", fields.join(","))
+}
+
+/// Whether a file starts with the encrypted export header
+pub fn is_encrypted_export(path: &Path) -> bool {
+    let mut header = [0u8; 8];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok_and(|_| header == ENCRYPTED_EXPORT_MAGIC)
+}
+
 /// Import database from a file
 pub fn import_database(path: &Path) -> Result<ExportData> {
+    if is_encrypted_export(path) {
+        return Err(anyhow::anyhow!(
+            "This export is encrypted, a passphrase is required"
+        ));
+    }
+
     let mut file = File::open(path).context("Failed to open import file")?;
     let mut contents = String::new();
     file.read_to_string(&mut contents)
         .context("Failed to read import file")?;
 
+    parse_import(path, &contents)
+}
+
+/// Decrypt and import a passphrase-encrypted export
+pub fn import_encrypted_database(path: &Path, passphrase: &str) -> Result<ExportData> {
+    let bytes = fs::read(path).context("Failed to read import file")?;
+    let plaintext = decrypt_export(&bytes, passphrase)?;
+    let contents = String::from_utf8(plaintext).context("Decrypted export is not valid UTF-8")?;
+
+    parse_import(path, &contents)
+}
+
+fn parse_import(path: &Path, contents: &str) -> Result<ExportData> {
+    let mut data = parse_import_contents(path, contents)?;
+    // Exports from older versions may still carry `[COLOR:n]` description prefixes
+    for notebook in data.notebooks.values_mut() {
+        notebook.migrate_legacy_color();
+    }
+    Ok(data)
+}
+
+fn parse_import_contents(path: &Path, contents: &str) -> Result<ExportData> {
     // Try to determine format from file extension
     if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
         match ext.to_lowercase().as_str() {
             "json" => {
                 let data =
-                    serde_json::from_str(&contents).context("Failed to parse JSON import file")?;
+                    serde_json::from_str(contents).context("Failed to parse JSON import file")?;
                 return Ok(data);
             }
             "yaml" | "yml" => {
                 let data =
-                    serde_yaml::from_str(&contents).context("Failed to parse YAML import file")?;
+                    serde_yaml::from_str(contents).context("Failed to parse YAML import file")?;
                 return Ok(data);
             }
             "toml" => {
-                let data = toml::from_str(&contents).context("Failed to parse TOML import file")?;
+                let data = toml::from_str(contents).context("Failed to parse TOML import file")?;
                 return Ok(data);
             }
+            "csv" => {
+                return Err(anyhow::anyhow!(
+                    "CSV files can only be exported, not imported"
+                ));
+            }
             _ => {}
         }
     }
 
-    if let Ok(data) = serde_json::from_str(&contents) {
+    if let Ok(data) = serde_json::from_str(contents) {
         return Ok(data);
     }
 
-    if let Ok(data) = serde_yaml::from_str(&contents) {
+    if let Ok(data) = serde_yaml::from_str(contents) {
         return Ok(data);
     }
 
-    if let Ok(data) = toml::from_str(&contents) {
+    if let Ok(data) = toml::from_str(contents) {
         return Ok(data);
     }
 
@@ -211,9 +647,14 @@ pub fn import_database(path: &Path) -> Result<ExportData> {
 /// Merge imported data into existing database
 pub fn merge_import_into_database(
     db: &mut SnippetDatabase,
-    import_data: ExportData,
+    mut import_data: ExportData,
     overwrite_existing: bool,
 ) -> Result<(usize, usize)> {
+    // Exports from older versions may still carry `[COLOR:n]` description prefixes
+    for notebook in import_data.notebooks.values_mut() {
+        notebook.migrate_legacy_color();
+    }
+
     // Returns (notebooks_added, snippets_added)
     let mut notebooks_added = 0;
     let mut snippets_added = 0;
@@ -270,6 +711,495 @@ pub fn merge_import_into_database_with_tags(
     Ok((notebooks_added, snippets_added))
 }
 
+/// Render a notebook and all of its descendants as a single Markdown document
+pub fn notebook_to_markdown(db: &SnippetDatabase, notebook_id: Uuid) -> Result<(String, usize)> {
+    let notebook = db
+        .notebooks
+        .get(&notebook_id)
+        .context("Notebook not found")?;
+
+    let mut output = String::new();
+    let count = write_notebook_markdown(db, notebook, &notebook.name, &mut output);
+    Ok((output, count))
+}
+
+/// Appends one notebook section and its children, returning the number of snippets written
+fn write_notebook_markdown(
+    db: &SnippetDatabase,
+    notebook: &Notebook,
+    heading: &str,
+    output: &mut String,
+) -> usize {
+    output.push_str(&format!("# {}\n\n", heading));
+    if let Some(description) = notebook
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        output.push_str(&format!("{}\n\n", description.trim()));
+    }
+
+    let mut snippets: Vec<&CodeSnippet> = db
+        .snippets
+        .values()
+        .filter(|s| s.notebook_id == notebook.id)
+        .collect();
+    snippets.sort_by_key(|s| s.title.to_lowercase());
+
+    for snippet in &snippets {
+        output.push_str(&format!("## {}\n\n", snippet.title));
+        if let Some(description) = snippet
+            .description
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+        {
+            output.push_str(&format!("{}\n\n", description.trim()));
+        }
+
+        // A fence longer than any backtick run inside keeps the block intact
+        let longest_run = snippet
+            .content
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat((longest_run + 1).max(3));
+        output.push_str(&format!(
+            "{}{}\n{}\n{}\n\n",
+            fence,
+            snippet.language.fence_tag(),
+            snippet.content.trim_end_matches('\n'),
+            fence
+        ));
+    }
+
+    let mut children: Vec<&Notebook> = db
+        .notebooks
+        .values()
+        .filter(|n| n.parent_id == Some(notebook.id))
+        .collect();
+    children.sort_by_key(|n| n.name.to_lowercase());
+
+    let mut count = snippets.len();
+    for child in children {
+        let child_heading = format!("{} / {}", heading, child.name);
+        count += write_notebook_markdown(db, child, &child_heading, output);
+    }
+    count
+}
+
+/// Counts reported after importing a folder of source files
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SourceImportSummary {
+    pub notebooks: usize,
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+/// Import a folder of source files, creating a notebook per folder and a snippet per file
+///
+/// `progress` counts the files looked at so far, for callers on another thread, which can
+/// stop the walk early by setting `cancelled`.
+pub fn import_source_directory(
+    db: &mut SnippetDatabase,
+    root: &Path,
+    progress: &AtomicUsize,
+    cancelled: &AtomicBool,
+) -> Result<SourceImportSummary> {
+    if !root.is_dir() {
+        return Err(anyhow::anyhow!("{} is not a directory", root.display()));
+    }
+
+    let name = root
+        .canonicalize()
+        .ok()
+        .and_then(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported".to_string());
+
+    let mut summary = SourceImportSummary::default();
+    let root_id = import_source_folder(db, root, name, None, &mut summary, progress, cancelled)?;
+
+    match root_id {
+        Some(id) => db.root_notebooks.push(id),
+        None if summary.skipped == 0 => {
+            return Err(anyhow::anyhow!(
+                "No source files found in {}",
+                root.display()
+            ));
+        }
+        None => {}
+    }
+
+    Ok(summary)
+}
+
+/// Imports one folder, returning the notebook id unless nothing was imported from it
+fn import_source_folder(
+    db: &mut SnippetDatabase,
+    dir: &Path,
+    name: String,
+    parent_id: Option<Uuid>,
+    summary: &mut SourceImportSummary,
+    progress: &AtomicUsize,
+    cancelled: &AtomicBool,
+) -> Result<Option<Uuid>> {
+    let mut entries: Vec<fs::DirEntry> = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .flatten()
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let notebook = match parent_id {
+        Some(parent_id) => Notebook::new_with_parent(name, parent_id),
+        None => Notebook::new(name),
+    };
+    let notebook_id = notebook.id;
+    db.notebooks.insert(notebook_id, notebook);
+
+    let mut children = Vec::new();
+    let mut snippet_count = 0;
+
+    for entry in entries {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(anyhow::anyhow!("Import cancelled"));
+        }
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        // Hidden files and folders such as .git are not snippets
+        if file_name.starts_with('.') || file_name == DIRECTORY_MANIFEST_FILE {
+            continue;
+        }
+
+        let Ok(file_type) = entry.file_type() else {
+            summary.skipped += 1;
+            continue;
+        };
+
+        if file_type.is_dir() {
+            if let Some(child_id) = import_source_folder(
+                db,
+                &entry.path(),
+                file_name,
+                Some(notebook_id),
+                summary,
+                progress,
+                cancelled,
+            )? {
+                children.push(child_id);
+            }
+        } else if file_type.is_file() {
+            progress.fetch_add(1, Ordering::Relaxed);
+            match read_source_file(&entry.path()) {
+                Some((title, language, content)) => {
+                    let mut snippet = CodeSnippet::new(title, language, notebook_id);
+                    snippet.update_content(content);
+                    db.snippets.insert(snippet.id, snippet);
+                    snippet_count += 1;
+                    summary.imported += 1;
+                }
+                None => summary.skipped += 1,
+            }
+        }
+    }
+
+    if snippet_count == 0 && children.is_empty() {
+        db.notebooks.remove(&notebook_id);
+        return Ok(None);
+    }
+
+    if let Some(notebook) = db.notebooks.get_mut(&notebook_id) {
+        for child_id in children {
+            notebook.add_child(child_id);
+        }
+        notebook.update_snippet_count(snippet_count);
+    }
+    summary.notebooks += 1;
+
+    Ok(Some(notebook_id))
+}
+
+/// Reads a file as a snippet, or None if it is too large, binary or of an unknown type
+fn read_source_file(path: &Path) -> Option<(String, SnippetLanguage, String)> {
+    let file_name = path.file_name()?.to_string_lossy().to_string();
+    let language = if file_name.eq_ignore_ascii_case("dockerfile") {
+        SnippetLanguage::Dockerfile
+    } else {
+        match SnippetLanguage::from_extension(path.extension()?.to_str()?) {
+            SnippetLanguage::Other(_) => return None,
+            language => language,
+        }
+    };
+
+    if fs::metadata(path).ok()?.len() > MAX_SOURCE_FILE_SIZE {
+        return None;
+    }
+
+    let bytes = fs::read(path).ok()?;
+    if bytes.contains(&0) {
+        return None;
+    }
+    let content = String::from_utf8(bytes).ok()?;
+
+    let title = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or(file_name);
+
+    Some((title, language, content))
+}
+
+/// What to do with an imported item that collides with an existing one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    KeepExisting,
+    Overwrite,
+    /// Import alongside the existing item under a new id and an "(imported)" name
+    KeepBoth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictKind {
+    Notebook,
+    Snippet,
+}
+
+/// Why an imported item collides with an existing one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictReason {
+    SameId,
+    /// Same name in the same parent notebook
+    SameName,
+}
+
+/// An imported notebook or snippet that collides with one already in the database
+#[derive(Debug, Clone)]
+pub struct ImportConflict {
+    pub kind: ConflictKind,
+    pub reason: ConflictReason,
+    /// Id of the item in the import
+    pub id: Uuid,
+    pub imported_name: String,
+    pub existing_name: String,
+}
+
+/// Collect the imported notebooks and snippets that collide by id or by name
+pub fn find_import_conflicts(
+    db: &SnippetDatabase,
+    import_data: &ExportData,
+) -> Vec<ImportConflict> {
+    let mut conflicts = Vec::new();
+
+    for notebook in import_data.notebooks.values() {
+        if let Some((existing_id, reason)) = notebook_conflict(db, notebook) {
+            conflicts.push(ImportConflict {
+                kind: ConflictKind::Notebook,
+                reason,
+                id: notebook.id,
+                imported_name: notebook.name.clone(),
+                existing_name: db.notebooks[&existing_id].name.clone(),
+            });
+        }
+    }
+
+    for snippet in import_data.snippets.values() {
+        if let Some((existing_id, reason)) = snippet_conflict(db, snippet) {
+            conflicts.push(ImportConflict {
+                kind: ConflictKind::Snippet,
+                reason,
+                id: snippet.id,
+                imported_name: snippet.title.clone(),
+                existing_name: db.snippets[&existing_id].title.clone(),
+            });
+        }
+    }
+
+    // Notebooks first so their answers are given before the snippets inside them
+    conflicts.sort_by(|a, b| {
+        (
+            a.kind == ConflictKind::Snippet,
+            a.imported_name.to_lowercase(),
+        )
+            .cmp(&(
+                b.kind == ConflictKind::Snippet,
+                b.imported_name.to_lowercase(),
+            ))
+    });
+    conflicts
+}
+
+fn notebook_conflict(db: &SnippetDatabase, notebook: &Notebook) -> Option<(Uuid, ConflictReason)> {
+    if db.notebooks.contains_key(&notebook.id) {
+        return Some((notebook.id, ConflictReason::SameId));
+    }
+
+    db.notebooks
+        .values()
+        .find(|existing| {
+            existing.parent_id == notebook.parent_id
+                && existing.name.eq_ignore_ascii_case(&notebook.name)
+        })
+        .map(|existing| (existing.id, ConflictReason::SameName))
+}
+
+fn snippet_conflict(db: &SnippetDatabase, snippet: &CodeSnippet) -> Option<(Uuid, ConflictReason)> {
+    if db.snippets.contains_key(&snippet.id) {
+        return Some((snippet.id, ConflictReason::SameId));
+    }
+
+    db.snippets
+        .values()
+        .find(|existing| {
+            existing.notebook_id == snippet.notebook_id
+                && existing.title.eq_ignore_ascii_case(&snippet.title)
+        })
+        .map(|existing| (existing.id, ConflictReason::SameName))
+}
+
+/// Merge imported data, settling each conflict with the given per-item resolution.
+/// Conflicts without an answer keep both items so nothing is lost.
+/// Snippets replaced by an overwrite are collected in `overwritten`, so their old content
+/// can be archived and their files cleaned up once the merged database is saved.
+pub fn merge_import_with_resolutions(
+    db: &mut SnippetDatabase,
+    tag_manager: &mut TagManager,
+    mut import_data: ExportData,
+    resolutions: &HashMap<Uuid, ConflictResolution>,
+    overwritten: &mut Vec<CodeSnippet>,
+) -> Result<(usize, usize)> {
+    for notebook in import_data.notebooks.values_mut() {
+        notebook.migrate_legacy_color();
+    }
+
+    let mut notebooks_added = 0;
+    let mut snippets_added = 0;
+    // Imported id -> id the item ended up with in the database
+    let mut notebook_ids: HashMap<Uuid, Uuid> = HashMap::new();
+    let mut snippet_ids: HashMap<Uuid, Uuid> = HashMap::new();
+    let mut inserted_notebooks = Vec::new();
+
+    // Parents go first so children can follow wherever their parent was mapped
+    let depth = |notebook: &Notebook| {
+        let mut depth = 0;
+        let mut parent = notebook.parent_id;
+        while let Some(id) = parent.filter(|_| depth < import_data.notebooks.len()) {
+            parent = import_data.notebooks.get(&id).and_then(|n| n.parent_id);
+            depth += 1;
+        }
+        depth
+    };
+    let mut ordered: Vec<&Notebook> = import_data.notebooks.values().collect();
+    ordered.sort_by_key(|notebook| depth(notebook));
+
+    for imported in ordered {
+        let mut notebook = imported.clone();
+        notebook.parent_id = notebook
+            .parent_id
+            .map(|parent| notebook_ids.get(&parent).copied().unwrap_or(parent));
+        notebook.children.clear();
+
+        let resolution = notebook_conflict(db, &notebook).map(|(existing_id, _)| {
+            let resolution = resolutions
+                .get(&imported.id)
+                .copied()
+                .unwrap_or(ConflictResolution::KeepBoth);
+            (existing_id, resolution)
+        });
+
+        match resolution {
+            Some((existing_id, ConflictResolution::KeepExisting)) => {
+                notebook_ids.insert(imported.id, existing_id);
+                continue;
+            }
+            Some((existing_id, ConflictResolution::Overwrite)) => {
+                // Stay where the existing notebook is, so the tree cannot end up in a cycle
+                let existing = &db.notebooks[&existing_id];
+                notebook.id = existing_id;
+                notebook.parent_id = existing.parent_id;
+                notebook.children = existing.children.clone();
+            }
+            Some((_, ConflictResolution::KeepBoth)) => {
+                notebook.id = Uuid::new_v4();
+                notebook.name = format!("{} (imported)", notebook.name);
+            }
+            None => {}
+        }
+
+        notebook_ids.insert(imported.id, notebook.id);
+        inserted_notebooks.push(notebook.id);
+        db.notebooks.insert(notebook.id, notebook);
+        notebooks_added += 1;
+    }
+
+    for id in &inserted_notebooks {
+        let parent_id = db.notebooks[id].parent_id;
+        match parent_id.and_then(|parent_id| db.notebooks.get_mut(&parent_id)) {
+            Some(parent) => parent.add_child(*id),
+            None => {
+                // Parent missing from both the import and the database
+                if let Some(notebook) = db.notebooks.get_mut(id) {
+                    notebook.parent_id = None;
+                }
+                if !db.root_notebooks.contains(id) {
+                    db.root_notebooks.push(*id);
+                }
+            }
+        }
+    }
+
+    for mut snippet in import_data.snippets.into_values() {
+        let imported_id = snippet.id;
+        snippet.notebook_id = notebook_ids
+            .get(&snippet.notebook_id)
+            .copied()
+            .unwrap_or(snippet.notebook_id);
+        if !db.notebooks.contains_key(&snippet.notebook_id) {
+            continue;
+        }
+
+        if let Some((existing_id, _)) = snippet_conflict(db, &snippet) {
+            match resolutions
+                .get(&imported_id)
+                .copied()
+                .unwrap_or(ConflictResolution::KeepBoth)
+            {
+                ConflictResolution::KeepExisting => continue,
+                ConflictResolution::Overwrite => {
+                    overwritten.push(db.snippets[&existing_id].clone());
+                    snippet.id = existing_id;
+                }
+                ConflictResolution::KeepBoth => {
+                    snippet.id = Uuid::new_v4();
+                    snippet.title = format!("{} (imported)", snippet.title);
+                }
+            }
+        }
+
+        snippet_ids.insert(imported_id, snippet.id);
+        db.snippets.insert(snippet.id, snippet);
+        snippets_added += 1;
+    }
+
+    for (tag_name, ids) in import_data.tags {
+        for id in ids {
+            if let Some(new_id) = snippet_ids.get(&id) {
+                tag_manager.add_tag_to_snippet(*new_id, tag_name.clone());
+            }
+        }
+    }
+
+    let mut counts: HashMap<Uuid, usize> = HashMap::new();
+    for snippet in db.snippets.values() {
+        *counts.entry(snippet.notebook_id).or_default() += 1;
+    }
+    for (id, notebook) in db.notebooks.iter_mut() {
+        let count = counts.get(id).copied().unwrap_or(0);
+        if notebook.snippet_count != count {
+            notebook.update_snippet_count(count);
+        }
+    }
+
+    Ok((notebooks_added, snippets_added))
+}
+
 /// Import from clipboard
 pub fn import_from_clipboard() -> Result<Option<ExportData>> {
     #[cfg(not(target_os = "linux"))]
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::models::{CodeSnippet, SnippetLanguage};
+
+const GITHUB_API: &str = "https://api.github.com";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Contents of `~/.snix/config.toml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct GistConfig {
+    github_token: Option<String>,
+}
+
+/// A file pulled from a Gist, ready to become a snippet
+#[derive(Debug, Clone)]
+pub struct GistFile {
+    pub title: String,
+    pub language: SnippetLanguage,
+    pub content: String,
+}
+
+/// A Gist pulled from GitHub
+#[derive(Debug, Clone)]
+pub struct PulledGist {
+    pub description: Option<String>,
+    pub files: Vec<GistFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GistResponse {
+    html_url: String,
+    description: Option<String>,
+    #[serde(default)]
+    files: HashMap<String, GistFileResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GistFileResponse {
+    filename: String,
+    content: Option<String>,
+    #[serde(default)]
+    truncated: bool,
+    raw_url: Option<String>,
+}
+
+/// Location of the config file holding the GitHub token
+pub fn config_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".snix").join("config.toml"))
+}
+
+/// Reads `github_token` from the config file, if one is set
+fn load_github_token() -> Result<Option<String>> {
+    let Some(path) = config_path() else {
+        return Ok(None);
+    };
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let config: GistConfig =
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    Ok(config
+        .github_token
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty()))
+}
+
+fn require_github_token() -> Result<String> {
+    load_github_token()?.ok_or_else(|| {
+        anyhow!(
+            "No GitHub token set. Add github_token = \"...\" to {}",
+            config_path()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "~/.snix/config.toml".to_string())
+        )
+    })
+}
+
+/// Extracts the Gist id from a Gist URL or a bare id
+pub fn parse_gist_id(input: &str) -> Option<String> {
+    let input = input.trim().trim_end_matches('/');
+    let input = input.split(['#', '?']).next().unwrap_or(input);
+    let id = input.rsplit('/').next()?.trim_end_matches(".git");
+
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Some(id.to_string())
+    } else {
+        None
+    }
+}
+
+/// Uploads a snippet as a new secret Gist and returns its URL
+pub fn push_snippet(snippet: &CodeSnippet) -> Result<String> {
+    let token = require_github_token()?;
+    let file_name = format!(
+        "{}.{}",
+        gist_file_stem(&snippet.title),
+        snippet.file_extension
+    );
+    let body = json!({
+        "description": snippet.description.clone().unwrap_or_else(|| snippet.title.clone()),
+        "public": false,
+        "files": { file_name: { "content": snippet.content } },
+    });
+
+    block_on(async move {
+        let client = client()?;
+        let response = send(
+            authorized(client.post(format!("{}/gists", GITHUB_API)), Some(&token)).json(&body),
+            true,
+        )
+        .await?;
+        let gist: GistResponse = response
+            .json()
+            .await
+            .context("Failed to parse GitHub response")?;
+        Ok(gist.html_url)
+    })
+}
+
+/// Downloads every file of a Gist given its URL or id
+pub fn pull_gist(url_or_id: &str) -> Result<PulledGist> {
+    let id = parse_gist_id(url_or_id).ok_or_else(|| anyhow!("Not a Gist URL or id"))?;
+    // Secret Gists can be read without a token, but one raises the rate limit
+    let token = load_github_token()?;
+
+    block_on(async move {
+        let client = client()?;
+        let response = send(
+            authorized(
+                client.get(format!("{}/gists/{}", GITHUB_API, id)),
+                token.as_deref(),
+            ),
+            false,
+        )
+        .await?;
+        let gist: GistResponse = response
+            .json()
+            .await
+            .context("Failed to parse GitHub response")?;
+
+        let mut entries: Vec<GistFileResponse> = gist.files.into_values().collect();
+        entries.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+        let mut files = Vec::with_capacity(entries.len());
+        for entry in entries {
+            // Large files come back truncated and have to be fetched in full
+            let content = match (entry.truncated, entry.content, entry.raw_url) {
+                (false, Some(content), _) => content,
+                (_, _, Some(raw_url)) => send(client.get(raw_url), false)
+                    .await?
+                    .text()
+                    .await
+                    .context("Failed to download Gist file")?,
+                (_, content, None) => content.unwrap_or_default(),
+            };
+
+            let (title, mut language) = split_gist_file_name(&entry.filename);
+            if !entry.filename.contains('.') && language == SnippetLanguage::Text {
+                language = SnippetLanguage::detect_from_content(&content).unwrap_or(language);
+            }
+            files.push(GistFile {
+                title,
+                language,
+                content,
+            });
+        }
+
+        if files.is_empty() {
+            return Err(anyhow!("Gist {} has no files", id));
+        }
+
+        Ok(PulledGist {
+            description: gist.description.filter(|d| !d.trim().is_empty()),
+            files,
+        })
+    })
+}
+
+/// Maps a Gist file name back to a snippet title and language
+fn split_gist_file_name(file_name: &str) -> (String, SnippetLanguage) {
+    if file_name.eq_ignore_ascii_case("dockerfile") {
+        return (file_name.to_string(), SnippetLanguage::Dockerfile);
+    }
+
+    match file_name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && !ext.is_empty() => {
+            (stem.to_string(), SnippetLanguage::from_extension(ext))
+        }
+        _ => (file_name.to_string(), SnippetLanguage::Text),
+    }
+}
+
+/// Gist file names cannot contain slashes
+fn gist_file_stem(title: &str) -> String {
+    let stem: String = title
+        .trim()
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+        .collect();
+
+    if stem.is_empty() {
+        "snippet".to_string()
+    } else {
+        stem
+    }
+}
+
+fn block_on<T>(future: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to start the network runtime")?
+        .block_on(future)
+}
+
+fn client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent(concat!("snix/", env!("CARGO_PKG_VERSION")))
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .context("Failed to create HTTP client")
+}
+
+fn authorized(request: RequestBuilder, token: Option<&str>) -> RequestBuilder {
+    let request = request
+        .header("Accept", "application/vnd.github+json")
+        .header("X-GitHub-Api-Version", "2022-11-28");
+
+    match token {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    }
+}
+
+/// Sends a request and turns GitHub's auth and rate-limit responses into readable errors
+async fn send(request: RequestBuilder, needs_gist_scope: bool) -> Result<Response> {
+    let response = request.send().await.context("Could not reach GitHub")?;
+    let status = response.status();
+    let headers = response.headers();
+
+    let rate_limited = headers
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.to_str().ok())
+        == Some("0");
+
+    if rate_limited && (status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS)
+    {
+        let reset = headers
+            .get("x-ratelimit-reset")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<i64>().ok())
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|at| {
+                at.with_timezone(&chrono::Local)
+                    .format(", resets at %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        return Err(anyhow!("GitHub rate limit reached{}", reset));
+    }
+
+    // Classic tokens list their scopes; fine-grained tokens do not send the header
+    let scopes = headers
+        .get("x-oauth-scopes")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+
+    match status {
+        StatusCode::UNAUTHORIZED => Err(anyhow!(
+            "GitHub rejected the token, check github_token in ~/.snix/config.toml"
+        )),
+        StatusCode::FORBIDDEN | StatusCode::NOT_FOUND
+            if needs_gist_scope
+                && scopes
+                    .as_deref()
+                    .is_some_and(|s| !s.split(',').any(|scope| scope.trim() == "gist")) =>
+        {
+            Err(anyhow!("The GitHub token is missing the 'gist' scope"))
+        }
+        StatusCode::NOT_FOUND => Err(anyhow!("Gist not found")),
+        status if !status.is_success() => Err(anyhow!("GitHub returned {}", status)),
+        _ => Ok(response),
+    }
+}
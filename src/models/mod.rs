@@ -1,14 +1,30 @@
+pub mod backup;
+pub mod boilerplate;
 pub mod export;
+pub mod gist;
 pub mod notebook;
+pub mod prompts;
+pub mod settings;
 pub mod snippet;
+pub mod stats;
 pub mod storage;
 pub mod tags;
+pub mod template;
+pub mod trash;
+pub mod vscode;
+pub mod watcher;
 
+pub use boilerplate::Boilerplate;
 pub use export::{
-    ExportFormat, ExportOptions, export_database_with_tags, import_database, import_from_clipboard,
-    merge_import_into_database_with_tags,
+    ExportFormat, ExportOptions, export_database_with_progress, export_database_with_tags,
+    import_database, import_encrypted_database, import_from_clipboard, import_source_directory,
+    is_encrypted_export, merge_import_into_database_with_tags,
 };
 pub use notebook::*;
-pub use snippet::{CodeSnippet, SnippetLanguage};
-pub use storage::StorageManager;
-pub use tags::TagManager;
+pub use settings::{Settings, SortBy};
+pub use snippet::{CodeSnippet, SnippetLanguage, SnippetVersion};
+pub use stats::{LibraryStats, NotebookStats};
+pub use storage::{IntegrityReport, StorageManager};
+pub use tags::{Tag, TagManager};
+pub use trash::{TrashEntry, TrashedItem};
+pub use watcher::SnippetWatcher;
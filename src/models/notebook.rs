@@ -17,6 +17,11 @@ pub struct Notebook {
     pub children: Vec<Uuid>,
     pub snippet_count: usize,
     pub metadata: HashMap<String, String>,
+    /// Index into the notebook color palette, picked in the details view
+    #[serde(default)]
+    pub color_index: Option<usize>,
+    #[serde(default)]
+    pub is_favorite: bool,
 }
 
 impl Notebook {
@@ -35,6 +40,8 @@ impl Notebook {
             children: Vec::new(),
             snippet_count: 0,
             metadata: HashMap::new(),
+            color_index: None,
+            is_favorite: false,
         }
     }
 
@@ -57,8 +64,84 @@ impl Notebook {
         self.updated_at = Utc::now();
     }
 
+    pub fn toggle_favorite(&mut self) {
+        self.is_favorite = !self.is_favorite;
+        self.updated_at = Utc::now();
+    }
+
+    /// Moves a `[COLOR:n]` description prefix left by older versions into `color_index`
+    pub fn migrate_legacy_color(&mut self) -> bool {
+        let Some(rest) = self
+            .description
+            .as_deref()
+            .and_then(|desc| desc.strip_prefix("[COLOR:"))
+        else {
+            return false;
+        };
+        let Some((index, remainder)) = rest.split_once(']') else {
+            return false;
+        };
+
+        if self.color_index.is_none() {
+            self.color_index = index.trim().parse().ok();
+        }
+        let remainder = remainder.trim().to_string();
+        self.description = if remainder.is_empty() {
+            None
+        } else {
+            Some(remainder)
+        };
+        true
+    }
+
     pub fn update_snippet_count(&mut self, count: usize) {
         self.snippet_count = count;
         self.updated_at = Utc::now();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notebook_with_description(description: &str) -> Notebook {
+        let mut notebook = Notebook::new("Legacy".to_string());
+        notebook.description = Some(description.to_string());
+        notebook
+    }
+
+    #[test]
+    fn migrate_legacy_color_prefix_only() {
+        let mut notebook = notebook_with_description("[COLOR:3]");
+
+        assert!(notebook.migrate_legacy_color());
+        assert_eq!(notebook.color_index, Some(3));
+        assert_eq!(notebook.description, None);
+    }
+
+    #[test]
+    fn migrate_legacy_color_prefix_with_text() {
+        let mut notebook = notebook_with_description("[COLOR:2] Work notes");
+
+        assert!(notebook.migrate_legacy_color());
+        assert_eq!(notebook.color_index, Some(2));
+        assert_eq!(notebook.description.as_deref(), Some("Work notes"));
+    }
+
+    #[test]
+    fn migrate_legacy_color_malformed() {
+        let mut unclosed = notebook_with_description("[COLOR:4 Work notes");
+        assert!(!unclosed.migrate_legacy_color());
+        assert_eq!(unclosed.color_index, None);
+        assert_eq!(unclosed.description.as_deref(), Some("[COLOR:4 Work notes"));
+
+        let mut not_a_number = notebook_with_description("[COLOR:red] Work notes");
+        assert!(not_a_number.migrate_legacy_color());
+        assert_eq!(not_a_number.color_index, None);
+        assert_eq!(not_a_number.description.as_deref(), Some("Work notes"));
+
+        let mut plain = notebook_with_description("Notes about [COLOR:1]");
+        assert!(!plain.migrate_legacy_color());
+        assert_eq!(plain.description.as_deref(), Some("Notes about [COLOR:1]"));
+    }
+}
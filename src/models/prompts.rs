@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::models::storage::snix_dir;
+
+/// Templates written the first time the prompts folder is created
+const BUILT_IN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Explain code",
+        "You are a patient programming mentor. Explain what the following code does, \
+step by step, and point out any idioms a newcomer might not know.\n\n{{snippets}}",
+    ),
+    (
+        "Find bugs",
+        "You are a meticulous code reviewer. Look for bugs, edge cases and unsafe \
+assumptions in the following code. List each problem with the line it concerns \
+and a suggested fix.\n\n{{snippets}}",
+    ),
+    (
+        "Write tests",
+        "You are an experienced test engineer. Write thorough unit tests for the \
+following {{language}} code, covering normal use, edge cases and failures.\n\n{{snippets}}",
+    ),
+    (
+        "Refactor",
+        "You are a senior engineer. Suggest refactorings that make the following code \
+simpler and easier to read without changing its behaviour.\n\n{{snippets}}",
+    ),
+];
+
+/// A named system prompt stored as `~/.snix/prompts/<name>.txt`
+///
+/// The body may use `{{title}}`, `{{language}}` and `{{code}}` for the first attached
+/// snippet and `{{snippets}}` for all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTemplate {
+    pub name: String,
+    pub body: String,
+}
+
+/// Folder holding the prompt templates, `~/.snix/prompts`
+pub fn prompts_dir() -> Option<PathBuf> {
+    snix_dir().map(|dir| dir.join("prompts"))
+}
+
+/// Loads every template ordered by name, writing the built-in ones on first use
+pub fn load_prompt_templates() -> Result<Vec<PromptTemplate>> {
+    let dir = prompts_dir().context("Failed to get home directory")?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).context("Failed to create prompts directory")?;
+        for (name, body) in BUILT_IN_TEMPLATES {
+            save_prompt_template(&PromptTemplate {
+                name: name.to_string(),
+                body: body.to_string(),
+            })?;
+        }
+    }
+
+    let mut templates = Vec::new();
+    for entry in fs::read_dir(&dir)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("txt") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let body = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read prompt {}", path.display()))?;
+        templates.push(PromptTemplate {
+            name: name.to_string(),
+            body: body.trim_end().to_string(),
+        });
+    }
+
+    templates.sort_by_key(|template| template.name.to_lowercase());
+    Ok(templates)
+}
+
+/// Writes the template to its file, replacing an earlier version
+pub fn save_prompt_template(template: &PromptTemplate) -> Result<()> {
+    let dir = prompts_dir().context("Failed to get home directory")?;
+    fs::create_dir_all(&dir).context("Failed to create prompts directory")?;
+
+    let path = dir.join(format!("{}.txt", template.name));
+    fs::write(&path, format!("{}\n", template.body))
+        .with_context(|| format!("Failed to save prompt {}", path.display()))
+}
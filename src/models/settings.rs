@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Where a stock Ollama install listens
+pub const DEFAULT_OLLAMA_HOST: &str = "http://localhost:11434";
+
+/// Order of sibling notebooks and snippets in the tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortBy {
+    /// Notebooks in the order they were created or moved, snippets by creation time
+    #[default]
+    Manual,
+    Name,
+    Created,
+    Updated,
+    SnippetCount,
+}
+
+impl SortBy {
+    pub const ALL: [SortBy; 5] = [
+        SortBy::Manual,
+        SortBy::Name,
+        SortBy::Created,
+        SortBy::Updated,
+        SortBy::SnippetCount,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortBy::Manual => "Manual",
+            SortBy::Name => "Name A-Z",
+            SortBy::Created => "Recently created",
+            SortBy::Updated => "Recently updated",
+            SortBy::SnippetCount => "Most snippets",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// User preferences persisted next to the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Display name of the selected color theme
+    pub theme: String,
+    /// Soft-wrap long lines in the snippet preview
+    pub preview_wrap: bool,
+    /// Base URL of the Ollama server used for chat
+    pub ollama_host: String,
+    /// Name of the prompt template applied to new Ollama chats, empty for none
+    pub ollama_prompt_template: String,
+    /// Seconds between auto-saves of a chat while a reply streams in, 0 to save only when it ends
+    pub ollama_autosave_seconds: u64,
+    /// Name filled into `{{author}}` snippet placeholders
+    pub author: String,
+    /// How siblings are ordered in the notebook tree
+    pub tree_sort: SortBy,
+    /// Write a backup to `~/.snix/backups` whenever the app exits
+    pub backup_on_exit: bool,
+    /// Backups kept regardless of age when old ones are pruned
+    pub backup_keep_last: usize,
+    /// Days for which the newest backup is kept
+    pub backup_keep_daily: usize,
+    /// Weeks for which the newest backup is kept
+    pub backup_keep_weekly: usize,
+    /// Tree color of snippets carrying a tag, keyed by tag name without `#`, e.g. `wip = "gold"`
+    pub tag_colors: BTreeMap<String, String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: "Rose Pine".to_string(),
+            preview_wrap: true,
+            ollama_host: DEFAULT_OLLAMA_HOST.to_string(),
+            ollama_prompt_template: String::new(),
+            ollama_autosave_seconds: 30,
+            author: String::new(),
+            tree_sort: SortBy::Manual,
+            backup_on_exit: false,
+            backup_keep_last: 5,
+            backup_keep_daily: 7,
+            backup_keep_weekly: 4,
+            tag_colors: BTreeMap::new(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
+use crate::models::StorageManager;
+use crate::models::template;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,11 +19,25 @@ pub struct CodeSnippet {
     pub accessed_at: DateTime<Utc>,
     pub tags: Vec<String>,
     pub is_favorite: bool,
+    /// Pinned snippets are listed above the notebooks in the tree
+    #[serde(default)]
+    pub is_pinned: bool,
     pub use_count: u32,
     pub file_extension: String,
     pub metadata: HashMap<String, String>,
     pub version: u32,
     pub syntax_theme: String,
+    /// Editor command tried before `$VISUAL`/`$EDITOR`; `{file}` marks where the path goes
+    #[serde(default)]
+    pub editor_override: Option<String>,
+}
+
+/// An archived copy of a snippet's content, kept on disk
+#[derive(Debug, Clone)]
+pub struct SnippetVersion {
+    pub version: u32,
+    pub saved_at: DateTime<Utc>,
+    pub path: PathBuf,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -53,6 +70,17 @@ pub enum SnippetLanguage {
     Toml,
     Ini,
     Config,
+    Lua,
+    Zig,
+    Elixir,
+    Haskell,
+    Scala,
+    R,
+    Perl,
+    Nix,
+    GraphQL,
+    Vue,
+    Svelte,
     Text,
     Other(String),
 }
@@ -95,6 +123,17 @@ impl SnippetLanguage {
             SnippetLanguage::Toml => "toml",
             SnippetLanguage::Ini => "ini",
             SnippetLanguage::Config => "conf",
+            SnippetLanguage::Lua => "lua",
+            SnippetLanguage::Zig => "zig",
+            SnippetLanguage::Elixir => "ex",
+            SnippetLanguage::Haskell => "hs",
+            SnippetLanguage::Scala => "scala",
+            SnippetLanguage::R => "r",
+            SnippetLanguage::Perl => "pl",
+            SnippetLanguage::Nix => "nix",
+            SnippetLanguage::GraphQL => "graphql",
+            SnippetLanguage::Vue => "vue",
+            SnippetLanguage::Svelte => "svelte",
             SnippetLanguage::Text => "txt",
             SnippetLanguage::Other(ext) => {
                 // Return the extension as is for custom types
@@ -106,7 +145,7 @@ impl SnippetLanguage {
     }
 
     /// Get language from file extension
-    pub fn _from_extension(ext: &str) -> Self {
+    pub fn from_extension(ext: &str) -> Self {
         match ext.to_lowercase().as_str() {
             "rs" => SnippetLanguage::Rust,
             "js" => SnippetLanguage::JavaScript,
@@ -136,11 +175,171 @@ impl SnippetLanguage {
             "toml" => SnippetLanguage::Toml,
             "ini" => SnippetLanguage::Ini,
             "conf" | "config" => SnippetLanguage::Config,
+            "lua" => SnippetLanguage::Lua,
+            "zig" => SnippetLanguage::Zig,
+            "ex" | "exs" => SnippetLanguage::Elixir,
+            "hs" | "lhs" => SnippetLanguage::Haskell,
+            "scala" | "sc" => SnippetLanguage::Scala,
+            "r" => SnippetLanguage::R,
+            "pl" | "pm" => SnippetLanguage::Perl,
+            "nix" => SnippetLanguage::Nix,
+            "graphql" | "gql" => SnippetLanguage::GraphQL,
+            "vue" => SnippetLanguage::Vue,
+            "svelte" => SnippetLanguage::Svelte,
             "txt" => SnippetLanguage::Text,
             _ => SnippetLanguage::Other(ext.to_string()),
         }
     }
 
+    /// Guess the language from a markdown fence info string such as "rust" or "py title=x"
+    pub fn from_fence_info(info: &str) -> Self {
+        let tag = info
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+
+        match tag.as_str() {
+            "rust" => SnippetLanguage::Rust,
+            "javascript" | "jsx" | "node" => SnippetLanguage::JavaScript,
+            "typescript" | "tsx" => SnippetLanguage::TypeScript,
+            "python" | "python3" | "py3" => SnippetLanguage::Python,
+            "golang" => SnippetLanguage::Go,
+            "ruby" => SnippetLanguage::Ruby,
+            "kotlin" => SnippetLanguage::Kotlin,
+            "markdown" => SnippetLanguage::Markdown,
+            "c++" => SnippetLanguage::Cpp,
+            "csharp" | "c#" => SnippetLanguage::CSharp,
+            "bash" | "shell" | "zsh" | "console" => SnippetLanguage::Bash,
+            "powershell" | "pwsh" => SnippetLanguage::PowerShell,
+            "docker" => SnippetLanguage::Dockerfile,
+            "elixir" => SnippetLanguage::Elixir,
+            "haskell" => SnippetLanguage::Haskell,
+            "perl" => SnippetLanguage::Perl,
+            "" | "text" | "plaintext" => SnippetLanguage::Text,
+            other => match Self::from_extension(other) {
+                SnippetLanguage::Other(_) => SnippetLanguage::Text,
+                language => language,
+            },
+        }
+    }
+
+    /// Guess the language of extension-less content from a shebang or a telltale first line
+    pub fn detect_from_content(content: &str) -> Option<Self> {
+        let first_line = content.lines().find(|line| !line.trim().is_empty())?.trim();
+
+        if let Some(command) = first_line.strip_prefix("#!") {
+            // "#!/usr/bin/env -S python3 -u" names the interpreter after env and its flags
+            let mut words = command.split_whitespace();
+            let mut program = words.next()?.rsplit('/').next()?;
+            if program == "env" {
+                program = words.find(|word| !word.starts_with('-'))?;
+            }
+            let program = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+
+            return match program {
+                "sh" | "bash" | "zsh" | "dash" | "ksh" => Some(SnippetLanguage::Bash),
+                "python" => Some(SnippetLanguage::Python),
+                "node" | "deno" | "bun" => Some(SnippetLanguage::JavaScript),
+                "ts-node" | "tsx" => Some(SnippetLanguage::TypeScript),
+                "ruby" => Some(SnippetLanguage::Ruby),
+                "perl" => Some(SnippetLanguage::Perl),
+                "php" => Some(SnippetLanguage::PHP),
+                "lua" | "luajit" => Some(SnippetLanguage::Lua),
+                "elixir" => Some(SnippetLanguage::Elixir),
+                "runhaskell" | "runghc" => Some(SnippetLanguage::Haskell),
+                "Rscript" => Some(SnippetLanguage::R),
+                "pwsh" => Some(SnippetLanguage::PowerShell),
+                "nix-shell" => Some(SnippetLanguage::Nix),
+                _ => None,
+            };
+        }
+
+        let lower = first_line.to_lowercase();
+        let language = if lower.starts_with("<?php") {
+            SnippetLanguage::PHP
+        } else if lower.starts_with("<?xml") {
+            SnippetLanguage::Xml
+        } else if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+            SnippetLanguage::HTML
+        } else if lower.starts_with("<template") {
+            SnippetLanguage::Vue
+        } else if first_line.starts_with("FROM ") {
+            SnippetLanguage::Dockerfile
+        } else if first_line.starts_with("package main") {
+            SnippetLanguage::Go
+        } else if first_line.starts_with("#include") {
+            if content.contains("std::") || content.contains("<iostream>") {
+                SnippetLanguage::Cpp
+            } else {
+                SnippetLanguage::C
+            }
+        } else if first_line.starts_with("use std::")
+            || first_line.starts_with("fn main()")
+            || first_line.starts_with("#[derive")
+        {
+            SnippetLanguage::Rust
+        } else if first_line.starts_with("defmodule ") {
+            SnippetLanguage::Elixir
+        } else if first_line.starts_with("module ") && first_line.ends_with(" where") {
+            SnippetLanguage::Haskell
+        } else if (first_line.starts_with('{') || first_line.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(content).is_ok()
+        {
+            SnippetLanguage::Json
+        } else {
+            return None;
+        };
+        Some(language)
+    }
+
+    /// Tag used on markdown code fences, as understood by common highlighters
+    pub fn fence_tag(&self) -> &str {
+        match self {
+            SnippetLanguage::Rust => "rust",
+            SnippetLanguage::JavaScript => "javascript",
+            SnippetLanguage::TypeScript => "typescript",
+            SnippetLanguage::Python => "python",
+            SnippetLanguage::Go => "go",
+            SnippetLanguage::Java => "java",
+            SnippetLanguage::C => "c",
+            SnippetLanguage::Cpp => "cpp",
+            SnippetLanguage::CSharp => "csharp",
+            SnippetLanguage::PHP => "php",
+            SnippetLanguage::Ruby => "ruby",
+            SnippetLanguage::Swift => "swift",
+            SnippetLanguage::Kotlin => "kotlin",
+            SnippetLanguage::Dart => "dart",
+            SnippetLanguage::HTML => "html",
+            SnippetLanguage::CSS => "css",
+            SnippetLanguage::SCSS => "scss",
+            SnippetLanguage::SQL => "sql",
+            SnippetLanguage::Bash => "bash",
+            SnippetLanguage::PowerShell => "powershell",
+            SnippetLanguage::Yaml => "yaml",
+            SnippetLanguage::Json => "json",
+            SnippetLanguage::Xml => "xml",
+            SnippetLanguage::Markdown => "markdown",
+            SnippetLanguage::Dockerfile => "dockerfile",
+            SnippetLanguage::Toml => "toml",
+            SnippetLanguage::Ini => "ini",
+            SnippetLanguage::Config => "conf",
+            SnippetLanguage::Lua => "lua",
+            SnippetLanguage::Zig => "zig",
+            SnippetLanguage::Elixir => "elixir",
+            SnippetLanguage::Haskell => "haskell",
+            SnippetLanguage::Scala => "scala",
+            SnippetLanguage::R => "r",
+            SnippetLanguage::Perl => "perl",
+            SnippetLanguage::Nix => "nix",
+            SnippetLanguage::GraphQL => "graphql",
+            SnippetLanguage::Vue => "vue",
+            SnippetLanguage::Svelte => "svelte",
+            SnippetLanguage::Text => "text",
+            SnippetLanguage::Other(ext) => ext,
+        }
+    }
+
     pub fn display_name(&self) -> &str {
         match self {
             SnippetLanguage::Rust => "Rust",
@@ -171,12 +370,23 @@ impl SnippetLanguage {
             SnippetLanguage::Toml => "TOML",
             SnippetLanguage::Ini => "INI",
             SnippetLanguage::Config => "Config",
+            SnippetLanguage::Lua => "Lua",
+            SnippetLanguage::Zig => "Zig",
+            SnippetLanguage::Elixir => "Elixir",
+            SnippetLanguage::Haskell => "Haskell",
+            SnippetLanguage::Scala => "Scala",
+            SnippetLanguage::R => "R",
+            SnippetLanguage::Perl => "Perl",
+            SnippetLanguage::Nix => "Nix",
+            SnippetLanguage::GraphQL => "GraphQL",
+            SnippetLanguage::Vue => "Vue",
+            SnippetLanguage::Svelte => "Svelte",
             SnippetLanguage::Text => "Text",
             SnippetLanguage::Other(name) => name,
         }
     }
 
-    /// Get icon for the language
+    /// Get the nerd-font icon for the language, a generic file for plain text and config
     pub fn icon(&self) -> &'static str {
         match self {
             SnippetLanguage::Rust => "",
@@ -199,14 +409,25 @@ impl SnippetLanguage {
             SnippetLanguage::SQL => "",
             SnippetLanguage::Bash => "",
             SnippetLanguage::PowerShell => "",
-            SnippetLanguage::Yaml => "",
+            SnippetLanguage::Yaml => "",
             SnippetLanguage::Json => "",
             SnippetLanguage::Xml => "󰗀",
             SnippetLanguage::Markdown => "",
             SnippetLanguage::Dockerfile => "",
-            SnippetLanguage::Toml => "",
-            SnippetLanguage::Ini => "",
+            SnippetLanguage::Toml => "",
+            SnippetLanguage::Ini => "",
             SnippetLanguage::Config => "",
+            SnippetLanguage::Lua => "",
+            SnippetLanguage::Zig => "",
+            SnippetLanguage::Elixir => "",
+            SnippetLanguage::Haskell => "",
+            SnippetLanguage::Scala => "",
+            SnippetLanguage::R => "",
+            SnippetLanguage::Perl => "",
+            SnippetLanguage::Nix => "",
+            SnippetLanguage::GraphQL => "",
+            SnippetLanguage::Vue => "󰡄",
+            SnippetLanguage::Svelte => "",
             SnippetLanguage::Text => "",
             SnippetLanguage::Other(_) => "",
         }
@@ -243,6 +464,17 @@ impl SnippetLanguage {
             SnippetLanguage::Toml => "TOML",
             SnippetLanguage::Ini => "INI",
             SnippetLanguage::Config => "Conf",
+            SnippetLanguage::Lua => "Lua",
+            SnippetLanguage::Zig => "Zig",
+            SnippetLanguage::Elixir => "Ex",
+            SnippetLanguage::Haskell => "Hs",
+            SnippetLanguage::Scala => "Scala",
+            SnippetLanguage::R => "R",
+            SnippetLanguage::Perl => "Perl",
+            SnippetLanguage::Nix => "Nix",
+            SnippetLanguage::GraphQL => "GQL",
+            SnippetLanguage::Vue => "Vue",
+            SnippetLanguage::Svelte => "Svelte",
             SnippetLanguage::Text => "Text",
             SnippetLanguage::Other(name) => Box::leak(name.clone().into_boxed_str()),
         }
@@ -266,11 +498,13 @@ impl CodeSnippet {
             accessed_at: now,
             tags: Vec::new(),
             is_favorite: false,
+            is_pinned: false,
             use_count: 0,
             file_extension,
             metadata: HashMap::new(),
             version: 1,
             syntax_theme: "default".to_string(),
+            editor_override: None,
         }
     }
 
@@ -344,4 +578,23 @@ impl CodeSnippet {
     pub fn is_favorited(&self) -> bool {
         self.is_favorite
     }
+
+    pub fn toggle_pinned(&mut self) {
+        self.is_pinned = !self.is_pinned;
+    }
+
+    /// Names of the `{{placeholders}}` in the content, in order of first use
+    pub fn template_variables(&self) -> Vec<String> {
+        template::template_variables([self.content.as_str()])
+    }
+
+    /// Content with its placeholders substituted; ones without a value are left as written
+    pub fn fill_template(&self, values: &HashMap<String, String>) -> String {
+        template::fill_template(&self.content, values)
+    }
+
+    /// Lists the archived versions of this snippet, newest first
+    pub fn list_versions(&self, storage: &StorageManager) -> Vec<SnippetVersion> {
+        storage.list_snippet_versions(self).unwrap_or_default()
+    }
 }
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::models::TagManager;
+use crate::models::storage::SnippetDatabase;
+
+/// How many tags the overview lists
+const TOP_TAGS: usize = 10;
+
+/// Reading speed behind the reading-time estimate
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Summary of the whole snippet library
+#[derive(Debug, Clone, Default)]
+pub struct LibraryStats {
+    pub total_snippets: usize,
+    pub total_notebooks: usize,
+    pub favorites: usize,
+    pub total_lines: usize,
+    pub storage_bytes: u64,
+    /// Language name and snippet count, most used first
+    pub by_language: Vec<(String, usize)>,
+    /// Notebook path and the number of snippets directly inside it, largest first
+    pub by_notebook: Vec<(String, usize)>,
+    /// Tag name and the number of snippets carrying it, most used first
+    pub top_tags: Vec<(String, usize)>,
+    /// Snippets grouped by when they were last opened
+    pub recency: Vec<(&'static str, usize)>,
+}
+
+impl LibraryStats {
+    pub fn compute(database: &SnippetDatabase, tag_manager: &TagManager, data_dir: &Path) -> Self {
+        let now = Utc::now();
+        let buckets = [
+            ("Under a day", Duration::days(1)),
+            ("1-7 days", Duration::days(7)),
+            ("8-30 days", Duration::days(30)),
+        ];
+        let mut recency: Vec<(&'static str, usize)> =
+            buckets.iter().map(|(label, _)| (*label, 0)).collect();
+        recency.push(("Over 30 days", 0));
+
+        let mut by_language: HashMap<String, usize> = HashMap::new();
+        let mut by_notebook: HashMap<String, usize> = HashMap::new();
+        let mut favorites = 0;
+        let mut total_lines = 0;
+
+        for snippet in database.snippets.values() {
+            *by_language
+                .entry(snippet.language.display_name().to_string())
+                .or_default() += 1;
+            *by_notebook
+                .entry(notebook_path(database, snippet.notebook_id))
+                .or_default() += 1;
+
+            if snippet.is_favorite {
+                favorites += 1;
+            }
+            total_lines += snippet.get_line_count();
+
+            let age = now.signed_duration_since(snippet.accessed_at);
+            let bucket = buckets
+                .iter()
+                .position(|(_, limit)| age < *limit)
+                .unwrap_or(buckets.len());
+            recency[bucket].1 += 1;
+        }
+
+        // Trashed snippets can still be linked to a tag, so only count live ones
+        let tag_counts = tag_manager.tags.values().filter_map(|tag| {
+            let count = tag_manager
+                .get_snippets_with_tag(&tag.id)
+                .map(|ids| {
+                    ids.iter()
+                        .filter(|id| database.snippets.contains_key(id))
+                        .count()
+                })
+                .unwrap_or(0);
+            (count > 0).then(|| (tag.name.clone(), count))
+        });
+        let mut top_tags = sorted_counts(tag_counts);
+        top_tags.truncate(TOP_TAGS);
+
+        Self {
+            total_snippets: database.snippets.len(),
+            total_notebooks: database.notebooks.len(),
+            favorites,
+            total_lines,
+            storage_bytes: dir_size(data_dir).unwrap_or(0),
+            by_language: sorted_counts(by_language),
+            by_notebook: sorted_counts(by_notebook),
+            top_tags,
+            recency,
+        }
+    }
+}
+
+/// Size and makeup of the snippets directly inside one notebook
+#[derive(Debug, Clone, Default)]
+pub struct NotebookStats {
+    pub snippets: usize,
+    pub lines: usize,
+    pub words: usize,
+    pub chars: usize,
+    /// Snippets in the notebook and every notebook nested below it
+    pub nested_snippets: usize,
+    pub nested_lines: usize,
+    /// Language short name and line count, largest first, leaving out empty snippets
+    pub by_language: Vec<(String, usize)>,
+}
+
+impl NotebookStats {
+    pub fn compute(database: &SnippetDatabase, notebook_id: uuid::Uuid) -> Self {
+        let mut stats = Self::default();
+        let mut by_language: HashMap<String, usize> = HashMap::new();
+
+        for snippet in database.snippets.values() {
+            if !is_within(database, snippet.notebook_id, notebook_id) {
+                continue;
+            }
+            let lines = snippet.get_line_count();
+            stats.nested_snippets += 1;
+            stats.nested_lines += lines;
+
+            if snippet.notebook_id == notebook_id {
+                stats.snippets += 1;
+                stats.lines += lines;
+                stats.words += snippet.content.split_whitespace().count();
+                stats.chars += snippet.content.chars().count();
+                *by_language
+                    .entry(snippet.language.short_name().to_string())
+                    .or_default() += lines;
+            }
+        }
+
+        by_language.retain(|_, lines| *lines > 0);
+        stats.by_language = sorted_counts(by_language);
+        stats
+    }
+
+    /// Mean snippet length in lines
+    pub fn average_lines(&self) -> f64 {
+        if self.snippets == 0 {
+            0.0
+        } else {
+            self.lines as f64 / self.snippets as f64
+        }
+    }
+
+    /// Minutes needed to read every snippet, rounded up
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE)
+    }
+}
+
+/// Formats a byte count with the largest fitting unit
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Sorts by count, largest first, breaking ties by name
+fn sorted_counts(counts: impl IntoIterator<Item = (String, usize)>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+fn notebook_path(database: &SnippetDatabase, notebook_id: uuid::Uuid) -> String {
+    let mut names = Vec::new();
+    let mut current = database.notebooks.get(&notebook_id);
+    while let Some(notebook) = current {
+        names.push(notebook.name.as_str());
+        current = notebook
+            .parent_id
+            .and_then(|parent_id| database.notebooks.get(&parent_id));
+    }
+
+    if names.is_empty() {
+        return "(no notebook)".to_string();
+    }
+    names.reverse();
+    names.join("/")
+}
+
+/// Whether `notebook_id` is `root_id` or nested somewhere below it
+fn is_within(database: &SnippetDatabase, notebook_id: uuid::Uuid, root_id: uuid::Uuid) -> bool {
+    let mut current = Some(notebook_id);
+    // Bounded so a broken tree cannot loop forever
+    for _ in 0..=database.notebooks.len() {
+        match current {
+            Some(id) if id == root_id => return true,
+            Some(id) => {
+                current = database
+                    .notebooks
+                    .get(&id)
+                    .and_then(|notebook| notebook.parent_id)
+            }
+            None => return false,
+        }
+    }
+    false
+}
+
+fn dir_size(dir: &Path) -> Result<u64> {
+    let mut size = 0;
+    if dir.is_dir() {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.is_file() {
+                size += entry.metadata()?.len();
+            } else if path.is_dir() {
+                size += dir_size(&path)?;
+            }
+        }
+    }
+    Ok(size)
+}
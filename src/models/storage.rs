@@ -1,9 +1,12 @@
-use crate::models::{CodeSnippet, Notebook, TagManager};
+use crate::models::{
+    CodeSnippet, Notebook, Settings, SnippetLanguage, SnippetVersion, TagManager, TrashEntry,
+};
 use anyhow::{Context, Result};
+use chrono::{NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,39 +26,139 @@ impl Default for SnippetDatabase {
     }
 }
 
+/// View state kept between sessions in `ui_state.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    pub collapsed_notebooks: HashSet<Uuid>,
+}
+
+/// Where the database and the files in the snippets folder disagree
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityReport {
+    /// Snippets whose content file is missing
+    pub missing_files: Vec<Uuid>,
+    /// Files in the snippets folder that no snippet points to
+    pub orphan_files: Vec<PathBuf>,
+}
+
+impl IntegrityReport {
+    pub fn is_clean(&self) -> bool {
+        self.missing_files.is_empty() && self.orphan_files.is_empty()
+    }
+}
+
+/// Root notebook that adopted orphan files go into when their folder is not a notebook
+const RECOVERED_NOTEBOOK_NAME: &str = "Recovered";
+
+/// Environment variable that moves the storage folder somewhere else
+pub const DATA_DIR_ENV: &str = "SNIX_DATA_DIR";
+
+/// The `data_dir` key of `~/.snix/config.toml`, which always stays in the home directory
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DataDirConfig {
+    data_dir: Option<String>,
+}
+
+/// Folder chosen with `SNIX_DATA_DIR`, or else `data_dir` in the config file
+pub fn data_dir_override() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(DATA_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+
+    let content = fs::read_to_string(crate::models::gist::config_path()?).ok()?;
+    let dir = toml::from_str::<DataDirConfig>(&content)
+        .ok()?
+        .data_dir
+        .filter(|dir| !dir.trim().is_empty())?;
+    match dir.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => Some(home.join(rest)),
+        None => Some(PathBuf::from(dir)),
+    }
+}
+
+/// Folder for chats, backups, prompts and boilerplates: the override, else `~/.snix`
+pub fn snix_dir() -> Option<PathBuf> {
+    data_dir_override().or_else(|| dirs::home_dir().map(|home| home.join(".snix")))
+}
+
+/// Number of archived versions kept per snippet
+pub const MAX_SNIPPET_VERSIONS: usize = 20;
+
+const VERSION_TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%3fZ";
+
 /// Storage Manager for disk operations
 #[derive(Debug)]
 pub struct StorageManager {
-    _data_dir: PathBuf,
+    data_dir: PathBuf,
     snippets_dir: PathBuf,
+    versions_dir: PathBuf,
+    trash_dir: PathBuf,
     _notebooks_dir: PathBuf,
     database_file: PathBuf,
     tag_manager_file: PathBuf,
+    settings_file: PathBuf,
+    ui_state_file: PathBuf,
+    trash_file: PathBuf,
 }
 
 impl StorageManager {
     pub fn new() -> Result<Self> {
-        let data_dir = dirs::data_dir()
-            .context("Failed to get data directory")?
-            .join("snix");
+        Self::with_data_dir(Self::resolve_data_dir()?)
+    }
 
+    /// Storage rooted at `data_dir`, creating its folders when missing
+    pub(crate) fn with_data_dir(data_dir: PathBuf) -> Result<Self> {
         let db_file = data_dir.join("database.json");
         let tags_file = data_dir.join("tags.json");
+        let settings_file = data_dir.join("settings.json");
+        let ui_state_file = data_dir.join("ui_state.json");
         let snippets_dir = data_dir.join("snippets");
+        let versions_dir = data_dir.join("versions");
+        let trash_dir = data_dir.join("trash");
+        let trash_file = trash_dir.join("index.json");
 
         // Create directories if they don't exist
-        fs::create_dir_all(&data_dir)?;
-        fs::create_dir_all(&snippets_dir)?;
+        for dir in [&data_dir, &snippets_dir, &trash_dir] {
+            fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+        }
 
         Ok(Self {
-            _data_dir: data_dir.clone(),
+            data_dir: data_dir.clone(),
             snippets_dir,
+            versions_dir,
+            trash_dir,
             _notebooks_dir: data_dir,
             database_file: db_file,
             tag_manager_file: tags_file,
+            settings_file,
+            ui_state_file,
+            trash_file,
         })
     }
 
+    /// Storage folder: the override when set, otherwise `snix` in the platform data directory
+    pub fn resolve_data_dir() -> Result<PathBuf> {
+        if let Some(dir) = data_dir_override() {
+            return Ok(dir);
+        }
+
+        Ok(dirs::data_dir()
+            .context("Failed to get data directory")?
+            .join("snix"))
+    }
+
+    /// Fails when files cannot be written to the storage folder, e.g. after a permission change
+    pub fn check_writable(&self) -> Result<()> {
+        // Existing read-only folders pass `create_dir_all`, so probe with a real write
+        let probe = self.data_dir.join(".write-test");
+        fs::write(&probe, b"")
+            .with_context(|| format!("Cannot write to {}", self.data_dir.display()))?;
+        let _ = fs::remove_file(&probe);
+        Ok(())
+    }
+
     pub fn load_database(&self) -> Result<SnippetDatabase> {
         if !self.database_file.exists() {
             return Ok(SnippetDatabase::default());
@@ -64,7 +167,15 @@ impl StorageManager {
         let content =
             fs::read_to_string(&self.database_file).context("Failed to read database file")?;
 
-        serde_json::from_str(&content).context("Failed to parse database JSON")
+        let mut db: SnippetDatabase =
+            serde_json::from_str(&content).context("Failed to parse database JSON")?;
+
+        // Older versions kept the notebook color inside the description
+        for notebook in db.notebooks.values_mut() {
+            notebook.migrate_legacy_color();
+        }
+
+        Ok(db)
     }
 
     pub fn save_database(&self, db: &SnippetDatabase) -> Result<()> {
@@ -91,6 +202,59 @@ impl StorageManager {
         fs::write(&self.tag_manager_file, content).context("Failed to write tag manager file")
     }
 
+    pub fn load_settings(&self) -> Result<Settings> {
+        if !self.settings_file.exists() {
+            return Ok(Settings::default());
+        }
+
+        let content =
+            fs::read_to_string(&self.settings_file).context("Failed to read settings file")?;
+
+        serde_json::from_str(&content).context("Failed to parse settings JSON")
+    }
+
+    pub fn save_settings(&self, settings: &Settings) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(settings).context("Failed to serialize settings")?;
+
+        fs::write(&self.settings_file, content).context("Failed to write settings file")
+    }
+
+    pub fn load_ui_state(&self) -> Result<UiState> {
+        if !self.ui_state_file.exists() {
+            return Ok(UiState::default());
+        }
+
+        let content =
+            fs::read_to_string(&self.ui_state_file).context("Failed to read UI state file")?;
+
+        serde_json::from_str(&content).context("Failed to parse UI state JSON")
+    }
+
+    pub fn save_ui_state(&self, ui_state: &UiState) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(ui_state).context("Failed to serialize UI state")?;
+
+        fs::write(&self.ui_state_file, content).context("Failed to write UI state file")
+    }
+
+    pub fn load_trash(&self) -> Result<Vec<TrashEntry>> {
+        if !self.trash_file.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.trash_file).context("Failed to read trash index")?;
+
+        serde_json::from_str(&content).context("Failed to parse trash index JSON")
+    }
+
+    pub fn save_trash(&self, entries: &[TrashEntry]) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(entries).context("Failed to serialize trash index")?;
+
+        fs::write(&self.trash_file, content).context("Failed to write trash index")
+    }
+
     pub fn save_snippet_content(&self, snippet: &CodeSnippet) -> Result<()> {
         let notebook_dir = self.snippets_dir.join(snippet.notebook_id.to_string());
         fs::create_dir_all(&notebook_dir)?;
@@ -120,6 +284,15 @@ impl StorageManager {
         fs::read_to_string(file_path).context("Failed to read snippet content")
     }
 
+    /// Removes only the content file of a snippet, keeping its trashed copy and versions
+    pub fn delete_snippet_content(&self, snippet: &CodeSnippet) -> Result<()> {
+        let file_path = self.get_snippet_file_path(snippet);
+        if file_path.exists() {
+            fs::remove_file(file_path).context("Failed to delete snippet file")?;
+        }
+        Ok(())
+    }
+
     pub fn delete_snippet_file(&self, snippet: &CodeSnippet) -> Result<()> {
         let filename = format!("{}.{}", snippet.id, snippet.file_extension);
         let file_path = self
@@ -131,9 +304,132 @@ impl StorageManager {
             fs::remove_file(file_path).context("Failed to delete snippet file")?;
         }
 
+        let trashed_path = self.trashed_snippet_path(snippet);
+        if trashed_path.exists() {
+            fs::remove_file(trashed_path).context("Failed to delete trashed snippet file")?;
+        }
+
+        let versions_dir = self.snippet_versions_dir(snippet);
+        if versions_dir.exists() {
+            fs::remove_dir_all(versions_dir).context("Failed to delete snippet versions")?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the backing file of a snippet to a new extension, keeping its content
+    pub fn rename_snippet_file(&self, snippet: &CodeSnippet, new_extension: &str) -> Result<()> {
+        let old_path = self.get_snippet_file_path(snippet);
+        if !old_path.exists() {
+            return Ok(());
+        }
+
+        let new_path = old_path.with_file_name(format!("{}.{}", snippet.id, new_extension));
+        fs::rename(old_path, new_path).context("Failed to rename snippet file")
+    }
+
+    /// Moves the backing file of a snippet into another notebook's directory
+    pub fn move_snippet_file(&self, snippet: &CodeSnippet, new_notebook_id: Uuid) -> Result<()> {
+        let old_path = self.get_snippet_file_path(snippet);
+        if !old_path.exists() {
+            return Ok(());
+        }
+
+        let notebook_dir = self.snippets_dir.join(new_notebook_id.to_string());
+        fs::create_dir_all(&notebook_dir)?;
+
+        let new_path = notebook_dir.join(format!("{}.{}", snippet.id, snippet.file_extension));
+        fs::rename(old_path, new_path).context("Failed to move snippet file")
+    }
+
+    /// Moves the backing file of a snippet into the trash directory
+    pub fn move_snippet_to_trash(&self, snippet: &CodeSnippet) -> Result<()> {
+        let file_path = self.get_snippet_file_path(snippet);
+        if !file_path.exists() {
+            return Ok(());
+        }
+
+        fs::rename(file_path, self.trashed_snippet_path(snippet))
+            .context("Failed to move snippet file to trash")
+    }
+
+    /// Moves a trashed snippet file back into its notebook directory
+    pub fn restore_snippet_from_trash(&self, snippet: &CodeSnippet) -> Result<()> {
+        let notebook_dir = self.snippets_dir.join(snippet.notebook_id.to_string());
+        fs::create_dir_all(&notebook_dir)?;
+
+        let trashed_path = self.trashed_snippet_path(snippet);
+        if !trashed_path.exists() {
+            // Fall back to the content kept in the trash index
+            return self.save_snippet_content(snippet);
+        }
+
+        fs::rename(trashed_path, self.get_snippet_file_path(snippet))
+            .context("Failed to restore snippet file from trash")
+    }
+
+    fn trashed_snippet_path(&self, snippet: &CodeSnippet) -> PathBuf {
+        self.trash_dir
+            .join(format!("{}.{}", snippet.id, snippet.file_extension))
+    }
+
+    /// Archives the current content of a snippet before it gets overwritten
+    pub fn archive_snippet_version(&self, snippet: &CodeSnippet) -> Result<()> {
+        let versions_dir = self.snippet_versions_dir(snippet);
+        fs::create_dir_all(&versions_dir)?;
+
+        let filename = format!(
+            "v{:05}-{}.{}",
+            snippet.version,
+            Utc::now().format(VERSION_TIMESTAMP_FORMAT),
+            snippet.file_extension
+        );
+
+        fs::write(versions_dir.join(filename), &snippet.content)
+            .context("Failed to write snippet version")?;
+
+        self.prune_snippet_versions(snippet)
+    }
+
+    /// Lists the archived versions of a snippet, newest first
+    pub fn list_snippet_versions(&self, snippet: &CodeSnippet) -> Result<Vec<SnippetVersion>> {
+        let versions_dir = self.snippet_versions_dir(snippet);
+        if !versions_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut versions = Vec::new();
+        for entry in fs::read_dir(&versions_dir).context("Failed to read snippet versions")? {
+            let path = entry?.path();
+            if let Some(version) = parse_version_file(path) {
+                versions.push(version);
+            }
+        }
+
+        versions.sort_by(|a, b| b.saved_at.cmp(&a.saved_at).then(b.version.cmp(&a.version)));
+        Ok(versions)
+    }
+
+    pub fn load_snippet_version(&self, version: &SnippetVersion) -> Result<String> {
+        fs::read_to_string(&version.path).context("Failed to read snippet version")
+    }
+
+    fn prune_snippet_versions(&self, snippet: &CodeSnippet) -> Result<()> {
+        for version in self
+            .list_snippet_versions(snippet)?
+            .into_iter()
+            .skip(MAX_SNIPPET_VERSIONS)
+        {
+            fs::remove_file(&version.path).context("Failed to prune snippet version")?;
+        }
+
         Ok(())
     }
 
+    fn snippet_versions_dir(&self, snippet: &CodeSnippet) -> PathBuf {
+        self.versions_dir.join(snippet.id.to_string())
+    }
+
     pub fn delete_notebook_directory(&self, notebook_id: Uuid) -> Result<()> {
         let notebook_dir = self.snippets_dir.join(notebook_id.to_string());
 
@@ -144,6 +440,150 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Compares the database with the snippets folder, listing snippets without a file
+    /// and files without a snippet
+    pub fn verify_integrity(&self, db: &SnippetDatabase) -> Result<IntegrityReport> {
+        let mut missing_files: Vec<&CodeSnippet> = db
+            .snippets
+            .values()
+            .filter(|snippet| !self.get_snippet_file_path(snippet).is_file())
+            .collect();
+        missing_files.sort_by_key(|snippet| snippet.title.to_lowercase());
+
+        let expected: HashSet<PathBuf> = db
+            .snippets
+            .values()
+            .map(|snippet| self.get_snippet_file_path(snippet))
+            .collect();
+        let mut orphan_files = Vec::new();
+        if self.snippets_dir.is_dir() {
+            for entry in fs::read_dir(&self.snippets_dir).context("Failed to read snippets")? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    for file in fs::read_dir(&path).context("Failed to read notebook folder")? {
+                        orphan_files.push(file?.path());
+                    }
+                } else {
+                    orphan_files.push(path);
+                }
+            }
+        }
+        orphan_files.retain(|path| {
+            // Skip editor swap files and the like
+            let hidden = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_none_or(|name| name.starts_with('.'));
+            path.is_file() && !hidden && !expected.contains(path)
+        });
+        orphan_files.sort();
+
+        Ok(IntegrityReport {
+            missing_files: missing_files.iter().map(|snippet| snippet.id).collect(),
+            orphan_files,
+        })
+    }
+
+    /// Writes the files of snippets missing from `known`; merges and imports only fill the
+    /// database, so the snippets they add get their files here
+    pub fn write_new_snippet_files(
+        &self,
+        db: &SnippetDatabase,
+        known: &HashSet<Uuid>,
+    ) -> Result<usize> {
+        let added: Vec<Uuid> = db
+            .snippets
+            .keys()
+            .filter(|id| !known.contains(id))
+            .copied()
+            .collect();
+        self.recreate_missing_files(db, &added)
+    }
+
+    /// Writes the files of snippets that lost them, using the content kept in the database
+    pub fn recreate_missing_files(&self, db: &SnippetDatabase, ids: &[Uuid]) -> Result<usize> {
+        let mut count = 0;
+        for snippet in ids.iter().filter_map(|id| db.snippets.get(id)) {
+            self.save_snippet_content(snippet)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Turns orphan files into snippets, titled after the file and placed in the notebook
+    /// of their folder, or a "Recovered" notebook. The caller saves the database.
+    pub fn import_orphan_files(
+        &self,
+        db: &mut SnippetDatabase,
+        paths: &[PathBuf],
+    ) -> Result<usize> {
+        let mut count = 0;
+        for path in paths {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let stem = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("snippet");
+            let extension = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or("txt");
+
+            let folder_notebook = path
+                .parent()
+                .and_then(|dir| dir.file_name())
+                .and_then(|name| name.to_str())
+                .and_then(|name| Uuid::parse_str(name).ok())
+                .filter(|id| db.notebooks.contains_key(id));
+            let notebook_id = match folder_notebook {
+                Some(id) => id,
+                None => recovered_notebook_id(db),
+            };
+
+            let title = match Uuid::parse_str(stem) {
+                Ok(id) => format!("Recovered {}", &id.to_string()[..8]),
+                Err(_) => stem.to_string(),
+            };
+            // Files named after an unused snippet id keep it, so links to that id work again
+            let file_id = Uuid::parse_str(stem)
+                .ok()
+                .filter(|id| !db.snippets.contains_key(id));
+
+            let mut snippet = CodeSnippet::new(
+                title,
+                SnippetLanguage::from_extension(extension),
+                notebook_id,
+            );
+            if let Some(id) = file_id {
+                snippet.id = id;
+            }
+            snippet.file_extension = extension.to_string();
+            snippet.content = content;
+
+            self.save_snippet_content(&snippet)?;
+            if self.get_snippet_file_path(&snippet) != *path {
+                fs::remove_file(path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
+
+            if let Some(notebook) = db.notebooks.get_mut(&notebook_id) {
+                notebook.snippet_count += 1;
+            }
+            db.snippets.insert(snippet.id, snippet);
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    pub fn snippets_dir(&self) -> &Path {
+        &self.snippets_dir
+    }
+
     pub fn get_snippet_file_path(&self, snippet: &CodeSnippet) -> PathBuf {
         let filename = format!("{}.{}", snippet.id, snippet.file_extension);
         self.snippets_dir
@@ -151,3 +591,37 @@ impl StorageManager {
             .join(filename)
     }
 }
+
+/// Finds or creates the root notebook that adopted orphan files go into
+fn recovered_notebook_id(db: &mut SnippetDatabase) -> Uuid {
+    if let Some(id) = db.root_notebooks.iter().find(|id| {
+        db.notebooks
+            .get(id)
+            .is_some_and(|notebook| notebook.name == RECOVERED_NOTEBOOK_NAME)
+    }) {
+        return *id;
+    }
+
+    let notebook = Notebook::new(RECOVERED_NOTEBOOK_NAME.to_string());
+    let id = notebook.id;
+    db.notebooks.insert(id, notebook);
+    db.root_notebooks.push(id);
+    id
+}
+
+/// Parses a `v<version>-<timestamp>.<ext>` file name from the versions directory
+fn parse_version_file(path: PathBuf) -> Option<SnippetVersion> {
+    let stem = path.file_stem()?.to_str()?;
+    let (version, timestamp) = stem.strip_prefix('v')?.split_once('-')?;
+
+    let version = version.parse().ok()?;
+    let saved_at = NaiveDateTime::parse_from_str(timestamp, VERSION_TIMESTAMP_FORMAT)
+        .ok()?
+        .and_utc();
+
+    Some(SnippetVersion {
+        version,
+        saved_at,
+        path,
+    })
+}
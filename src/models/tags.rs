@@ -1,3 +1,5 @@
+use crate::models::CodeSnippet;
+use anyhow::{Result, anyhow};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -131,6 +133,209 @@ impl TagManager {
             .collect()
     }
 
+    /// Looks up a tag by name, ignoring case and a leading #
+    pub fn find_tag(&self, name: &str) -> Option<Uuid> {
+        let name = name.trim().trim_start_matches('#');
+        self.tags
+            .values()
+            .find(|tag| tag.name.eq_ignore_ascii_case(name))
+            .map(|tag| tag.id)
+    }
+
+    /// Renames a tag on every snippet carrying it. Renaming onto another
+    /// existing tag merges the two. Returns the snippets that changed.
+    pub fn rename_tag(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+        snippets: &mut HashMap<Uuid, CodeSnippet>,
+    ) -> Result<Vec<Uuid>> {
+        let tag_id = self
+            .find_tag(old_name)
+            .ok_or_else(|| anyhow!("Tag #{} not found", old_name.trim_start_matches('#')))?;
+
+        let new_name = new_name.trim().trim_start_matches('#');
+        if new_name.is_empty() || new_name.contains(char::is_whitespace) {
+            return Err(anyhow!("Tag names must be a single word"));
+        }
+
+        if let Some(existing) = self.find_tag(new_name).filter(|id| *id != tag_id) {
+            let into_name = self.tags[&existing].name.clone();
+            return self.merge_tags(old_name, &into_name, snippets);
+        }
+
+        let current_name = self.tags[&tag_id].name.clone();
+        let affected = self.snippets_tagged(tag_id, &current_name, snippets);
+        for snippet_id in &affected {
+            if let Some(snippet) = snippets.get_mut(snippet_id) {
+                retag_snippet(snippet, &current_name, new_name);
+            }
+            self.link(*snippet_id, tag_id);
+        }
+
+        if let Some(tag) = self.tags.get_mut(&tag_id) {
+            tag.name = new_name.to_string();
+        }
+        Ok(affected)
+    }
+
+    /// Moves every snippet from one tag onto another and removes the first tag.
+    /// Returns the snippets that changed.
+    pub fn merge_tags(
+        &mut self,
+        from: &str,
+        into: &str,
+        snippets: &mut HashMap<Uuid, CodeSnippet>,
+    ) -> Result<Vec<Uuid>> {
+        let from_id = self
+            .find_tag(from)
+            .ok_or_else(|| anyhow!("Tag #{} not found", from.trim_start_matches('#')))?;
+        let into_id = self
+            .find_tag(into)
+            .ok_or_else(|| anyhow!("Tag #{} not found", into.trim_start_matches('#')))?;
+        if from_id == into_id {
+            return Err(anyhow!("Cannot merge a tag into itself"));
+        }
+
+        let from_name = self.tags[&from_id].name.clone();
+        let into_name = self.tags[&into_id].name.clone();
+        let affected = self.snippets_tagged(from_id, &from_name, snippets);
+        for snippet_id in &affected {
+            if let Some(snippet) = snippets.get_mut(snippet_id) {
+                retag_snippet(snippet, &from_name, &into_name);
+            }
+            if let Some(tag_ids) = self.snippet_tags.get_mut(snippet_id) {
+                tag_ids.remove(&from_id);
+            }
+            self.link(*snippet_id, into_id);
+        }
+
+        self.tag_snippets.remove(&from_id);
+        let from_tag = self.tags.remove(&from_id);
+        if let (Some(from_tag), Some(into_tag)) = (from_tag, self.tags.get_mut(&into_id)) {
+            into_tag.usage_count += from_tag.usage_count;
+            into_tag.last_used_at = into_tag.last_used_at.max(from_tag.last_used_at);
+        }
+        Ok(affected)
+    }
+
+    /// Adds a tag to each of the given snippets that lacks it. Returns the snippets that changed.
+    pub fn add_tag_to_snippets(
+        &mut self,
+        name: &str,
+        snippet_ids: &[Uuid],
+        snippets: &mut HashMap<Uuid, CodeSnippet>,
+    ) -> Result<Vec<Uuid>> {
+        let name = name.trim().trim_start_matches('#');
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(anyhow!("Tag names must be a single word"));
+        }
+        // Keep the spelling of a tag that already exists
+        let name = match self.find_tag(name) {
+            Some(tag_id) => self.tags[&tag_id].name.clone(),
+            None => name.to_string(),
+        };
+
+        let mut affected = Vec::new();
+        for snippet_id in snippet_ids {
+            let Some(snippet) = snippets.get_mut(snippet_id) else {
+                continue;
+            };
+            if snippet.tags.iter().any(|t| t.eq_ignore_ascii_case(&name)) {
+                continue;
+            }
+            snippet.tags.push(name.clone());
+            snippet.updated_at = Utc::now();
+            self.add_tag_to_snippet(*snippet_id, name.clone());
+            affected.push(*snippet_id);
+        }
+        Ok(affected)
+    }
+
+    /// Removes a tag from each of the given snippets, dropping the tag once no snippet
+    /// carries it. Returns the snippets that changed.
+    pub fn remove_tag_from_snippets(
+        &mut self,
+        name: &str,
+        snippet_ids: &[Uuid],
+        snippets: &mut HashMap<Uuid, CodeSnippet>,
+    ) -> Result<Vec<Uuid>> {
+        let name = name.trim().trim_start_matches('#');
+        if name.is_empty() {
+            return Err(anyhow!("Type the tag to remove"));
+        }
+        let tag_id = self.find_tag(name);
+
+        let mut affected = Vec::new();
+        for snippet_id in snippet_ids {
+            let Some(snippet) = snippets.get_mut(snippet_id) else {
+                continue;
+            };
+            let before = snippet.tags.len();
+            snippet.tags.retain(|t| !t.eq_ignore_ascii_case(name));
+            if snippet.tags.len() != before {
+                snippet.updated_at = Utc::now();
+                affected.push(*snippet_id);
+            }
+
+            if let Some(tag_id) = tag_id {
+                if let Some(tag_ids) = self.snippet_tags.get_mut(snippet_id) {
+                    tag_ids.remove(&tag_id);
+                }
+                if let Some(tagged) = self.tag_snippets.get_mut(&tag_id) {
+                    tagged.remove(snippet_id);
+                }
+            }
+        }
+
+        if let Some(tag_id) = tag_id {
+            let unused = self.tag_snippets.get(&tag_id).is_none_or(HashSet::is_empty)
+                && !snippets
+                    .values()
+                    .any(|snippet| snippet.tags.iter().any(|t| t.eq_ignore_ascii_case(name)));
+            if unused {
+                self.tag_snippets.remove(&tag_id);
+                self.tags.remove(&tag_id);
+            }
+        }
+        Ok(affected)
+    }
+
+    /// Snippets carrying a tag, whether recorded in the index or only on the snippet itself
+    fn snippets_tagged(
+        &self,
+        tag_id: Uuid,
+        name: &str,
+        snippets: &HashMap<Uuid, CodeSnippet>,
+    ) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = snippets
+            .values()
+            .filter(|snippet| snippet.tags.iter().any(|t| t.eq_ignore_ascii_case(name)))
+            .map(|snippet| snippet.id)
+            .chain(
+                self.tag_snippets
+                    .get(&tag_id)
+                    .into_iter()
+                    .flatten()
+                    .copied(),
+            )
+            .collect();
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
+    fn link(&mut self, snippet_id: Uuid, tag_id: Uuid) {
+        self.snippet_tags
+            .entry(snippet_id)
+            .or_default()
+            .insert(tag_id);
+        self.tag_snippets
+            .entry(tag_id)
+            .or_default()
+            .insert(snippet_id);
+    }
+
     /// Handle when a snippet is deleted
     pub fn handle_snippet_deleted(&mut self, snippet_id: &Uuid) {
         // Get all tags associated with this snippet
@@ -150,3 +355,23 @@ impl TagManager {
         }
     }
 }
+
+/// Replaces one tag name on a snippet, dropping the duplicate if it already had the new one
+fn retag_snippet(snippet: &mut CodeSnippet, from: &str, to: &str) {
+    let mut tags: Vec<String> = Vec::with_capacity(snippet.tags.len());
+    for tag in &snippet.tags {
+        let tag = if tag.eq_ignore_ascii_case(from) {
+            to.to_string()
+        } else {
+            tag.clone()
+        };
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            tags.push(tag);
+        }
+    }
+
+    if tags != snippet.tags {
+        snippet.tags = tags;
+        snippet.updated_at = Utc::now();
+    }
+}
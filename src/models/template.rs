@@ -0,0 +1,40 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+
+/// Placeholder marking where the cursor belongs; it is dropped when filling a template
+pub const TEMPLATE_CURSOR: &str = "cursor";
+
+static TEMPLATE_PLACEHOLDER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_-]*)\s*\}\}").unwrap());
+
+/// Names of the `{{placeholders}}` across all texts, in order of first use
+pub fn template_variables<'a>(texts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for text in texts {
+        for captures in TEMPLATE_PLACEHOLDER.captures_iter(text) {
+            let name = &captures[1];
+            if name != TEMPLATE_CURSOR && !names.iter().any(|n| n == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Substitutes placeholders; ones without a value are left as written
+pub fn fill_template(text: &str, values: &HashMap<String, String>) -> String {
+    TEMPLATE_PLACEHOLDER
+        .replace_all(text, |captures: &Captures| {
+            let name = &captures[1];
+            if name == TEMPLATE_CURSOR {
+                String::new()
+            } else {
+                values
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_else(|| captures[0].to_string())
+            }
+        })
+        .into_owned()
+}
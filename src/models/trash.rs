@@ -0,0 +1,46 @@
+use crate::models::{CodeSnippet, Notebook};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An item that was moved to the trash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TrashedItem {
+    Snippet(CodeSnippet),
+    Notebook(Notebook),
+}
+
+impl TrashedItem {
+    pub fn id(&self) -> Uuid {
+        match self {
+            TrashedItem::Snippet(snippet) => snippet.id,
+            TrashedItem::Notebook(notebook) => notebook.id,
+        }
+    }
+}
+
+/// A trashed item along with where it used to live
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub item: TrashedItem,
+    pub deleted_at: DateTime<Utc>,
+    /// Notebook path the item was deleted from, kept for display
+    pub original_location: String,
+}
+
+impl TrashEntry {
+    pub fn new(item: TrashedItem, original_location: String) -> Self {
+        Self {
+            item,
+            deleted_at: Utc::now(),
+            original_location,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match &self.item {
+            TrashedItem::Snippet(snippet) => &snippet.title,
+            TrashedItem::Notebook(notebook) => &notebook.name,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::models::SnippetLanguage;
+
+/// A snippet read from a VS Code snippet file, ready to be added to a notebook
+#[derive(Debug, Clone)]
+pub struct VsCodeSnippet {
+    pub title: String,
+    pub language: SnippetLanguage,
+    pub content: String,
+    pub description: Option<String>,
+    /// Tag names made from the prefixes, without the `#`
+    pub tags: Vec<String>,
+}
+
+/// VS Code lets `prefix` and `body` be either a single string or a list of them
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            OneOrMany::One(text) => vec![text],
+            OneOrMany::Many(list) => list,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SnippetEntry {
+    prefix: Option<OneOrMany>,
+    body: Option<OneOrMany>,
+    description: Option<String>,
+    /// Comma-separated language ids, only used in `.code-snippets` files
+    scope: Option<String>,
+}
+
+/// Reads a `.code-snippets` file or a per-language file such as `rust.json`
+///
+/// Entries without a body are skipped. The language comes from the entry's scope, then
+/// from the file name of a per-language file, and falls back to plain text.
+pub fn read_snippet_file(path: &Path) -> Result<Vec<VsCodeSnippet>> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let entries: BTreeMap<String, SnippetEntry> = serde_json::from_str(&strip_jsonc(&text))
+        .with_context(|| format!("{} is not a VS Code snippet file", path.display()))?;
+
+    // User snippets for one language live in `<language id>.json`
+    let file_language = path
+        .extension()
+        .filter(|ext| ext.eq_ignore_ascii_case("json"))
+        .and(path.file_stem())
+        .map(|stem| language_from_id(&stem.to_string_lossy()));
+
+    let snippets = entries
+        .into_iter()
+        .filter_map(|(title, entry)| {
+            let content = entry.body?.into_vec().join("\n");
+            let language = entry
+                .scope
+                .as_deref()
+                .and_then(|scope| scope.split(',').map(str::trim).find(|id| !id.is_empty()))
+                .map(language_from_id)
+                .or_else(|| file_language.clone())
+                .unwrap_or(SnippetLanguage::Text);
+
+            let mut tags: Vec<String> = Vec::new();
+            for prefix in entry.prefix.map(OneOrMany::into_vec).unwrap_or_default() {
+                let tag = prefix
+                    .trim()
+                    .trim_start_matches('#')
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join("-");
+                if !tag.is_empty() && !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+
+            Some(VsCodeSnippet {
+                title,
+                language,
+                content,
+                description: entry.description.filter(|d| !d.trim().is_empty()),
+                tags,
+            })
+        })
+        .collect();
+
+    Ok(snippets)
+}
+
+/// Name for a notebook holding the snippets of this file, e.g. "rust" for `rust.json`
+pub fn notebook_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "VS Code Snippets".to_string())
+}
+
+/// Maps a VS Code language id to a snippet language
+fn language_from_id(id: &str) -> SnippetLanguage {
+    match id.to_lowercase().as_str() {
+        "shellscript" => SnippetLanguage::Bash,
+        "javascriptreact" => SnippetLanguage::JavaScript,
+        "typescriptreact" => SnippetLanguage::TypeScript,
+        "jsonc" => SnippetLanguage::Json,
+        other => SnippetLanguage::from_fence_info(other),
+    }
+}
+
+/// Drops the comments and trailing commas VS Code allows in its JSON files
+fn strip_jsonc(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|&next| next != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            (',', _) => {
+                // A comma followed only by whitespace and a closing bracket is dropped
+                let rest = chars.clone().find(|next| !next.is_whitespace());
+                if !matches!(rest, Some('}' | ']')) {
+                    output.push(c);
+                }
+            }
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
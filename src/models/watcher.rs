@@ -0,0 +1,49 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, channel};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{DebounceEventResult, Debouncer, new_debouncer};
+
+/// Bursts of events within this window are reported as a single change
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(500);
+
+/// Watches the snippet files for changes made outside of snix
+#[derive(Debug)]
+pub struct SnippetWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+    events: Receiver<DebounceEventResult>,
+}
+
+impl SnippetWatcher {
+    pub fn new(snippets_dir: &Path) -> Result<Self> {
+        let (sender, events) = channel();
+        let mut debouncer =
+            new_debouncer(DEBOUNCE_DELAY, sender).context("Failed to start the file watcher")?;
+        debouncer
+            .watcher()
+            .watch(snippets_dir, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", snippets_dir.display()))?;
+
+        Ok(Self {
+            _debouncer: debouncer,
+            events,
+        })
+    }
+
+    /// Files that changed since the last call, without blocking
+    pub fn changed_files(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self
+            .events
+            .try_iter()
+            .filter_map(|result| result.ok())
+            .flatten()
+            .map(|event| event.path)
+            .collect();
+
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+}
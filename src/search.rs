@@ -1,11 +1,40 @@
-use crate::app::{App, RecentSearchEntry, SearchResult, SearchResultType};
+use crate::app::{App, ContextLine, RecentSearchEntry, SearchResult, SearchResultType};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use regex::{Regex, RegexBuilder};
 use uuid::Uuid;
 
 const MAX_RECENT_SEARCHES: usize = 10;
 
+/// Minimum fuzzy score per query character for a result to be kept
+const MIN_SCORE_PER_CHAR: i64 = 10;
+
+/// Number of characters shown around a match in `match_context`
+const CONTEXT_WIDTH: usize = 60;
+
+/// Lines of code shown above and below a content match
+const CONTEXT_LINES: usize = 1;
+
 /// Performs a search across all notebooks, snippets, and content
 /// Returns the number of results found
 pub fn perform_search(app: &mut App, query: &str) -> usize {
+    if app.regex_search && !query.trim().is_empty() {
+        // Keep the previous results on screen while the pattern is invalid
+        return match build_regex(query) {
+            Ok(regex) => {
+                app.search_results.clear();
+                app.selected_search_result = 0;
+                let count = perform_regex_search(app, query, &regex);
+                app.search_regex = Some(regex);
+                count
+            }
+            Err(e) => {
+                app.set_error_message(format!("Invalid regex: {}", e));
+                app.search_results.len()
+            }
+        };
+    }
+
     app.search_results.clear();
     app.selected_search_result = 0;
 
@@ -53,7 +82,9 @@ fn perform_tag_search(app: &mut App, tag_name: &str) -> usize {
                 name: snippet.title.clone(),
                 result_type: SearchResultType::Snippet,
                 match_context: format!("Tagged with #{}", tag_name),
+                context_lines: Vec::new(),
                 parent_id: Some(snippet.notebook_id),
+                score: 0,
             });
         }
 
@@ -70,7 +101,9 @@ fn perform_tag_search(app: &mut App, tag_name: &str) -> usize {
                         name: snippet.title.clone(),
                         result_type: SearchResultType::Snippet,
                         match_context: format!("Tagged with {}", tag.display_name()),
+                        context_lines: Vec::new(),
                         parent_id: Some(snippet.notebook_id),
+                        score: 0,
                     });
                 }
             }
@@ -78,16 +111,16 @@ fn perform_tag_search(app: &mut App, tag_name: &str) -> usize {
 
         // Also check for snippets that have this tag directly
         for (id, snippet) in &app.snippet_database.snippets {
-            if snippet.has_tag(&tag.name) {
-                if !app.search_results.iter().any(|r| r.id == *id) {
-                    app.search_results.push(SearchResult {
-                        id: *id,
-                        name: snippet.title.clone(),
-                        result_type: SearchResultType::Snippet,
-                        match_context: format!("Tagged with {}", tag.display_name()),
-                        parent_id: Some(snippet.notebook_id),
-                    });
-                }
+            if snippet.has_tag(&tag.name) && !app.search_results.iter().any(|r| r.id == *id) {
+                app.search_results.push(SearchResult {
+                    id: *id,
+                    name: snippet.title.clone(),
+                    result_type: SearchResultType::Snippet,
+                    match_context: format!("Tagged with {}", tag.display_name()),
+                    context_lines: Vec::new(),
+                    parent_id: Some(snippet.notebook_id),
+                    score: 0,
+                });
             }
         }
     }
@@ -98,114 +131,345 @@ fn perform_tag_search(app: &mut App, tag_name: &str) -> usize {
     result_count
 }
 
-/// Perform a regular search across notebooks, snippets, and content
+/// Perform a fuzzy search across notebooks, snippets, and content
 fn perform_regular_search(app: &mut App, query: &str) -> usize {
+    let matcher = SkimMatcherV2::default();
+    let min_score = min_fuzzy_score(query);
+    let mut results: Vec<SearchResult> = Vec::new();
+
     // Search in notebooks
     for (id, notebook) in &app.snippet_database.notebooks {
-        if notebook.name.to_lowercase().contains(query) {
-            app.search_results.push(SearchResult {
+        let name_hit = fuzzy_score(&matcher, &notebook.name, query)
+            .map(|(score, _)| (score, format!("Notebook name match: {}", notebook.name)));
+
+        let desc_hit = notebook.description.as_ref().and_then(|desc| {
+            fuzzy_score(&matcher, desc, query).map(|(score, indices)| {
+                (
+                    score,
+                    format!("Description: {}", context_window(desc, &indices)),
+                )
+            })
+        });
+
+        if let Some((score, match_context)) = best_hit([name_hit, desc_hit], min_score) {
+            results.push(SearchResult {
                 id: *id,
                 name: notebook.name.clone(),
                 result_type: SearchResultType::Notebook,
-                match_context: format!("Notebook name match: {}", notebook.name),
+                match_context,
+                context_lines: Vec::new(),
                 parent_id: notebook.parent_id,
+                score,
             });
         }
-
-        // Search in notebook descriptions
-        if let Some(desc) = &notebook.description {
-            if desc.to_lowercase().contains(query) {
-                app.search_results.push(SearchResult {
-                    id: *id,
-                    name: notebook.name.clone(),
-                    result_type: SearchResultType::Notebook,
-                    match_context: format!("Description: {}", desc),
-                    parent_id: notebook.parent_id,
-                });
-            }
-        }
     }
 
     // Search in snippets
     for (id, snippet) in &app.snippet_database.snippets {
-        // Search in snippet titles
-        if snippet.title.to_lowercase().contains(query) {
-            app.search_results.push(SearchResult {
+        let title_hit = fuzzy_score(&matcher, &snippet.title, query)
+            .map(|(score, _)| (score, format!("Snippet title match: {}", snippet.title)));
+
+        let desc_hit = snippet.description.as_ref().and_then(|desc| {
+            fuzzy_score(&matcher, desc, query).map(|(score, indices)| {
+                (
+                    score,
+                    format!("Description: {}", context_window(desc, &indices)),
+                )
+            })
+        });
+
+        let tags_hit = snippet
+            .tags
+            .iter()
+            .filter_map(|tag| fuzzy_score(&matcher, tag, query).map(|(score, _)| (score, tag)))
+            .max_by_key(|(score, _)| *score)
+            .map(|(score, tag)| (score, format!("Tags: #{}", tag)));
+
+        if let Some((score, match_context)) = best_hit([title_hit, desc_hit, tags_hit], min_score) {
+            results.push(SearchResult {
                 id: *id,
                 name: snippet.title.clone(),
                 result_type: SearchResultType::Snippet,
-                match_context: format!("Snippet title match: {}", snippet.title),
+                match_context,
+                context_lines: Vec::new(),
                 parent_id: Some(snippet.notebook_id),
+                score,
             });
         }
 
-        // Search in snippet descriptions
-        if let Some(desc) = &snippet.description {
-            if desc.to_lowercase().contains(query) {
-                app.search_results.push(SearchResult {
-                    id: *id,
-                    name: snippet.title.clone(),
-                    result_type: SearchResultType::Snippet,
-                    match_context: format!("Description: {}", desc),
-                    parent_id: Some(snippet.notebook_id),
-                });
-            }
+        // Search in snippet content, keeping the best scoring line
+        let content_hit = snippet
+            .content
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                fuzzy_score(&matcher, line, query).map(|(score, indices)| (score, i, indices))
+            })
+            .max_by_key(|(score, _, _)| *score);
+
+        if let Some((score, line_idx, indices)) = content_hit.filter(|hit| hit.0 >= min_score) {
+            let line = snippet.content.lines().nth(line_idx).unwrap_or_default();
+
+            results.push(SearchResult {
+                id: *id,
+                name: snippet.title.clone(),
+                result_type: SearchResultType::CodeContent,
+                match_context: format!("Line {}: {}", line_idx + 1, context_window(line, &indices)),
+                context_lines: code_context(&snippet.content, line_idx, &indices),
+                parent_id: Some(snippet.notebook_id),
+                score,
+            });
         }
+    }
 
-        // Search in snippet tags
-        if !snippet.tags.is_empty() {
-            let matching_tags: Vec<_> = snippet
-                .tags
-                .iter()
-                .filter(|tag| tag.to_lowercase().contains(query))
-                .collect();
+    // Best matches first
+    results.sort_by_key(|r| std::cmp::Reverse(r.score));
+    app.search_results = results;
 
-            if !matching_tags.is_empty() {
-                let tag_list = matching_tags
-                    .iter()
-                    .map(|t| format!("#{}", t))
-                    .collect::<Vec<_>>()
-                    .join(", ");
+    let result_count = app.search_results.len();
+    save_to_recent_searches(app, query.to_string(), result_count);
 
-                app.search_results.push(SearchResult {
-                    id: *id,
-                    name: snippet.title.clone(),
-                    result_type: SearchResultType::Snippet,
-                    match_context: format!("Tags: {}", tag_list),
-                    parent_id: Some(snippet.notebook_id),
-                });
-            }
+    result_count
+}
+
+/// Compiles a case-insensitive regex from the search query
+fn build_regex(query: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(query).case_insensitive(true).build()
+}
+
+/// Perform a regex search against snippet titles and code content
+fn perform_regex_search(app: &mut App, query: &str, regex: &Regex) -> usize {
+    let mut results: Vec<SearchResult> = Vec::new();
+
+    for (id, snippet) in &app.snippet_database.snippets {
+        if regex.is_match(&snippet.title) {
+            results.push(SearchResult {
+                id: *id,
+                name: snippet.title.clone(),
+                result_type: SearchResultType::Snippet,
+                match_context: format!("Snippet title match: {}", snippet.title),
+                context_lines: Vec::new(),
+                parent_id: Some(snippet.notebook_id),
+                score: 2,
+            });
         }
 
-        // Search in snippet content
-        if snippet.content.to_lowercase().contains(query) {
-            // Find the matching line(s) for context
-            let mut match_context = String::new();
-            for (i, line) in snippet.content.lines().enumerate() {
-                if line.to_lowercase().contains(query) {
-                    let line_num = i + 1;
-                    let trimmed_line = line.trim();
-                    match_context = format!("Line {}: {}", line_num, trimmed_line);
-                    break;
-                }
-            }
+        let content_hit = snippet
+            .content
+            .lines()
+            .enumerate()
+            .find_map(|(i, line)| regex.find(line).map(|m| (i, line, m.start())));
 
-            app.search_results.push(SearchResult {
+        if let Some((line_idx, line, byte_start)) = content_hit {
+            let char_start = line[..byte_start].chars().count();
+
+            results.push(SearchResult {
                 id: *id,
                 name: snippet.title.clone(),
                 result_type: SearchResultType::CodeContent,
-                match_context,
+                match_context: format!(
+                    "Line {}: {}",
+                    line_idx + 1,
+                    context_window(line, &[char_start])
+                ),
+                context_lines: code_context(&snippet.content, line_idx, &[char_start]),
                 parent_id: Some(snippet.notebook_id),
+                score: 1,
             });
         }
     }
 
+    // Title matches first, then content matches
+    results.sort_by_key(|r| std::cmp::Reverse(r.score));
+    app.search_results = results;
+
     let result_count = app.search_results.len();
     save_to_recent_searches(app, query.to_string(), result_count);
 
     result_count
 }
 
+/// Snippets for the quick open picker, ranked by how well the title (or failing that,
+/// a tag) matches `query`, with the matched title positions. An empty query lists the
+/// most recently opened snippets first.
+pub fn quick_open_matches(app: &App, query: &str) -> Vec<(Uuid, Vec<usize>)> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        let mut recent: Vec<_> = app.snippet_database.snippets.values().collect();
+        recent.sort_by_key(|snippet| std::cmp::Reverse(snippet.accessed_at));
+        return recent
+            .into_iter()
+            .map(|snippet| (snippet.id, Vec::new()))
+            .collect();
+    }
+
+    let matcher = SkimMatcherV2::default();
+    let min_score = min_fuzzy_score(&query);
+    let mut ranked: Vec<_> = app
+        .snippet_database
+        .snippets
+        .values()
+        .filter_map(|snippet| {
+            if let Some((score, indices)) = fuzzy_score(&matcher, &snippet.title, &query)
+                .filter(|(score, _)| *score >= min_score)
+            {
+                return Some((score, snippet, indices));
+            }
+            // Tag hits rank below title hits
+            snippet
+                .tags
+                .iter()
+                .filter_map(|tag| fuzzy_score(&matcher, tag, &query))
+                .map(|(score, _)| score)
+                .filter(|score| *score >= min_score)
+                .max()
+                .map(|score| (score / 2, snippet, Vec::new()))
+        })
+        .collect();
+
+    ranked.sort_by_key(|(score, snippet, _)| {
+        (std::cmp::Reverse(*score), snippet.title.to_lowercase())
+    });
+    ranked
+        .into_iter()
+        .map(|(_, snippet, indices)| (snippet.id, indices))
+        .collect()
+}
+
+/// Scores `text` against every whitespace separated token in `query`, so tokens
+/// may appear in any order. Returns the summed score and matched char indices.
+fn fuzzy_score(matcher: &SkimMatcherV2, text: &str, query: &str) -> Option<(i64, Vec<usize>)> {
+    let mut total = 0;
+    let mut indices = Vec::new();
+
+    for token in query.split_whitespace() {
+        let (score, token_indices) = matcher.fuzzy_indices(text, token)?;
+        total += score;
+        indices.extend(token_indices);
+    }
+
+    indices.sort_unstable();
+    indices.dedup();
+    Some((total, indices))
+}
+
+/// Returns the char positions in `text` matched by the current search query,
+/// honouring regex mode, used to highlight results
+pub fn match_indices(app: &App, text: &str) -> Vec<usize> {
+    if app.regex_search {
+        // Compiled once per search; a pattern left over from an older query is not used
+        let Some(regex) = app
+            .search_regex
+            .as_ref()
+            .filter(|regex| regex.as_str() == app.search_query)
+        else {
+            return Vec::new();
+        };
+
+        let mut indices = Vec::new();
+        for m in regex.find_iter(text) {
+            let start = text[..m.start()].chars().count();
+            let len = m.as_str().chars().count();
+            indices.extend(start..start + len);
+        }
+        return indices;
+    }
+
+    let matcher = SkimMatcherV2::default();
+    fuzzy_score(&matcher, text, &app.search_query.to_lowercase())
+        .map(|(_, indices)| indices)
+        .unwrap_or_default()
+}
+
+/// Minimum score a match needs so scattered single-character hits are dropped
+fn min_fuzzy_score(query: &str) -> i64 {
+    let query_len = query.chars().filter(|c| !c.is_whitespace()).count() as i64;
+    query_len * MIN_SCORE_PER_CHAR
+}
+
+/// Picks the highest scoring hit that clears the threshold
+fn best_hit<const N: usize>(
+    hits: [Option<(i64, String)>; N],
+    min_score: i64,
+) -> Option<(i64, String)> {
+    hits.into_iter()
+        .flatten()
+        .filter(|(score, _)| *score >= min_score)
+        .max_by_key(|(score, _)| *score)
+}
+
+/// Trims a matched line to a window around the first matched character
+fn context_window(line: &str, indices: &[usize]) -> String {
+    let trimmed = line.trim();
+    let offset = line.chars().take_while(|c| c.is_whitespace()).count();
+    let chars: Vec<char> = trimmed.chars().collect();
+
+    if chars.len() <= CONTEXT_WIDTH {
+        return trimmed.to_string();
+    }
+
+    let first_match = indices
+        .first()
+        .map(|i| i.saturating_sub(offset))
+        .unwrap_or(0);
+    let start = first_match
+        .saturating_sub(CONTEXT_WIDTH / 3)
+        .min(chars.len() - CONTEXT_WIDTH);
+    let end = start + CONTEXT_WIDTH;
+
+    let mut window: String = chars[start..end].iter().collect();
+    if start > 0 {
+        window.insert(0, '…');
+    }
+    if end < chars.len() {
+        window.push('…');
+    }
+    window
+}
+
+/// The matched line with `CONTEXT_LINES` on either side, dedented together and clipped
+fn code_context(content: &str, line_idx: usize, indices: &[usize]) -> Vec<ContextLine> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = line_idx.saturating_sub(CONTEXT_LINES);
+    let end = (line_idx + CONTEXT_LINES + 1).min(lines.len());
+    let indent_of = |line: &str| line.chars().take_while(|c| c.is_whitespace()).count();
+
+    // Relative indentation is kept so the lines still read as code
+    let common_indent = lines[start..end]
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| indent_of(line))
+        .min()
+        .unwrap_or(0);
+
+    (start..end)
+        .map(|i| {
+            let line = lines[i];
+            let indent = " ".repeat(indent_of(line).saturating_sub(common_indent));
+            let text = if i == line_idx {
+                context_window(line, indices)
+            } else {
+                clip_line(line.trim())
+            };
+            ContextLine {
+                number: i + 1,
+                text: format!("{}{}", indent, text),
+                is_match: i == line_idx,
+            }
+        })
+        .collect()
+}
+
+/// Cuts a line down to `CONTEXT_WIDTH` characters
+fn clip_line(line: &str) -> String {
+    if line.chars().count() <= CONTEXT_WIDTH {
+        return line.to_string();
+    }
+    let mut clipped: String = line.chars().take(CONTEXT_WIDTH).collect();
+    clipped.push('…');
+    clipped
+}
+
 /// Saves a search query to the recent searches list
 fn save_to_recent_searches(app: &mut App, query: String, result_count: usize) {
     // Don't save empty queries
@@ -1,5 +1,5 @@
 use crate::app::App;
-use crate::ui::colors::RosePine;
+use crate::ui::colors::palette;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -32,7 +32,7 @@ pub fn render_about(frame: &mut Frame, app: &App) {
         .title(" ⓘ About Snix ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::IRIS));
+        .style(Style::default().fg(palette().iris));
 
     let inner_area = popup_block.inner(popup_area);
     popup_block.render(popup_area, frame.buffer_mut());
@@ -53,8 +53,8 @@ pub fn render_about(frame: &mut Frame, app: &App) {
     ];
     let tabs = Tabs::new(tab_titles)
         .select(app.selected_about_tab)
-        .style(Style::default().fg(RosePine::SUBTLE))
-        .highlight_style(Style::default().fg(RosePine::LOVE).bold())
+        .style(Style::default().fg(palette().subtle))
+        .highlight_style(Style::default().fg(palette().love).bold())
         .divider("")
         .padding(" ", " ");
 
@@ -72,7 +72,7 @@ pub fn render_about(frame: &mut Frame, app: &App) {
     let help_text = "Tab: Switch sections • ←/→: Navigate tabs • Esc: Close";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 }
 
@@ -82,39 +82,39 @@ fn render_overview_tab(frame: &mut Frame, area: Rect, app: &App) {
 
     text.push(Line::from(vec![Span::styled(
         "  ███████╗███╗   ██╗██╗██╗  ██╗  ",
-        Style::default().fg(RosePine::LOVE),
+        Style::default().fg(palette().love),
     )]));
     text.push(Line::from(vec![Span::styled(
         "  ██╔════╝████╗  ██║██║╚██╗██╔╝  ",
-        Style::default().fg(RosePine::LOVE),
+        Style::default().fg(palette().love),
     )]));
     text.push(Line::from(vec![Span::styled(
         "  ███████╗██╔██╗ ██║██║ ╚███╔╝   ",
-        Style::default().fg(RosePine::LOVE),
+        Style::default().fg(palette().love),
     )]));
     text.push(Line::from(vec![Span::styled(
         "  ╚════██║██║╚██╗██║██║ ██╔██╗   ",
-        Style::default().fg(RosePine::LOVE),
+        Style::default().fg(palette().love),
     )]));
     text.push(Line::from(vec![Span::styled(
         "  ███████║██║ ╚████║██║██╔╝ ██╗  ",
-        Style::default().fg(RosePine::LOVE),
+        Style::default().fg(palette().love),
     )]));
     text.push(Line::from(vec![Span::styled(
         "  ╚══════╝╚═╝  ╚═══╝╚═╝╚═╝  ╚═╝  ",
-        Style::default().fg(RosePine::LOVE),
+        Style::default().fg(palette().love),
     )]));
 
     text.push(Line::from(""));
     text.push(Line::from(vec![Span::styled(
         format!("Version: {}", version),
-        Style::default().fg(RosePine::GOLD).bold(),
+        Style::default().fg(palette().gold).bold(),
     )]));
     text.push(Line::from(""));
 
     // App description
     text.push(Line::from(vec![
-        Span::styled("Snix", Style::default().fg(RosePine::IRIS).bold()),
+        Span::styled("Snix", Style::default().fg(palette().iris).bold()),
         Span::raw(" is a powerful snippet manager for developers that lets you organize, search,"),
     ]));
     text.push(Line::from(
@@ -125,7 +125,7 @@ fn render_overview_tab(frame: &mut Frame, area: Rect, app: &App) {
     // Stats summary
     text.push(Line::from(vec![Span::styled(
         "Your Snix Library",
-        Style::default().fg(RosePine::FOAM).bold(),
+        Style::default().fg(palette().foam).bold(),
     )]));
     text.push(Line::from(""));
 
@@ -139,31 +139,31 @@ fn render_overview_tab(frame: &mut Frame, area: Rect, app: &App) {
         .count();
 
     text.push(Line::from(vec![
-        Span::styled("  󰠮 ", Style::default().fg(RosePine::GOLD)),
+        Span::styled("  󰠮 ", Style::default().fg(palette().gold)),
         Span::styled(
             format!("{} Notebooks", num_notebooks),
-            Style::default().fg(RosePine::TEXT),
+            Style::default().fg(palette().text),
         ),
     ]));
     text.push(Line::from(vec![
-        Span::styled("  󰈮 ", Style::default().fg(RosePine::PINE)),
+        Span::styled("  󰈮 ", Style::default().fg(palette().pine)),
         Span::styled(
             format!("{} Snippets", num_snippets),
-            Style::default().fg(RosePine::TEXT),
+            Style::default().fg(palette().text),
         ),
     ]));
     text.push(Line::from(vec![
-        Span::styled("   ", Style::default().fg(RosePine::LOVE)),
+        Span::styled("   ", Style::default().fg(palette().love)),
         Span::styled(
             format!("{} Favorites", num_favorites),
-            Style::default().fg(RosePine::TEXT),
+            Style::default().fg(palette().text),
         ),
     ]));
 
     text.push(Line::from(""));
     text.push(Line::from(vec![Span::styled(
         "Use Tab to navigate through the sections for more information.",
-        Style::default().fg(RosePine::SUBTLE).italic(),
+        Style::default().fg(palette().subtle).italic(),
     )]));
 
     let paragraph = Paragraph::new(text)
@@ -228,7 +228,7 @@ fn render_features_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let chunks = Layout::vertical([Constraint::Length(2), Constraint::Min(1)]).split(area);
 
     let title = Paragraph::new("󱓞 Features")
-        .style(Style::default().fg(RosePine::GOLD).bold())
+        .style(Style::default().fg(palette().gold).bold())
         .alignment(Alignment::Center);
     frame.render_widget(title, chunks[0]);
 
@@ -245,17 +245,16 @@ fn render_features_tab(frame: &mut Frame, area: Rect, _app: &App) {
 
     let left_text = left_features
         .iter()
-        .map(|(title, desc)| {
+        .flat_map(|(title, desc)| {
             vec![
                 Line::from(vec![Span::styled(
                     format!("{} ", title),
-                    Style::default().fg(RosePine::IRIS).bold(),
+                    Style::default().fg(palette().iris).bold(),
                 )]),
                 Line::from(vec![Span::raw(format!("   {}", desc))]),
                 Line::from(""),
             ]
         })
-        .flatten()
         .collect::<Vec<Line>>();
 
     let left_paragraph = Paragraph::new(left_text)
@@ -265,17 +264,16 @@ fn render_features_tab(frame: &mut Frame, area: Rect, _app: &App) {
 
     let right_text = right_features
         .iter()
-        .map(|(title, desc)| {
+        .flat_map(|(title, desc)| {
             vec![
                 Line::from(vec![Span::styled(
                     format!("{} ", title),
-                    Style::default().fg(RosePine::IRIS).bold(),
+                    Style::default().fg(palette().iris).bold(),
                 )]),
                 Line::from(vec![Span::raw(format!("   {}", desc))]),
                 Line::from(""),
             ]
         })
-        .flatten()
         .collect::<Vec<Line>>();
 
     let right_paragraph = Paragraph::new(right_text)
@@ -285,16 +283,18 @@ fn render_features_tab(frame: &mut Frame, area: Rect, _app: &App) {
 }
 
 fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
-    let global_keys = vec![
+    let global_keys = [
         ("Shift+?", "Toggle help menu"),
         ("q", "Quit (from start page)"),
         ("Esc", "Go back/close overlay"),
         ("h", "Go to home page"),
         ("Shift+F", "Show favorites popup"),
+        ("Ctrl+N", "Quick snippet in the Inbox notebook"),
+        ("Ctrl+P", "Quick open a snippet by title"),
         ("Backspace", "Navigate back"),
     ];
 
-    let navigation_keys = vec![
+    let navigation_keys = [
         ("↑/k", "Move up"),
         ("↓/j", "Move down"),
         ("Enter", "Select item"),
@@ -302,20 +302,23 @@ fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
         ("Tab", "Cycle through options"),
     ];
 
-    let snippet_keys = vec![
+    let snippet_keys = [
         ("s", "Create snippet"),
-        ("f", "Toggle favorite"),
-        ("y", "Copy to clipboard"),
+        ("P", "Create snippet from clipboard"),
+        ("f", "Toggle favorite snippet or notebook"),
+        ("y", "Copy to clipboard (fills {{placeholders}})"),
         ("d", "Edit description"),
-        ("t", "Edit tags"),
+        ("Ctrl+E", "Set the snippet's editor command"),
+        ("t", "Edit tags (on a notebook: tag all)"),
         ("x", "Delete item"),
     ];
 
-    let notebook_keys = vec![
+    let notebook_keys = [
         ("n", "Create notebook"),
         ("b", "Create nested notebook"),
         ("Space", "Collapse/expand notebook"),
         ("v", "View notebook details"),
+        ("k", "Clone notebook (in details)"),
         ("Shift+↑", "Move item up"),
         ("Shift+↓", "Move item down"),
         ("Shift+←→", "Reorder siblings"),
@@ -324,7 +327,7 @@ fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let chunks = Layout::vertical([Constraint::Length(2), Constraint::Min(1)]).split(area);
 
     let title = Paragraph::new("⌨️ Keyboard Shortcuts")
-        .style(Style::default().fg(RosePine::GOLD).bold())
+        .style(Style::default().fg(palette().gold).bold())
         .alignment(Alignment::Center);
     frame.render_widget(title, chunks[0]);
 
@@ -341,14 +344,14 @@ fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let global_text = vec![
         Line::from(vec![Span::styled(
             "Global",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
     ]
     .into_iter()
     .chain(global_keys.iter().map(|(key, desc)| {
         Line::from(vec![
-            Span::styled(format!("{:8}", key), Style::default().fg(RosePine::GOLD)),
+            Span::styled(format!("{:8}", key), Style::default().fg(palette().gold)),
             Span::raw(format!("{}", desc)),
         ])
     }))
@@ -360,14 +363,14 @@ fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let nav_text = vec![
         Line::from(vec![Span::styled(
             "Navigation",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
     ]
     .into_iter()
     .chain(navigation_keys.iter().map(|(key, desc)| {
         Line::from(vec![
-            Span::styled(format!("{:8}", key), Style::default().fg(RosePine::GOLD)),
+            Span::styled(format!("{:8}", key), Style::default().fg(palette().gold)),
             Span::raw(format!("{}", desc)),
         ])
     }))
@@ -379,14 +382,14 @@ fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let snippet_text = vec![
         Line::from(vec![Span::styled(
             "Snippets",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
     ]
     .into_iter()
     .chain(snippet_keys.iter().map(|(key, desc)| {
         Line::from(vec![
-            Span::styled(format!("{:8}", key), Style::default().fg(RosePine::GOLD)),
+            Span::styled(format!("{:8}", key), Style::default().fg(palette().gold)),
             Span::raw(format!("{}", desc)),
         ])
     }))
@@ -398,14 +401,14 @@ fn render_keybindings_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let notebook_text = vec![
         Line::from(vec![Span::styled(
             "Notebooks",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
     ]
     .into_iter()
     .chain(notebook_keys.iter().map(|(key, desc)| {
         Line::from(vec![
-            Span::styled(format!("{:8}", key), Style::default().fg(RosePine::GOLD)),
+            Span::styled(format!("{:8}", key), Style::default().fg(palette().gold)),
             Span::raw(format!("{}", desc)),
         ])
     }))
@@ -466,7 +469,7 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::vertical([Constraint::Length(2), Constraint::Min(1)]).split(area);
 
     let title = Paragraph::new(" Analytics")
-        .style(Style::default().fg(RosePine::GOLD).bold())
+        .style(Style::default().fg(palette().gold).bold())
         .alignment(Alignment::Center);
     frame.render_widget(title, chunks[0]);
 
@@ -483,25 +486,25 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
     let stats_text = vec![
         Line::from(vec![Span::styled(
             "General Statistics",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
         Line::from(vec![
             Span::styled(
                 "Total Notebooks: ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!("{}", app.snippet_database.notebooks.len())),
         ]),
         Line::from(vec![
             Span::styled(
                 "Total Snippets: ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!("{}", total_snippets)),
         ]),
         Line::from(vec![
-            Span::styled("Favorites: ", Style::default().fg(RosePine::TEXT).bold()),
+            Span::styled("Favorites: ", Style::default().fg(palette().text).bold()),
             Span::raw(format!(
                 "{}",
                 app.snippet_database
@@ -514,25 +517,25 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
         Line::from(vec![
             Span::styled(
                 "Average Snippet Size: ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!("{} characters", avg_size)),
         ]),
         Line::from(vec![
             Span::styled(
                 "Largest Snippet: ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!("{} characters", max_size)),
         ]),
         Line::from(vec![
-            Span::styled("Tags Created: ", Style::default().fg(RosePine::TEXT).bold()),
+            Span::styled("Tags Created: ", Style::default().fg(palette().text).bold()),
             Span::raw(format!("{}", tags_count)),
         ]),
         Line::from(vec![
             Span::styled(
                 "Tagged Snippets: ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!(
                 "{} ({}%)",
@@ -553,31 +556,31 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
     let activity_text = vec![
         Line::from(vec![Span::styled(
             "Recent Activity",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
         Line::from(vec![
             Span::styled(
                 "New snippets (last 7 days): ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!("{}", snippets_last_week)),
         ]),
         Line::from(vec![
             Span::styled(
                 "New snippets (last 30 days): ",
-                Style::default().fg(RosePine::TEXT).bold(),
+                Style::default().fg(palette().text).bold(),
             ),
             Span::raw(format!("{}", snippets_last_month)),
         ]),
         Line::from(vec![
-            Span::styled("Library age: ", Style::default().fg(RosePine::TEXT).bold()),
+            Span::styled("Library age: ", Style::default().fg(palette().text).bold()),
             Span::raw(format!("{} days since first snippet", oldest_snippet)),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Most recent snippets:",
-            Style::default().fg(RosePine::TEXT).bold(),
+            Style::default().fg(palette().text).bold(),
         )]),
     ];
 
@@ -589,7 +592,7 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
     for (i, snippet) in recent_snippets.iter().take(3).enumerate() {
         activity_lines.push(Line::from(vec![
             Span::raw(format!("{}. ", i + 1)),
-            Span::styled(&snippet.title, Style::default().fg(RosePine::IRIS)),
+            Span::styled(&snippet.title, Style::default().fg(palette().iris)),
             Span::raw(format!(" ({})", snippet.created_at.format("%Y-%m-%d"))),
         ]));
     }
@@ -601,7 +604,7 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
     let language_text = vec![
         Line::from(vec![Span::styled(
             "Language Distribution",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
     ];
@@ -614,7 +617,7 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
             0
         };
         language_lines.push(Line::from(vec![
-            Span::styled(format!("{:8}", lang), Style::default().fg(RosePine::GOLD)),
+            Span::styled(format!("{:8}", lang), Style::default().fg(palette().gold)),
             Span::raw(format!("{} snippets ({}%)", count, percentage)),
         ]));
     }
@@ -626,40 +629,40 @@ fn render_analytics_tab(frame: &mut Frame, area: Rect, app: &App) {
 fn render_credits_tab(frame: &mut Frame, area: Rect, _app: &App) {
     let text = vec![
         Line::from(vec![
-            Span::styled("Snix", Style::default().fg(RosePine::LOVE).bold()),
+            Span::styled("Snix", Style::default().fg(palette().love).bold()),
             Span::styled(
                 " is created with ♥  by parazeeknova",
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Built with:",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Rust", Style::default().fg(RosePine::IRIS).bold()),
+            Span::styled("Rust", Style::default().fg(palette().iris).bold()),
             Span::raw(
                 " - A language empowering everyone to build reliable and efficient software.",
             ),
         ]),
         Line::from(vec![
-            Span::styled("Ratatui", Style::default().fg(RosePine::IRIS).bold()),
+            Span::styled("Ratatui", Style::default().fg(palette().iris).bold()),
             Span::raw(" - A Rust library to build rich terminal user interfaces."),
         ]),
         Line::from(vec![
-            Span::styled("Syntect", Style::default().fg(RosePine::IRIS).bold()),
+            Span::styled("Syntect", Style::default().fg(palette().iris).bold()),
             Span::raw(" - Syntax highlighting for code snippets."),
         ]),
         Line::from(vec![
-            Span::styled("Rose Pine", Style::default().fg(RosePine::IRIS).bold()),
+            Span::styled("Rose Pine", Style::default().fg(palette().iris).bold()),
             Span::raw(" - Color scheme inspired by nature."),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Special Thanks:",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
         Line::from(
@@ -668,19 +671,19 @@ fn render_credits_tab(frame: &mut Frame, area: Rect, _app: &App) {
         Line::from(""),
         Line::from(vec![Span::styled(
             "License:",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
         Line::from("Snix is open source software licensed under the MIT License."),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Source Code:",
-            Style::default().fg(RosePine::FOAM).bold(),
+            Style::default().fg(palette().foam).bold(),
         )]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "https://github.com/parazeeknova/snix",
-            Style::default().fg(RosePine::IRIS),
+            Style::default().fg(palette().iris),
         )]),
     ];
 
@@ -1,8 +1,9 @@
 use crate::app::App;
+use crate::models::backup::{RetentionPolicy, apply_retention, backups_dir, new_backup_path};
 use crate::models::{
     ExportOptions, export_database_with_tags, import_database, merge_import_into_database_with_tags,
 };
-use crate::ui::colors::RosePine;
+use crate::ui::colors::palette;
 use chrono::{DateTime, TimeZone, Utc};
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -53,6 +54,8 @@ pub struct BackupRestoreState {
     pub restore_strategy: RestoreStrategy,
     pub current_restore_backup: Option<usize>,
     pub restore_option_selected: usize,
+    /// Policy used by the prune action, refreshed from the settings on render
+    pub retention: RetentionPolicy,
 }
 
 impl Default for BackupRestoreState {
@@ -73,6 +76,7 @@ impl Default for BackupRestoreState {
             restore_strategy: RestoreStrategy::SkipExisting,
             current_restore_backup: None,
             restore_option_selected: 0,
+            retention: RetentionPolicy::from_settings(&Default::default()),
         }
     }
 }
@@ -89,12 +93,25 @@ pub struct BackupInfo {
     pub root_notebook_count: usize,
 }
 
-const BACKUP_DIR: &str = "backups";
+/// Folder backups were written to before `~/.snix/backups`, relative to the working directory
+const LEGACY_BACKUP_DIR: &str = "backups";
+
+/// Path for a new backup, falling back to the legacy folder without a home directory
+fn new_backup_file(prefix: &str) -> PathBuf {
+    new_backup_path(prefix).unwrap_or_else(|_| {
+        Path::new(LEGACY_BACKUP_DIR).join(format!(
+            "{}{}.json",
+            prefix,
+            Utc::now().format("%Y%m%d-%H%M%S")
+        ))
+    })
+}
 
 /// Main render function for the backup/restore floating window
 pub fn render(frame: &mut Frame, app: &mut App) {
     let mut state = app.backup_restore_state.clone().unwrap_or_default();
     state.backups = list_backups();
+    state.retention = RetentionPolicy::from_settings(&app.settings);
     let main_area = frame.area();
 
     if state.auto_backup_enabled {
@@ -104,8 +121,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             None => true,
         };
         if should_backup {
-            let fname = format!("backup-auto-{}.json", now.format("%Y%m%d-%H%M%S"));
-            let path = std::path::Path::new(BACKUP_DIR).join(fname);
+            let path = new_backup_file("backup-auto-");
             let options = ExportOptions::default();
             let tag_manager_clone = app.tag_manager.clone();
             match export_database_with_tags(
@@ -119,6 +135,8 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                         Some(format!("Automatic backup created: {}", path.display()));
                     state.is_error = false;
                     state.last_auto_backup = Some(now);
+                    // Interval backups would pile up quickly, so prune right away
+                    let _ = apply_retention(&state.retention);
                     state.backups = list_backups();
                 }
                 Err(e) => {
@@ -146,7 +164,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
@@ -180,8 +198,11 @@ pub fn render(frame: &mut Frame, app: &mut App) {
 
 fn list_backups() -> Vec<BackupInfo> {
     let mut backups = Vec::new();
-    let dir = PathBuf::from(BACKUP_DIR);
-    if let Ok(entries) = fs::read_dir(&dir) {
+    // Backups written on exit live in ~/.snix/backups, next to the ones made here
+    for dir in std::iter::once(PathBuf::from(LEGACY_BACKUP_DIR)).chain(backups_dir()) {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
         for entry in entries.flatten() {
             let path = entry.path();
             if path.extension().map(|e| e == "json").unwrap_or(false) {
@@ -335,7 +356,7 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &mut BackupRestoreStat
         .title("  Actions & Backups ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .style(Style::default().fg(RosePine::IRIS));
+        .style(Style::default().fg(palette().iris));
 
     let inner_area = menu_block.inner(area);
     menu_block.render(area, frame.buffer_mut());
@@ -395,15 +416,15 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &mut BackupRestoreStat
         .iter()
         .map(|(title, desc)| {
             ListItem::new(format!("{}\n   {}", title, desc))
-                .style(Style::default().fg(RosePine::TEXT))
+                .style(Style::default().fg(palette().text))
         })
         .chain(std::iter::once(
             ListItem::new("────────────────────────────")
-                .style(Style::default().fg(RosePine::HIGHLIGHT_LOW)),
+                .style(Style::default().fg(palette().highlight_low)),
         ))
         .chain(backup_items.iter().map(|(title, desc)| {
             ListItem::new(format!("{}\n   {}", title, desc))
-                .style(Style::default().fg(RosePine::TEXT))
+                .style(Style::default().fg(palette().text))
         }))
         .collect();
 
@@ -413,8 +434,8 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &mut BackupRestoreStat
     let list = List::new(all_items)
         .highlight_style(
             Style::default()
-                .fg(RosePine::LOVE)
-                .bg(RosePine::HIGHLIGHT_LOW)
+                .fg(palette().love)
+                .bg(palette().highlight_low)
                 .add_modifier(ratatui::style::Modifier::BOLD),
         )
         .highlight_symbol("▶ ");
@@ -441,17 +462,20 @@ fn render_backup_preview(frame: &mut Frame, area: Rect, state: &mut BackupRestor
             .title(" 󱏒 Preview ")
             .title_alignment(Alignment::Center)
             .borders(Borders::ALL)
-            .style(Style::default().fg(RosePine::FOAM));
+            .style(Style::default().fg(palette().foam));
 
         let inner = block.inner(area);
         block.render(area, frame.buffer_mut());
 
-        let help_text = vec![
+        let retention = format!("  {}", state.retention.describe());
+        let help_text = [
             "Select a backup to view its contents.",
             "",
             "Use the up/down keys to navigate.",
             "Press Enter on a backup to restore it.",
             "Press 'd' on a backup to delete it.",
+            "Press 'p' to prune old backups, keeping:",
+            &retention,
             "",
             "You can create a new backup with the first option.",
             "Toggle auto-backup with the third option.",
@@ -460,7 +484,7 @@ fn render_backup_preview(frame: &mut Frame, area: Rect, state: &mut BackupRestor
 
         let para = Paragraph::new(help_text)
             .alignment(Alignment::Left)
-            .style(Style::default().fg(RosePine::TEXT))
+            .style(Style::default().fg(palette().text))
             .wrap(Wrap { trim: false });
 
         para.render(inner, frame.buffer_mut());
@@ -492,7 +516,7 @@ fn render_backup_preview(frame: &mut Frame, area: Rect, state: &mut BackupRestor
         .title(title)
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .style(Style::default().fg(RosePine::FOAM));
+        .style(Style::default().fg(palette().foam));
 
     let inner = block.inner(area);
     block.render(area, frame.buffer_mut());
@@ -530,7 +554,7 @@ fn render_backup_preview(frame: &mut Frame, area: Rect, state: &mut BackupRestor
 
     let para = Paragraph::new(visible_lines)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::TEXT))
+        .style(Style::default().fg(palette().text))
         .wrap(Wrap { trim: false });
 
     para.render(inner, frame.buffer_mut());
@@ -540,16 +564,16 @@ fn render_confirm_delete(frame: &mut Frame, area: Rect, _state: &mut BackupResto
     let msg = "Are you sure you want to delete this backup   ? [y/n]";
     let p = Paragraph::new(msg)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::ROSE));
+        .style(Style::default().fg(palette().rose));
     p.render(area, frame.buffer_mut());
 }
 
 fn render_status_message(frame: &mut Frame, area: Rect, state: &BackupRestoreState) {
     let msg = state.status_message.as_deref().unwrap_or("");
     let color = if state.is_error {
-        RosePine::LOVE
+        palette().love
     } else {
-        RosePine::FOAM
+        palette().foam
     };
     let p = Paragraph::new(msg)
         .alignment(Alignment::Center)
@@ -562,7 +586,7 @@ fn render_restore_options(frame: &mut Frame, area: Rect, state: &mut BackupResto
         .title(" Restore Options ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .style(Style::default().fg(RosePine::LOVE));
+        .style(Style::default().fg(palette().love));
 
     let inner_area = block.inner(area);
     block.render(area, frame.buffer_mut());
@@ -607,7 +631,7 @@ fn render_restore_options(frame: &mut Frame, area: Rect, state: &mut BackupResto
 
     let header = format!("Choose how to restore backup: {}", backup_name);
     let header_para = Paragraph::new(header)
-        .style(Style::default().fg(RosePine::FOAM))
+        .style(Style::default().fg(palette().foam))
         .alignment(Alignment::Center);
 
     let layout = Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).split(inner_area);
@@ -620,10 +644,10 @@ fn render_restore_options(frame: &mut Frame, area: Rect, state: &mut BackupResto
         .map(|(i, (title, desc, _strategy))| {
             let style = if i == state.restore_option_selected {
                 Style::default()
-                    .fg(RosePine::LOVE)
-                    .bg(RosePine::HIGHLIGHT_LOW)
+                    .fg(palette().love)
+                    .bg(palette().highlight_low)
             } else {
-                Style::default().fg(RosePine::TEXT)
+                Style::default().fg(palette().text)
             };
 
             let prefix = if i == state.restore_option_selected {
@@ -646,7 +670,7 @@ fn render_restore_options(frame: &mut Frame, area: Rect, state: &mut BackupResto
 fn render_keybindings(frame: &mut Frame, area: Rect, state: &BackupRestoreState) {
     let keys = match state.mode {
         BackupRestoreMode::MainMenu => {
-            "↑/↓: Navigate   Enter: Select/Restore   d: Delete   t: Tree   q/Esc: Close   PageUp/Down: Scroll"
+            "↑/↓: Navigate   Enter: Select/Restore   d: Delete   t: Tree   p: Prune   q/Esc: Close   PageUp/Down: Scroll"
         }
         BackupRestoreMode::ConfirmDelete => "y: Confirm Delete   n/Esc: Cancel",
         BackupRestoreMode::RestoreOptions => "↑/↓: Navigate   Enter: Confirm Restore   Esc: Cancel",
@@ -655,7 +679,7 @@ fn render_keybindings(frame: &mut Frame, area: Rect, state: &BackupRestoreState)
 
     let p = Paragraph::new(keys)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
     p.render(area, frame.buffer_mut());
 }
 
@@ -703,9 +727,7 @@ pub fn handle_backup_restore_keys(key: KeyEvent, app: &mut App) -> bool {
             KeyCode::Enter => {
                 match state.selected_option {
                     0 => {
-                        let now = Utc::now();
-                        let fname = format!("backup-{}.json", now.format("%Y%m%d-%H%M%S"));
-                        let path = Path::new(BACKUP_DIR).join(fname);
+                        let path = new_backup_file("backup-");
                         let options = ExportOptions::default();
                         let tag_manager_clone = app.tag_manager.clone();
                         match export_database_with_tags(
@@ -728,9 +750,7 @@ pub fn handle_backup_restore_keys(key: KeyEvent, app: &mut App) -> bool {
                         state.mode = BackupRestoreMode::StatusMessage;
                     }
                     1 => {
-                        let now = Utc::now();
-                        let fname = format!("backup-{}.json", now.format("%Y%m%d-%H%M%S"));
-                        let path = Path::new(BACKUP_DIR).join(fname);
+                        let path = new_backup_file("backup-");
                         let options = ExportOptions::default();
                         let tag_manager_clone = app.tag_manager.clone();
                         match export_database_with_tags(
@@ -783,6 +803,27 @@ pub fn handle_backup_restore_keys(key: KeyEvent, app: &mut App) -> bool {
                 app.backup_restore_state = Some(state);
                 return false;
             }
+            KeyCode::Char('p') => {
+                match apply_retention(&state.retention) {
+                    Ok(removed) => {
+                        state.status_message = Some(format!(
+                            "Removed {} old backups (keeping {})",
+                            removed,
+                            state.retention.describe()
+                        ));
+                        state.is_error = false;
+                        state.backups = list_backups();
+                        state.selected_option = state.selected_option.min(state.backups.len() + 3);
+                    }
+                    Err(e) => {
+                        state.status_message = Some(format!("Prune failed: {}", e));
+                        state.is_error = true;
+                    }
+                }
+                state.mode = BackupRestoreMode::StatusMessage;
+                app.backup_restore_state = Some(state);
+                false
+            }
             KeyCode::Char('t') => {
                 if state.selected_option >= 4 && state.selected_option < menu_len {
                     let backup_idx = state.selected_option - 4;
@@ -856,6 +897,7 @@ pub fn handle_backup_restore_keys(key: KeyEvent, app: &mut App) -> bool {
                         match import_database(&backup.file_path) {
                             Ok(import_data) => {
                                 let mut tag_manager_clone = app.tag_manager.clone();
+                                let known = app.snippet_ids();
 
                                 let overwrite = match state.restore_strategy {
                                     RestoreStrategy::OverwriteAll => true,
@@ -872,7 +914,7 @@ pub fn handle_backup_restore_keys(key: KeyEvent, app: &mut App) -> bool {
                                 ) {
                                     Ok((notebooks, snippets)) => {
                                         app.tag_manager = tag_manager_clone;
-                                        let _ = app.save_database();
+                                        let _ = app.save_imported_database(&known);
                                         app.refresh_tree_items();
 
                                         let strategy_name = match state.restore_strategy {
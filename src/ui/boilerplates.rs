@@ -0,0 +1,149 @@
+use crate::app::App;
+use crate::models::boilerplate::boilerplates_dir;
+use crate::ui::colors::palette;
+use crate::ui::components::{render_bottom_bar, render_prompt_bar};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, List, ListItem, ListState, Paragraph, Widget, Wrap},
+};
+
+/// Render the boilerplate list with a preview of the selected template
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(format!(" Boilerplates ({}) ", app.boilerplates.len()))
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(inner_area);
+
+    if app.boilerplates.is_empty() {
+        let folder = boilerplates_dir()
+            .map(|dir| dir.display().to_string())
+            .unwrap_or_else(|| "~/.snix/boilerplates".to_string());
+        Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No boilerplates yet").fg(palette().subtle),
+            Line::from(format!("Add a .toml file to {} and press r", folder)).fg(palette().muted),
+        ])
+        .alignment(Alignment::Center)
+        .render(chunks[0], frame.buffer_mut());
+    } else {
+        let columns =
+            Layout::horizontal([Constraint::Percentage(35), Constraint::Fill(1)]).split(chunks[0]);
+        render_list(frame, columns[0], app);
+        render_preview(frame, columns[1], app);
+    }
+
+    render_bottom_bar(frame, chunks[1], app);
+
+    if app.boilerplate_prompt.is_some() {
+        render_prompt(frame, main_area, app);
+    }
+}
+
+fn render_list(frame: &mut Frame, area: Rect, app: &App) {
+    let items: Vec<ListItem> = app
+        .boilerplates
+        .iter()
+        .map(|boilerplate| {
+            ListItem::new(Line::from(vec![
+                Span::styled("󰘦 ", Style::default().fg(palette().iris)),
+                Span::styled(
+                    boilerplate.name.clone(),
+                    Style::default().fg(palette().text),
+                ),
+                Span::styled(
+                    format!(" ({} files)", boilerplate.files.len()),
+                    Style::default().fg(palette().muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().subtle)),
+        )
+        .highlight_style(Style::default().bg(palette().highlight_low).bold())
+        .highlight_symbol("▶ ");
+
+    let mut list_state = ListState::default().with_selected(Some(app.selected_boilerplate));
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn render_preview(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(boilerplate) = app.boilerplates.get(app.selected_boilerplate) else {
+        return;
+    };
+
+    let mut lines = Vec::new();
+    if !boilerplate.description.is_empty() {
+        lines.push(Line::from(Span::styled(
+            boilerplate.description.clone(),
+            Style::default().fg(palette().text),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        "Files",
+        Style::default().fg(palette().gold).bold(),
+    )));
+    for file in &boilerplate.files {
+        lines.push(Line::from(vec![
+            Span::styled("   ", Style::default().fg(palette().foam)),
+            Span::styled(file.path.clone(), Style::default().fg(palette().subtle)),
+        ]));
+    }
+
+    let variables = boilerplate.template_variables();
+    if !variables.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Placeholders",
+            Style::default().fg(palette().gold).bold(),
+        )));
+        lines.push(Line::from(Span::styled(
+            variables
+                .iter()
+                .map(|name| format!("{{{{{}}}}}", name))
+                .collect::<Vec<_>>()
+                .join("  "),
+            Style::default().fg(palette().rose),
+        )));
+    }
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::bordered()
+                .title(format!(" {} ", boilerplate.name))
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().subtle)),
+        )
+        .render(area, frame.buffer_mut());
+}
+
+fn render_prompt(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(prompt) = &app.boilerplate_prompt else {
+        return;
+    };
+
+    let title = match (&prompt.target, prompt.missing.first()) {
+        (Some(_), Some(name)) => format!("Value for {{{{{}}}}}", name),
+        _ => "Scaffold Into".to_string(),
+    };
+
+    render_prompt_bar(frame, area, &title, &app.input_buffer, app.input_cursor);
+}
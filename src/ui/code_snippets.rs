@@ -1,21 +1,29 @@
 use crate::app::{App, CodeSnippetsState, InputMode, TreeItem};
-use crate::ui::colors::RosePine;
-use crate::ui::components::render_bottom_bar;
+use crate::models::{SnippetLanguage, SortBy};
+use crate::ui::colors::{active_theme, language_color, palette, tag_color};
+use crate::ui::components::{render_bottom_bar, render_text_input, sanitize_for_display};
+use crate::ui::markdown::render_markdown;
 use crate::ui::search;
 use once_cell::sync::Lazy;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Style, Stylize},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{
         Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
         ScrollbarOrientation, ScrollbarState, Widget, Wrap,
     },
 };
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
 use syntect::{
     easy::HighlightLines, highlighting::ThemeSet, parsing::SyntaxSet, util::LinesWithEndings,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use uuid::Uuid;
 
 static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(|| SyntaxSet::load_defaults_newlines());
 static THEME_SET: Lazy<ThemeSet> = Lazy::new(|| ThemeSet::load_defaults());
@@ -41,6 +49,10 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             if app.show_favorites_popup {
                 crate::ui::favorites::render_floating_favorites(frame, app);
             }
+
+            if app.show_snippet_info {
+                render_snippet_info(frame, app);
+            }
         }
         CodeSnippetsState::NotebookView { notebook_id } => {
             render_notebook_view(frame, main_area, app, notebook_id);
@@ -85,7 +97,17 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             render_main_view(frame, main_area, app);
             search::render_floating_search(frame, app);
         }
-        CodeSnippetsState::Settings => render_settings_view(frame, main_area, app),
+        CodeSnippetsState::Settings => crate::ui::settings::render(frame, app),
+        CodeSnippetsState::VersionHistory { snippet_id } => {
+            crate::ui::version_history::render(frame, app, snippet_id);
+        }
+        CodeSnippetsState::CompareSnippets { left, right } => {
+            crate::ui::snippet_diff::render(frame, app, left, right);
+        }
+    }
+
+    if app.input_mode == InputMode::QuickOpen {
+        crate::ui::quick_open::render(frame, app);
     }
 }
 
@@ -94,7 +116,7 @@ fn render_welcome_screen(frame: &mut Frame, area: Rect, app: &mut App) {
         .title("  Code Snippets Manager ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(area);
     block.render(area, frame.buffer_mut());
@@ -111,7 +133,7 @@ fn render_welcome_screen(frame: &mut Frame, area: Rect, app: &mut App) {
         Line::from(""),
         Line::from(Span::styled(
             " Welcome to Code Snippets Manager!",
-            Style::default().fg(RosePine::LOVE).bold(),
+            Style::default().fg(palette().love).bold(),
         )),
         Line::from(""),
         Line::from("You haven't created any notebooks yet."),
@@ -126,7 +148,7 @@ fn render_welcome_screen(frame: &mut Frame, area: Rect, app: &mut App) {
         Line::from(""),
         Line::from(Span::styled(
             "󰀨 Tips:",
-            Style::default().fg(RosePine::GOLD).bold(),
+            Style::default().fg(palette().gold).bold(),
         )),
         Line::from("• Use descriptive names for your notebooks"),
         Line::from("• Organize by project, language, or functionality"),
@@ -136,37 +158,183 @@ fn render_welcome_screen(frame: &mut Frame, area: Rect, app: &mut App) {
     let welcome_paragraph = Paragraph::new(welcome_text)
         .alignment(Alignment::Center)
         .wrap(Wrap { trim: true })
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
 
     welcome_paragraph.render(chunks[1], frame.buffer_mut());
+    if app.input_mode == InputMode::Command {
+        let line_area = Rect {
+            y: chunks[2].bottom().saturating_sub(1),
+            height: 1,
+            ..chunks[2]
+        };
+        render_command_line(frame, line_area, app);
+    }
     render_bottom_bar(frame, chunks[3], app);
     render_overlays(frame, area, app);
 }
 
+/// Draws the `:` prompt in place of the status line
+fn render_command_line(frame: &mut Frame, area: Rect, app: &App) {
+    let [prompt_area, input_area] =
+        Layout::horizontal([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+
+    Paragraph::new(":")
+        .style(
+            Style::default()
+                .fg(palette().iris)
+                .bg(palette().surface)
+                .bold(),
+        )
+        .render(prompt_area, frame.buffer_mut());
+    render_text_input(
+        frame,
+        input_area,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text).bg(palette().surface),
+    );
+}
+
 fn render_main_view(frame: &mut Frame, area: Rect, app: &mut App) {
     let block = Block::bordered()
         .title(" 󱃖 Code Snippets Manager ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(area);
     block.render(area, frame.buffer_mut());
 
-    let main_chunks =
-        Layout::vertical([Constraint::Fill(1), Constraint::Length(3)]).split(inner_area);
+    let main_chunks = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Length(3),
+    ])
+    .split(inner_area);
 
     let content_chunks =
         Layout::horizontal([Constraint::Percentage(35), Constraint::Fill(1)]).split(main_chunks[0]);
 
     render_preview_panel(frame, content_chunks[1], app);
     render_tree_view_with_colors(frame, content_chunks[0], app);
-    render_bottom_bar(frame, main_chunks[1], app);
+    render_status_bar(frame, main_chunks[1], app);
+    render_bottom_bar(frame, main_chunks[2], app);
     render_overlays(frame, area, app);
 }
 
 /// Render all overlays (input dialogs, language selection, etc.)
 /// This function should ALWAYS be called last to ensure overlays appear on top
+/// Floating panel with the timestamps, size and flags of the selected snippet
+fn render_snippet_info(frame: &mut Frame, app: &App) {
+    let Some(TreeItem::Snippet(snippet_id, _)) = app.get_selected_item() else {
+        return;
+    };
+    let Some(snippet) = app.snippet_database.snippets.get(snippet_id) else {
+        return;
+    };
+
+    let area = frame.area();
+    let popup_area = Rect::new(
+        area.width.saturating_sub(60) / 2,
+        area.height.saturating_sub(16) / 2,
+        60.min(area.width),
+        16.min(area.height),
+    );
+    Clear.render(popup_area, frame.buffer_mut());
+
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(
+                format!(" {:<13}", label),
+                Style::default().fg(palette().gold),
+            ),
+            Span::styled(value, Style::default().fg(palette().text)),
+        ])
+    };
+    let timestamp = |time: &chrono::DateTime<chrono::Utc>| {
+        format!(
+            "{}  ({})",
+            time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"),
+            crate::ui::start_page::format_time_ago(time)
+        )
+    };
+
+    let tags = if snippet.tags.is_empty() {
+        "none".to_string()
+    } else {
+        snippet
+            .tags
+            .iter()
+            .map(|tag| format!("#{}", tag))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut flags = Vec::new();
+    if snippet.is_favorited() {
+        flags.push(" Favorite");
+    }
+    if snippet.is_pinned {
+        flags.push("󰐃 Pinned");
+    }
+    let size = app
+        .snippet_file_size(snippet)
+        .map(crate::models::stats::format_size)
+        .unwrap_or_else(|| "not saved".to_string());
+
+    let lines = vec![
+        Line::from(""),
+        row(
+            "Language",
+            format!(
+                "{} {}",
+                snippet.language.icon(),
+                snippet.language.display_name()
+            ),
+        ),
+        row("Notebook", app.notebook_location(snippet.notebook_id)),
+        row("Created", timestamp(&snippet.created_at)),
+        row("Updated", timestamp(&snippet.updated_at)),
+        row("Last opened", timestamp(&snippet.accessed_at)),
+        row(
+            "Size",
+            format!(
+                "{} lines, {} chars",
+                snippet.get_line_count(),
+                snippet.content.chars().count()
+            ),
+        ),
+        row("On disk", size),
+        row("Tags", tags),
+        row(
+            "Status",
+            if flags.is_empty() {
+                "—".to_string()
+            } else {
+                flags.join("  ")
+            },
+        ),
+        row(
+            "Opened",
+            format!("{} times, version {}", snippet.use_count, snippet.version),
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            " [i/Esc] Close",
+            Style::default().fg(palette().muted),
+        )),
+    ];
+
+    Paragraph::new(lines)
+        .block(
+            Block::bordered()
+                .title(format!(" 󰋼 {} ", snippet.title))
+                .title_alignment(Alignment::Center)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().iris)),
+        )
+        .render(popup_area, frame.buffer_mut());
+}
+
 fn render_overlays(frame: &mut Frame, area: Rect, app: &mut App) {
     match app.input_mode {
         InputMode::CreateNotebook
@@ -174,10 +342,15 @@ fn render_overlays(frame: &mut Frame, area: Rect, app: &mut App) {
         | InputMode::CreateSnippet
         | InputMode::Search
         | InputMode::_RenameNotebook
-        | InputMode::_RenameSnippet
+        | InputMode::RenameSnippet
+        | InputMode::ExportSelection
+        | InputMode::ExportNotebookMarkdown
         | InputMode::EditSnippetDescription
+        | InputMode::EditSnippetEditor
         | InputMode::EditNotebookDescription
-        | InputMode::EditNotebookName => {
+        | InputMode::EditNotebookName
+        | InputMode::BulkTagNotebook
+        | InputMode::FillTemplate => {
             render_input_overlay(frame, area, app);
         }
         InputMode::SelectLanguage => {
@@ -186,9 +359,8 @@ fn render_overlays(frame: &mut Frame, area: Rect, app: &mut App) {
         InputMode::SelectNotebookColor => {
             render_color_selection_overlay(frame, area, app);
         }
-        InputMode::HelpMenu => {
-            render_help_menu_overlay(frame, area, app);
-        }
+        // Drawn over every page by `App::render`
+        InputMode::HelpMenu => {}
         InputMode::Normal => {
             if let Some(ref message) = app.error_message {
                 render_message_overlay(frame, area, message, true);
@@ -199,265 +371,27 @@ fn render_overlays(frame: &mut Frame, area: Rect, app: &mut App) {
         InputMode::EditTags => {
             render_tags_editing(frame, app);
         }
+        // Drawn in place of the status line
+        InputMode::Command => {}
+        // Only opened from the notebook details page, which draws it
+        InputMode::CloneNotebook => {}
+        // Drawn over every snippets screen by `render`
+        InputMode::QuickOpen => {}
     }
 }
 
 /// Render language selection overlay
-/// Renders a help menu overlay showing all available keyboard shortcuts
-fn render_help_menu_overlay(frame: &mut Frame, area: Rect, _app: &mut App) {
-    let width = 70;
-    let height = 36;
-    let popup_area = Rect::new(
-        area.width.saturating_sub(width + 2),
-        area.height.saturating_sub(height + 2),
-        width.min(area.width),
-        height.min(area.height),
-    );
-
-    Clear.render(popup_area, frame.buffer_mut());
-
-    let block = Block::bordered()
-        .title(" 󰘳 Keyboard Shortcuts ")
-        .title_alignment(Alignment::Center)
-        .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::IRIS));
-
-    let inner_area = block.inner(popup_area);
-    block.render(popup_area, frame.buffer_mut());
-
-    // Split the shortcuts into a two-column layout
-    let columns = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(inner_area);
-
-    let left_column = vec![
-        Line::from(Span::styled(
-            "Navigation",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ↑/k ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Move up"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ↓/j ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Move down"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ⏎   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Select/Edit"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ←/h ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Go back"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Notebooks",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  n   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Create root notebook"),
-        ]),
-        Line::from(vec![
-            Span::styled("  b   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Create nested notebook"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Space", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Collapse/expand notebook"),
-        ]),
-        Line::from(vec![
-            Span::styled("  v   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("View notebook details"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+⏎ ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Open classic notebook view"),
-        ]),
-        Line::from(vec![
-            Span::styled("  x   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Delete notebook/snippet"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Movement",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  Shift+↑", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Move up one level (to parent)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+↓", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Move down one level (to child)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+→", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Move to next sibling notebook"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+←", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Move to previous sibling notebook"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Content Navigation",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  PgUp ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Scroll content up (5 lines)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  PgDn ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Scroll content down (5 lines)"),
-        ]),
-    ];
-
-    let right_column = vec![
-        Line::from(Span::styled(
-            "Snippets",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  s   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Create snippet in current notebook"),
-        ]),
-        Line::from(vec![
-            Span::styled("  d   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Edit snippet description"),
-        ]),
-        Line::from(vec![
-            Span::styled("  y   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Copy snippet content to clipboard"),
-        ]),
-        Line::from(vec![
-            Span::styled("  l   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Open Ollama chat for selected snippet"),
-        ]),
-        Line::from(vec![
-            Span::styled("  /   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Search snippets"),
-        ]),
-        Line::from(vec![
-            Span::styled("  f   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Toggle favorite status"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+F", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Show favorites popup"),
-        ]),
-        Line::from(vec![
-            Span::styled("  r   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Refresh tree view"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Notebook Details",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  Tab  ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Next tab"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+Tab ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Previous tab"),
-        ]),
-        Line::from(vec![
-            Span::styled("  1-4  ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Switch to tab 1-4"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Notebook Details Actions",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  s    ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Create snippet in current notebook"),
-        ]),
-        Line::from(vec![
-            Span::styled("  e    ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Edit notebook description"),
-        ]),
-        Line::from(vec![
-            Span::styled("  c    ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Change notebook color"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Esc  ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Return to notebook list"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Features",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from("• Full syntax highlighting for 20+ languages"),
-        Line::from("• Copy to clipboard functionality"),
-        Line::from("• Content scrolling with scrollbar"),
-        Line::from("• Detailed notebook statistics and graphs"),
-        Line::from("• Ollama integration for AI-assisted code understanding"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "General",
-            Style::default().fg(RosePine::LOVE).bold(),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ?   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Toggle this help menu"),
-        ]),
-        Line::from(vec![
-            Span::styled("  h   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Go to home page"),
-        ]),
-        Line::from(vec![
-            Span::styled("  q   ", Style::default().fg(RosePine::GOLD)),
-            Span::raw("Quit application"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Press Esc or ? to close this menu",
-            Style::default().fg(RosePine::SUBTLE).italic(),
-        )),
-    ];
-
-    let left_para = Paragraph::new(left_column)
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: false })
-        .style(Style::default().fg(RosePine::TEXT));
-
-    let right_para = Paragraph::new(right_column)
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: false })
-        .style(Style::default().fg(RosePine::TEXT));
-
-    left_para.render(columns[0], frame.buffer_mut());
-    right_para.render(columns[1], frame.buffer_mut());
-}
-
 fn render_language_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
     let popup_area = spotlight_bar(70, area);
     Clear.render(popup_area, frame.buffer_mut());
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
 
-    let title = "Select Programming Language";
+    let title = "Filter by Language";
     let chunks = Layout::horizontal([
         Constraint::Length(title.len() as u16 + 4),
         Constraint::Min(10),
@@ -467,22 +401,24 @@ fn render_language_selection_overlay(frame: &mut Frame, area: Rect, app: &mut Ap
 
     let title_paragraph = Paragraph::new(title)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::IRIS).bold());
+        .style(Style::default().fg(palette().iris).bold());
     title_paragraph.render(chunks[0], frame.buffer_mut());
 
-    let languages = get_available_languages();
-    let selected_lang = &languages[app.selected_language];
-    let selected_text = format!("{} {}", selected_lang.icon(), selected_lang.display_name());
+    let languages = app.filter_languages();
+    let selected_text = languages
+        .get(app.selected_language)
+        .map(|lang| format!("{} {}", lang.icon(), lang.display_name()))
+        .unwrap_or_default();
 
     let dropdown_paragraph = Paragraph::new(selected_text)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
     dropdown_paragraph.render(chunks[1], frame.buffer_mut());
 
     let help_text = "↑↓ Navigate • ⏎ Select";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 
     let list_area = Rect::new(
@@ -496,7 +432,7 @@ fn render_language_selection_overlay(frame: &mut Frame, area: Rect, app: &mut Ap
 
     let list_block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_list_area = list_block.inner(list_area);
     list_block.render(list_area, frame.buffer_mut());
@@ -510,9 +446,9 @@ fn render_language_selection_overlay(frame: &mut Frame, area: Rect, app: &mut Ap
             let content = format!("{} {}", icon, name);
 
             let style = if i == app.selected_language {
-                Style::default().fg(RosePine::LOVE).bold()
+                Style::default().fg(palette().love).bold()
             } else {
-                Style::default().fg(RosePine::TEXT)
+                Style::default().fg(palette().text)
             };
 
             ListItem::new(content).style(style)
@@ -522,8 +458,8 @@ fn render_language_selection_overlay(frame: &mut Frame, area: Rect, app: &mut Ap
     let language_list = List::new(language_items)
         .highlight_style(
             Style::default()
-                .fg(RosePine::BASE)
-                .bg(RosePine::LOVE)
+                .fg(palette().base)
+                .bg(palette().love)
                 .bold(),
         )
         .highlight_symbol("▶ ");
@@ -539,7 +475,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
     Clear.render(popup_area, frame.buffer_mut());
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
@@ -554,7 +490,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let title_paragraph = Paragraph::new(title)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::IRIS).bold());
+        .style(Style::default().fg(palette().iris).bold());
     title_paragraph.render(chunks[0], frame.buffer_mut());
 
     let colors = get_available_colors();
@@ -569,7 +505,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
     let help_text = "↑↓ Navigate • ⏎ Select";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 
     let list_area = Rect::new(
@@ -583,7 +519,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let list_block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_list_area = list_block.inner(list_area);
     list_block.render(list_area, frame.buffer_mut());
@@ -607,8 +543,8 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
     let color_list = List::new(color_items)
         .highlight_style(
             Style::default()
-                .fg(RosePine::BASE)
-                .bg(RosePine::LOVE)
+                .fg(palette().base)
+                .bg(palette().love)
                 .bold(),
         )
         .highlight_symbol("▶ ");
@@ -621,13 +557,13 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
 pub fn get_available_colors() -> Vec<(&'static str, ratatui::style::Color)> {
     vec![
-        ("Default", RosePine::TEXT),
-        ("Red", RosePine::LOVE),
-        ("Orange", RosePine::GOLD),
-        ("Green", RosePine::FOAM),
-        ("Blue", RosePine::IRIS),
-        ("Purple", RosePine::IRIS),
-        ("Pink", RosePine::ROSE),
+        ("Default", palette().text),
+        ("Red", palette().love),
+        ("Orange", palette().gold),
+        ("Green", palette().foam),
+        ("Blue", palette().iris),
+        ("Purple", palette().iris),
+        ("Pink", palette().rose),
         ("White", ratatui::style::Color::White),
     ]
 }
@@ -638,14 +574,14 @@ pub(crate) fn render_message_overlay(frame: &mut Frame, area: Rect, message: &st
     Clear.render(popup_area, frame.buffer_mut());
 
     let (icon, color) = if is_error {
-        ("✗", RosePine::LOVE)
+        ("✗", palette().love)
     } else {
-        ("✓", RosePine::FOAM)
+        ("✓", palette().foam)
     };
 
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
 
@@ -663,13 +599,13 @@ pub(crate) fn render_message_overlay(frame: &mut Frame, area: Rect, message: &st
 
     let message_paragraph = Paragraph::new(message)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
     message_paragraph.render(chunks[1], frame.buffer_mut());
 
     let help_text = "Press any key to dismiss";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 }
 
@@ -703,6 +639,17 @@ fn get_available_languages() -> Vec<crate::models::SnippetLanguage> {
         crate::models::SnippetLanguage::Toml,
         crate::models::SnippetLanguage::Ini,
         crate::models::SnippetLanguage::Config,
+        crate::models::SnippetLanguage::Lua,
+        crate::models::SnippetLanguage::Zig,
+        crate::models::SnippetLanguage::Elixir,
+        crate::models::SnippetLanguage::Haskell,
+        crate::models::SnippetLanguage::Scala,
+        crate::models::SnippetLanguage::R,
+        crate::models::SnippetLanguage::Perl,
+        crate::models::SnippetLanguage::Nix,
+        crate::models::SnippetLanguage::GraphQL,
+        crate::models::SnippetLanguage::Vue,
+        crate::models::SnippetLanguage::Svelte,
         crate::models::SnippetLanguage::Text,
     ]
 }
@@ -728,10 +675,24 @@ fn create_tree_indent(depth: usize, is_last_item: bool) -> String {
 }
 
 fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
+    let title = if app.multi_select_mode {
+        format!(
+            "  Notebooks & Snippets [SELECT: {} marked] ",
+            app.marked_snippets.len()
+        )
+    } else if app.settings.tree_sort != SortBy::Manual {
+        format!(
+            "  Notebooks & Snippets [{}] ",
+            app.settings.tree_sort.label()
+        )
+    } else {
+        "  Notebooks & Snippets ".to_string()
+    };
+
     let block = Block::bordered()
-        .title("  Notebooks & Snippets ")
+        .title(title)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_area = block.inner(area);
     block.render(area, frame.buffer_mut());
@@ -739,7 +700,7 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
     if app.tree_items.is_empty() {
         let empty_text = Paragraph::new("No notebooks found.\nPress 'n' to create one.")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::MUTED));
+            .style(Style::default().fg(palette().muted));
         empty_text.render(inner_area, frame.buffer_mut());
         return;
     }
@@ -764,6 +725,16 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
         }
     }
 
+    // Jump labels use the rows visible in the previous frame, which stay put while they are shown
+    let jump_labels: std::collections::HashMap<usize, String> = match &app.jump_input {
+        Some(_) => app
+            .jump_targets()
+            .into_iter()
+            .map(|(label, index)| (index, label))
+            .collect(),
+        None => std::collections::HashMap::new(),
+    };
+
     let items: Vec<ListItem> = app
         .tree_items
         .iter()
@@ -823,29 +794,19 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
                     } else {
                         // When expanded, show the notebook's description if available
                         let mut display = format!("{} ({})", notebook.name, notebook.snippet_count);
-                        if let Some(desc) = &notebook.description {
-                            let desc_without_color = if desc.starts_with("[COLOR:") {
-                                if let Some(end_idx) = desc.find(']') {
-                                    let clean_desc = desc[end_idx + 1..].trim();
-                                    if !clean_desc.is_empty() {
-                                        format!(" - {}", clean_desc)
-                                    } else {
-                                        String::new()
-                                    }
-                                } else {
-                                    format!(" - {}", desc)
-                                }
-                            } else if !desc.is_empty() {
-                                format!(" - {}", desc)
-                            } else {
-                                String::new()
-                            };
-
-                            display.push_str(&desc_without_color);
+                        if let Some(desc) =
+                            notebook.description.as_deref().filter(|d| !d.is_empty())
+                        {
+                            display.push_str(&format!(" - {}", desc));
                         }
 
                         display
                     };
+                    let display_name = if notebook.is_favorite {
+                        format!("{} ", display_name)
+                    } else {
+                        display_name
+                    };
 
                     let spans = vec![
                         Span::styled(indent_str, Style::default().fg(notebook_color)),
@@ -853,7 +814,7 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
                         Span::styled(
                             display_name,
                             if i == app.selected_tree_item {
-                                Style::default().fg(RosePine::LOVE).bold()
+                                Style::default().fg(palette().love).bold()
                             } else if Some(i) == app.hovered_tree_item {
                                 Style::default().fg(notebook_color).bold().underlined()
                             } else {
@@ -862,12 +823,12 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
                         ),
                     ];
 
-                    ListItem::new(Line::from(spans))
+                    ListItem::new(with_jump_label(spans, jump_labels.get(&i), app))
                 } else {
                     let indent_str = create_tree_indent(*depth, false);
                     let icon = "✗";
                     ListItem::new(format!("{}{} Unknown Notebook", indent_str, icon))
-                        .style(Style::default().fg(RosePine::LOVE))
+                        .style(Style::default().fg(palette().love))
                 }
             }
             TreeItem::Snippet(id, depth) => {
@@ -875,9 +836,14 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
                     let parent_color = notebook_colors
                         .get(&snippet.notebook_id)
                         .copied()
-                        .unwrap_or(RosePine::TEXT);
+                        .unwrap_or(palette().text);
 
-                    let indent_str = create_tree_indent(*depth, true);
+                    // Depth 0 only happens in the pinned section above the notebooks
+                    let indent_str = if *depth == 0 {
+                        "󰐃 ".to_string()
+                    } else {
+                        create_tree_indent(*depth, true)
+                    };
                     let icon = snippet.language.icon();
 
                     let mut title_text = snippet.title.clone();
@@ -886,6 +852,10 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
                         title_text = format!("{} ", title_text);
                     }
 
+                    if snippet.is_pinned && *depth > 0 {
+                        title_text = format!("{} 󰐃", title_text);
+                    }
+
                     if let Some(desc) = &snippet.description {
                         if !desc.is_empty() {
                             let short_desc = if desc.len() > 30 {
@@ -897,27 +867,52 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
                         }
                     }
 
+                    let is_marked = app.marked_snippets.contains(id);
+                    let mark = if is_marked {
+                        "✓ "
+                    } else if app.multi_select_mode {
+                        "· "
+                    } else {
+                        ""
+                    };
+
+                    let indent_color = if *depth == 0 {
+                        palette().rose
+                    } else {
+                        parent_color
+                    };
+
                     let spans = vec![
-                        Span::styled(indent_str, Style::default().fg(parent_color)),
-                        Span::styled(format!("{} ", icon), Style::default().fg(RosePine::GOLD)),
+                        Span::styled(indent_str, Style::default().fg(indent_color)),
+                        Span::styled(mark, Style::default().fg(palette().pine).bold()),
+                        Span::styled(
+                            format!("{} ", icon),
+                            Style::default().fg(language_color(&snippet.language)),
+                        ),
                         Span::styled(
                             title_text,
-                            if i == app.selected_tree_item {
-                                Style::default().fg(RosePine::GOLD).bold()
+                            if is_marked {
+                                Style::default().fg(palette().pine).bold()
+                            } else if i == app.selected_tree_item {
+                                Style::default().fg(palette().gold).bold()
                             } else if Some(i) == app.hovered_tree_item {
-                                Style::default().fg(RosePine::GOLD).underlined()
+                                Style::default().fg(palette().gold).underlined()
                             } else {
-                                Style::default().fg(RosePine::SUBTLE)
+                                Style::default().fg(tag_color(
+                                    &app.settings.tag_colors,
+                                    &snippet.tags,
+                                )
+                                .unwrap_or(palette().subtle))
                             },
                         ),
                     ];
 
-                    ListItem::new(Line::from(spans))
+                    ListItem::new(with_jump_label(spans, jump_labels.get(&i), app))
                 } else {
                     let indent_str = create_tree_indent(*depth, true);
                     let icon = "✗";
                     ListItem::new(format!("{}{} Unknown Snippet", indent_str, icon))
-                        .style(Style::default().fg(RosePine::LOVE))
+                        .style(Style::default().fg(palette().love))
                 }
             }
         })
@@ -926,8 +921,8 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
     let list = List::new(items)
         .highlight_style(
             Style::default()
-                .fg(RosePine::BASE)
-                .bg(RosePine::LOVE)
+                .fg(palette().base)
+                .bg(palette().love)
                 .bold(),
         )
         .highlight_symbol("▶ ");
@@ -936,6 +931,126 @@ fn render_tree_view_with_colors(frame: &mut Frame, area: Rect, app: &mut App) {
     list_state.select(Some(app.selected_tree_item));
 
     frame.render_stateful_widget(list, inner_area, &mut list_state);
+
+    let first_row = list_state.offset();
+    app.tree_viewport = first_row..first_row + inner_area.height as usize;
+}
+
+/// Prefixes a tree row with its jump label; labels that no longer match the typed keys are dimmed
+fn with_jump_label<'a>(mut spans: Vec<Span<'a>>, label: Option<&String>, app: &App) -> Line<'a> {
+    if let (Some(label), Some(typed)) = (label, &app.jump_input) {
+        let style = if label.starts_with(typed.as_str()) {
+            Style::default()
+                .fg(palette().base)
+                .bg(palette().gold)
+                .bold()
+        } else {
+            Style::default().fg(palette().muted)
+        };
+        spans.insert(0, Span::raw(" "));
+        spans.insert(0, Span::styled(label.clone(), style));
+    }
+    Line::from(spans)
+}
+
+/// One-line footer with totals, the current notebook path and active filters
+fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
+    if app.input_mode == InputMode::Command {
+        render_command_line(frame, area, app);
+        return;
+    }
+
+    let counts = format!(
+        " 󱙾 {} notebooks  󰅩 {} snippets ",
+        app.snippet_database.notebooks.len(),
+        app.snippet_database.snippets.len()
+    );
+
+    let notebook_id = match app.get_selected_item() {
+        Some(TreeItem::Notebook(id, _)) => Some(*id),
+        Some(TreeItem::Snippet(id, _)) => app
+            .snippet_database
+            .snippets
+            .get(id)
+            .map(|snippet| snippet.notebook_id),
+        None => None,
+    };
+    let location = notebook_id
+        .map(|id| app.notebook_location(id))
+        .unwrap_or_default();
+
+    let mut filters = Vec::new();
+    if app.show_favorites_only {
+        filters.push("favorites only".to_string());
+    }
+    if let Some(language) = &app.language_filter {
+        filters.push(format!("language: {}", language.display_name()));
+    }
+    if app.input_mode == InputMode::Search
+        || app.code_snippets_state == CodeSnippetsState::SearchSnippets
+    {
+        filters.push(format!("search: {}", app.search_query));
+    }
+    if app.settings.tree_sort != SortBy::Manual {
+        filters.push(format!("sort: {}", app.settings.tree_sort.label()));
+    }
+    if app.multi_select_mode {
+        filters.push(format!("{} marked", app.marked_snippets.len()));
+    }
+    let mut filters = if filters.is_empty() {
+        String::new()
+    } else {
+        format!(" {} ", filters.join(" · "))
+    };
+
+    // Filters give way before the counts, and the path shrinks from the left
+    let width = area.width as usize;
+    if counts.width() + filters.width() > width {
+        filters.clear();
+    }
+    let room = width.saturating_sub(counts.width() + filters.width() + 3);
+    let location = truncate_start(&location, room);
+
+    let mut spans = vec![Span::styled(counts, Style::default().fg(palette().subtle))];
+    if !location.is_empty() {
+        spans.push(Span::styled(
+            "│ ",
+            Style::default().fg(palette().highlight_high),
+        ));
+        spans.push(Span::styled(location, Style::default().fg(palette().iris)));
+    }
+    let left_width: usize = spans.iter().map(|span| span.content.width()).sum();
+
+    if !filters.is_empty() {
+        let gap = width.saturating_sub(left_width + filters.width());
+        spans.push(Span::raw(" ".repeat(gap)));
+        spans.push(Span::styled(filters, Style::default().fg(palette().gold)));
+    }
+
+    Paragraph::new(Line::from(spans)).render(area, frame.buffer_mut());
+}
+
+/// Keeps the end of `text`, prefixing "…" when it does not fit in `max_width` columns
+fn truncate_start(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let mut kept = Vec::new();
+    let mut used = 1;
+    for c in text.chars().rev() {
+        let w = c.width().unwrap_or(0);
+        if used + w > max_width {
+            break;
+        }
+        used += w;
+        kept.push(c);
+    }
+
+    std::iter::once('…').chain(kept.into_iter().rev()).collect()
 }
 
 fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
@@ -944,7 +1059,7 @@ fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
     let block = Block::bordered()
         .title("  Preview ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::BASE));
+        .style(Style::default().fg(palette().subtle).bg(palette().base));
 
     let inner_area = block.inner(area);
     block.render(area, frame.buffer_mut());
@@ -964,14 +1079,14 @@ fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
         }
     } else {
         let lang_block = Block::default()
-            .style(Style::default().bg(RosePine::BASE))
+            .style(Style::default().bg(palette().base))
             .borders(ratatui::widgets::Borders::NONE);
         lang_block.render(inner_area, frame.buffer_mut());
 
         let languages = get_available_languages();
 
         let title_block = Block::default()
-            .style(Style::default().bg(RosePine::SURFACE))
+            .style(Style::default().bg(palette().surface))
             .borders(ratatui::widgets::Borders::NONE);
 
         let title_area = Rect::new(inner_area.x, inner_area.y + 2, inner_area.width, 3);
@@ -980,7 +1095,7 @@ fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
 
         let title = Paragraph::new("Supported Languages")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::IRIS).bold());
+            .style(Style::default().fg(palette().iris).bold());
 
         title.render(title_area, frame.buffer_mut());
 
@@ -998,9 +1113,9 @@ fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
 
             let item_block = Block::default()
                 .style(Style::default().bg(if i % 2 == 0 {
-                    RosePine::HIGHLIGHT_LOW
+                    palette().highlight_low
                 } else {
-                    RosePine::BASE
+                    palette().base
                 }))
                 .borders(ratatui::widgets::Borders::NONE);
             item_block.render(item_area, frame.buffer_mut());
@@ -1013,13 +1128,13 @@ fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
 
             let lang_text = Paragraph::new(format!("  {} {}", icon, name))
                 .alignment(Alignment::Left)
-                .style(Style::default().fg(RosePine::TEXT));
+                .style(Style::default().fg(palette().text));
 
             lang_text.render(text_area, frame.buffer_mut());
         }
 
         let help_block = Block::default()
-            .style(Style::default().bg(RosePine::SURFACE))
+            .style(Style::default().bg(palette().surface))
             .borders(ratatui::widgets::Borders::NONE);
 
         let help_area = Rect::new(
@@ -1035,7 +1150,7 @@ fn render_preview_panel(frame: &mut Frame, area: Rect, app: &mut App) {
             "Press 'n' to create a new notebook or select a notebook to add snippets",
         )
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
 
         let help_text_area = Rect::new(help_area.x, help_area.y + 1, help_area.width, 1);
 
@@ -1050,7 +1165,7 @@ fn render_notebook_preview(
     app: &App,
 ) {
     let bg_block = Block::default()
-        .style(Style::default().bg(RosePine::SURFACE))
+        .style(Style::default().bg(palette().surface))
         .borders(ratatui::widgets::Borders::NONE);
     bg_block.render(area, frame.buffer_mut());
 
@@ -1067,50 +1182,47 @@ fn render_notebook_preview(
         .unwrap_or(&colors[0])
         .1;
 
+    // Ancestors lead the name so nested notebooks show where they live
+    let ancestors = notebook
+        .parent_id
+        .map(|parent_id| format!("{} › ", app.notebook_path(parent_id)))
+        .unwrap_or_default();
+
     let info_lines = vec![
         Line::from(vec![
-            Span::styled("󰠮 ", Style::default().fg(RosePine::GOLD)),
+            Span::styled("󰠮 ", Style::default().fg(palette().gold)),
+            Span::styled(ancestors, Style::default().fg(palette().muted)),
             Span::styled(&notebook.name, Style::default().fg(notebook_color).bold()),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Created: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Created: ", Style::default().fg(palette().muted)),
             Span::styled(
                 notebook.created_at.format("%Y-%m-%d %H:%M").to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Updated: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Updated: ", Style::default().fg(palette().muted)),
             Span::styled(
                 notebook.updated_at.format("%Y-%m-%d %H:%M").to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Snippets: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Snippets: ", Style::default().fg(palette().muted)),
             Span::styled(
                 notebook.snippet_count.to_string(),
-                Style::default().fg(RosePine::LOVE),
+                Style::default().fg(palette().love),
             ),
         ]),
         Line::from(""),
     ];
 
-    // Get and display description without color prefix
     let desc = notebook.description.clone().unwrap_or_default();
-    let desc_without_color = if desc.starts_with("[COLOR:") {
-        if let Some(end_idx) = desc.find(']') {
-            desc[end_idx + 1..].trim().to_string()
-        } else {
-            desc
-        }
-    } else {
-        desc
-    };
 
     let mut all_lines = info_lines;
-    all_lines.push(Line::from(desc_without_color).style(Style::default().fg(RosePine::SUBTLE)));
+    all_lines.push(Line::from(desc).style(Style::default().fg(palette().subtle)));
 
     // Get snippets for analytics
     let snippets: Vec<_> = app
@@ -1133,11 +1245,11 @@ fn render_notebook_preview(
     all_lines.push(Line::from(""));
     all_lines.push(Line::from(vec![Span::styled(
         "Analytics: ",
-        Style::default().fg(RosePine::LOVE).bold(),
+        Style::default().fg(palette().love).bold(),
     )]));
     all_lines.push(Line::from(vec![
-        Span::styled("Total Lines: ", Style::default().fg(RosePine::MUTED)),
-        Span::styled(total_lines.to_string(), Style::default().fg(RosePine::GOLD)),
+        Span::styled("Total Lines: ", Style::default().fg(palette().muted)),
+        Span::styled(total_lines.to_string(), Style::default().fg(palette().gold)),
     ]));
 
     // Show top languages if any & Sort languages by count
@@ -1146,7 +1258,7 @@ fn render_notebook_preview(
         lang_counts.sort_by(|a, b| b.1.cmp(&a.1));
 
         all_lines.push(Line::from(vec![
-            Span::styled("Top Languages: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Top Languages: ", Style::default().fg(palette().muted)),
             Span::styled(
                 lang_counts
                     .iter()
@@ -1154,7 +1266,7 @@ fn render_notebook_preview(
                     .map(|(lang, _)| lang.short_name())
                     .collect::<Vec<_>>()
                     .join(", "),
-                Style::default().fg(RosePine::FOAM),
+                Style::default().fg(palette().foam),
             ),
         ]));
     }
@@ -1175,7 +1287,7 @@ fn render_notebook_preview(
                     snippet.title,
                     snippet.language.display_name()
                 );
-                ListItem::new(name).style(Style::default().fg(RosePine::TEXT))
+                ListItem::new(name).style(Style::default().fg(palette().text))
             })
             .collect();
 
@@ -1184,9 +1296,9 @@ fn render_notebook_preview(
                 Block::bordered()
                     .title(" Snippets ")
                     .border_type(BorderType::Rounded)
-                    .style(Style::default().fg(RosePine::HIGHLIGHT_LOW)),
+                    .style(Style::default().fg(palette().highlight_low)),
             )
-            .style(Style::default().fg(RosePine::TEXT));
+            .style(Style::default().fg(palette().text));
 
         snippets_list.render(chunks[1], frame.buffer_mut());
     }
@@ -1199,7 +1311,7 @@ fn render_snippet_preview(
     app: &App,
 ) {
     let bg_block = Block::default()
-        .style(Style::default().bg(RosePine::SURFACE))
+        .style(Style::default().bg(palette().surface))
         .borders(ratatui::widgets::Borders::NONE);
     bg_block.render(area, frame.buffer_mut());
 
@@ -1220,49 +1332,58 @@ fn render_snippet_preview(
     .split(top_chunks[0]);
 
     // Basic metadata
-    let info_lines = vec![
+    let mut info_lines = vec![
         Line::from(vec![
-            Span::styled(snippet.language.icon(), Style::default().fg(RosePine::GOLD)),
+            Span::styled(
+                snippet.language.icon(),
+                Style::default().fg(language_color(&snippet.language)),
+            ),
             Span::raw(" "),
-            Span::styled(&snippet.title, Style::default().fg(RosePine::TEXT).bold()),
+            Span::styled(&snippet.title, Style::default().fg(palette().text).bold()),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Language: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Language: ", Style::default().fg(palette().muted)),
             Span::styled(
                 snippet.language.display_name(),
-                Style::default().fg(RosePine::FOAM),
+                Style::default().fg(palette().foam),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Created: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Created: ", Style::default().fg(palette().muted)),
             Span::styled(
                 snippet.created_at.format("%Y-%m-%d %H:%M").to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Updated: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Updated: ", Style::default().fg(palette().muted)),
             Span::styled(
                 snippet.updated_at.format("%Y-%m-%d %H:%M").to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Used: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Used: ", Style::default().fg(palette().muted)),
             Span::styled(
                 format!("{} times", snippet.use_count),
-                Style::default().fg(RosePine::GOLD),
+                Style::default().fg(palette().gold),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Lines: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Lines: ", Style::default().fg(palette().muted)),
             Span::styled(
                 snippet.get_line_count().to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
     ];
+    if let Some(ref editor) = snippet.editor_override {
+        info_lines.push(Line::from(vec![
+            Span::styled("Editor: ", Style::default().fg(palette().muted)),
+            Span::styled(editor.as_str(), Style::default().fg(palette().iris)),
+        ]));
+    }
 
     let info_paragraph = Paragraph::new(info_lines).wrap(Wrap { trim: true });
     info_paragraph.render(metadata_chunks[0], frame.buffer_mut());
@@ -1272,7 +1393,7 @@ fn render_snippet_preview(
         .title(" 󰭻 Associated Chats ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::IRIS).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().iris).bg(palette().surface));
 
     frame.render_widget(chats_block, metadata_chunks[1]);
 
@@ -1284,7 +1405,7 @@ fn render_snippet_preview(
     };
 
     let bg_block = Block::default()
-        .style(Style::default().bg(RosePine::HIGHLIGHT_LOW))
+        .style(Style::default().bg(palette().highlight_low))
         .borders(Borders::NONE);
     frame.render_widget(bg_block, chats_inner);
 
@@ -1364,7 +1485,7 @@ fn render_snippet_preview(
     };
 
     let chats_paragraph = Paragraph::new(chats_text)
-        .style(Style::default().fg(RosePine::TEXT))
+        .style(Style::default().fg(palette().text))
         .wrap(Wrap { trim: true });
 
     frame.render_widget(chats_paragraph, chats_inner);
@@ -1381,7 +1502,7 @@ fn render_snippet_preview(
         .title("  Description ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     frame.render_widget(desc_block, desc_chunks[0]);
 
@@ -1393,22 +1514,21 @@ fn render_snippet_preview(
     };
 
     let bg_block = Block::default()
-        .style(Style::default().bg(RosePine::SURFACE))
+        .style(Style::default().bg(palette().surface))
         .borders(Borders::NONE);
     frame.render_widget(bg_block, desc_inner);
 
-    let desc_text = if let Some(desc) = &snippet.description {
-        if desc.trim().is_empty() {
-            "No description. Press 'd' to add a  description.".to_string()
-        } else {
-            desc.clone()
-        }
-    } else {
-        "No description. Press 'd' to add a description.".to_string()
+    let desc_text = match snippet
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        Some(desc) => render_markdown(desc, desc_inner.width as usize),
+        None => Text::from("No description. Press 'd' to add a description."),
     };
 
     let desc_paragraph = Paragraph::new(desc_text)
-        .style(Style::default().fg(RosePine::SUBTLE))
+        .style(Style::default().fg(palette().subtle))
         .wrap(Wrap { trim: true });
 
     frame.render_widget(desc_paragraph, desc_inner);
@@ -1418,7 +1538,7 @@ fn render_snippet_preview(
         .title(" 󰓹 Tags ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     frame.render_widget(tags_block, desc_chunks[1]);
 
@@ -1430,7 +1550,7 @@ fn render_snippet_preview(
     };
 
     let bg_block = Block::default()
-        .style(Style::default().bg(RosePine::SURFACE))
+        .style(Style::default().bg(palette().surface))
         .borders(Borders::NONE);
     frame.render_widget(bg_block, tags_inner);
 
@@ -1441,7 +1561,7 @@ fn render_snippet_preview(
     };
 
     let tags_paragraph = Paragraph::new(tags_text)
-        .style(Style::default().fg(RosePine::IRIS))
+        .style(Style::default().fg(palette().iris))
         .wrap(Wrap { trim: true });
 
     frame.render_widget(tags_paragraph, tags_inner);
@@ -1453,62 +1573,21 @@ fn render_snippet_preview(
         let content_block = Block::bordered()
             .title(title)
             .border_type(BorderType::Rounded)
-            .style(Style::default().fg(RosePine::FOAM).bg(RosePine::BASE));
+            .style(Style::default().fg(palette().foam).bg(palette().base));
 
         let inner_content_area = content_block.inner(main_chunks[1]);
         content_block.render(main_chunks[1], frame.buffer_mut());
 
         let content_bg = Block::default()
-            .style(Style::default().bg(RosePine::SURFACE))
+            .style(Style::default().bg(palette().surface))
             .borders(ratatui::widgets::Borders::NONE);
         content_bg.render(inner_content_area, frame.buffer_mut());
 
-        let clean_content = preview_content
-            .lines()
-            .filter_map(|line| {
-                if line.trim().chars().all(|c| {
-                    c.is_numeric()
-                        || c == ';'
-                        || c == ':'
-                        || c == ','
-                        || c == '.'
-                        || c == '['
-                        || c == ']'
-                        || c == 'm'
-                }) {
-                    return None;
-                }
-
-                let leading_spaces = line.chars().take_while(|c| c.is_whitespace()).count();
-                let mut cleaned = line.to_string();
-
-                while let Some(start) = cleaned.find('[') {
-                    if let Some(end) = cleaned[start..].find('m') {
-                        cleaned.replace_range(start..=start + end, "");
-                    } else {
-                        break;
-                    }
-                }
-
-                cleaned = cleaned
-                    .chars()
-                    .filter(|&c| c >= ' ' && c != '\u{7F}')
-                    .collect();
-
-                if cleaned.trim().is_empty() {
-                    return None;
-                }
-
-                Some(format!("{}{}", " ".repeat(leading_spaces), cleaned.trim()))
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        display_highlighted_content(frame, inner_content_area, &clean_content, snippet, app);
+        display_highlighted_content(frame, inner_content_area, &preview_content, snippet, app);
     } else {
         let empty_text = Paragraph::new("Empty snippet\nPress Enter to edit")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::MUTED));
+            .style(Style::default().fg(palette().muted));
         empty_text.render(main_chunks[1], frame.buffer_mut());
     }
 }
@@ -1527,19 +1606,13 @@ fn get_detailed_chats_for_snippet(
 ) -> Vec<ChatDetails> {
     // Get the ollama state from the app
     if let Some(ollama_state) = &app.ollama_state {
-        // Generate snippet hash the same way it's done in ollama.rs
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+        let snippet_hash = crate::ui::ollama::snippet_content_hash(&snippet.content);
 
-        let mut hasher = DefaultHasher::new();
-        snippet.content.hash(&mut hasher);
-        let snippet_hash = format!("{:x}", hasher.finish());
-
-        // Find sessions with matching snippet hash and sort by most recent
+        // Find sessions this snippet was attached to and sort by most recent
         let mut matching_sessions: Vec<_> = ollama_state
             .saved_sessions
             .iter()
-            .filter(|session| session.snippet_hash.as_ref() == Some(&snippet_hash))
+            .filter(|session| session.involves_snippet(&snippet_hash))
             .collect();
 
         // Sort by updated_at (most recent first)
@@ -1577,6 +1650,127 @@ fn get_detailed_chats_for_snippet(
     }
 }
 
+/// Highlighted snippet content keyed by snippet id and content hash
+type HighlightCache = HashMap<(Uuid, u64), Vec<Line<'static>>>;
+
+static HIGHLIGHT_CACHE: Lazy<Mutex<HighlightCache>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Upper bound on cached highlight entries before the cache is flushed
+const HIGHLIGHT_CACHE_LIMIT: usize = 64;
+
+/// Maps a snippet language to the syntect syntax name used for highlighting
+pub(crate) fn syntect_syntax_name(language: &SnippetLanguage) -> &'static str {
+    match language {
+        SnippetLanguage::Rust => "Rust",
+        SnippetLanguage::JavaScript => "JavaScript",
+        SnippetLanguage::TypeScript => "TypeScript",
+        SnippetLanguage::Python => "Python",
+        SnippetLanguage::Go => "Go",
+        SnippetLanguage::Java => "Java",
+        SnippetLanguage::C => "C",
+        SnippetLanguage::Cpp => "C++",
+        SnippetLanguage::CSharp => "C#",
+        SnippetLanguage::PHP => "PHP",
+        SnippetLanguage::Ruby => "Ruby",
+        SnippetLanguage::HTML => "HTML",
+        SnippetLanguage::CSS => "CSS",
+        SnippetLanguage::SCSS => "SCSS",
+        SnippetLanguage::SQL => "SQL",
+        SnippetLanguage::Bash => "Bash",
+        SnippetLanguage::PowerShell => "PowerShell",
+        SnippetLanguage::Yaml => "YAML",
+        SnippetLanguage::Json => "JSON",
+        SnippetLanguage::Xml => "XML",
+        SnippetLanguage::Markdown => "Markdown",
+        SnippetLanguage::Toml => "TOML",
+        SnippetLanguage::Ini => "INI",
+        SnippetLanguage::Lua => "Lua",
+        SnippetLanguage::Haskell => "Haskell",
+        SnippetLanguage::Scala => "Scala",
+        SnippetLanguage::R => "R",
+        SnippetLanguage::Perl => "Perl",
+        _ => "Plain Text",
+    }
+}
+
+/// Converts snippet content into syntax-highlighted lines on the theme surface.
+/// Results are cached per snippet id, content and theme so scrolling stays cheap.
+pub(crate) fn highlight_snippet_lines(
+    snippet_id: Uuid,
+    content: &str,
+    language: &SnippetLanguage,
+) -> Vec<Line<'static>> {
+    let content = &*sanitize_for_display(content);
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    language.display_name().hash(&mut hasher);
+    active_theme().name().hash(&mut hasher);
+    let key = (snippet_id, hasher.finish());
+
+    let cached = HIGHLIGHT_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(&key).cloned());
+    if let Some(lines) = cached {
+        return lines;
+    }
+
+    let lines: Vec<Line<'static>> = if *language == SnippetLanguage::Text {
+        content
+            .lines()
+            .map(|line| {
+                Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(palette().text).bg(palette().surface),
+                ))
+            })
+            .collect()
+    } else {
+        let syntax = SYNTAX_SET
+            .find_syntax_by_name(syntect_syntax_name(language))
+            .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text());
+        let theme = &THEME_SET.themes["base16-mocha.dark"];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        LinesWithEndings::from(content)
+            .map(|line| {
+                let highlighted = highlighter
+                    .highlight_line(line, &SYNTAX_SET)
+                    .unwrap_or_default();
+
+                let spans: Vec<Span<'static>> = highlighted
+                    .iter()
+                    .map(|(style, text)| {
+                        let fg_color = style.foreground;
+
+                        let ratatui_style = Style::default()
+                            .fg(ratatui::style::Color::Rgb(
+                                fg_color.r, fg_color.g, fg_color.b,
+                            ))
+                            .bg(palette().surface);
+
+                        Span::styled(
+                            text.trim_end_matches(['\n', '\r']).to_string(),
+                            ratatui_style,
+                        )
+                    })
+                    .collect();
+
+                Line::from(spans)
+            })
+            .collect()
+    };
+
+    if let Ok(mut cache) = HIGHLIGHT_CACHE.lock() {
+        if cache.len() >= HIGHLIGHT_CACHE_LIMIT {
+            cache.clear();
+        }
+        cache.insert(key, lines.clone());
+    }
+
+    lines
+}
+
 pub(crate) fn display_highlighted_content(
     frame: &mut Frame,
     area: Rect,
@@ -1591,53 +1785,11 @@ pub(crate) fn display_highlighted_content(
     // Only apply background in main view, not in search preview
     if area.width > 30 && area.height > 10 {
         let bg_block = Block::default()
-            .style(Style::default().bg(RosePine::SURFACE))
+            .style(Style::default().bg(palette().surface))
             .borders(ratatui::widgets::Borders::NONE);
         bg_block.render(area, frame.buffer_mut());
     }
 
-    let syntax_name = match snippet.language {
-        crate::models::SnippetLanguage::Rust => "Rust",
-        crate::models::SnippetLanguage::JavaScript => "JavaScript",
-        crate::models::SnippetLanguage::TypeScript => "TypeScript",
-        crate::models::SnippetLanguage::Python => "Python",
-        crate::models::SnippetLanguage::Go => "Go",
-        crate::models::SnippetLanguage::Java => "Java",
-        crate::models::SnippetLanguage::C => "C",
-        crate::models::SnippetLanguage::Cpp => "C++",
-        crate::models::SnippetLanguage::CSharp => "C#",
-        crate::models::SnippetLanguage::PHP => "PHP",
-        crate::models::SnippetLanguage::Ruby => "Ruby",
-        crate::models::SnippetLanguage::HTML => "HTML",
-        crate::models::SnippetLanguage::CSS => "CSS",
-        crate::models::SnippetLanguage::SCSS => "SCSS",
-        crate::models::SnippetLanguage::SQL => "SQL",
-        crate::models::SnippetLanguage::Bash => "Bash",
-        crate::models::SnippetLanguage::PowerShell => "PowerShell",
-        crate::models::SnippetLanguage::Yaml => "YAML",
-        crate::models::SnippetLanguage::Json => "JSON",
-        crate::models::SnippetLanguage::Xml => "XML",
-        crate::models::SnippetLanguage::Markdown => "Markdown",
-        crate::models::SnippetLanguage::Toml => "TOML",
-        crate::models::SnippetLanguage::Ini => "INI",
-        _ => "Plain Text",
-    };
-
-    let syntax = SYNTAX_SET
-        .find_syntax_by_name(syntax_name)
-        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text());
-
-    let theme = &THEME_SET.themes["base16-mocha.dark"];
-
-    // Count the total number of lines for scrollbar position calculation
-    let lines: Vec<&str> = content.lines().collect();
-    let total_lines = lines.len();
-    let visible_lines = area.height as usize;
-
-    // Ensure scroll position doesn't go beyond the content bounds
-    let max_scroll = total_lines.saturating_sub(visible_lines);
-    let scroll_position = app.content_scroll_position.min(max_scroll);
-
     // Split the area to make room for scrollbar
     let content_area = Rect {
         width: area.width.saturating_sub(1),
@@ -1651,62 +1803,172 @@ pub(crate) fn display_highlighted_content(
         height: area.height,
     };
 
-    let mut highlighter = HighlightLines::new(syntax, theme);
+    let highlighted = highlight_snippet_lines(snippet.id, content, &snippet.language);
 
-    let visible_start = scroll_position;
-    let visible_end = (scroll_position + visible_lines).min(total_lines);
+    // Line number gutter, e.g. " 42 │ "
+    let number_width = highlighted.len().max(1).to_string().len().max(3);
+    let gutter_width = number_width + 3;
+    let text_width = (content_area.width as usize)
+        .saturating_sub(gutter_width)
+        .max(1);
+    let gutter_style = Style::default().fg(palette().muted).bg(palette().surface);
 
-    let visible_content = if visible_start < total_lines {
-        lines[visible_start..visible_end].join("\n")
+    // Scrolling works in visual rows, so a wrapped line counts once per row
+    let wrap = app.settings.preview_wrap;
+    let row_starts: Vec<Vec<usize>> = highlighted
+        .iter()
+        .map(|line| {
+            if wrap {
+                wrapped_row_starts(line, text_width)
+            } else {
+                vec![0]
+            }
+        })
+        .collect();
+
+    // Without wrap long lines scroll sideways, and the bottom row tells which columns show
+    let widest = if wrap {
+        0
     } else {
-        String::new()
+        highlighted
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.chars().count())
+                    .sum()
+            })
+            .max()
+            .unwrap_or(0)
     };
+    let column_limit = widest.saturating_sub(text_width);
+    app.preview_column_limit.set(column_limit);
+    let first_column = app.horizontal_scroll.min(column_limit);
+    let shows_columns = column_limit > 0;
 
-    // Highlight only the visible content
-    let styled_lines: Vec<Line> = LinesWithEndings::from(visible_content.as_str())
-        .map(|line| {
-            let highlighted = highlighter
-                .highlight_line(line, &SYNTAX_SET)
-                .unwrap_or_default();
-
-            let spans: Vec<Span> = highlighted
-                .iter()
-                .map(|(style, text)| {
-                    let fg_color = style.foreground;
+    let total_rows: usize = row_starts.iter().map(Vec::len).sum();
+    let visible_rows = (area.height as usize).saturating_sub(usize::from(shows_columns));
 
-                    let ratatui_style = Style::default()
-                        .fg(ratatui::style::Color::Rgb(
-                            fg_color.r, fg_color.g, fg_color.b,
-                        ))
-                        .bg(RosePine::SURFACE);
+    // Ensure scroll position doesn't go beyond the content bounds
+    let max_scroll = total_rows.saturating_sub(visible_rows);
+    app.preview_scroll_limit.set(max_scroll);
+    let scroll_position = app.content_scroll_position.min(max_scroll);
 
-                    Span::styled(text.to_string(), ratatui_style)
-                })
-                .collect();
+    // Only hand the visible window to the paragraph
+    let mut styled_lines: Vec<Line> = Vec::with_capacity(visible_rows);
+    let mut row = 0;
+    'lines: for (index, (line, starts)) in highlighted.iter().zip(&row_starts).enumerate() {
+        if row + starts.len() <= scroll_position {
+            row += starts.len();
+            continue;
+        }
 
-            Line::from(spans)
-        })
-        .collect();
+        for (part, &start) in starts.iter().enumerate() {
+            if row >= scroll_position + visible_rows {
+                break 'lines;
+            }
+            if row >= scroll_position {
+                let number = if part == 0 {
+                    format!("{:>width$} │ ", index + 1, width = number_width)
+                } else {
+                    format!("{:>width$} │ ", "", width = number_width)
+                };
+                let (start, end) = if wrap {
+                    (start, starts.get(part + 1).copied().unwrap_or(usize::MAX))
+                } else {
+                    (first_column, first_column + text_width)
+                };
 
-    let content_paragraph = Paragraph::new(styled_lines).wrap(Wrap { trim: false });
-    // No need for scroll if we're already selecting the visible window
-    // .scroll((0, 0));
+                let mut spans = vec![Span::styled(number, gutter_style)];
+                spans.extend(slice_spans(line, start, end));
+                styled_lines.push(Line::from(spans));
+            }
+            row += 1;
+        }
+    }
 
+    // Rows are already wrapped or cut to the visible columns above
+    let content_paragraph = Paragraph::new(styled_lines);
     content_paragraph.render(content_area, frame.buffer_mut());
 
-    if total_lines > visible_lines {
+    if shows_columns {
+        let indicator_area = Rect {
+            y: content_area.y + content_area.height.saturating_sub(1),
+            height: 1,
+            ..content_area
+        };
+        let indicator = Paragraph::new(format!(
+            "←/→ columns {}-{} of {} ",
+            first_column + 1,
+            (first_column + text_width).min(widest),
+            widest
+        ))
+        .style(Style::default().fg(palette().muted).bg(palette().surface))
+        .alignment(Alignment::Right);
+        indicator.render(indicator_area, frame.buffer_mut());
+    }
+
+    if total_rows > visible_rows {
         let mut scrollbar_state = ScrollbarState::default()
-            .content_length(total_lines)
+            .content_length(max_scroll + 1)
             .position(scroll_position);
 
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .style(Style::default().fg(RosePine::SUBTLE))
-            .thumb_style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+            .style(Style::default().fg(palette().subtle))
+            .thumb_style(Style::default().fg(palette().highlight_high));
 
         frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
     }
 }
 
+/// Char offsets at which each wrapped row of `line` begins
+fn wrapped_row_starts(line: &Line, width: usize) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut column = 0;
+
+    for (offset, c) in line
+        .spans
+        .iter()
+        .flat_map(|span| span.content.chars())
+        .enumerate()
+    {
+        let char_width = c.width().unwrap_or(0);
+        if column + char_width > width && column > 0 {
+            starts.push(offset);
+            column = 0;
+        }
+        column += char_width;
+    }
+
+    starts
+}
+
+/// Styled pieces of `line` between the char offsets `start` and `end`
+fn slice_spans(line: &Line<'static>, start: usize, end: usize) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut offset = 0;
+
+    for span in &line.spans {
+        let len = span.content.chars().count();
+        let (from, to) = (start.max(offset), end.min(offset + len));
+        if from < to {
+            let text: String = span
+                .content
+                .chars()
+                .skip(from - offset)
+                .take(to - from)
+                .collect();
+            spans.push(Span::styled(text, span.style));
+        }
+        offset += len;
+        if offset >= end {
+            break;
+        }
+    }
+
+    spans
+}
+
 fn render_input_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
     let popup_area = spotlight_bar(70, area);
 
@@ -1718,8 +1980,12 @@ fn render_input_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
         InputMode::CreateSnippet => "Create New Snippet",
         InputMode::Search => "Search Snippets",
         InputMode::_RenameNotebook => "Rename Notebook",
-        InputMode::_RenameSnippet => "Rename Snippet",
+        InputMode::RenameSnippet => "Rename Snippet",
+        InputMode::ExportSelection => "Export Selection To",
+        InputMode::ExportNotebookMarkdown => "Export Notebook To",
         InputMode::EditSnippetDescription => "Edit Snippet Description",
+        InputMode::EditSnippetEditor => "Editor Command ({file} = path)",
+        InputMode::BulkTagNotebook => "Tag Notebook Snippets (-tag removes)",
         _ => "Input",
     };
 
@@ -1727,13 +1993,20 @@ fn render_input_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
         && !app.pending_snippet_title.is_empty()
     {
         format!("Edit Description for '{}'", app.pending_snippet_title)
+    } else if let (InputMode::FillTemplate, Some(name)) = (
+        &app.input_mode,
+        app.pending_template
+            .as_ref()
+            .and_then(|pending| pending.missing.first()),
+    ) {
+        format!("Value for {{{{{}}}}}", name)
     } else {
         static_title.to_string()
     };
 
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
 
@@ -1746,19 +2019,21 @@ fn render_input_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let title_paragraph = Paragraph::new(title_text)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::IRIS).bold());
+        .style(Style::default().fg(palette().iris).bold());
     title_paragraph.render(chunks[0], frame.buffer_mut());
 
-    let input_text = format!("{}", app.input_buffer);
-    let input_paragraph = Paragraph::new(input_text)
-        .style(Style::default().fg(RosePine::TEXT))
-        .alignment(Alignment::Left);
-    input_paragraph.render(chunks[1], frame.buffer_mut());
+    render_text_input(
+        frame,
+        chunks[1],
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
 
     let help_text = "⎋ Cancel • ⏎ Confirm";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 }
 
@@ -1766,14 +2041,14 @@ fn render_input_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
 fn render_notebook_view(frame: &mut Frame, area: Rect, _app: &App, _notebook_id: uuid::Uuid) {
     let paragraph = Paragraph::new("Detailed notebook view coming soon...")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
     paragraph.render(area, frame.buffer_mut());
 }
 
 fn render_snippet_editor(frame: &mut Frame, area: Rect, _app: &App, _snippet_id: uuid::Uuid) {
     let paragraph = Paragraph::new("External editor integration active\nFile opened in vim/nvim")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
     paragraph.render(area, frame.buffer_mut());
 }
 
@@ -1809,12 +2084,19 @@ fn render_create_snippet_dialog(
 
                 let block = Block::bordered()
                     .border_type(BorderType::Rounded)
-                    .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+                    .style(Style::default().fg(palette().subtle).bg(palette().surface));
 
                 let inner_area = block.inner(popup_area);
                 block.render(popup_area, frame.buffer_mut());
 
-                let title = format!("Create Snippet in {}", notebook_name);
+                let title = match &app.pending_paste {
+                    Some(content) => format!(
+                        "Paste {} lines into {}",
+                        content.lines().count(),
+                        notebook_name
+                    ),
+                    None => format!("Create Snippet in {}", notebook_name),
+                };
                 let chunks = Layout::horizontal([
                     Constraint::Length(title.len() as u16 + 4),
                     Constraint::Min(10),
@@ -1824,19 +2106,21 @@ fn render_create_snippet_dialog(
 
                 let title_paragraph = Paragraph::new(title)
                     .alignment(Alignment::Left)
-                    .style(Style::default().fg(RosePine::IRIS).bold());
+                    .style(Style::default().fg(palette().iris).bold());
                 title_paragraph.render(chunks[0], frame.buffer_mut());
 
-                let input_text = format!("{}", app.input_buffer);
-                let input_paragraph = Paragraph::new(input_text)
-                    .style(Style::default().fg(RosePine::TEXT))
-                    .alignment(Alignment::Left);
-                input_paragraph.render(chunks[1], frame.buffer_mut());
+                render_text_input(
+                    frame,
+                    chunks[1],
+                    &app.input_buffer,
+                    app.input_cursor,
+                    Style::default().fg(palette().text),
+                );
 
                 let help_text = "⎋ Cancel • ⏎ Confirm";
                 let help_paragraph = Paragraph::new(help_text)
                     .alignment(Alignment::Right)
-                    .style(Style::default().fg(RosePine::MUTED));
+                    .style(Style::default().fg(palette().muted));
                 help_paragraph.render(chunks[2], frame.buffer_mut());
             } else {
                 let message = "Error: Selected notebook not found";
@@ -1853,7 +2137,7 @@ fn render_create_snippet_dialog(
     }
 }
 
-fn spotlight_bar(width_percent: u16, r: Rect) -> Rect {
+pub(crate) fn spotlight_bar(width_percent: u16, r: Rect) -> Rect {
     let layout = Layout::vertical([
         Constraint::Length(3),
         Constraint::Length(3),
@@ -1869,17 +2153,11 @@ fn spotlight_bar(width_percent: u16, r: Rect) -> Rect {
     .split(layout[1])[1]
 }
 
-fn render_settings_view(frame: &mut Frame, area: Rect, _app: &App) {
-    let paragraph = Paragraph::new("Settings coming soon...")
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::TEXT));
-    paragraph.render(area, frame.buffer_mut());
-}
-
 fn render_tags_editing(frame: &mut Frame, app: &App) {
     let area = frame.area();
+    let suggestions = app.tag_suggestions();
     let popup_width = 70;
-    let popup_height = 10;
+    let popup_height = 10 + suggestions.len() as u16;
 
     // Calculate centered position for the popup
     let popup_area = Rect::new(
@@ -1896,16 +2174,17 @@ fn render_tags_editing(frame: &mut Frame, app: &App) {
         .title(" Edit Tags ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::IRIS));
+        .style(Style::default().fg(palette().iris));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
 
     // Split inner area for content
     let chunks = Layout::vertical([
-        Constraint::Length(1), // Help text
-        Constraint::Length(3), // Input area
-        Constraint::Fill(1),   // Info area
+        Constraint::Length(1),                        // Help text
+        Constraint::Length(3),                        // Input area
+        Constraint::Length(suggestions.len() as u16), // Existing tags matching the last word
+        Constraint::Fill(1),                          // Info area
     ])
     .split(inner_area);
 
@@ -1913,26 +2192,60 @@ fn render_tags_editing(frame: &mut Frame, app: &App) {
     let help_text = "Enter tags separated by spaces, prefix with # (e.g. #rust #web)";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
     help_paragraph.render(chunks[0], frame.buffer_mut());
 
     // Render input field
     let input_block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let input_inner = input_block.inner(chunks[1]);
     input_block.render(chunks[1], frame.buffer_mut());
 
-    let input_text = Paragraph::new(app.input_buffer.as_str())
-        .style(Style::default().fg(RosePine::TEXT))
-        .alignment(Alignment::Left);
-    input_text.render(input_inner, frame.buffer_mut());
+    render_text_input(
+        frame,
+        input_inner,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
+
+    let suggestion_lines: Vec<Line> = suggestions
+        .iter()
+        .enumerate()
+        .map(|(i, tag)| {
+            let selected = i == app.selected_tag_suggestion;
+            Line::from(vec![
+                Span::styled(
+                    if selected { " ▶ " } else { "   " },
+                    Style::default().fg(palette().iris),
+                ),
+                Span::styled(
+                    tag.display_name(),
+                    if selected {
+                        Style::default().fg(palette().rose).bold()
+                    } else {
+                        Style::default().fg(palette().rose)
+                    },
+                ),
+                Span::styled(
+                    format!("  used {} times", tag.usage_count),
+                    Style::default().fg(palette().muted),
+                ),
+            ])
+        })
+        .collect();
+    Paragraph::new(suggestion_lines).render(chunks[2], frame.buffer_mut());
 
     // Render info text
-    let info_text = "Press Enter to save, Esc to cancel";
+    let info_text = if suggestions.is_empty() {
+        "Press Enter to save, Esc to cancel"
+    } else {
+        "Tab to complete, ↑↓ to pick, Enter to save, Esc to cancel"
+    };
     let info_paragraph = Paragraph::new(info_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
-    info_paragraph.render(chunks[2], frame.buffer_mut());
+        .style(Style::default().fg(palette().muted));
+    info_paragraph.render(chunks[3], frame.buffer_mut());
 }
@@ -1,22 +1,232 @@
-//! Rose Pine Color Palette Module
-//! Based on the official Rose Pine theme: https://rosepinetheme.com/
+//! Color Palette Module
+//! Every widget pulls its colors from `palette()`, which follows the theme
+//! selected in Settings. Rose Pine (https://rosepinetheme.com/) is the default.
 
+use crate::models::SnippetLanguage;
 use ratatui::style::Color;
-pub struct RosePine;
-
-impl RosePine {
-    pub const BASE: Color = Color::Rgb(25, 23, 36);
-    pub const SURFACE: Color = Color::Rgb(31, 29, 46);
-    pub const OVERLAY: Color = Color::Rgb(38, 35, 58);
-    pub const TEXT: Color = Color::Rgb(224, 222, 244);
-    pub const SUBTLE: Color = Color::Rgb(144, 140, 170);
-    pub const MUTED: Color = Color::Rgb(110, 106, 134);
-    pub const LOVE: Color = Color::Rgb(235, 111, 146);
-    pub const GOLD: Color = Color::Rgb(246, 193, 119);
-    pub const ROSE: Color = Color::Rgb(235, 188, 186);
-    pub const FOAM: Color = Color::Rgb(156, 207, 216);
-    pub const IRIS: Color = Color::Rgb(196, 167, 231);
-    pub const PINE: Color = Color::Rgb(121, 179, 167);
-    pub const HIGHLIGHT_HIGH: Color = Color::Rgb(82, 79, 103);
-    pub const HIGHLIGHT_LOW: Color = Color::Rgb(33, 32, 46);
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Named color slots shared by every theme, modelled on the Rose Pine roles
+pub struct Palette {
+    pub base: Color,
+    pub surface: Color,
+    pub overlay: Color,
+    pub text: Color,
+    pub subtle: Color,
+    pub muted: Color,
+    pub love: Color,
+    pub gold: Color,
+    pub rose: Color,
+    pub foam: Color,
+    pub iris: Color,
+    pub pine: Color,
+    pub highlight_high: Color,
+    pub highlight_low: Color,
+}
+
+pub const ROSE_PINE: Palette = Palette {
+    base: Color::Rgb(25, 23, 36),
+    surface: Color::Rgb(31, 29, 46),
+    overlay: Color::Rgb(38, 35, 58),
+    text: Color::Rgb(224, 222, 244),
+    subtle: Color::Rgb(144, 140, 170),
+    muted: Color::Rgb(110, 106, 134),
+    love: Color::Rgb(235, 111, 146),
+    gold: Color::Rgb(246, 193, 119),
+    rose: Color::Rgb(235, 188, 186),
+    foam: Color::Rgb(156, 207, 216),
+    iris: Color::Rgb(196, 167, 231),
+    pine: Color::Rgb(121, 179, 167),
+    highlight_high: Color::Rgb(82, 79, 103),
+    highlight_low: Color::Rgb(33, 32, 46),
+};
+
+pub const NORD: Palette = Palette {
+    base: Color::Rgb(46, 52, 64),
+    surface: Color::Rgb(59, 66, 82),
+    overlay: Color::Rgb(67, 76, 94),
+    text: Color::Rgb(236, 239, 244),
+    subtle: Color::Rgb(163, 172, 188),
+    muted: Color::Rgb(116, 128, 150),
+    love: Color::Rgb(191, 97, 106),
+    gold: Color::Rgb(235, 203, 139),
+    rose: Color::Rgb(208, 135, 112),
+    foam: Color::Rgb(136, 192, 208),
+    iris: Color::Rgb(180, 142, 173),
+    pine: Color::Rgb(163, 190, 140),
+    highlight_high: Color::Rgb(76, 86, 106),
+    highlight_low: Color::Rgb(59, 66, 82),
+};
+
+pub const GRUVBOX: Palette = Palette {
+    base: Color::Rgb(40, 40, 40),
+    surface: Color::Rgb(50, 48, 47),
+    overlay: Color::Rgb(60, 56, 54),
+    text: Color::Rgb(235, 219, 178),
+    subtle: Color::Rgb(168, 153, 132),
+    muted: Color::Rgb(124, 111, 100),
+    love: Color::Rgb(251, 73, 52),
+    gold: Color::Rgb(250, 189, 47),
+    rose: Color::Rgb(254, 128, 25),
+    foam: Color::Rgb(131, 165, 152),
+    iris: Color::Rgb(211, 134, 155),
+    pine: Color::Rgb(142, 192, 124),
+    highlight_high: Color::Rgb(80, 73, 69),
+    highlight_low: Color::Rgb(50, 48, 47),
+};
+
+pub const DRACULA: Palette = Palette {
+    base: Color::Rgb(40, 42, 54),
+    surface: Color::Rgb(33, 34, 44),
+    overlay: Color::Rgb(68, 71, 90),
+    text: Color::Rgb(248, 248, 242),
+    subtle: Color::Rgb(166, 172, 205),
+    muted: Color::Rgb(98, 114, 164),
+    love: Color::Rgb(255, 85, 85),
+    gold: Color::Rgb(241, 250, 140),
+    rose: Color::Rgb(255, 121, 198),
+    foam: Color::Rgb(139, 233, 253),
+    iris: Color::Rgb(189, 147, 249),
+    pine: Color::Rgb(80, 250, 123),
+    highlight_high: Color::Rgb(68, 71, 90),
+    highlight_low: Color::Rgb(52, 55, 70),
+};
+
+/// Selectable color themes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    RosePine,
+    Nord,
+    Gruvbox,
+    Dracula,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [Theme::RosePine, Theme::Nord, Theme::Gruvbox, Theme::Dracula];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::RosePine => "Rose Pine",
+            Theme::Nord => "Nord",
+            Theme::Gruvbox => "Gruvbox",
+            Theme::Dracula => "Dracula",
+        }
+    }
+
+    /// Looks a theme up by its display name, ignoring case and spaces
+    pub fn from_name(name: &str) -> Option<Self> {
+        let wanted: String = name
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+
+        Self::ALL
+            .into_iter()
+            .find(|theme| theme.name().replace(' ', "").to_lowercase() == wanted)
+    }
+
+    pub fn palette(&self) -> &'static Palette {
+        match self {
+            Theme::RosePine => &ROSE_PINE,
+            Theme::Nord => &NORD,
+            Theme::Gruvbox => &GRUVBOX,
+            Theme::Dracula => &DRACULA,
+        }
+    }
+}
+
+static ACTIVE_THEME: AtomicU8 = AtomicU8::new(0);
+
+/// Switches the theme used by every subsequent frame
+pub fn set_active_theme(theme: Theme) {
+    let index = Theme::ALL.iter().position(|t| *t == theme).unwrap_or(0);
+    ACTIVE_THEME.store(index as u8, Ordering::Relaxed);
+}
+
+pub fn active_theme() -> Theme {
+    Theme::ALL
+        .get(ACTIVE_THEME.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(Theme::RosePine)
+}
+
+/// Palette of the active theme
+pub fn palette() -> &'static Palette {
+    active_theme().palette()
+}
+
+/// Palette colors that can be given to a tag with `:tagcolor`
+pub const TAG_COLOR_NAMES: [&str; 6] = ["love", "gold", "rose", "foam", "iris", "pine"];
+
+impl Palette {
+    /// Accent color by its Rose Pine name, which every theme maps to its own shade
+    pub fn named(&self, name: &str) -> Option<Color> {
+        match name.trim().to_lowercase().as_str() {
+            "love" => Some(self.love),
+            "gold" => Some(self.gold),
+            "rose" => Some(self.rose),
+            "foam" => Some(self.foam),
+            "iris" => Some(self.iris),
+            "pine" => Some(self.pine),
+            _ => None,
+        }
+    }
+}
+
+/// Color of the first of `tags` that has one configured, if any
+pub fn tag_color(tag_colors: &BTreeMap<String, String>, tags: &[String]) -> Option<Color> {
+    tags.iter().find_map(|tag| {
+        let name = tag.trim_start_matches('#').to_lowercase();
+        tag_colors
+            .get(&name)
+            .and_then(|color| palette().named(color))
+    })
+}
+
+/// Themed color for a language's icon, loosely following each language's brand color
+pub fn language_color(language: &SnippetLanguage) -> Color {
+    let palette = palette();
+    match language {
+        SnippetLanguage::Rust
+        | SnippetLanguage::Swift
+        | SnippetLanguage::HTML
+        | SnippetLanguage::Scala => palette.rose,
+        SnippetLanguage::JavaScript
+        | SnippetLanguage::Python
+        | SnippetLanguage::SQL
+        | SnippetLanguage::Json
+        | SnippetLanguage::Zig => palette.gold,
+        SnippetLanguage::Java
+        | SnippetLanguage::Ruby
+        | SnippetLanguage::SCSS
+        | SnippetLanguage::Svelte
+        | SnippetLanguage::GraphQL => palette.love,
+        SnippetLanguage::TypeScript
+        | SnippetLanguage::C
+        | SnippetLanguage::Cpp
+        | SnippetLanguage::CSS
+        | SnippetLanguage::PowerShell
+        | SnippetLanguage::Lua
+        | SnippetLanguage::R => palette.pine,
+        SnippetLanguage::Go
+        | SnippetLanguage::Dart
+        | SnippetLanguage::Dockerfile
+        | SnippetLanguage::Nix
+        | SnippetLanguage::Vue => palette.foam,
+        SnippetLanguage::CSharp
+        | SnippetLanguage::PHP
+        | SnippetLanguage::Kotlin
+        | SnippetLanguage::Elixir
+        | SnippetLanguage::Haskell
+        | SnippetLanguage::Perl => palette.iris,
+        SnippetLanguage::Bash | SnippetLanguage::Markdown | SnippetLanguage::Xml => palette.text,
+        SnippetLanguage::Yaml
+        | SnippetLanguage::Toml
+        | SnippetLanguage::Ini
+        | SnippetLanguage::Config
+        | SnippetLanguage::Text
+        | SnippetLanguage::Other(_) => palette.subtle,
+    }
 }
@@ -1,12 +1,165 @@
 use crate::app::{App, TreeItem};
-use crate::ui::colors::RosePine;
+use crate::ui::colors::palette;
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Layout, Rect},
+    layout::{Alignment, Constraint, Flex, Layout, Position, Rect},
     style::{Style, Stylize},
     text::{Line, Span},
     widgets::{Block, BorderType, Clear, Paragraph, Widget},
 };
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthStr;
+
+/// Draws a one-line text field with the terminal cursor in it, scrolling long text sideways
+///
+/// `cursor` counts characters back from the end of `text`, as `App::input_cursor` does.
+pub fn render_text_input(frame: &mut Frame, area: Rect, text: &str, cursor: usize, style: Style) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let cursor_x = text_width_before_cursor(text, cursor);
+    let scroll = (cursor_x + 1).saturating_sub(area.width);
+    Paragraph::new(text)
+        .style(style)
+        .scroll((0, scroll))
+        .render(area, frame.buffer_mut());
+    frame.set_cursor_position(Position {
+        x: area.x + cursor_x - scroll,
+        y: area.y,
+    });
+}
+
+/// Display width of the text left of the cursor
+pub fn text_width_before_cursor(text: &str, cursor: usize) -> u16 {
+    let position = text.chars().count().saturating_sub(cursor);
+    let before: String = text.chars().take(position).collect();
+    before.width() as u16
+}
+
+/// Renders a one-line text prompt floating over the page
+pub fn render_prompt_bar(frame: &mut Frame, area: Rect, title: &str, input: &str, cursor: usize) {
+    let popup_area = crate::ui::code_snippets::spotlight_bar(70, area);
+    Clear.render(popup_area, frame.buffer_mut());
+
+    let block = Block::bordered()
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
+    let inner_area = block.inner(popup_area);
+    block.render(popup_area, frame.buffer_mut());
+
+    let chunks = Layout::horizontal([
+        Constraint::Length(title.len() as u16 + 4),
+        Constraint::Min(10),
+        Constraint::Length(24),
+    ])
+    .split(inner_area);
+
+    Paragraph::new(title)
+        .style(Style::default().fg(palette().iris).bold())
+        .render(chunks[0], frame.buffer_mut());
+
+    render_text_input(
+        frame,
+        chunks[1],
+        input,
+        cursor,
+        Style::default().fg(palette().text),
+    );
+
+    Paragraph::new("⎋ Cancel • ⏎ Confirm")
+        .alignment(Alignment::Right)
+        .style(Style::default().fg(palette().muted))
+        .render(chunks[2], frame.buffer_mut());
+}
+
+/// Renders the save/discard/cancel dialog shown when quitting with unsaved work
+pub fn render_quit_prompt(frame: &mut Frame) {
+    let [row] = Layout::vertical([Constraint::Length(9)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [popup_area] = Layout::horizontal([Constraint::Length(56)])
+        .flex(Flex::Center)
+        .areas(row);
+    Clear.render(popup_area, frame.buffer_mut());
+
+    let key = |label: &'static str| Span::styled(label, Style::default().fg(palette().gold).bold());
+    Paragraph::new(vec![
+        Line::from(""),
+        Line::from("An Ollama chat has unsaved changes.").fg(palette().text),
+        Line::from("Save it before quitting?").fg(palette().text),
+        Line::from(""),
+        Line::from(vec![
+            key("[y]"),
+            Span::raw(" Save & quit   "),
+            key("[n]"),
+            Span::raw(" Discard & quit   "),
+            key("[Esc]"),
+            Span::raw(" Cancel"),
+        ])
+        .fg(palette().subtle),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .title(" 󰀨 Unsaved Changes ")
+            .title_alignment(Alignment::Center)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(palette().gold).bg(palette().surface)),
+    )
+    .render(popup_area, frame.buffer_mut());
+}
+
+/// Renders the full screen error shown when the storage folder cannot be opened or written
+pub fn render_storage_error(frame: &mut Frame, error: &str) {
+    let area = frame.area();
+    Clear.render(area, frame.buffer_mut());
+
+    let [row] = Layout::vertical([Constraint::Length(14)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup_area] = Layout::horizontal([Constraint::Length(76)])
+        .flex(Flex::Center)
+        .areas(row);
+
+    let key = |label: &'static str| Span::styled(label, Style::default().fg(palette().gold).bold());
+    Paragraph::new(vec![
+        Line::from(""),
+        Line::from("snix cannot use its storage folder.")
+            .fg(palette().text)
+            .bold(),
+        Line::from(""),
+        Line::from(error.to_string()).fg(palette().love),
+        Line::from(""),
+        Line::from("Check that the folder exists and that you may write to it,")
+            .fg(palette().subtle),
+        Line::from(format!(
+            "or point {} (or data_dir in ~/.snix/config.toml) elsewhere and restart.",
+            crate::models::storage::DATA_DIR_ENV
+        ))
+        .fg(palette().subtle),
+        Line::from("Without storage nothing you create is kept after exiting.")
+            .fg(palette().subtle),
+        Line::from(""),
+        Line::from(vec![
+            key("[q]"),
+            Span::raw(" Quit   "),
+            key("[c]"),
+            Span::raw(" Continue without saving"),
+        ])
+        .fg(palette().subtle),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(ratatui::widgets::Wrap { trim: true })
+    .block(
+        Block::bordered()
+            .title(" 󰀨 Storage Unavailable ")
+            .title_alignment(Alignment::Center)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(palette().love).bg(palette().surface)),
+    )
+    .render(popup_area, frame.buffer_mut());
+}
 
 /// Renders the bottom navigation bar with breadcrumbs and keyboard shortcut
 pub fn render_bottom_bar(frame: &mut Frame, area: Rect, app: &mut App) {
@@ -16,22 +169,22 @@ pub fn render_bottom_bar(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let left_content = Paragraph::new(breadcrumbs)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::SUBTLE))
+        .style(Style::default().fg(palette().subtle))
         .block(
             Block::bordered()
                 .border_type(BorderType::Rounded)
-                .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH)),
+                .style(Style::default().fg(palette().highlight_high)),
         );
 
     let shortcuts = get_context_shortcuts(app);
 
     let right_content = Paragraph::new(shortcuts)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED))
+        .style(Style::default().fg(palette().muted))
         .block(
             Block::bordered()
                 .border_type(BorderType::Rounded)
-                .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH)),
+                .style(Style::default().fg(palette().highlight_high)),
         );
 
     left_content.render(navbar_chunks[0], frame.buffer_mut());
@@ -48,6 +201,8 @@ fn get_context_shortcuts(app: &mut App) -> String {
         (_, InputMode::CreateNotebook | InputMode::CreateSnippet | InputMode::Search) => {
             format!(" [⏎] Confirm │ [Esc] Cancel ")
         }
+        (_, InputMode::Command) => " [⏎] Run │ [↑↓] History │ [Esc] Cancel │ :help ".to_string(),
+        (_, InputMode::QuickOpen) => " [⏎] Open │ [↑↓] Select │ [Esc] Close ".to_string(),
         (_, InputMode::SelectLanguage) => {
             format!(" [↑↓] Navigate │ [⏎] Select │ [Esc] Cancel ")
         }
@@ -67,6 +222,15 @@ fn get_context_shortcuts(app: &mut App) -> String {
                         back_hint
                     )
                 }
+                _ if app.jump_input.is_some() => {
+                    format!("{} JUMP │ Type a label to jump │ [Esc] Cancel ", back_hint)
+                }
+                _ if app.multi_select_mode => {
+                    format!(
+                        "{} SELECT │ [Space] Mark │ [x] Trash │ [m] Move Here │ [f] Favorite │ [e] Export │ [d] Diff │ [V/Esc] Done ",
+                        back_hint
+                    )
+                }
                 _ => {
                     if app.snippet_database.notebooks.is_empty() {
                         format!("{} [n 󰠮] │ [/ 󰭎] │ [h  ]│ [q 󰈆] ", back_hint)
@@ -130,19 +294,42 @@ fn get_context_shortcuts(app: &mut App) -> String {
                             back_hint
                         )
                     }
+                    ExportImportMode::ExportTags => {
+                        format!(
+                            "{} [↑↓] Navigate │ [Space/⏎] Toggle │ [c] Clear │ [Esc] Done ",
+                            back_hint
+                        )
+                    }
                     ExportImportMode::ExportPath | ExportImportMode::_ImportPath => {
                         format!("{} [⏎] Confirm │ [Esc] Back ", back_hint)
                     }
                     ExportImportMode::ImportClipboard => {
                         format!("{} [⏎] Import │ [Esc] Back ", back_hint)
                     }
-                    ExportImportMode::Importing => {
-                        format!("{} [⏎] Import │ [Esc] Back ", back_hint)
+                    ExportImportMode::Exporting | ExportImportMode::Importing => {
+                        "Working, keys are paused until the transfer finishes ".to_string()
                     }
                     ExportImportMode::ImportPathPopup => {
                         format!("{} [⏎] Import File │ [Esc] Back ", back_hint)
                     }
-                    _ => format!("{} [Esc] Back ", back_hint),
+                    ExportImportMode::ImportNotebook => {
+                        format!(
+                            "{} [↑↓] Navigate │ [⏎] Import Here │ [Esc] Back ",
+                            back_hint
+                        )
+                    }
+                    ExportImportMode::ImportGist => {
+                        format!("{} [⏎] Import Gist │ [Esc] Back ", back_hint)
+                    }
+                    ExportImportMode::Passphrase => {
+                        format!("{} [⏎] Confirm │ [Esc] Back ", back_hint)
+                    }
+                    ExportImportMode::ResolveConflicts => {
+                        format!(
+                            "{} [k] Keep │ [o] Overwrite │ [b] Both │ [Shift] All │ [Esc] Cancel ",
+                            back_hint
+                        )
+                    }
                 }
             } else {
                 format!(
@@ -152,6 +339,52 @@ fn get_context_shortcuts(app: &mut App) -> String {
             }
         }
 
+        (AppState::Settings, InputMode::Normal) => {
+            format!(
+                "{} [↑↓] Navigate │ [⏎] Apply Theme │ [b] Backup on Exit │ [v] Verify Storage │ [Esc] Back │ [h] Home ",
+                back_hint
+            )
+        }
+
+        (AppState::Boilerplates, InputMode::Normal) => {
+            format!(
+                "{} [↑↓] Navigate │ [⏎] Scaffold │ [r] Reload │ [Esc] Back │ [h] Home ",
+                back_hint
+            )
+        }
+
+        (AppState::Tags, InputMode::Normal) if app.tag_snippets_focused => {
+            format!(
+                "{} [↑↓] Navigate │ [⏎] Edit │ [←] Tags │ [h] Home ",
+                back_hint
+            )
+        }
+
+        (AppState::Tags, InputMode::Normal) => {
+            format!(
+                "{} [↑↓] Navigate │ [⏎] Snippets │ [r] Rename │ [m] Merge │ [h] Home ",
+                back_hint
+            )
+        }
+
+        (AppState::Statistics, InputMode::Normal) => {
+            format!("{} [r] Refresh │ [Esc] Back │ [h] Home ", back_hint)
+        }
+
+        (AppState::Recent, InputMode::Normal) => {
+            format!(
+                "{} [↑↓] Navigate │ [⏎] Open │ [Esc] Back │ [h] Home ",
+                back_hint
+            )
+        }
+
+        (AppState::Trash, InputMode::Normal) => {
+            format!(
+                "{} [↑↓] Navigate │ [r] Restore │ [x] Purge │ [X] Empty Trash │ [h] Home ",
+                back_hint
+            )
+        }
+
         // Other pages
         _ => {
             format!(
@@ -170,36 +403,36 @@ fn get_breadcrumbs_with_symbols(app: &mut App) -> Line<'static> {
     if app.state == crate::app::AppState::StartPage {
         spans.push(Span::styled(
             " 󰋜 Home ",
-            Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+            Style::default().fg(palette().base).bg(palette().iris),
         ));
     } else {
         spans.push(Span::styled(
             " 󰋜 Home ",
-            Style::default().fg(RosePine::SUBTLE),
+            Style::default().fg(palette().subtle),
         ));
     }
 
     // Add the current section (Snippets, Boilerplates, etc.)
     if app.state != crate::app::AppState::StartPage {
-        spans.push(Span::styled(" ❯ ", Style::default().fg(RosePine::MUTED)));
+        spans.push(Span::styled(" ❯ ", Style::default().fg(palette().muted)));
 
         match app.state {
             crate::app::AppState::Boilerplates => {
                 spans.push(Span::styled(
                     " Boilerplates ",
-                    Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+                    Style::default().fg(palette().base).bg(palette().iris),
                 ));
             }
             crate::app::AppState::Marketplace => {
                 spans.push(Span::styled(
                     " Marketplace ",
-                    Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+                    Style::default().fg(palette().base).bg(palette().iris),
                 ));
             }
             crate::app::AppState::CodeSnippets => {
                 spans.push(Span::styled(
                     "  Snippets ",
-                    Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+                    Style::default().fg(palette().base).bg(palette().iris),
                 ));
 
                 // Add the full path for the selected item in the tree view
@@ -223,7 +456,7 @@ fn get_breadcrumbs_with_symbols(app: &mut App) -> Line<'static> {
                             for (_i, (id, name)) in path.iter().rev().enumerate() {
                                 spans.push(Span::styled(
                                     " ❯ ",
-                                    Style::default().fg(RosePine::MUTED),
+                                    Style::default().fg(palette().muted),
                                 ));
 
                                 // Add collapse/expand indicator if this is the selected notebook
@@ -248,9 +481,9 @@ fn get_breadcrumbs_with_symbols(app: &mut App) -> Line<'static> {
                                 };
 
                                 let style = if *id == *notebook_id {
-                                    Style::default().fg(RosePine::BASE).bg(RosePine::LOVE)
+                                    Style::default().fg(palette().base).bg(palette().love)
                                 } else {
-                                    Style::default().fg(RosePine::SUBTLE)
+                                    Style::default().fg(palette().subtle)
                                 };
 
                                 spans.push(Span::styled(notebook_name, style));
@@ -277,21 +510,21 @@ fn get_breadcrumbs_with_symbols(app: &mut App) -> Line<'static> {
                                 for (_id, name) in path.iter().rev() {
                                     spans.push(Span::styled(
                                         " ❯ ",
-                                        Style::default().fg(RosePine::MUTED),
+                                        Style::default().fg(palette().muted),
                                     ));
                                     spans.push(Span::styled(
                                         format!("   {} ", name),
-                                        Style::default().fg(RosePine::SUBTLE),
+                                        Style::default().fg(palette().subtle),
                                     ));
                                 }
 
                                 spans.push(Span::styled(
                                     " ❯ ",
-                                    Style::default().fg(RosePine::MUTED),
+                                    Style::default().fg(palette().muted),
                                 ));
                                 spans.push(Span::styled(
                                     format!(" {} {} ", snippet.language.icon(), snippet.title),
-                                    Style::default().fg(RosePine::BASE).bg(RosePine::GOLD),
+                                    Style::default().fg(palette().base).bg(palette().gold),
                                 ));
                             }
                         }
@@ -301,68 +534,98 @@ fn get_breadcrumbs_with_symbols(app: &mut App) -> Line<'static> {
             crate::app::AppState::ExportImport => {
                 spans.push(Span::styled(
                     " Export/Import ",
-                    Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+                    Style::default().fg(palette().base).bg(palette().iris),
                 ));
 
                 // Add mode-specific breadcrumb
                 if let Some(export_state) = &app.export_import_state {
                     use crate::ui::export_import::ExportImportMode;
 
-                    spans.push(Span::styled(" ❯ ", Style::default().fg(RosePine::MUTED)));
+                    spans.push(Span::styled(" ❯ ", Style::default().fg(palette().muted)));
 
                     match export_state.mode {
                         ExportImportMode::MainMenu => {
                             spans.push(Span::styled(
                                 " 󰍜 Menu ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::ExportOptions => {
                             spans.push(Span::styled(
                                 " 󰥞 Export Options ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
+                            ));
+                        }
+                        ExportImportMode::ExportTags => {
+                            spans.push(Span::styled(
+                                " 󰓹 Export Tags ",
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::ExportPath => {
                             spans.push(Span::styled(
                                 "  Export Path ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::ImportOptions => {
                             spans.push(Span::styled(
                                 " 󰥝 Import Options ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::_ImportPath => {
                             spans.push(Span::styled(
                                 "  Import Path ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::ImportClipboard => {
                             spans.push(Span::styled(
                                 "  Import from Clipboard ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::Exporting => {
                             spans.push(Span::styled(
                                 "  Exporting... ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::Importing => {
                             spans.push(Span::styled(
                                 " 󰋺 Importing... ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                         ExportImportMode::ImportPathPopup => {
                             spans.push(Span::styled(
                                 "  Import File ",
-                                Style::default().fg(RosePine::BASE).bg(RosePine::LOVE),
+                                Style::default().fg(palette().base).bg(palette().love),
+                            ));
+                        }
+                        ExportImportMode::ImportNotebook => {
+                            spans.push(Span::styled(
+                                " 󰠮 Import Into ",
+                                Style::default().fg(palette().base).bg(palette().love),
+                            ));
+                        }
+                        ExportImportMode::ImportGist => {
+                            spans.push(Span::styled(
+                                "  Import Gist ",
+                                Style::default().fg(palette().base).bg(palette().love),
+                            ));
+                        }
+                        ExportImportMode::Passphrase => {
+                            spans.push(Span::styled(
+                                " 󰍁 Passphrase ",
+                                Style::default().fg(palette().base).bg(palette().love),
+                            ));
+                        }
+                        ExportImportMode::ResolveConflicts => {
+                            spans.push(Span::styled(
+                                " 󰮱 Conflicts ",
+                                Style::default().fg(palette().base).bg(palette().love),
                             ));
                         }
                     }
@@ -371,13 +634,37 @@ fn get_breadcrumbs_with_symbols(app: &mut App) -> Line<'static> {
             crate::app::AppState::InfoPage => {
                 spans.push(Span::styled(
                     " 󱁯 Info ",
-                    Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+                    Style::default().fg(palette().base).bg(palette().iris),
                 ));
             }
             crate::app::AppState::Settings => {
                 spans.push(Span::styled(
                     "  Settings ",
-                    Style::default().fg(RosePine::BASE).bg(RosePine::IRIS),
+                    Style::default().fg(palette().base).bg(palette().iris),
+                ));
+            }
+            crate::app::AppState::Trash => {
+                spans.push(Span::styled(
+                    " 󰩺 Trash ",
+                    Style::default().fg(palette().base).bg(palette().iris),
+                ));
+            }
+            crate::app::AppState::Statistics => {
+                spans.push(Span::styled(
+                    " 󰄨 Statistics ",
+                    Style::default().fg(palette().base).bg(palette().iris),
+                ));
+            }
+            crate::app::AppState::Tags => {
+                spans.push(Span::styled(
+                    " 󰓹 Tags ",
+                    Style::default().fg(palette().base).bg(palette().iris),
+                ));
+            }
+            crate::app::AppState::Recent => {
+                spans.push(Span::styled(
+                    " ⏱ Recent ",
+                    Style::default().fg(palette().base).bg(palette().iris),
                 ));
             }
             _ => {}
@@ -393,7 +680,7 @@ pub fn render_wip_dialog(frame: &mut Frame, area: Rect, page_title: &str, app: &
         .title(format!(" {} ", page_title))
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(area);
     block.render(area, frame.buffer_mut());
@@ -418,21 +705,21 @@ pub fn render_wip_dialog(frame: &mut Frame, area: Rect, page_title: &str, app: &
         Line::from(vec![Span::styled("", Style::default())]),
         Line::from(vec![Span::styled(
             "          WORK IN PROGRESS  ⚠️",
-            Style::default().fg(RosePine::GOLD).bold(),
+            Style::default().fg(palette().gold).bold(),
         )]),
         Line::from(vec![Span::styled("", Style::default())]),
         Line::from(vec![Span::styled(
             "    This page is currently under development.",
-            Style::default().fg(RosePine::TEXT),
+            Style::default().fg(palette().text),
         )]),
         Line::from(vec![Span::styled(
             "      Please check back later for updates!",
-            Style::default().fg(RosePine::SUBTLE),
+            Style::default().fg(palette().subtle),
         )]),
         Line::from(vec![Span::styled("", Style::default())]),
         Line::from(vec![Span::styled(
             "        Press [←] or [Esc] to go back",
-            Style::default().fg(RosePine::FOAM),
+            Style::default().fg(palette().foam),
         )]),
         Line::from(vec![Span::styled("", Style::default())]),
     ];
@@ -444,12 +731,110 @@ pub fn render_wip_dialog(frame: &mut Frame, area: Rect, page_title: &str, app: &
                 .title(" Under Construction 🚧 ")
                 .title_alignment(Alignment::Center)
                 .border_type(BorderType::Double)
-                .style(Style::default().fg(RosePine::LOVE)),
+                .style(Style::default().fg(palette().love)),
         )
-        .style(Style::default().bg(RosePine::SURFACE));
+        .style(Style::default().bg(palette().surface));
 
     Clear.render(dialog_vertical, frame.buffer_mut());
     dialog.render(dialog_vertical, frame.buffer_mut());
 
     render_bottom_bar(frame, chunks[1], app);
 }
+
+/// Smallest terminal the pages are laid out in; anything smaller only gets a resize hint
+pub const MIN_TERMINAL_WIDTH: u16 = 80;
+pub const MIN_TERMINAL_HEIGHT: u16 = 20;
+
+/// Drawn in place of every page while the terminal is below the minimum size
+pub fn render_terminal_too_small(frame: &mut Frame) {
+    let area = frame.area();
+    Clear.render(area, frame.buffer_mut());
+
+    let lines = vec![
+        Line::from(format!(
+            "Terminal too small — resize to at least {}x{}",
+            MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT
+        ))
+        .fg(palette().love)
+        .bold(),
+        Line::from(format!(
+            "Currently {}x{} · q to quit",
+            area.width, area.height
+        ))
+        .fg(palette().muted),
+    ];
+    let [row] = Layout::vertical([Constraint::Length(lines.len() as u16)])
+        .flex(Flex::Center)
+        .areas(area);
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true })
+        .render(row, frame.buffer_mut());
+}
+
+/// Columns a tab takes in snippet text drawn by `sanitize_for_display`
+const DISPLAY_TAB_WIDTH: usize = 4;
+
+/// Makes stored snippet text safe to draw in a widget without changing what is saved.
+///
+/// Escape sequences such as ANSI colors in captured terminal output are dropped, tabs
+/// become spaces, carriage returns are removed and any other control character is shown
+/// in caret notation (`^G`), so nothing in a snippet can reach the terminal as a command.
+pub fn sanitize_for_display(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| c.is_control() && c != '\n') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => output.push(c),
+            '\t' => output.push_str(&" ".repeat(DISPLAY_TAB_WIDTH)),
+            '\r' => {}
+            '\u{1b}' => skip_escape_sequence(&mut chars),
+            // The single-character form of `ESC [`
+            '\u{9b}' => skip_csi(&mut chars),
+            '\u{7f}' => output.push_str("^?"),
+            c if (c as u32) < 0x20 => {
+                output.push('^');
+                output.push(char::from(b'@' + c as u8));
+            }
+            c if c.is_control() => output.push(char::REPLACEMENT_CHARACTER),
+            c => output.push(c),
+        }
+    }
+    Cow::Owned(output)
+}
+
+/// Consumes the rest of a sequence that started with ESC; a line break after it is kept
+fn skip_escape_sequence(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    match chars.next_if(|c| !c.is_control()) {
+        Some('[') => skip_csi(chars),
+        // OSC, DCS, SOS, PM and APC strings run until BEL or the string terminator
+        Some(']' | 'P' | 'X' | '^' | '_') => {
+            while let Some(c) = chars.next() {
+                match c {
+                    '\u{7}' | '\u{9c}' => break,
+                    '\u{1b}' => {
+                        chars.next_if_eq(&'\\');
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        // Intermediate bytes followed by a final byte, e.g. `ESC ( B`
+        Some(' '..='/') => {
+            while chars.next_if(|c| matches!(c, ' '..='/')).is_some() {}
+            chars.next_if(|c| matches!(c, '0'..='~'));
+        }
+        _ => {}
+    }
+}
+
+/// Consumes the parameters and final byte of a CSI sequence such as `[1;31m`
+fn skip_csi(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while chars.next_if(|c| matches!(c, ' '..='?')).is_some() {}
+    chars.next_if(|c| matches!(c, '@'..='~'));
+}
@@ -1,7 +1,10 @@
 use crate::app::App;
-use crate::models::ExportFormat;
-use crate::ui::colors::RosePine;
-use crate::ui::components::render_bottom_bar;
+use crate::models::export::{
+    ConflictKind, ConflictReason, ConflictResolution, ExportData, ImportConflict,
+};
+use crate::models::{ExportFormat, TagManager};
+use crate::ui::colors::palette;
+use crate::ui::components::{render_bottom_bar, render_text_input};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -9,7 +12,9 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Widget},
 };
+use std::collections::HashMap;
 use std::path::PathBuf;
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct ExportImportState {
@@ -20,7 +25,25 @@ pub struct ExportImportState {
     pub selected_option: usize,
     pub include_content: bool,
     pub favorites_only: bool,
+    /// Only export snippets carrying one of these tags, all snippets when empty
+    pub export_tags: Vec<String>,
+    /// Cursor in the export tag picker
+    pub tag_cursor: usize,
     pub overwrite_existing: bool,
+    /// What the import path points at
+    pub import_source: ImportSource,
+    /// Cursor in the notebook picker of a VS Code snippet import
+    pub notebook_cursor: usize,
+    /// Encrypt the export with a passphrase
+    pub encrypt: bool,
+    /// Whether the passphrase prompt unlocks an import rather than an export
+    pub passphrase_for_import: bool,
+    /// Import waiting for its conflicts to be resolved
+    pub pending_import: Option<ExportData>,
+    pub conflicts: Vec<ImportConflict>,
+    pub conflict_index: usize,
+    /// Answers so far, keyed by the id of the imported item
+    pub resolutions: HashMap<Uuid, ConflictResolution>,
     pub status_message: Option<String>,
     pub is_error: bool,
 }
@@ -35,17 +58,49 @@ impl Default for ExportImportState {
             selected_option: 0,
             include_content: true,
             favorites_only: false,
+            export_tags: Vec::new(),
+            tag_cursor: 0,
             overwrite_existing: false,
+            import_source: ImportSource::ExportFile,
+            notebook_cursor: 0,
+            encrypt: false,
+            passphrase_for_import: false,
+            pending_import: None,
+            conflicts: Vec::new(),
+            conflict_index: 0,
+            resolutions: HashMap::new(),
             status_message: None,
             is_error: false,
         }
     }
 }
 
+impl ExportImportState {
+    /// Whether the current mode is a text field, where typed keys must not trigger shortcuts
+    pub fn takes_text(&self) -> bool {
+        matches!(
+            self.mode,
+            ExportImportMode::ExportPath
+                | ExportImportMode::ImportPathPopup
+                | ExportImportMode::ImportGist
+                | ExportImportMode::Passphrase
+        )
+    }
+}
+
+/// Kinds of file or folder the import path popup accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportSource {
+    ExportFile,
+    SourceFolder,
+    VsCodeSnippets,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExportImportMode {
     MainMenu,
     ExportOptions,
+    ExportTags,
     ExportPath,
     ImportOptions,
     _ImportPath,
@@ -53,6 +108,11 @@ pub enum ExportImportMode {
     Exporting,
     Importing,
     ImportPathPopup,
+    /// Choosing the notebook that receives VS Code snippets
+    ImportNotebook,
+    ImportGist,
+    Passphrase,
+    ResolveConflicts,
 }
 
 /// Main render function for the export/import page
@@ -71,7 +131,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         .title(" Export & Import Manager ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(main_area);
     block.render(main_area, frame.buffer_mut());
@@ -82,6 +142,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     match current_mode {
         ExportImportMode::MainMenu => render_main_menu(frame, chunks[0], app),
         ExportImportMode::ExportOptions => render_export_options(frame, chunks[0], app),
+        ExportImportMode::ExportTags => render_export_tags(frame, chunks[0], app),
         ExportImportMode::ExportPath => {
             render_export_options(frame, chunks[0], app);
             render_export_path(frame, main_area, app);
@@ -89,8 +150,24 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         ExportImportMode::ImportOptions => render_import_options(frame, chunks[0], app),
         ExportImportMode::_ImportPath => render_import_path(frame, chunks[0], app),
         ExportImportMode::ImportClipboard => render_import_clipboard(frame, chunks[0], app),
-        ExportImportMode::Exporting => render_exporting(frame, chunks[0], app),
-        ExportImportMode::Importing => render_importing(frame, chunks[0], app),
+        ExportImportMode::ImportNotebook => render_import_notebook(frame, chunks[0], app),
+        ExportImportMode::ImportGist => render_import_gist(frame, chunks[0], app),
+        ExportImportMode::Passphrase => render_passphrase(frame, chunks[0], app),
+        ExportImportMode::ResolveConflicts => render_resolve_conflicts(frame, chunks[0], app),
+        ExportImportMode::Exporting => render_transfer(
+            frame,
+            chunks[0],
+            app,
+            "Exporting...",
+            "Exporting your snippets and notebooks...",
+        ),
+        ExportImportMode::Importing => render_transfer(
+            frame,
+            chunks[0],
+            app,
+            "Importing...",
+            "Importing snippets and notebooks...",
+        ),
         ExportImportMode::ImportPathPopup => {
             render_main_menu(frame, chunks[0], app);
             render_import_path_popup(frame, main_area, app);
@@ -117,18 +194,18 @@ fn render_main_menu(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let content_chunks = Layout::vertical([
         Constraint::Length(3),
-        Constraint::Length(10),
+        Constraint::Length(13),
         Constraint::Fill(1),
     ])
     .split(content_area);
 
     let title = Paragraph::new("Export & Import Snippets and Notebooks")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .style(Style::default().fg(palette().gold).bold());
 
     title.render(content_chunks[0], frame.buffer_mut());
 
-    let menu_items = vec![
+    let menu_items = [
         (
             "  [E] Export to File",
             "Export snippets and notebooks to JSON, YAML, or TOML format",
@@ -141,6 +218,10 @@ fn render_main_menu(frame: &mut Frame, area: Rect, app: &mut App) {
             "  [C] Import from Clipboard",
             "Import snippets and notebooks from clipboard",
         ),
+        (
+            "  [G] Import from GitHub Gist",
+            "Pull every file of a Gist into the current notebook",
+        ),
     ];
 
     let list_items: Vec<ListItem> = menu_items
@@ -148,9 +229,9 @@ fn render_main_menu(frame: &mut Frame, area: Rect, app: &mut App) {
         .enumerate()
         .map(|(i, (name, desc))| {
             let style = if i == export_import_state.selected_option {
-                Style::default().fg(RosePine::LOVE).bold()
+                Style::default().fg(palette().love).bold()
             } else {
-                Style::default().fg(RosePine::TEXT)
+                Style::default().fg(palette().text)
             };
 
             let prefix = if i == export_import_state.selected_option {
@@ -163,7 +244,7 @@ fn render_main_menu(frame: &mut Frame, area: Rect, app: &mut App) {
 
             let desc_line = Line::from(vec![Span::styled(
                 format!("   {}", desc),
-                Style::default().fg(RosePine::SUBTLE),
+                Style::default().fg(palette().subtle),
             )]);
 
             ListItem::new(vec![title_line, desc_line, Line::from("")])
@@ -174,8 +255,8 @@ fn render_main_menu(frame: &mut Frame, area: Rect, app: &mut App) {
         .block(Block::default())
         .highlight_style(
             Style::default()
-                .fg(RosePine::LOVE)
-                .bg(RosePine::HIGHLIGHT_LOW)
+                .fg(palette().love)
+                .bg(palette().highlight_low)
                 .bold(),
         );
 
@@ -199,7 +280,7 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let content_chunks = Layout::vertical([
         Constraint::Length(3),
-        Constraint::Length(12),
+        Constraint::Length(18),
         Constraint::Fill(1),
     ])
     .split(content_area);
@@ -208,10 +289,12 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
         ExportFormat::JSON => "JSON",
         ExportFormat::YAML => "YAML",
         ExportFormat::TOML => "TOML",
+        ExportFormat::CSV => "CSV",
+        ExportFormat::Directory => "Directory",
     };
     let title = Paragraph::new(format!("Export Options (Format: {})", format_name))
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .style(Style::default().fg(palette().gold).bold());
 
     title.render(content_chunks[0], frame.buffer_mut());
 
@@ -221,10 +304,26 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
             ExportFormat::JSON => "JSON",
             ExportFormat::YAML => "YAML",
             ExportFormat::TOML => "TOML",
+            ExportFormat::CSV => "CSV",
+            ExportFormat::Directory => "Directory",
         }
     );
 
-    let options = vec![
+    let tags_label = if export_import_state.export_tags.is_empty() {
+        "Filter by tags (current: all snippets)".to_string()
+    } else {
+        format!(
+            "Filter by tags (current: {})",
+            export_import_state
+                .export_tags
+                .iter()
+                .map(|tag| format!("#{}", tag))
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    };
+
+    let options = [
         (
             "Include snippet content",
             export_import_state.include_content,
@@ -235,6 +334,16 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
             export_import_state.favorites_only,
             "Only export snippets that are marked as favorites",
         ),
+        (
+            "Encrypt with a passphrase",
+            export_import_state.encrypt,
+            "Write a .snix.enc file sealed with AES-GCM",
+        ),
+        (
+            tags_label.as_str(),
+            true,
+            "Only export snippets carrying one of the chosen tags",
+        ),
         (
             format_label.as_str(),
             true,
@@ -252,9 +361,9 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
         .enumerate()
         .map(|(i, (name, enabled, desc))| {
             let style = if i == export_import_state.selected_option {
-                Style::default().fg(RosePine::LOVE).bold()
+                Style::default().fg(palette().love).bold()
             } else {
-                Style::default().fg(RosePine::TEXT)
+                Style::default().fg(palette().text)
             };
 
             let prefix = if i == export_import_state.selected_option {
@@ -263,7 +372,7 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
                 " "
             };
 
-            let checkbox = if i < 2 {
+            let checkbox = if i < 3 {
                 if *enabled { "[✓]" } else { "[ ]" }
             } else {
                 ""
@@ -276,7 +385,7 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
 
             let desc_line = Line::from(vec![Span::styled(
                 format!("   {}", desc),
-                Style::default().fg(RosePine::SUBTLE),
+                Style::default().fg(palette().subtle),
             )]);
 
             ListItem::new(vec![title_line, desc_line, Line::from("")])
@@ -287,8 +396,8 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
         .block(Block::default())
         .highlight_style(
             Style::default()
-                .fg(RosePine::LOVE)
-                .bg(RosePine::HIGHLIGHT_LOW)
+                .fg(palette().love)
+                .bg(palette().highlight_low)
                 .bold(),
         );
 
@@ -298,6 +407,152 @@ fn render_export_options(frame: &mut Frame, area: Rect, app: &mut App) {
     frame.render_stateful_widget(options_list, content_chunks[1], &mut list_state);
 }
 
+/// Tag names offered by the export tag picker, ordered by name
+pub fn export_tag_names(tag_manager: &TagManager) -> Vec<String> {
+    let mut names: Vec<String> = tag_manager
+        .tags
+        .values()
+        .map(|tag| tag.name.clone())
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names
+}
+
+/// Targets offered when importing VS Code snippets, a new notebook first, then by path
+pub fn import_notebook_choices(app: &App) -> Vec<(Option<Uuid>, String)> {
+    let mut notebooks: Vec<(Option<Uuid>, String)> = app
+        .snippet_database
+        .notebooks
+        .keys()
+        .map(|id| (Some(*id), app.notebook_path(*id)))
+        .collect();
+    notebooks.sort_by_key(|(_, path)| path.to_lowercase());
+
+    let new_notebook = match &app.export_import_state {
+        Some(state) => format!(
+            "New notebook \"{}\"",
+            crate::models::vscode::notebook_name(&state.import_path)
+        ),
+        None => "New notebook".to_string(),
+    };
+    notebooks.insert(0, (None, new_notebook));
+    notebooks
+}
+
+// Notebook picker for VS Code snippet imports
+fn render_import_notebook(frame: &mut Frame, area: Rect, app: &mut App) {
+    let cursor = app
+        .export_import_state
+        .as_ref()
+        .map_or(0, |state| state.notebook_cursor);
+
+    let content_area = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(70),
+        Constraint::Fill(1),
+    ])
+    .split(area)[1];
+
+    let content_chunks =
+        Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).split(content_area);
+
+    Paragraph::new("Import VS Code Snippets Into")
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().gold).bold())
+        .render(content_chunks[0], frame.buffer_mut());
+
+    let choices = import_notebook_choices(app);
+    let list_items: Vec<ListItem> = choices
+        .iter()
+        .map(|(id, name)| {
+            let (icon, color) = match id {
+                Some(_) => ("󰠮 ", palette().foam),
+                None => ("+ ", palette().iris),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(icon, Style::default().fg(color)),
+                Span::styled(name.clone(), Style::default().fg(palette().text)),
+            ]))
+        })
+        .collect();
+
+    let notebooks_list = List::new(list_items)
+        .block(Block::default())
+        .highlight_style(Style::default().bg(palette().highlight_low).bold());
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(cursor.min(choices.len() - 1)));
+
+    frame.render_stateful_widget(notebooks_list, content_chunks[1], &mut list_state);
+}
+
+// Tag picker for filtered exports
+fn render_export_tags(frame: &mut Frame, area: Rect, app: &mut App) {
+    let default_state = ExportImportState::default();
+    let export_import_state = app.export_import_state.as_ref().unwrap_or(&default_state);
+
+    let content_area = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(70),
+        Constraint::Fill(1),
+    ])
+    .split(area)[1];
+
+    let content_chunks =
+        Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).split(content_area);
+
+    Paragraph::new("Export Snippets Tagged With")
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().gold).bold())
+        .render(content_chunks[0], frame.buffer_mut());
+
+    let tags = export_tag_names(&app.tag_manager);
+    if tags.is_empty() {
+        Paragraph::new("No tags yet. Tag some snippets to filter exports by tag.")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(palette().muted))
+            .render(content_chunks[1], frame.buffer_mut());
+        return;
+    }
+
+    let list_items: Vec<ListItem> = tags
+        .iter()
+        .map(|tag| {
+            let chosen = export_import_state
+                .export_tags
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(tag));
+            let count = app
+                .snippet_database
+                .snippets
+                .values()
+                .filter(|snippet| snippet.has_tag(tag))
+                .count();
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    if chosen { "[✓] " } else { "[ ] " },
+                    Style::default().fg(palette().foam),
+                ),
+                Span::styled(format!("#{}", tag), Style::default().fg(palette().iris)),
+                Span::styled(
+                    format!("  {} snippets", count),
+                    Style::default().fg(palette().subtle),
+                ),
+            ]))
+        })
+        .collect();
+
+    let tags_list = List::new(list_items)
+        .block(Block::default())
+        .highlight_style(Style::default().bg(palette().highlight_low).bold());
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(export_import_state.tag_cursor.min(tags.len() - 1)));
+
+    frame.render_stateful_widget(tags_list, content_chunks[1], &mut list_state);
+}
+
 // Path input for export
 fn render_export_path(frame: &mut Frame, area: Rect, app: &mut App) {
     let popup_width = 70;
@@ -315,7 +570,7 @@ fn render_export_path(frame: &mut Frame, area: Rect, app: &mut App) {
         .title(" Export Path ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::IRIS));
+        .style(Style::default().fg(palette().iris));
 
     let inner_area = popup_block.inner(popup_area);
     popup_block.render(popup_area, frame.buffer_mut());
@@ -332,28 +587,33 @@ fn render_export_path(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let header = Paragraph::new("Enter the export file path:")
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::TEXT).bold());
+        .style(Style::default().fg(palette().text).bold());
     header.render(chunks[0], frame.buffer_mut());
 
     // Path input field
     let input_block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::FOAM));
+        .style(Style::default().fg(palette().foam));
 
     // Get the inner area before rendering
     let input_area = input_block.inner(chunks[1]);
     input_block.render(chunks[1], frame.buffer_mut());
 
-    let input_text = Paragraph::new(app.input_buffer.as_str())
-        .style(Style::default().fg(RosePine::TEXT))
-        .alignment(Alignment::Left);
-    input_text.render(input_area, frame.buffer_mut());
+    render_text_input(
+        frame,
+        input_area,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
 
     let export_state = app.export_import_state.as_ref().unwrap();
-    let formats = vec![
+    let formats = [
         ("JSON", ExportFormat::JSON),
         ("YAML", ExportFormat::YAML),
         ("TOML", ExportFormat::TOML),
+        ("CSV", ExportFormat::CSV),
+        ("Folder", ExportFormat::Directory),
     ];
 
     let format_area = chunks[3];
@@ -370,11 +630,11 @@ fn render_export_path(frame: &mut Frame, area: Rect, app: &mut App) {
         let is_selected = export_state.export_format == *format;
         let style = if is_selected {
             Style::default()
-                .fg(RosePine::BASE)
-                .bg(RosePine::IRIS)
+                .fg(palette().base)
+                .bg(palette().iris)
                 .bold()
         } else {
-            Style::default().fg(RosePine::TEXT)
+            Style::default().fg(palette().text)
         };
 
         let format_text = format!(" {} ", label);
@@ -398,13 +658,13 @@ fn render_export_path(frame: &mut Frame, area: Rect, app: &mut App) {
     let instructions_text = instructions.join("\n");
     let instructions_para = Paragraph::new(instructions_text)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     instructions_para.render(chunks[5], frame.buffer_mut());
 
     let status_text = "Tab: Change Format • Enter: Confirm • Esc: Back";
     let status = Paragraph::new(status_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
     status.render(chunks[6], frame.buffer_mut());
 }
 
@@ -422,7 +682,7 @@ fn render_import_options(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let content_chunks = Layout::vertical([
         Constraint::Length(3),
-        Constraint::Length(9),
+        Constraint::Length(12),
         Constraint::Fill(1),
     ])
     .split(content_area);
@@ -430,22 +690,32 @@ fn render_import_options(frame: &mut Frame, area: Rect, app: &mut App) {
     // Title
     let title = Paragraph::new("Import Options")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .style(Style::default().fg(palette().gold).bold());
 
     title.render(content_chunks[0], frame.buffer_mut());
 
     // Options
-    let options = vec![
+    let options = [
         (
             "Overwrite existing snippets and notebooks",
             export_import_state.overwrite_existing,
-            "Replace snippets and notebooks with the same ID",
+            "Replace colliding items instead of asking about each conflict",
         ),
         (
             "Continue to select import file",
             true,
             "Proceed to select the file to import",
         ),
+        (
+            "Import a folder of source files",
+            true,
+            "One notebook per folder, one snippet per recognized file",
+        ),
+        (
+            "Import VS Code snippets",
+            true,
+            "A .code-snippets or per-language .json file, prefixes become tags",
+        ),
     ];
 
     let list_items: Vec<ListItem> = options
@@ -453,9 +723,9 @@ fn render_import_options(frame: &mut Frame, area: Rect, app: &mut App) {
         .enumerate()
         .map(|(i, (name, enabled, desc))| {
             let style = if i == export_import_state.selected_option {
-                Style::default().fg(RosePine::LOVE).bold()
+                Style::default().fg(palette().love).bold()
             } else {
-                Style::default().fg(RosePine::TEXT)
+                Style::default().fg(palette().text)
             };
 
             let prefix = if i == export_import_state.selected_option {
@@ -477,7 +747,7 @@ fn render_import_options(frame: &mut Frame, area: Rect, app: &mut App) {
 
             let desc_line = Line::from(vec![Span::styled(
                 format!("   {}", desc),
-                Style::default().fg(RosePine::SUBTLE),
+                Style::default().fg(palette().subtle),
             )]);
 
             ListItem::new(vec![title_line, desc_line, Line::from("")])
@@ -488,8 +758,8 @@ fn render_import_options(frame: &mut Frame, area: Rect, app: &mut App) {
         .block(Block::default())
         .highlight_style(
             Style::default()
-                .fg(RosePine::LOVE)
-                .bg(RosePine::HIGHLIGHT_LOW)
+                .fg(palette().love)
+                .bg(palette().highlight_low)
                 .bold(),
         );
 
@@ -518,7 +788,7 @@ fn render_import_path(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let title = Paragraph::new("Import Path")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .style(Style::default().fg(palette().gold).bold());
     title.render(content_chunks[0], frame.buffer_mut());
 
     // Path input field
@@ -526,21 +796,25 @@ fn render_import_path(frame: &mut Frame, area: Rect, app: &mut App) {
         .title(" Enter import file path ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_input_area = input_block.inner(content_chunks[1]);
     input_block.render(content_chunks[1], frame.buffer_mut());
 
-    let input_text = Paragraph::new(&*app.input_buffer).style(Style::default().fg(RosePine::TEXT));
-
-    input_text.render(inner_input_area, frame.buffer_mut());
+    render_text_input(
+        frame,
+        inner_input_area,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
 
     // Default location suggestions
     let suggestion_block = Block::bordered()
         .title(" Suggested files ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_suggestion_area = suggestion_block.inner(content_chunks[2]);
     suggestion_block.render(content_chunks[2], frame.buffer_mut());
@@ -552,14 +826,14 @@ fn render_import_path(frame: &mut Frame, area: Rect, app: &mut App) {
     ];
 
     let suggestion_text =
-        Paragraph::new(suggestions.join("\n")).style(Style::default().fg(RosePine::TEXT));
+        Paragraph::new(suggestions.join("\n")).style(Style::default().fg(palette().text));
 
     suggestion_text.render(inner_suggestion_area, frame.buffer_mut());
 
     // Help text
     let help_text = Paragraph::new("Press Enter to confirm, Esc to cancel")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
 
     help_text.render(content_chunks[3], frame.buffer_mut());
 }
@@ -582,34 +856,34 @@ fn render_import_clipboard(frame: &mut Frame, area: Rect, _app: &mut App) {
 
     let title = Paragraph::new("Import from Clipboard")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .style(Style::default().fg(palette().gold).bold());
     title.render(content_chunks[0], frame.buffer_mut());
 
     // Info message
     let info_block = Block::bordered()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_info_area = info_block.inner(content_chunks[1]);
     info_block.render(content_chunks[1], frame.buffer_mut());
 
     let info_text = Paragraph::new("Press Enter to import from clipboard, Esc to cancel")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
 
     info_text.render(inner_info_area, frame.buffer_mut());
 
     // Help text
     let help_text = Paragraph::new("The clipboard should contain a valid JSON or YAML export")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
 
     help_text.render(content_chunks[2], frame.buffer_mut());
 }
 
-/// Render the exporting status screen
-fn render_exporting(frame: &mut Frame, area: Rect, _app: &mut App) {
+/// Render the Gist import screen
+fn render_import_gist(frame: &mut Frame, area: Rect, app: &mut App) {
     let content_area = Layout::horizontal([
         Constraint::Fill(1),
         Constraint::Length(70),
@@ -619,36 +893,110 @@ fn render_exporting(frame: &mut Frame, area: Rect, _app: &mut App) {
 
     let content_chunks = Layout::vertical([
         Constraint::Length(3),
-        Constraint::Length(5),
+        Constraint::Length(3),
         Constraint::Fill(1),
     ])
     .split(content_area);
 
-    // Title
-    let title = Paragraph::new("Exporting...")
+    let title = Paragraph::new("Import from GitHub Gist")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .style(Style::default().fg(palette().gold).bold());
+    title.render(content_chunks[0], frame.buffer_mut());
+
+    let input_block = Block::bordered()
+        .title(" Gist URL or id ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().subtle));
+
+    let inner_input_area = input_block.inner(content_chunks[1]);
+    input_block.render(content_chunks[1], frame.buffer_mut());
+
+    render_text_input(
+        frame,
+        inner_input_area,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
+
+    let help_text = Paragraph::new(vec![
+        Line::from("Press Enter to import, Esc to cancel"),
+        Line::from(""),
+        Line::from("A github_token in ~/.snix/config.toml is used when present"),
+    ])
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(palette().muted));
+    help_text.render(content_chunks[2], frame.buffer_mut());
+}
+
+/// Render the passphrase prompt for encrypted exports and imports
+fn render_passphrase(frame: &mut Frame, area: Rect, app: &mut App) {
+    let for_import = app
+        .export_import_state
+        .as_ref()
+        .is_some_and(|state| state.passphrase_for_import);
 
+    let content_area = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(70),
+        Constraint::Fill(1),
+    ])
+    .split(area)[1];
+
+    let content_chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Fill(1),
+    ])
+    .split(content_area);
+
+    let title = Paragraph::new(if for_import {
+        "Unlock Encrypted Export"
+    } else {
+        "Encrypt Export"
+    })
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(palette().gold).bold());
     title.render(content_chunks[0], frame.buffer_mut());
 
-    // Status message
-    let status_block = Block::bordered()
+    let input_block = Block::bordered()
+        .title(" Passphrase ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
-    let inner_status_area = status_block.inner(content_chunks[1]);
-    status_block.render(content_chunks[1], frame.buffer_mut());
+    let inner_input_area = input_block.inner(content_chunks[1]);
+    input_block.render(content_chunks[1], frame.buffer_mut());
 
-    let status_text = Paragraph::new("Exporting your snippets and notebooks...")
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::TEXT));
+    // Never echo the passphrase itself
+    render_text_input(
+        frame,
+        inner_input_area,
+        &"•".repeat(app.input_buffer.chars().count()),
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
 
-    status_text.render(inner_status_area, frame.buffer_mut());
+    let help = if for_import {
+        "Press Enter to decrypt and import, Esc to cancel"
+    } else {
+        "Press Enter to encrypt and export, Esc to cancel"
+    };
+    Paragraph::new(help)
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().muted))
+        .render(content_chunks[2], frame.buffer_mut());
 }
 
-/// Render the importing status screen
-fn render_importing(frame: &mut Frame, area: Rect, _app: &mut App) {
+/// Render the prompt for the current import conflict
+fn render_resolve_conflicts(frame: &mut Frame, area: Rect, app: &mut App) {
+    let default_state = ExportImportState::default();
+    let state = app.export_import_state.as_ref().unwrap_or(&default_state);
+    let Some(conflict) = state.conflicts.get(state.conflict_index) else {
+        return;
+    };
+
     let content_area = Layout::horizontal([
         Constraint::Fill(1),
         Constraint::Length(70),
@@ -658,31 +1006,142 @@ fn render_importing(frame: &mut Frame, area: Rect, _app: &mut App) {
 
     let content_chunks = Layout::vertical([
         Constraint::Length(3),
-        Constraint::Length(5),
+        Constraint::Length(6),
+        Constraint::Length(1),
         Constraint::Fill(1),
     ])
     .split(content_area);
 
-    let title = Paragraph::new("Importing...")
+    Paragraph::new(format!(
+        "Resolve Import Conflicts ({} of {})",
+        state.conflict_index + 1,
+        state.conflicts.len()
+    ))
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(palette().gold).bold())
+    .render(content_chunks[0], frame.buffer_mut());
+
+    let kind = match conflict.kind {
+        ConflictKind::Notebook => "Notebook",
+        ConflictKind::Snippet => "Snippet",
+    };
+    let reason = match conflict.reason {
+        ConflictReason::SameId => "has the same id as an existing item",
+        ConflictReason::SameName => "has the same name as an item in the same notebook",
+    };
+
+    let details = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(format!("{} ", kind), Style::default().fg(palette().iris)),
+            Span::styled(
+                format!("'{}'", conflict.imported_name),
+                Style::default().fg(palette().text).bold(),
+            ),
+        ]),
+        Line::from(Span::styled(reason, Style::default().fg(palette().subtle))),
+        Line::from(vec![
+            Span::styled("Existing: ", Style::default().fg(palette().muted)),
+            Span::styled(
+                conflict.existing_name.clone(),
+                Style::default().fg(palette().text),
+            ),
+        ]),
+    ])
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(palette().subtle)),
+    );
+    details.render(content_chunks[1], frame.buffer_mut());
+
+    let choices = Line::from(vec![
+        Span::styled("k", Style::default().fg(palette().gold).bold()),
+        Span::styled(" Keep existing   ", Style::default().fg(palette().text)),
+        Span::styled("o", Style::default().fg(palette().gold).bold()),
+        Span::styled(" Overwrite   ", Style::default().fg(palette().text)),
+        Span::styled("b", Style::default().fg(palette().gold).bold()),
+        Span::styled(" Keep both", Style::default().fg(palette().text)),
+    ]);
+    Paragraph::new(choices)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+        .render(content_chunks[2], frame.buffer_mut());
 
-    title.render(content_chunks[0], frame.buffer_mut());
+    Paragraph::new(vec![
+        Line::from(""),
+        Line::from("Shift + key applies the choice to all remaining conflicts"),
+        Line::from("Keep both imports the item as a copy named \"(imported)\""),
+        Line::from("Esc cancels the import without changing anything"),
+    ])
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(palette().muted))
+    .render(content_chunks[3], frame.buffer_mut());
+}
+
+/// Render the exporting/importing status screen with a spinner while the transfer runs
+fn render_transfer(frame: &mut Frame, area: Rect, app: &App, title: &str, status: &str) {
+    let content_area = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(70),
+        Constraint::Fill(1),
+    ])
+    .split(area)[1];
+
+    let content_chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(6),
+        Constraint::Fill(1),
+    ])
+    .split(content_area);
+
+    Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().gold).bold())
+        .render(content_chunks[0], frame.buffer_mut());
 
     // Status message
     let status_block = Block::bordered()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_status_area = status_block.inner(content_chunks[1]);
     status_block.render(content_chunks[1], frame.buffer_mut());
 
-    let status_text = Paragraph::new("Importing snippets and notebooks...")
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::TEXT));
+    let mut lines = Vec::new();
+    match &app.transfer_job {
+        Some(job) => {
+            let loading_chars = ["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"];
+            let animation_char = loading_chars[job.animation_frame % loading_chars.len()];
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ", animation_char),
+                    Style::default().fg(palette().iris).bold(),
+                ),
+                Span::styled(status.to_string(), Style::default().fg(palette().text)),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} items processed · {}s elapsed",
+                    job.processed(),
+                    job.elapsed_secs()
+                ),
+                Style::default().fg(palette().muted),
+            )));
+            lines.push(Line::from(Span::styled(
+                "Esc cancels · q cancels and quits",
+                Style::default().fg(palette().muted),
+            )));
+        }
+        None => lines.push(Line::from(Span::styled(
+            status.to_string(),
+            Style::default().fg(palette().text),
+        ))),
+    }
 
-    status_text.render(inner_status_area, frame.buffer_mut());
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .render(inner_status_area, frame.buffer_mut());
 }
 
 /// Render the import path as a popup overlay
@@ -706,7 +1165,7 @@ fn render_import_path_popup(frame: &mut Frame, area: Rect, app: &mut App) {
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = popup_block.inner(popup_area);
     popup_block.render(popup_area, frame.buffer_mut());
@@ -720,9 +1179,18 @@ fn render_import_path_popup(frame: &mut Frame, area: Rect, app: &mut App) {
     ])
     .split(inner_area);
 
-    let title = Paragraph::new("Select file to import")
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::GOLD).bold());
+    let source = app
+        .export_import_state
+        .as_ref()
+        .map_or(ImportSource::ExportFile, |state| state.import_source);
+
+    let title = Paragraph::new(match source {
+        ImportSource::ExportFile => "Select file to import",
+        ImportSource::SourceFolder => "Select folder of source files to import",
+        ImportSource::VsCodeSnippets => "Select VS Code snippet file to import",
+    })
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(palette().gold).bold());
     title.render(chunks[0], frame.buffer_mut());
 
     // Input field
@@ -730,20 +1198,25 @@ fn render_import_path_popup(frame: &mut Frame, area: Rect, app: &mut App) {
         .title(" Enter import file path ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_input_area = input_block.inner(chunks[1]);
     input_block.render(chunks[1], frame.buffer_mut());
 
-    let input_text = Paragraph::new(&*app.input_buffer).style(Style::default().fg(RosePine::TEXT));
-    input_text.render(inner_input_area, frame.buffer_mut());
+    render_text_input(
+        frame,
+        inner_input_area,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
 
     // Autocompletion suggestions
     let suggestions_block = Block::bordered()
         .title(" Autocompletion ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_suggestions_area = suggestions_block.inner(chunks[2]);
     suggestions_block.render(chunks[2], frame.buffer_mut());
@@ -794,7 +1267,7 @@ fn render_import_path_popup(frame: &mut Frame, area: Rect, app: &mut App) {
 
                     if is_dir {
                         completion_suggestions.push(format!("{}/", path));
-                    } else if name.ends_with(".json") {
+                    } else if name.ends_with(".json") || name.ends_with(".snix.enc") {
                         completion_suggestions.push(path);
                     }
                 }
@@ -805,13 +1278,13 @@ fn render_import_path_popup(frame: &mut Frame, area: Rect, app: &mut App) {
     let items: Vec<String> = completion_suggestions.into_iter().take(8).collect();
 
     let suggestions_text =
-        Paragraph::new(items.join("\n")).style(Style::default().fg(RosePine::TEXT));
+        Paragraph::new(items.join("\n")).style(Style::default().fg(palette().text));
 
     suggestions_text.render(inner_suggestions_area, frame.buffer_mut());
 
     let help_text = Paragraph::new("Press Enter to import, Esc to cancel, Tab to autocomplete")
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_text.render(chunks[3], frame.buffer_mut());
 }
 
@@ -830,9 +1303,9 @@ fn render_status_message(frame: &mut Frame, area: Rect, message: &str, is_error:
     Clear.render(popup_area, frame.buffer_mut());
 
     let (icon, color) = if is_error {
-        ("✗", RosePine::LOVE)
+        ("✗", palette().love)
     } else {
-        ("✓", RosePine::FOAM)
+        ("✓", palette().foam)
     };
 
     let block = Block::bordered()
@@ -851,11 +1324,11 @@ fn render_status_message(frame: &mut Frame, area: Rect, message: &str, is_error:
         )]),
         Line::from(vec![Span::styled(
             message,
-            Style::default().fg(RosePine::TEXT),
+            Style::default().fg(palette().text),
         )]),
         Line::from(vec![Span::styled(
             "Press any key to continue",
-            Style::default().fg(RosePine::MUTED),
+            Style::default().fg(palette().muted),
         )]),
     ];
 
@@ -1,5 +1,5 @@
 use crate::app::App;
-use crate::ui::colors::RosePine;
+use crate::ui::colors::palette;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -31,12 +31,21 @@ pub fn render_floating_favorites(frame: &mut Frame, app: &App) {
         .title(" ★ Favorites ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::LOVE));
+        .style(Style::default().fg(palette().love));
 
     let inner_area = popup_block.inner(popup_area);
     popup_block.render(popup_area, frame.buffer_mut());
 
-    // Get favorited snippets
+    // Get favorited notebooks and snippets
+    let mut favorite_notebooks: Vec<_> = app
+        .snippet_database
+        .notebooks
+        .values()
+        .filter(|n| n.is_favorite)
+        .map(|n| (app.notebook_path(n.id), n))
+        .collect();
+    favorite_notebooks.sort_by_key(|(path, _)| path.to_lowercase());
+
     let favorite_snippets: Vec<_> = app
         .snippet_database
         .snippets
@@ -44,17 +53,18 @@ pub fn render_floating_favorites(frame: &mut Frame, app: &App) {
         .filter(|s| s.is_favorited())
         .collect();
 
-    if favorite_snippets.is_empty() {
-        let no_favorites =
-            Paragraph::new("No favorites yet. Press 'f' on a snippet to mark it as a favorite.")
-                .alignment(Alignment::Center)
-                .style(Style::default().fg(RosePine::MUTED));
+    if favorite_notebooks.is_empty() && favorite_snippets.is_empty() {
+        let no_favorites = Paragraph::new(
+            "No favorites yet. Press 'f' on a snippet or notebook to mark it as a favorite.",
+        )
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().muted));
         no_favorites.render(inner_area, frame.buffer_mut());
 
         let help_text = "Press Esc to close";
         let help_paragraph = Paragraph::new(help_text)
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::MUTED));
+            .style(Style::default().fg(palette().muted));
 
         let chunks =
             Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(inner_area);
@@ -65,41 +75,63 @@ pub fn render_floating_favorites(frame: &mut Frame, app: &App) {
 
     // Create a table with favorites
     let header = Row::new(vec![
-        Cell::from("Title").style(Style::default().fg(RosePine::IRIS).bold()),
-        Cell::from("Language").style(Style::default().fg(RosePine::IRIS).bold()),
-        Cell::from("Description").style(Style::default().fg(RosePine::IRIS).bold()),
-        Cell::from("Tags").style(Style::default().fg(RosePine::IRIS).bold()),
+        Cell::from("Title").style(Style::default().fg(palette().iris).bold()),
+        Cell::from("Language").style(Style::default().fg(palette().iris).bold()),
+        Cell::from("Description").style(Style::default().fg(palette().iris).bold()),
+        Cell::from("Tags").style(Style::default().fg(palette().iris).bold()),
     ]);
 
-    let rows: Vec<Row> = favorite_snippets
-        .iter()
-        .map(|snippet| {
-            let tags_display = if snippet.tags.is_empty() {
-                "-".to_string()
-            } else {
-                snippet.get_tags_display_string()
-            };
-
-            let description = snippet.description.clone().unwrap_or_default();
-            let truncated_desc = if description.len() > 40 {
-                format!("{}...", &description[..37])
-            } else {
-                description
-            };
-
-            Row::new(vec![
-                Cell::from(snippet.title.clone()).style(Style::default().fg(RosePine::TEXT)),
-                Cell::from(format!(
-                    "{} {}",
-                    snippet.language.icon(),
-                    snippet.language.short_name()
-                ))
-                .style(Style::default().fg(RosePine::FOAM)),
-                Cell::from(truncated_desc).style(Style::default().fg(RosePine::PINE)),
-                Cell::from(tags_display).style(Style::default().fg(RosePine::GOLD)),
-            ])
-        })
-        .collect();
+    let notebook_rows = favorite_notebooks.iter().map(|(path, notebook)| {
+        let tags_display = if notebook.tags.is_empty() {
+            "-".to_string()
+        } else {
+            notebook.tags.join(", ")
+        };
+
+        let description = notebook.description.clone().unwrap_or_default();
+        let truncated_desc = if description.chars().count() > 40 {
+            format!("{}...", description.chars().take(37).collect::<String>())
+        } else {
+            description
+        };
+
+        Row::new(vec![
+            Cell::from(path.clone()).style(Style::default().fg(palette().text)),
+            Cell::from(format!("{} Notebook", notebook.icon))
+                .style(Style::default().fg(palette().iris)),
+            Cell::from(truncated_desc).style(Style::default().fg(palette().pine)),
+            Cell::from(tags_display).style(Style::default().fg(palette().gold)),
+        ])
+    });
+
+    let snippet_rows = favorite_snippets.iter().map(|snippet| {
+        let tags_display = if snippet.tags.is_empty() {
+            "-".to_string()
+        } else {
+            snippet.get_tags_display_string()
+        };
+
+        let description = snippet.description.clone().unwrap_or_default();
+        let truncated_desc = if description.len() > 40 {
+            format!("{}...", &description[..37])
+        } else {
+            description
+        };
+
+        Row::new(vec![
+            Cell::from(snippet.title.clone()).style(Style::default().fg(palette().text)),
+            Cell::from(format!(
+                "{} {}",
+                snippet.language.icon(),
+                snippet.language.short_name()
+            ))
+            .style(Style::default().fg(palette().foam)),
+            Cell::from(truncated_desc).style(Style::default().fg(palette().pine)),
+            Cell::from(tags_display).style(Style::default().fg(palette().gold)),
+        ])
+    });
+
+    let rows: Vec<Row> = notebook_rows.chain(snippet_rows).collect();
 
     let chunks = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(inner_area);
 
@@ -121,6 +153,6 @@ pub fn render_floating_favorites(frame: &mut Frame, app: &App) {
     let help_text = "Press Esc to close";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[1], frame.buffer_mut());
 }
@@ -0,0 +1,104 @@
+use crate::app::App;
+use crate::handlers::keymap::{KeySection, sections_for};
+use crate::ui::colors::palette;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+/// Render the `?` help menu with the shortcuts of the current screen
+pub fn render(frame: &mut Frame, app: &App) {
+    let sections = sections_for(&app.state, &app.code_snippets_state);
+    let (left, right) = split_columns(&sections);
+    let key_width = sections
+        .iter()
+        .flat_map(|section| section.bindings)
+        .map(|binding| binding.keys.chars().count())
+        .max()
+        .unwrap_or(0);
+    let left_lines = column_lines(&left, key_width);
+    let right_lines = column_lines(&right, key_width);
+
+    let area = frame.area();
+    let rows = left_lines.len().max(right_lines.len()) + 1;
+    let popup_width = 100.min(area.width);
+    let popup_height = (rows as u16 + 2).min(area.height);
+    let popup_area = Rect::new(
+        area.x + (area.width - popup_width) / 2,
+        area.y + (area.height - popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    Clear.render(popup_area, frame.buffer_mut());
+
+    let block = Block::bordered()
+        .title(" 󰘳 Keyboard Shortcuts ")
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().iris).bg(palette().surface));
+
+    let inner_area = block.inner(popup_area);
+    block.render(popup_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(inner_area);
+    let columns = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    Paragraph::new(left_lines)
+        .style(Style::default().fg(palette().text))
+        .render(columns[0], frame.buffer_mut());
+    Paragraph::new(right_lines)
+        .style(Style::default().fg(palette().text))
+        .render(columns[1], frame.buffer_mut());
+
+    Paragraph::new("Press Esc or ? to close this menu")
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().subtle).italic())
+        .render(chunks[1], frame.buffer_mut());
+}
+
+/// Splits the sections into two columns of roughly equal height, keeping their order
+fn split_columns(
+    sections: &[&'static KeySection],
+) -> (Vec<&'static KeySection>, Vec<&'static KeySection>) {
+    let height = |section: &KeySection| section.bindings.len() + 2;
+    let total: usize = sections.iter().map(|section| height(section)).sum();
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    let mut left_height = 0;
+    for section in sections {
+        if right.is_empty() && left_height + height(section) / 2 <= total / 2 {
+            left_height += height(section);
+            left.push(*section);
+        } else {
+            right.push(*section);
+        }
+    }
+    (left, right)
+}
+
+fn column_lines(sections: &[&KeySection], key_width: usize) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for section in sections {
+        lines.push(Line::from(Span::styled(
+            section.title,
+            Style::default().fg(palette().love).bold(),
+        )));
+        for binding in section.bindings {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<key_width$}  ", binding.keys),
+                    Style::default().fg(palette().gold),
+                ),
+                Span::raw(binding.action),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    lines
+}
@@ -0,0 +1,508 @@
+//! Markdown rendering shared by the chat view and notebook and snippet descriptions
+
+use crate::ui::colors::palette;
+use crate::ui::components::sanitize_for_display;
+use once_cell::sync::Lazy;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Renders markdown as styled text wrapped to `width`, with syntax-highlighted code blocks
+pub fn render_markdown(markdown: &str, width: usize) -> Text<'static> {
+    use syntect::{highlighting::ThemeSet, parsing::SyntaxSet};
+
+    static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+    static THEME_SET: Lazy<ThemeSet> = Lazy::new(ThemeSet::load_defaults);
+
+    // Chat replies and pasted snippets may carry terminal escape sequences
+    let markdown = &*sanitize_for_display(markdown);
+
+    // Safety checks - return basic text if inputs are invalid
+    if markdown.trim().is_empty() || width == 0 {
+        return Text::from(markdown.to_string());
+    }
+
+    let safe_width = width.clamp(10, 1000); // Ensure reasonable width bounds
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let parser = Parser::new_ext(markdown, options);
+
+    let mut text = Text::default();
+    let mut current_line = Line::default();
+    let mut current_style = Style::default().fg(palette().text);
+    let mut in_code_block = false;
+    let mut code_block_language: Option<String> = None;
+    let mut code_block_content = String::new();
+    let mut list_depth: usize = 0;
+    let mut in_blockquote = false;
+
+    // Get the "base16-ocean.dark" theme for syntax highlighting
+    let theme = THEME_SET
+        .themes
+        .get("base16-ocean.dark")
+        .unwrap_or_else(|| THEME_SET.themes.values().next().unwrap());
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::Paragraph) => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+
+                // Add blockquote prefix if we're in a blockquote
+                if in_blockquote {
+                    current_line
+                        .spans
+                        .push(Span::styled("▌ ", Style::default().fg(palette().muted)));
+                }
+            }
+            Event::End(TagEnd::Paragraph) => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+                text.lines.push(Line::default());
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                let (level_style, prefix) = match level {
+                    HeadingLevel::H1 => (
+                        Style::default()
+                            .fg(palette().foam)
+                            .add_modifier(Modifier::BOLD),
+                        "# ",
+                    ),
+                    HeadingLevel::H2 => (
+                        Style::default()
+                            .fg(palette().foam)
+                            .add_modifier(Modifier::BOLD),
+                        "## ",
+                    ),
+                    HeadingLevel::H3 => (
+                        Style::default()
+                            .fg(palette().foam)
+                            .add_modifier(Modifier::BOLD),
+                        "### ",
+                    ),
+                    _ => (
+                        Style::default()
+                            .fg(palette().iris)
+                            .add_modifier(Modifier::BOLD),
+                        "#### ",
+                    ),
+                };
+                current_style = level_style;
+
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+
+                current_line.spans.push(Span::styled(prefix, level_style));
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+                current_style = Style::default().fg(palette().text);
+                text.lines.push(Line::default());
+            }
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+
+                in_code_block = true;
+                code_block_language = if lang.is_empty() {
+                    None
+                } else {
+                    Some(lang.to_string())
+                };
+                code_block_content.clear();
+
+                // Add code block header with language indicator
+                let lang_display = code_block_language.as_deref().unwrap_or("text");
+                let header_line = Line::from(vec![
+                    Span::styled("┌─ ", Style::default().fg(palette().muted)),
+                    Span::styled("", Style::default().fg(palette().pine)),
+                    Span::styled(
+                        format!(" {} ", lang_display.to_uppercase()),
+                        Style::default()
+                            .fg(palette().base)
+                            .bg(palette().pine)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(" ", Style::default()),
+                ]);
+                text.lines.push(header_line);
+            }
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+
+                in_code_block = true;
+                code_block_language = None;
+                code_block_content.clear();
+
+                // Add simple code block header
+                let header_line = Line::from(vec![
+                    Span::styled("┌─ ", Style::default().fg(palette().muted)),
+                    Span::styled("", Style::default().fg(palette().subtle)),
+                    Span::styled(
+                        " CODE ",
+                        Style::default()
+                            .fg(palette().text)
+                            .bg(palette().subtle)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(" ", Style::default()),
+                ]);
+                text.lines.push(header_line);
+            }
+            Event::End(TagEnd::CodeBlock) if in_code_block => {
+                // Apply syntax highlighting to the collected code block content
+                if let Some(ref lang) = code_block_language {
+                    if let Some(syntax) = SYNTAX_SET.find_syntax_by_token(lang) {
+                        render_highlighted_code_block(
+                            &mut text,
+                            &code_block_content,
+                            syntax,
+                            theme,
+                            &SYNTAX_SET,
+                        );
+                    } else {
+                        // Fallback to plain text with basic styling
+                        render_plain_code_block(&mut text, &code_block_content);
+                    }
+                } else {
+                    // Plain code block
+                    render_plain_code_block(&mut text, &code_block_content);
+                }
+
+                // Add code block footer
+                let footer_width = safe_width.saturating_sub(2);
+                let footer_line = Line::from(vec![
+                    Span::styled("└─", Style::default().fg(palette().muted)),
+                    Span::styled(
+                        "─".repeat(footer_width),
+                        Style::default().fg(palette().muted),
+                    ),
+                ]);
+                text.lines.push(footer_line);
+                text.lines.push(Line::default());
+
+                in_code_block = false;
+                code_block_language = None;
+                code_block_content.clear();
+            }
+            Event::Start(Tag::List(start_num)) => {
+                list_depth += 1;
+                if start_num.is_some() {
+                    // Ordered list - we'll handle numbering in list items
+                }
+            }
+            Event::End(TagEnd::List(_)) => {
+                list_depth = list_depth.saturating_sub(1);
+                if list_depth == 0 {
+                    text.lines.push(Line::default());
+                }
+            }
+            Event::Start(Tag::Item) => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+
+                // Add indentation and bullet point based on depth
+                let indent = "  ".repeat(list_depth.saturating_sub(1));
+                current_line.spans.push(Span::styled(
+                    format!("{}• ", indent),
+                    Style::default().fg(palette().gold),
+                ));
+            }
+            Event::End(TagEnd::Item) if !current_line.spans.is_empty() => {
+                text.lines.push(current_line);
+                current_line = Line::default();
+            }
+            Event::Start(Tag::BlockQuote(_)) => {
+                in_blockquote = true;
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+            }
+            Event::End(TagEnd::BlockQuote(_)) => {
+                in_blockquote = false;
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+                text.lines.push(Line::default());
+            }
+            Event::Start(Tag::Emphasis) => {
+                current_style = current_style.add_modifier(Modifier::ITALIC);
+            }
+            Event::End(TagEnd::Emphasis) => {
+                current_style = current_style.remove_modifier(Modifier::ITALIC);
+            }
+            Event::Start(Tag::Strong) => {
+                current_style = current_style.add_modifier(Modifier::BOLD);
+            }
+            Event::End(TagEnd::Strong) => {
+                current_style = current_style.remove_modifier(Modifier::BOLD);
+            }
+            Event::Start(Tag::Strikethrough) => {
+                current_style = current_style.add_modifier(Modifier::CROSSED_OUT);
+            }
+            Event::End(TagEnd::Strikethrough) => {
+                current_style = current_style.remove_modifier(Modifier::CROSSED_OUT);
+            }
+            Event::Code(text_str) => {
+                current_line.spans.push(Span::styled(
+                    format!("`{}`", text_str),
+                    Style::default()
+                        .fg(palette().base)
+                        .bg(palette().rose)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            Event::Text(text_str) => {
+                if in_code_block {
+                    // Collect code block content for later syntax highlighting
+                    code_block_content.push_str(&text_str);
+                } else {
+                    let text_content = text_str.to_string();
+                    if text_content.contains('\n') {
+                        for (i, line) in text_content.split('\n').enumerate() {
+                            if i > 0 {
+                                if !current_line.spans.is_empty() {
+                                    text.lines.push(current_line);
+                                    current_line = Line::default();
+                                }
+                                // Preserve blockquote prefix on new lines
+                                if in_blockquote {
+                                    current_line.spans.push(Span::styled(
+                                        "▌ ",
+                                        Style::default().fg(palette().muted),
+                                    ));
+                                }
+                            }
+                            if !line.is_empty() {
+                                // Handle word wrapping for each line
+                                let wrapped_lines =
+                                    wrap_text_to_width(line, safe_width, in_blockquote);
+                                for (j, wrapped_line) in wrapped_lines.iter().enumerate() {
+                                    if j > 0 {
+                                        if !current_line.spans.is_empty() {
+                                            text.lines.push(current_line);
+                                            current_line = Line::default();
+                                        }
+                                        // Add blockquote prefix for continuation lines
+                                        if in_blockquote {
+                                            current_line.spans.push(Span::styled(
+                                                "▌ ",
+                                                Style::default().fg(palette().muted),
+                                            ));
+                                        }
+                                    }
+                                    current_line
+                                        .spans
+                                        .push(Span::styled(wrapped_line.clone(), current_style));
+                                }
+                            }
+                        }
+                    } else {
+                        // Handle word wrapping for single lines
+                        let wrapped_lines =
+                            wrap_text_to_width(&text_content, safe_width, in_blockquote);
+                        for (i, wrapped_line) in wrapped_lines.iter().enumerate() {
+                            if i > 0 {
+                                if !current_line.spans.is_empty() {
+                                    text.lines.push(current_line);
+                                    current_line = Line::default();
+                                }
+                                // Add blockquote prefix for continuation lines
+                                if in_blockquote {
+                                    current_line.spans.push(Span::styled(
+                                        "▌ ",
+                                        Style::default().fg(palette().muted),
+                                    ));
+                                }
+                            }
+                            current_line
+                                .spans
+                                .push(Span::styled(wrapped_line.clone(), current_style));
+                        }
+                    }
+                }
+            }
+            Event::SoftBreak => {
+                current_line.spans.push(Span::styled(" ", current_style));
+            }
+            Event::HardBreak => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+                // Preserve blockquote prefix on new lines
+                if in_blockquote {
+                    current_line
+                        .spans
+                        .push(Span::styled("▌ ", Style::default().fg(palette().muted)));
+                }
+            }
+            Event::Rule => {
+                if !current_line.spans.is_empty() {
+                    text.lines.push(current_line);
+                    current_line = Line::default();
+                }
+                // Add horizontal rule
+                let rule_width = if safe_width > 4 {
+                    safe_width - 4
+                } else {
+                    safe_width
+                };
+                let rule_line = Line::from(vec![Span::styled(
+                    "─".repeat(rule_width),
+                    Style::default().fg(palette().muted),
+                )]);
+                text.lines.push(rule_line);
+                text.lines.push(Line::default());
+            }
+            _ => {}
+        }
+    }
+
+    if !current_line.spans.is_empty() {
+        text.lines.push(current_line);
+    }
+
+    text
+}
+
+// Helper function to render syntax-highlighted code blocks
+fn render_highlighted_code_block(
+    text: &mut Text,
+    code_content: &str,
+    syntax: &syntect::parsing::SyntaxReference,
+    theme: &syntect::highlighting::Theme,
+    syntax_set: &syntect::parsing::SyntaxSet,
+) {
+    use syntect::easy::HighlightLines;
+    use syntect::util::LinesWithEndings;
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    for line in LinesWithEndings::from(code_content) {
+        if let Ok(highlighted) = highlighter.highlight_line(line, syntax_set) {
+            let mut spans = vec![Span::styled("│ ", Style::default().fg(palette().muted))];
+
+            for (style, content) in highlighted {
+                let fg_color = convert_syntect_color_to_ratatui(style.foreground);
+                let text_style = if style
+                    .font_style
+                    .contains(syntect::highlighting::FontStyle::BOLD)
+                {
+                    Style::default().fg(fg_color).add_modifier(Modifier::BOLD)
+                } else if style
+                    .font_style
+                    .contains(syntect::highlighting::FontStyle::ITALIC)
+                {
+                    Style::default().fg(fg_color).add_modifier(Modifier::ITALIC)
+                } else {
+                    Style::default().fg(fg_color)
+                };
+
+                spans.push(Span::styled(content.to_string(), text_style));
+            }
+
+            text.lines.push(Line::from(spans));
+        } else {
+            // Fallback for highlighting errors
+            text.lines.push(Line::from(vec![
+                Span::styled("│ ", Style::default().fg(palette().muted)),
+                Span::styled(line.to_string(), Style::default().fg(palette().gold)),
+            ]));
+        }
+    }
+}
+
+// Helper function to render plain code blocks
+fn render_plain_code_block(text: &mut Text, code_content: &str) {
+    for line in code_content.lines() {
+        let code_line = Line::from(vec![
+            Span::styled("│ ", Style::default().fg(palette().muted)),
+            Span::styled(line.to_string(), Style::default().fg(palette().gold)),
+        ]);
+        text.lines.push(code_line);
+    }
+}
+
+// Helper function to convert syntect colors to ratatui colors
+fn convert_syntect_color_to_ratatui(color: syntect::highlighting::Color) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
+}
+
+// Helper function to intelligently wrap text to a given width
+fn wrap_text_to_width(text: &str, width: usize, in_blockquote: bool) -> Vec<String> {
+    if width == 0 || text.is_empty() {
+        return vec![text.to_string()];
+    }
+
+    // Account for blockquote prefix
+    let effective_width = if in_blockquote {
+        // Account for "▌ " prefix
+        width.saturating_sub(2)
+    } else {
+        width
+    };
+
+    if effective_width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+    let mut current_width = 0;
+
+    for word in words {
+        let word_width = UnicodeWidthStr::width(word);
+
+        // Check if we need to break the line
+        if current_width + word_width + 1 > effective_width && !current_line.is_empty() {
+            lines.push(current_line);
+            current_line = word.to_string();
+            current_width = word_width;
+        } else {
+            if !current_line.is_empty() {
+                current_line.push(' ');
+                current_width += 1;
+            }
+            current_line.push_str(word);
+            current_width += word_width;
+        }
+    }
+
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+
+    if lines.is_empty() {
+        vec![String::new()]
+    } else {
+        lines
+    }
+}
@@ -1,11 +1,23 @@
 pub mod about;
 pub mod backup_restore;
+pub mod boilerplates;
 pub mod code_snippets;
 pub mod colors;
 pub mod components;
 pub mod export_import;
 pub mod favorites;
+pub mod help;
+pub mod markdown;
 pub mod notebook_details;
 pub mod ollama;
+pub mod quick_open;
+pub mod recent;
 pub mod search;
+pub mod settings;
+pub mod snippet_diff;
 pub mod start_page;
+pub mod stats;
+pub mod tags;
+pub mod toast;
+pub mod trash;
+pub mod version_history;
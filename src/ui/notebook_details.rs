@@ -1,11 +1,13 @@
 use crate::app::{App, CodeSnippetsState, InputMode};
-use crate::models::SnippetLanguage;
-use crate::ui::colors::RosePine;
+use crate::models::{NotebookStats, SnippetLanguage};
+use crate::ui::colors::palette;
+use crate::ui::components::render_text_input;
+use crate::ui::markdown::render_markdown;
 use ratatui::widgets::Widget;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
-    style::{Style, Stylize},
+    style::{Color, Style, Stylize},
     text::{Line, Span},
     widgets::{
         BarChart, Block, BorderType, Cell, List, ListItem, ListState, Paragraph, Row, Table, Wrap,
@@ -40,7 +42,7 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
         .title(format!(" {} Notebook Details: {} ", "", notebook.name))
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(main_area);
     block.render(main_area, frame.buffer_mut());
@@ -79,15 +81,16 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     let nav_block = Block::bordered()
         .title(" Actions ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let nav_area = nav_block.inner(chunks[0]);
     nav_block.render(chunks[0], frame.buffer_mut());
 
-    let nav_buttons = vec![
+    let nav_buttons = [
         ("e", "Edit Description"),
         ("c", "Change Color"),
         ("s", "New Snippet"),
+        ("k", "Clone"),
     ];
 
     let button_width = nav_area.width / nav_buttons.len() as u16;
@@ -105,8 +108,8 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
             .alignment(Alignment::Center)
             .style(
                 Style::default()
-                    .fg(RosePine::TEXT)
-                    .bg(RosePine::HIGHLIGHT_LOW),
+                    .fg(palette().text)
+                    .bg(palette().highlight_low),
             );
 
         button.render(button_areas[i], frame.buffer_mut());
@@ -116,11 +119,12 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     let status_text = "← Back (Esc) • 's' to Create Snippet • 'e' to Edit Description • 'c' to Change Color • 'f' to Toggle Favorite";
     let status = Paragraph::new(status_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     status.render(chunks[5], frame.buffer_mut());
 
     // Calculate statistics
     let total_lines: usize = snippets.iter().map(|s| s.get_line_count()).sum();
+    let content_stats = NotebookStats::compute(&app.snippet_database, notebook_id);
 
     let avg_use_count = if !snippets.is_empty() {
         snippets.iter().map(|s| s.use_count as usize).sum::<usize>() as f64 / snippets.len() as f64
@@ -180,50 +184,57 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     let overview_block = Block::bordered()
         .title(" Overview ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let overview_area = overview_block.inner(chunks[1]);
     overview_block.render(chunks[1], frame.buffer_mut());
 
     let overview_chunks = Layout::horizontal([
-        Constraint::Percentage(60), // Basic info
-        Constraint::Percentage(40), // Stats
+        Constraint::Percentage(40), // Basic info
+        Constraint::Percentage(30), // Stats
+        Constraint::Percentage(30), // Content size
     ])
     .split(overview_area);
 
     // Left side - basic info
-    let info_lines = vec![
+    let mut info_lines = vec![
         Line::from(vec![
-            Span::styled("Name: ", Style::default().fg(RosePine::MUTED)),
-            Span::styled(&notebook.name, Style::default().fg(RosePine::TEXT).bold()),
+            Span::styled("Name: ", Style::default().fg(palette().muted)),
+            Span::styled(&notebook.name, Style::default().fg(palette().text).bold()),
         ]),
         Line::from(vec![
-            Span::styled("Created: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Created: ", Style::default().fg(palette().muted)),
             Span::styled(
                 notebook.created_at.format("%Y-%m-%d %H:%M").to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Updated: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Updated: ", Style::default().fg(palette().muted)),
             Span::styled(
                 notebook.updated_at.format("%Y-%m-%d %H:%M").to_string(),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Description: ",
-            Style::default().fg(RosePine::MUTED),
-        )]),
-        Line::from(vec![Span::styled(
-            notebook
-                .description
-                .clone()
-                .unwrap_or_else(|| "No description".to_string()),
-            Style::default().fg(RosePine::TEXT),
+            Style::default().fg(palette().muted),
         )]),
     ];
+    match notebook
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        Some(desc) => {
+            info_lines.extend(render_markdown(desc, overview_chunks[0].width as usize).lines)
+        }
+        None => info_lines.push(Line::from(Span::styled(
+            "No description",
+            Style::default().fg(palette().text),
+        ))),
+    }
 
     let info_paragraph = Paragraph::new(info_lines)
         .alignment(Alignment::Left)
@@ -234,59 +245,59 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     // Right side - Key stats
     let stats_lines = vec![
         Line::from(vec![
-            Span::styled("Snippets: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Snippets: ", Style::default().fg(palette().muted)),
             Span::styled(
                 snippets.len().to_string(),
-                Style::default().fg(RosePine::LOVE).bold(),
+                Style::default().fg(palette().love).bold(),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Total Lines: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Total Lines: ", Style::default().fg(palette().muted)),
             Span::styled(
                 total_lines.to_string(),
-                Style::default().fg(RosePine::GOLD).bold(),
+                Style::default().fg(palette().gold).bold(),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Total Tags: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Total Tags: ", Style::default().fg(palette().muted)),
             Span::styled(
                 total_tags.to_string(),
-                Style::default().fg(RosePine::IRIS).bold(),
+                Style::default().fg(palette().iris).bold(),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Unique Tags: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Unique Tags: ", Style::default().fg(palette().muted)),
             Span::styled(
                 sorted_tags.len().to_string(),
-                Style::default().fg(RosePine::FOAM).bold(),
+                Style::default().fg(palette().foam).bold(),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Avg. Usage: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Avg. Usage: ", Style::default().fg(palette().muted)),
             Span::styled(
                 format!("{:.1}", avg_use_count),
-                Style::default().fg(RosePine::FOAM).bold(),
+                Style::default().fg(palette().foam).bold(),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Notebook Age: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Notebook Age: ", Style::default().fg(palette().muted)),
             Span::styled(
                 format!("{} days", notebook_age),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Last Updated: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Last Updated: ", Style::default().fg(palette().muted)),
             Span::styled(
                 format!("{} days ago", last_update),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Activity Rate: ", Style::default().fg(RosePine::MUTED)),
+            Span::styled("Activity Rate: ", Style::default().fg(palette().muted)),
             Span::styled(
                 format!("{:.2} snippets/day", snippets_per_day),
-                Style::default().fg(RosePine::TEXT),
+                Style::default().fg(palette().text),
             ),
         ]),
     ];
@@ -297,11 +308,62 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
 
     stats_paragraph.render(overview_chunks[1], frame.buffer_mut());
 
+    // Far right - how much content the notebook holds
+    let mut content_lines = vec![
+        Line::from(vec![
+            Span::styled("Words: ", Style::default().fg(palette().muted)),
+            Span::styled(
+                content_stats.words.to_string(),
+                Style::default().fg(palette().gold).bold(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Characters: ", Style::default().fg(palette().muted)),
+            Span::styled(
+                content_stats.chars.to_string(),
+                Style::default().fg(palette().gold).bold(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Avg. Size: ", Style::default().fg(palette().muted)),
+            Span::styled(
+                format!("{:.0} lines", content_stats.average_lines()),
+                Style::default().fg(palette().foam).bold(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Reading Time: ", Style::default().fg(palette().muted)),
+            Span::styled(
+                format!("~{} min", content_stats.reading_minutes()),
+                Style::default().fg(palette().text),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("With Nested: ", Style::default().fg(palette().muted)),
+            Span::styled(
+                format!(
+                    "{} snippets, {} lines",
+                    content_stats.nested_snippets, content_stats.nested_lines
+                ),
+                Style::default().fg(palette().text),
+            ),
+        ]),
+        Line::from(""),
+    ];
+    content_lines.extend(language_bar(
+        &content_stats.by_language,
+        overview_chunks[2].width as usize,
+    ));
+
+    Paragraph::new(content_lines)
+        .alignment(Alignment::Left)
+        .render(overview_chunks[2], frame.buffer_mut());
+
     // 2. LANGUAGE DISTRIBUTION SECTION
     let lang_block = Block::bordered()
         .title(" Language Distribution ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let lang_area = lang_block.inner(chunks[2]);
     lang_block.render(chunks[2], frame.buffer_mut());
@@ -309,7 +371,7 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     if snippets.is_empty() {
         let no_data = Paragraph::new("No snippets in this notebook")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::MUTED));
+            .style(Style::default().fg(palette().muted));
         no_data.render(lang_area, frame.buffer_mut());
     } else {
         let lang_chunks = Layout::horizontal([
@@ -329,8 +391,8 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
             let barchart = BarChart::default()
                 .bar_width(5)
                 .bar_gap(1)
-                .bar_style(Style::default().fg(RosePine::FOAM))
-                .value_style(Style::default().fg(RosePine::TEXT))
+                .bar_style(Style::default().fg(palette().foam))
+                .value_style(Style::default().fg(palette().text))
                 .data(&data)
                 .max(
                     lang_counts
@@ -359,18 +421,18 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
 
                     Row::new(vec![
                         Cell::from(format!("{}", lang.short_name()))
-                            .style(Style::default().fg(RosePine::FOAM)),
-                        Cell::from(count.to_string()).style(Style::default().fg(RosePine::TEXT)),
+                            .style(Style::default().fg(palette().foam)),
+                        Cell::from(count.to_string()).style(Style::default().fg(palette().text)),
                         Cell::from(format!("{:.1}%", line_percentage))
-                            .style(Style::default().fg(RosePine::LOVE)),
+                            .style(Style::default().fg(palette().love)),
                     ])
                 })
                 .collect();
 
             let header = Row::new(vec![
-                Cell::from("Lang").style(Style::default().fg(RosePine::IRIS).bold()),
-                Cell::from("Count").style(Style::default().fg(RosePine::IRIS).bold()),
-                Cell::from("Lines %").style(Style::default().fg(RosePine::IRIS).bold()),
+                Cell::from("Lang").style(Style::default().fg(palette().iris).bold()),
+                Cell::from("Count").style(Style::default().fg(palette().iris).bold()),
+                Cell::from("Lines %").style(Style::default().fg(palette().iris).bold()),
             ]);
 
             let lang_table = Table::new(
@@ -393,7 +455,7 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     let tags_block = Block::bordered()
         .title(" Tags ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let tags_area = tags_block.inner(chunks[3]);
     tags_block.render(chunks[3], frame.buffer_mut());
@@ -401,7 +463,7 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     if sorted_tags.is_empty() {
         let no_tags = Paragraph::new("No tags found in this notebook")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::MUTED));
+            .style(Style::default().fg(palette().muted));
         no_tags.render(tags_area, frame.buffer_mut());
     } else {
         let tag_columns =
@@ -414,12 +476,12 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
             let tag_line = Line::from(vec![
                 Span::styled(
                     format!("#{}", tag),
-                    Style::default().fg(RosePine::IRIS).bold(),
+                    Style::default().fg(palette().iris).bold(),
                 ),
                 Span::raw(" "),
                 Span::styled(
                     format!("({})", count),
-                    Style::default().fg(RosePine::SUBTLE),
+                    Style::default().fg(palette().subtle),
                 ),
             ]);
 
@@ -447,7 +509,7 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     let snippets_block = Block::bordered()
         .title(" Snippets ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let snippets_area = snippets_block.inner(chunks[4]);
     snippets_block.render(chunks[4], frame.buffer_mut());
@@ -456,15 +518,15 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
         let no_snippets =
             Paragraph::new("No snippets in this notebook\nPress 's' to create a new snippet")
                 .alignment(Alignment::Center)
-                .style(Style::default().fg(RosePine::MUTED));
+                .style(Style::default().fg(palette().muted));
         no_snippets.render(snippets_area, frame.buffer_mut());
     } else {
         let header = Row::new(vec![
-            Cell::from("Title").style(Style::default().fg(RosePine::LOVE).bold()),
-            Cell::from("Language").style(Style::default().fg(RosePine::LOVE).bold()),
-            Cell::from("Lines").style(Style::default().fg(RosePine::LOVE).bold()),
-            Cell::from("Used").style(Style::default().fg(RosePine::LOVE).bold()),
-            Cell::from("Updated").style(Style::default().fg(RosePine::LOVE).bold()),
+            Cell::from("Title").style(Style::default().fg(palette().love).bold()),
+            Cell::from("Language").style(Style::default().fg(palette().love).bold()),
+            Cell::from("Lines").style(Style::default().fg(palette().love).bold()),
+            Cell::from("Used").style(Style::default().fg(palette().love).bold()),
+            Cell::from("Updated").style(Style::default().fg(palette().love).bold()),
         ]);
 
         let rows: Vec<Row> = snippets
@@ -474,17 +536,17 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
                 let updated = snippet.updated_at.format("%Y-%m-%d").to_string();
 
                 Row::new(vec![
-                    Cell::from(snippet.title.clone()).style(Style::default().fg(RosePine::TEXT)),
+                    Cell::from(snippet.title.clone()).style(Style::default().fg(palette().text)),
                     Cell::from(format!(
                         "{} {}",
                         snippet.language.icon(),
                         snippet.language.short_name()
                     ))
-                    .style(Style::default().fg(RosePine::FOAM)),
-                    Cell::from(line_count.to_string()).style(Style::default().fg(RosePine::GOLD)),
+                    .style(Style::default().fg(palette().foam)),
+                    Cell::from(line_count.to_string()).style(Style::default().fg(palette().gold)),
                     Cell::from(snippet.use_count.to_string())
-                        .style(Style::default().fg(RosePine::IRIS)),
-                    Cell::from(updated).style(Style::default().fg(RosePine::SUBTLE)),
+                        .style(Style::default().fg(palette().iris)),
+                    Cell::from(updated).style(Style::default().fg(palette().subtle)),
                 ])
             })
             .collect();
@@ -517,6 +579,9 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
         InputMode::SelectNotebookColor => {
             render_color_selection_overlay(frame, main_area, app);
         }
+        InputMode::CloneNotebook => {
+            render_clone_overlay(frame, main_area, app);
+        }
         InputMode::Normal => {
             if let Some(ref message) = app.error_message {
                 render_message_overlay(frame, main_area, message, true);
@@ -528,15 +593,68 @@ pub fn render(frame: &mut Frame, app: &mut App, notebook_id: Uuid) {
     }
 }
 
+/// A one-row bar split by each language's share of lines, with a legend for the largest
+fn language_bar(by_language: &[(String, usize)], width: usize) -> Vec<Line<'static>> {
+    let total: usize = by_language.iter().map(|(_, lines)| lines).sum();
+    if total == 0 || width == 0 {
+        return vec![Line::from(Span::styled(
+            "No code yet",
+            Style::default().fg(palette().muted),
+        ))];
+    }
+
+    let colors: [Color; 6] = [
+        palette().foam,
+        palette().iris,
+        palette().gold,
+        palette().love,
+        palette().pine,
+        palette().rose,
+    ];
+
+    let mut bar = Vec::new();
+    let mut used = 0;
+    for (i, (_, lines)) in by_language.iter().enumerate() {
+        // The last segment takes the rounding remainder so the bar fills the row
+        let cells = if i + 1 == by_language.len() {
+            width - used
+        } else {
+            (lines * width / total).min(width - used)
+        };
+        used += cells;
+        bar.push(Span::styled(
+            "█".repeat(cells),
+            Style::default().fg(colors[i % colors.len()]),
+        ));
+    }
+
+    let legend: Vec<Span> = by_language
+        .iter()
+        .take(3)
+        .enumerate()
+        .flat_map(|(i, (name, lines))| {
+            [
+                Span::styled("■ ", Style::default().fg(colors[i % colors.len()])),
+                Span::styled(
+                    format!("{} {}%  ", name, lines * 100 / total),
+                    Style::default().fg(palette().subtle),
+                ),
+            ]
+        })
+        .collect();
+
+    vec![Line::from(bar), Line::from(legend)]
+}
+
 fn get_available_colors() -> Vec<(&'static str, ratatui::style::Color)> {
     vec![
-        ("Default", RosePine::TEXT),
-        ("Red", RosePine::LOVE),
-        ("Orange", RosePine::GOLD),
-        ("Green", RosePine::FOAM),
-        ("Blue", RosePine::IRIS),
-        ("Purple", RosePine::IRIS),
-        ("Pink", RosePine::ROSE),
+        ("Default", palette().text),
+        ("Red", palette().love),
+        ("Orange", palette().gold),
+        ("Green", palette().foam),
+        ("Blue", palette().iris),
+        ("Purple", palette().iris),
+        ("Pink", palette().rose),
         ("White", ratatui::style::Color::White),
     ]
 }
@@ -548,7 +666,7 @@ fn render_edit_description_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
@@ -563,19 +681,66 @@ fn render_edit_description_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let title_paragraph = Paragraph::new(title)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::IRIS).bold());
+        .style(Style::default().fg(palette().iris).bold());
     title_paragraph.render(chunks[0], frame.buffer_mut());
 
-    let input_text = format!("{}", app.input_buffer);
-    let input_paragraph = Paragraph::new(input_text)
-        .style(Style::default().fg(RosePine::TEXT))
-        .alignment(Alignment::Left);
-    input_paragraph.render(chunks[1], frame.buffer_mut());
+    render_text_input(
+        frame,
+        chunks[1],
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
 
     let help_text = "⎋ Cancel • ⏎ Confirm";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
+    help_paragraph.render(chunks[2], frame.buffer_mut());
+}
+
+/// Name prompt for a copy of the notebook, with Tab choosing whether snippets come along
+fn render_clone_overlay(frame: &mut Frame, area: Rect, app: &mut App) {
+    let popup_area = spotlight_bar(70, area);
+
+    ratatui::widgets::Clear.render(popup_area, frame.buffer_mut());
+
+    let block = Block::bordered()
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
+
+    let inner_area = block.inner(popup_area);
+    block.render(popup_area, frame.buffer_mut());
+
+    let title = if app.clone_with_snippets {
+        "Clone With Snippets"
+    } else {
+        "Clone Structure"
+    };
+    let chunks = Layout::horizontal([
+        Constraint::Length(title.len() as u16 + 4),
+        Constraint::Min(10),
+        Constraint::Length(36),
+    ])
+    .split(inner_area);
+
+    let title_paragraph = Paragraph::new(title)
+        .alignment(Alignment::Left)
+        .style(Style::default().fg(palette().iris).bold());
+    title_paragraph.render(chunks[0], frame.buffer_mut());
+
+    render_text_input(
+        frame,
+        chunks[1],
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
+
+    let help_text = "⇥ Snippets • ⎋ Cancel • ⏎ Clone";
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Right)
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 }
 
@@ -586,7 +751,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
@@ -601,7 +766,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let title_paragraph = Paragraph::new(title)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::IRIS).bold());
+        .style(Style::default().fg(palette().iris).bold());
     title_paragraph.render(chunks[0], frame.buffer_mut());
 
     let colors = get_available_colors();
@@ -616,7 +781,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
     let help_text = "↑↓ Navigate • ⏎ Select";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 
     let list_area = Rect::new(
@@ -630,7 +795,7 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
 
     let list_block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_list_area = list_block.inner(list_area);
     list_block.render(list_area, frame.buffer_mut());
@@ -654,8 +819,8 @@ fn render_color_selection_overlay(frame: &mut Frame, area: Rect, app: &mut App)
     let color_list = List::new(color_items)
         .highlight_style(
             Style::default()
-                .fg(RosePine::BASE)
-                .bg(RosePine::LOVE)
+                .fg(palette().base)
+                .bg(palette().love)
                 .bold(),
         )
         .highlight_symbol("▶ ");
@@ -672,14 +837,14 @@ fn render_message_overlay(frame: &mut Frame, area: Rect, message: &str, is_error
     ratatui::widgets::Clear.render(popup_area, frame.buffer_mut());
 
     let (icon, color) = if is_error {
-        ("✗", RosePine::LOVE)
+        ("✗", palette().love)
     } else {
-        ("✓", RosePine::FOAM)
+        ("✓", palette().foam)
     };
 
     let block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE).bg(RosePine::SURFACE));
+        .style(Style::default().fg(palette().subtle).bg(palette().surface));
 
     let inner_area = block.inner(popup_area);
     block.render(popup_area, frame.buffer_mut());
@@ -698,13 +863,13 @@ fn render_message_overlay(frame: &mut Frame, area: Rect, message: &str, is_error
 
     let message_paragraph = Paragraph::new(message)
         .alignment(Alignment::Left)
-        .style(Style::default().fg(RosePine::TEXT));
+        .style(Style::default().fg(palette().text));
     message_paragraph.render(chunks[1], frame.buffer_mut());
 
     let help_text = "Press any key to dismiss";
     let help_paragraph = Paragraph::new(help_text)
         .alignment(Alignment::Right)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
     help_paragraph.render(chunks[2], frame.buffer_mut());
 }
 
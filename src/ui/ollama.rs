@@ -1,19 +1,73 @@
 use crate::app::App;
+use crate::ui::colors::palette;
+use crate::ui::toast::Toasts;
 use chrono::{DateTime, Utc};
-use once_cell::sync::Lazy;
-use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 use ratatui::{
+    Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
-    Frame,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
 };
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::{panic, time::Instant};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use uuid::Uuid;
 
+/// Sampling options sent with each request; `None` leaves Ollama's default in place
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationParams {
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub top_p: Option<f32>,
+    #[serde(default)]
+    pub num_ctx: Option<u32>,
+}
+
+impl GenerationParams {
+    /// Values Ollama uses when an option is not sent
+    pub const DEFAULT_TEMPERATURE: f32 = 0.8;
+    pub const DEFAULT_TOP_P: f32 = 0.9;
+    pub const DEFAULT_NUM_CTX: u32 = 2048;
+
+    pub fn adjust_temperature(&mut self, delta: f32) {
+        let current = self.temperature.unwrap_or(Self::DEFAULT_TEMPERATURE);
+        let value = ((current + delta) * 10.0).round() / 10.0;
+        self.temperature = Some(value.clamp(0.0, 2.0));
+    }
+
+    pub fn adjust_top_p(&mut self, delta: f32) {
+        let current = self.top_p.unwrap_or(Self::DEFAULT_TOP_P);
+        let value = ((current + delta) * 100.0).round() / 100.0;
+        self.top_p = Some(value.clamp(0.05, 1.0));
+    }
+
+    /// Doubles or halves the context window
+    pub fn scale_num_ctx(&mut self, grow: bool) {
+        let current = self.num_ctx.unwrap_or(Self::DEFAULT_NUM_CTX);
+        let value = if grow { current * 2 } else { current / 2 };
+        self.num_ctx = Some(value.clamp(512, 131_072));
+    }
+
+    /// The `options` object for an Ollama request, omitting unset values
+    pub fn to_options(&self) -> serde_json::Map<String, serde_json::Value> {
+        let mut options = serde_json::Map::new();
+        if let Some(temperature) = self.temperature {
+            options.insert("temperature".to_string(), temperature.into());
+        }
+        if let Some(top_p) = self.top_p {
+            options.insert("top_p".to_string(), top_p.into());
+        }
+        if let Some(num_ctx) = self.num_ctx {
+            options.insert("num_ctx".to_string(), num_ctx.into());
+        }
+        options
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageMetrics {
     #[serde(default)]
@@ -22,6 +76,12 @@ pub struct MessageMetrics {
     pub total_tokens: Option<u32>,
     #[serde(default)]
     pub response_time_ms: Option<u64>,
+    /// Time the model spent generating, as reported by Ollama
+    #[serde(default)]
+    pub eval_duration_ms: Option<u64>,
+    /// Token figures are a word-count guess because Ollama did not report them
+    #[serde(default)]
+    pub tokens_estimated: bool,
     #[serde(default = "Utc::now")]
     pub timestamp: DateTime<Utc>,
 }
@@ -32,11 +92,35 @@ impl Default for MessageMetrics {
             tokens_per_second: None,
             total_tokens: None,
             response_time_ms: None,
+            eval_duration_ms: None,
+            tokens_estimated: false,
             timestamp: Utc::now(),
         }
     }
 }
 
+/// Token count and generation time Ollama reports in the final chunk of a response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvalStats {
+    pub eval_count: u32,
+    pub eval_duration_ns: u64,
+}
+
+impl EvalStats {
+    /// Reads `eval_count` and `eval_duration` from a streamed response line
+    pub fn from_response(json: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            eval_count: u32::try_from(json.get("eval_count")?.as_u64()?).ok()?,
+            eval_duration_ns: json.get("eval_duration")?.as_u64()?,
+        })
+    }
+
+    pub fn tokens_per_second(&self) -> Option<f64> {
+        (self.eval_duration_ns > 0)
+            .then(|| self.eval_count as f64 / (self.eval_duration_ns as f64 / 1e9))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatSession {
     pub id: Uuid,
@@ -47,6 +131,9 @@ pub struct ChatSession {
     pub system_prompt: String,
     pub snippet_hash: Option<String>,
     pub snippet_title: Option<String>,
+    /// Content hashes of every snippet attached to the chat
+    #[serde(default)]
+    pub snippet_hashes: Vec<String>,
     pub conversation: Vec<ChatMessage>,
     #[serde(default)]
     pub is_favorited: bool,
@@ -56,6 +143,8 @@ pub struct ChatSession {
     pub total_context_tokens: u32,
     #[serde(default)]
     pub session_stats: SessionStats,
+    #[serde(default)]
+    pub params: GenerationParams,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,30 +182,53 @@ impl ChatSession {
             system_prompt,
             snippet_hash: None,
             snippet_title: None,
+            snippet_hashes: Vec::new(),
             conversation: Vec::new(),
             is_favorited: false,
             tags: Vec::new(),
             total_context_tokens: 0,
             session_stats: SessionStats::default(),
+            params: GenerationParams::default(),
         }
     }
 
-    pub fn with_snippet(mut self, snippet_content: &str, snippet_title: String) -> Self {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-        snippet_content.hash(&mut hasher);
-        self.snippet_hash = Some(format!("{:x}", hasher.finish()));
-        self.snippet_title = Some(snippet_title.clone());
-        self.title = format!(
-            "{} - {}",
-            snippet_title,
-            self.created_at.format("%b %d, %H:%M")
-        );
+    pub fn with_snippets(mut self, snippets: &[AttachedSnippet]) -> Self {
+        self.set_snippets(snippets);
+        if let Some(snippet_title) = &self.snippet_title {
+            self.title = format!(
+                "{} - {}",
+                snippet_title,
+                self.created_at.format("%b %d, %H:%M")
+            );
+        }
         self
     }
 
+    /// Records which snippets the chat is about; the first one stays in `snippet_hash`
+    pub fn set_snippets(&mut self, snippets: &[AttachedSnippet]) {
+        self.snippet_hashes = snippets
+            .iter()
+            .map(|snippet| snippet_content_hash(&snippet.content))
+            .collect();
+        self.snippet_hash = self.snippet_hashes.first().cloned();
+        self.snippet_title = if snippets.is_empty() {
+            None
+        } else {
+            Some(
+                snippets
+                    .iter()
+                    .map(|snippet| snippet.title.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        };
+    }
+
+    /// Whether a snippet with this content hash was attached to the chat
+    pub fn involves_snippet(&self, hash: &str) -> bool {
+        self.snippet_hashes.iter().any(|h| h == hash) || self.snippet_hash.as_deref() == Some(hash)
+    }
+
     pub fn add_message(&mut self, role: ChatRole, content: String) {
         self.add_message_with_metrics(role, content, MessageMetrics::default(), 0);
     }
@@ -133,34 +245,62 @@ impl ChatSession {
             content,
             metrics: metrics.clone(),
             context_length,
+            bookmarked: false,
         };
 
         self.conversation.push(message);
         self.updated_at = Utc::now();
         self.total_context_tokens = context_length;
 
-        // Update session stats
         if role == ChatRole::Assistant {
-            self.session_stats.total_messages += 1;
-
-            if let Some(tokens_per_second) = metrics.tokens_per_second {
-                let old_avg = self.session_stats.average_tokens_per_second;
-                let count = self.session_stats.total_messages as f64;
-                self.session_stats.average_tokens_per_second =
-                    (old_avg * (count - 1.0) + tokens_per_second) / count;
-            }
+            self.refresh_stats();
+        }
+    }
 
-            if let Some(response_time) = metrics.response_time_ms {
-                let old_avg = self.session_stats.average_response_time_ms;
-                let count = self.session_stats.total_messages as f64;
-                self.session_stats.average_response_time_ms =
-                    (old_avg * (count - 1.0) + response_time as f64) / count;
+    /// Stores the final metrics of the streamed reply and updates the session stats
+    pub fn set_last_response_metrics(&mut self, metrics: MessageMetrics, context_length: u32) {
+        let Some(last_msg) = self
+            .conversation
+            .last_mut()
+            .filter(|msg| msg.role == ChatRole::Assistant)
+        else {
+            return;
+        };
+        last_msg.metrics = metrics;
+        last_msg.context_length = context_length;
+        self.total_context_tokens = context_length;
+        self.refresh_stats();
+    }
+
+    /// Recomputes the session stats from the assistant messages, averaging only measured values
+    fn refresh_stats(&mut self) {
+        let metrics: Vec<&MessageMetrics> = self
+            .conversation
+            .iter()
+            .filter(|msg| msg.role == ChatRole::Assistant)
+            .map(|msg| &msg.metrics)
+            .collect();
+        let average = |values: Vec<f64>| {
+            if values.is_empty() {
+                0.0
+            } else {
+                values.iter().sum::<f64>() / values.len() as f64
             }
+        };
 
-            if let Some(tokens) = metrics.total_tokens {
-                self.session_stats.total_tokens_generated += tokens;
-            }
-        }
+        self.session_stats = SessionStats {
+            total_messages: metrics.len() as u32,
+            average_response_time_ms: average(
+                metrics
+                    .iter()
+                    .filter_map(|m| m.response_time_ms.map(|ms| ms as f64))
+                    .collect(),
+            ),
+            average_tokens_per_second: average(
+                metrics.iter().filter_map(|m| m.tokens_per_second).collect(),
+            ),
+            total_tokens_generated: metrics.iter().filter_map(|m| m.total_tokens).sum(),
+        };
     }
 
     pub fn get_preview(&self) -> String {
@@ -198,62 +338,47 @@ impl ChatSession {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct ToastNotification {
-    pub message: String,
-    pub notification_type: NotificationType,
-    pub created_at: std::time::Instant,
-    pub duration_seconds: u64,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum NotificationType {
-    Success,
-    Error,
-    Info,
-}
-
-impl ToastNotification {
-    pub fn new(
-        message: String,
-        notification_type: NotificationType,
-        duration_seconds: u64,
-    ) -> Self {
-        Self {
-            message,
-            notification_type,
-            created_at: std::time::Instant::now(),
-            duration_seconds,
-        }
-    }
-
-    pub fn is_expired(&self) -> bool {
-        self.created_at.elapsed().as_secs() >= self.duration_seconds
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct OllamaState {
     pub show_popup: bool,
-    pub models: Vec<String>,
+    pub models: Vec<ModelInfo>,
     pub selected_model_index: usize,
     pub loading_models: bool,
     pub error_message: Option<String>,
     pub conversation: Vec<ChatMessage>,
     pub input_buffer: String,
     pub is_sending: bool,
-    pub current_snippet: Option<String>,
+    /// Snippets the chat is about, the one it was opened from first
+    pub attached_snippets: Vec<AttachedSnippet>,
+    /// Open while snippets are being picked to attach to the chat
+    pub snippet_picker: Option<SnippetPicker>,
+    /// Sampling options for new requests, mirrored into the current session
+    pub params: GenerationParams,
     pub scroll_position: usize,
+    /// Whether new content keeps the chat scrolled to the bottom, cleared by scrolling up
+    pub follow_output: bool,
+    /// Where each chat left earlier was scrolled to, missing when it was at the bottom
+    pub session_scroll_positions: std::collections::HashMap<Uuid, usize>,
     pub scroll_speed: usize,
     pub loading_animation_frame: usize,
     pub pending_response_id: Option<u64>,
     pub typing_indicator: String,
+    /// Set to abort the in-flight response stream
+    pub cancel_token: Option<Arc<AtomicBool>>,
+    /// Base URL of the Ollama server, e.g. `http://localhost:11434`
+    pub host: String,
+    pub host_buffer: String,
+    pub editing_host: bool,
 
     // Simplified sidebar state
     pub active_panel: ActivePanel,
     pub system_prompt: String,
     pub system_prompt_buffer: String,
     pub editing_system_prompt: bool,
+    /// Named prompts from `~/.snix/prompts`, ordered by name
+    pub prompt_templates: Vec<crate::models::prompts::PromptTemplate>,
+    /// Template applied when a new chat starts; `None` keeps the edited prompt
+    pub active_template: Option<usize>,
 
     // Chat session management
     pub chat_storage: Option<ChatStorage>,
@@ -263,9 +388,17 @@ pub struct OllamaState {
     pub history_filter: HistoryFilter,
     pub search_query: String,
     pub auto_save_enabled: bool,
+    /// Seconds between auto-saves while a reply streams in, 0 to save only when it finishes
+    pub autosave_seconds: u64,
+    /// When the current chat was last written to disk
+    pub last_saved_at: Option<Instant>,
+    /// Path being typed to export the listed sessions as one markdown file
+    pub export_path_input: Option<String>,
+    /// Open while searching the messages of the current chat
+    pub message_search: Option<MessageSearch>,
 
     // Toast notification system
-    pub toast_notifications: Vec<ToastNotification>,
+    pub toasts: Toasts,
 
     // Performance tracking
     pub current_message_start_time: Option<Instant>,
@@ -280,6 +413,170 @@ pub struct OllamaState {
     pub last_assistant_response: Option<String>,
     pub copy_button_pressed: bool,
     pub copy_button_pressed_at: Option<std::time::Instant>,
+
+    /// Index of the code block being picked for saving as a snippet or copying
+    pub code_block_picker: Option<usize>,
+    /// What Enter does with the block chosen in the picker
+    pub code_block_action: CodeBlockAction,
+
+    /// Whether the snippet the chat is about is shown above the conversation
+    pub show_snippet_panel: bool,
+    /// First visible line of the snippet panel
+    pub snippet_panel_scroll: usize,
+
+    /// Message the bookmark keys act on, moved with Alt+↑/↓
+    pub focused_message: Option<usize>,
+    /// Inner width the conversation was last drawn at, to find where a message starts
+    pub chat_width: std::cell::Cell<u16>,
+}
+
+/// An installed model as listed by Ollama's `/api/tags`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelInfo {
+    pub name: String,
+    /// Size on disk in bytes
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub details: ModelDetails,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ModelDetails {
+    /// Parameter count as Ollama writes it, e.g. "7B"
+    #[serde(default)]
+    pub parameter_size: Option<String>,
+}
+
+impl ModelInfo {
+    /// Short summary such as "7B, 4.1 GB"
+    pub fn describe(&self) -> String {
+        let size = crate::models::stats::format_size(self.size);
+        match self.details.parameter_size.as_deref() {
+            Some(parameters) if !parameters.is_empty() => format!("{}, {}", parameters, size),
+            _ => size,
+        }
+    }
+}
+
+/// System prompt used when no snippet is attached to the chat
+pub const DEFAULT_SYSTEM_PROMPT: &str = "You are a helpful AI assistant. When working with code snippets, provide clear explanations and suggestions for improvement.";
+
+/// A snippet attached to the chat as context
+#[derive(Debug, Clone)]
+pub struct AttachedSnippet {
+    pub id: Uuid,
+    pub title: String,
+    pub language: crate::models::SnippetLanguage,
+    pub content: String,
+}
+
+impl AttachedSnippet {
+    pub fn from_snippet(snippet: &crate::models::CodeSnippet) -> Self {
+        Self {
+            id: snippet.id,
+            title: snippet.title.clone(),
+            language: snippet.language.clone(),
+            content: snippet.content.clone(),
+        }
+    }
+}
+
+/// Filter text and cursor of the overlay for attaching snippets to the chat
+#[derive(Debug, Clone, Default)]
+pub struct SnippetPicker {
+    pub filter: String,
+    pub selected: usize,
+}
+
+/// Find-in-conversation prompt, opened with `/` on an empty input or Ctrl+F
+#[derive(Debug, Clone, Default)]
+pub struct MessageSearch {
+    pub query: String,
+    /// Indices of the messages containing the query, oldest first
+    pub matches: Vec<usize>,
+    /// Position in `matches` of the message brought into view
+    pub current: usize,
+}
+
+/// The snippets as fenced blocks headed by their title, for system prompts
+pub fn snippet_listing(snippets: &[AttachedSnippet]) -> String {
+    snippets
+        .iter()
+        .map(|snippet| {
+            format!(
+                "'{}' ({}):\n```{}\n{}\n```",
+                snippet.title, snippet.language, snippet.language, snippet.content
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Fills a prompt template's placeholders from the attached snippets
+pub fn fill_prompt_template(body: &str, snippets: &[AttachedSnippet]) -> String {
+    let first = snippets.first();
+    let values = std::collections::HashMap::from([
+        (
+            "title".to_string(),
+            first.map(|s| s.title.clone()).unwrap_or_default(),
+        ),
+        (
+            "language".to_string(),
+            first.map(|s| s.language.to_string()).unwrap_or_default(),
+        ),
+        (
+            "code".to_string(),
+            first.map(|s| s.content.clone()).unwrap_or_default(),
+        ),
+        ("snippets".to_string(), snippet_listing(snippets)),
+    ]);
+    crate::models::template::fill_template(body, &values)
+}
+
+/// Hash of a snippet's content, used to find the chats about it
+pub fn snippet_content_hash(content: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+/// Snippets offered by the attach picker: title or notebook matching `filter`, ordered by location
+pub fn snippet_picker_candidates<'a>(
+    app: &'a App,
+    filter: &str,
+) -> Vec<&'a crate::models::CodeSnippet> {
+    let filter = filter.to_lowercase();
+    let mut candidates: Vec<(String, &crate::models::CodeSnippet)> = app
+        .snippet_database
+        .snippets
+        .values()
+        .map(|snippet| (app.notebook_location(snippet.notebook_id), snippet))
+        .filter(|(location, snippet)| {
+            snippet.title.to_lowercase().contains(&filter)
+                || location.to_lowercase().contains(&filter)
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.title.cmp(&b.1.title)));
+    candidates.into_iter().map(|(_, snippet)| snippet).collect()
+}
+
+/// What the code block picker does with the chosen block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeBlockAction {
+    Save,
+    Copy,
+}
+
+/// A fenced code block found in an assistant response
+#[derive(Debug, Clone)]
+pub struct CodeBlock {
+    /// Fence info string, e.g. "rust"
+    pub info: String,
+    pub code: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -290,6 +587,9 @@ pub struct ChatMessage {
     pub metrics: MessageMetrics,
     #[serde(default)]
     pub context_length: u32,
+    /// Marked by the reader to find the message again, see `OllamaState::jump_to_bookmark`
+    #[serde(default)]
+    pub bookmarked: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -317,12 +617,14 @@ pub enum HistoryFilter {
 #[derive(Debug, Clone)]
 pub enum OllamaMessage {
     ModelsLoaded {
-        models: Vec<String>,
+        models: Vec<ModelInfo>,
     },
     ResponseChunk {
         request_id: u64,
         content: String,
         done: bool,
+        /// Ollama's token stats, only present on the final chunk
+        eval: Option<EvalStats>,
     },
     Error {
         request_id: u64,
@@ -342,18 +644,28 @@ impl Default for OllamaState {
             conversation: Vec::new(),
             input_buffer: String::new(),
             is_sending: false,
-            current_snippet: None,
+            attached_snippets: Vec::new(),
+            snippet_picker: None,
+            params: GenerationParams::default(),
             scroll_position: 0,
+            follow_output: true,
+            session_scroll_positions: std::collections::HashMap::new(),
             scroll_speed: 3,
             loading_animation_frame: 0,
             pending_response_id: None,
             typing_indicator: String::new(),
+            cancel_token: None,
+            host: crate::models::settings::DEFAULT_OLLAMA_HOST.to_string(),
+            host_buffer: String::new(),
+            editing_host: false,
 
             // Simplified sidebar state
             active_panel: ActivePanel::CurrentChat,
-            system_prompt: "You are a helpful AI assistant. When working with code snippets, provide clear explanations and suggestions for improvement.".to_string(),
+            system_prompt: DEFAULT_SYSTEM_PROMPT.to_string(),
             system_prompt_buffer: String::new(),
             editing_system_prompt: false,
+            prompt_templates: Vec::new(),
+            active_template: None,
 
             // Chat session management
             chat_storage,
@@ -363,9 +675,13 @@ impl Default for OllamaState {
             history_filter: HistoryFilter::All,
             search_query: String::new(),
             auto_save_enabled: true,
+            autosave_seconds: 30,
+            last_saved_at: None,
+            export_path_input: None,
+            message_search: None,
 
             // Toast notification system
-            toast_notifications: Vec::new(),
+            toasts: Toasts::default(),
 
             // Performance tracking
             current_message_start_time: None,
@@ -380,6 +696,14 @@ impl Default for OllamaState {
             last_assistant_response: None,
             copy_button_pressed: false,
             copy_button_pressed_at: None,
+
+            code_block_picker: None,
+            code_block_action: CodeBlockAction::Save,
+            show_snippet_panel: false,
+            snippet_panel_scroll: 0,
+
+            focused_message: None,
+            chat_width: std::cell::Cell::new(0),
         }
     }
 }
@@ -397,11 +721,183 @@ impl OllamaState {
             estimated_total_height.saturating_sub(estimated_visible_height)
         };
         self.scroll_position = max_scroll;
+        self.follow_output = true;
+    }
+
+    /// Scroll to the bottom for new content, unless the reader scrolled up
+    pub fn follow_new_output(&mut self) {
+        if self.follow_output {
+            self.scroll_to_bottom();
+        }
+    }
+
+    /// Keep the current chat's scroll position before switching to another chat
+    pub fn remember_scroll_position(&mut self) {
+        let Some(session_id) = self.current_session.as_ref().map(|session| session.id) else {
+            return;
+        };
+        if self.follow_output {
+            self.session_scroll_positions.remove(&session_id);
+        } else {
+            self.session_scroll_positions
+                .insert(session_id, self.scroll_position);
+        }
+    }
+
+    /// Return to where the loaded chat was left, or to its bottom the first time
+    pub fn restore_scroll_position(&mut self) {
+        let saved = self
+            .current_session
+            .as_ref()
+            .and_then(|session| self.session_scroll_positions.get(&session.id).copied());
+        match saved {
+            Some(position) => {
+                self.scroll_position = position;
+                self.follow_output = false;
+            }
+            None => self.scroll_to_bottom(),
+        }
+    }
+
+    /// First line of the message at `index`, at the width the chat was last drawn
+    fn message_line_offset(&self, index: usize) -> usize {
+        let width = self.chat_width.get() as usize;
+        self.conversation
+            .iter()
+            .take(index)
+            .map(|msg| message_height(&msg.content, width) + 1)
+            .sum()
+    }
+
+    /// Focuses the message at `index` and scrolls it to the top of the chat
+    pub fn focus_message(&mut self, index: usize) {
+        if index >= self.conversation.len() {
+            return;
+        }
+        self.focused_message = Some(index);
+        self.scroll_position = self.message_line_offset(index);
+        self.follow_output = false;
+    }
+
+    /// Moves the focus to the previous or next message, starting from the latest one
+    pub fn move_message_focus(&mut self, forward: bool) {
+        let Some(last) = self.conversation.len().checked_sub(1) else {
+            return;
+        };
+        let index = match self.focused_message.filter(|&index| index <= last) {
+            None => last,
+            Some(index) if forward => (index + 1).min(last),
+            Some(index) => index.saturating_sub(1),
+        };
+        self.focus_message(index);
+    }
+
+    /// Flips the bookmark of the focused message, or of the latest reply when none is focused
+    pub fn toggle_message_bookmark(&mut self) -> Option<bool> {
+        let index = self
+            .focused_message
+            .filter(|&index| index < self.conversation.len())
+            .or_else(|| {
+                self.conversation
+                    .iter()
+                    .rposition(|msg| msg.role == ChatRole::Assistant)
+            })?;
+        let message = &mut self.conversation[index];
+        message.bookmarked = !message.bookmarked;
+        let bookmarked = message.bookmarked;
+
+        if let Some(session) = &mut self.current_session {
+            if let Some(saved) = session.conversation.get_mut(index) {
+                saved.bookmarked = bookmarked;
+            }
+            self.unsaved_changes = true;
+        }
+        self.focused_message = Some(index);
+        Some(bookmarked)
+    }
+
+    /// Focuses the next or previous bookmarked message, wrapping around; false when there are none
+    pub fn jump_to_bookmark(&mut self, forward: bool) -> bool {
+        let bookmarks: Vec<usize> = self
+            .conversation
+            .iter()
+            .enumerate()
+            .filter(|(_, msg)| msg.bookmarked)
+            .map(|(index, _)| index)
+            .collect();
+        let target = match (self.focused_message, forward) {
+            (Some(current), true) => bookmarks
+                .iter()
+                .find(|&&index| index > current)
+                .or(bookmarks.first()),
+            (Some(current), false) => bookmarks
+                .iter()
+                .rev()
+                .find(|&&index| index < current)
+                .or(bookmarks.last()),
+            (None, true) => bookmarks.first(),
+            (None, false) => bookmarks.last(),
+        };
+        match target.copied() {
+            Some(index) => {
+                self.focus_message(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Finds the messages containing the search query and shows the most recent one
+    pub fn update_message_search(&mut self) {
+        let Some(search) = &mut self.message_search else {
+            return;
+        };
+        search.matches = self
+            .conversation
+            .iter()
+            .enumerate()
+            .filter(|(_, msg)| !find_ignore_case(&msg.content, &search.query).is_empty())
+            .map(|(index, _)| index)
+            .collect();
+        search.current = search.matches.len().saturating_sub(1);
+
+        if let Some(&index) = search.matches.last() {
+            self.focus_message(index);
+        }
+    }
+
+    /// Shows the next older or newer match, wrapping around
+    pub fn step_message_search(&mut self, older: bool) {
+        let Some(search) = &mut self.message_search else {
+            return;
+        };
+        let count = search.matches.len();
+        if count == 0 {
+            return;
+        }
+        search.current = if older {
+            (search.current + count - 1) % count
+        } else {
+            (search.current + 1) % count
+        };
+        let index = search.matches[search.current];
+        self.focus_message(index);
+    }
+
+    pub fn bookmark_count(&self) -> usize {
+        self.conversation
+            .iter()
+            .filter(|msg| msg.bookmarked)
+            .count()
     }
 
     pub fn new() -> Self {
         let mut state = Self::default();
 
+        if let Ok(templates) = crate::models::prompts::load_prompt_templates() {
+            state.prompt_templates = templates;
+        }
+
         // Initialize chat storage
         if let Ok(storage) = ChatStorage::new() {
             state.chat_storage = Some(storage);
@@ -414,6 +910,48 @@ impl OllamaState {
         state
     }
 
+    /// Creates the state pointed at the given Ollama server, with the saved prompt template active
+    pub fn from_settings(settings: &crate::models::Settings) -> Self {
+        let mut state = Self::new();
+        state.host = settings.ollama_host.clone();
+        state.autosave_seconds = settings.ollama_autosave_seconds;
+        state.active_template = state
+            .prompt_templates
+            .iter()
+            .position(|template| template.name == settings.ollama_prompt_template);
+        state.apply_prompt_template();
+        state
+    }
+
+    pub fn active_prompt_template(&self) -> Option<&crate::models::prompts::PromptTemplate> {
+        self.active_template
+            .and_then(|index| self.prompt_templates.get(index))
+    }
+
+    /// Steps through no template and then each template in turn
+    pub fn cycle_prompt_template(&mut self, forward: bool) {
+        let count = self.prompt_templates.len();
+        if count == 0 {
+            return;
+        }
+        self.active_template = match (self.active_template, forward) {
+            (None, true) => Some(0),
+            (None, false) => Some(count - 1),
+            (Some(index), true) if index + 1 < count => Some(index + 1),
+            (Some(index), false) if index > 0 => Some(index - 1),
+            (Some(_), _) => None,
+        };
+    }
+
+    /// Fills the active template into `system_prompt`; returns false when none is active
+    pub fn apply_prompt_template(&mut self) -> bool {
+        let Some(template) = self.active_prompt_template() else {
+            return false;
+        };
+        self.system_prompt = fill_prompt_template(&template.body, &self.attached_snippets);
+        true
+    }
+
     pub fn add_message(&mut self, role: ChatRole, content: String) {
         // Track last assistant response for copy functionality
         if role == ChatRole::Assistant {
@@ -425,6 +963,7 @@ impl OllamaState {
             content,
             metrics: MessageMetrics::default(),
             context_length: 0,
+            bookmarked: false,
         });
         // Auto-scroll to the bottom when a new message is added
         // Use usize::MAX which will be safely clamped in render
@@ -435,40 +974,33 @@ impl OllamaState {
         if self.models.is_empty() || self.selected_model_index >= self.models.len() {
             None
         } else {
-            Some(&self.models[self.selected_model_index])
+            Some(&self.models[self.selected_model_index].name)
         }
     }
 
     // Toast notification management
-    pub fn add_toast(
-        &mut self,
-        message: String,
-        notification_type: NotificationType,
-        duration_seconds: u64,
-    ) {
-        let toast = ToastNotification::new(message, notification_type, duration_seconds);
-        self.toast_notifications.push(toast);
-
-        // Keep only the last 5 notifications to avoid cluttering
-        if self.toast_notifications.len() > 5 {
-            self.toast_notifications.remove(0);
-        }
-    }
-
     pub fn add_success_toast(&mut self, message: String) {
-        self.add_toast(message, NotificationType::Success, 5);
+        self.toasts.success(message);
     }
 
     pub fn add_error_toast(&mut self, message: String) {
-        self.add_toast(message, NotificationType::Error, 8); // Error messages stay longer
+        self.toasts.error(message);
     }
 
     pub fn add_info_toast(&mut self, message: String) {
-        self.add_toast(message, NotificationType::Info, 5);
+        self.toasts.info(message);
     }
 
     pub fn clean_expired_toasts(&mut self) {
-        self.toast_notifications.retain(|toast| !toast.is_expired());
+        self.toasts.clean_expired();
+    }
+
+    /// Whether a spinner, toast or copy feedback needs frequent redraws
+    pub fn is_animating(&self) -> bool {
+        self.is_sending
+            || self.loading_models
+            || self.copy_button_pressed
+            || !self.toasts.is_empty()
     }
 
     pub fn start_message_timing(&mut self) {
@@ -479,22 +1011,33 @@ impl OllamaState {
 
     pub fn add_response_chunk(&mut self, chunk: &str) {
         self.current_response_buffer.push_str(chunk);
-        // Rough token estimation (words / 0.75)
+        // Rough token estimation (words / 0.75), replaced by Ollama's count once the reply ends
         let words = chunk.split_whitespace().count();
         self.current_message_token_count += (words as f64 / 0.75) as u32;
     }
 
-    pub fn finish_message_timing(&mut self) -> MessageMetrics {
+    /// Metrics of the finished reply, exact when Ollama reported `eval` stats and estimated otherwise
+    pub fn finish_message_timing(&mut self, eval: Option<EvalStats>) -> MessageMetrics {
         let mut metrics = MessageMetrics::default();
 
         if let Some(start_time) = self.current_message_start_time.take() {
             let duration = start_time.elapsed();
             metrics.response_time_ms = Some(duration.as_millis() as u64);
-            metrics.total_tokens = Some(self.current_message_token_count);
 
-            if duration.as_secs_f64() > 0.0 && self.current_message_token_count > 0 {
-                metrics.tokens_per_second =
-                    Some(self.current_message_token_count as f64 / duration.as_secs_f64());
+            match eval {
+                Some(eval) => {
+                    metrics.total_tokens = Some(eval.eval_count);
+                    metrics.tokens_per_second = eval.tokens_per_second();
+                    metrics.eval_duration_ms = Some(eval.eval_duration_ns / 1_000_000);
+                }
+                None => {
+                    metrics.total_tokens = Some(self.current_message_token_count);
+                    metrics.tokens_estimated = true;
+                    if duration.as_secs_f64() > 0.0 && self.current_message_token_count > 0 {
+                        metrics.tokens_per_second =
+                            Some(self.current_message_token_count as f64 / duration.as_secs_f64());
+                    }
+                }
             }
         }
 
@@ -502,32 +1045,92 @@ impl OllamaState {
         metrics
     }
 
+    /// Stops the in-flight response, keeping whatever was generated so far
+    pub fn cancel_generation(&mut self) {
+        if let Some(token) = self.cancel_token.take() {
+            token.store(true, Ordering::Relaxed);
+        }
+
+        // A cancelled stream never reaches the final chunk, so the counts stay estimated
+        let metrics = self.finish_message_timing(None);
+        let context_length = self.conversation.len() as u32;
+
+        // Only a reply that already started streaming is kept
+        let is_partial_reply = |msg: &&mut ChatMessage| msg.role == ChatRole::Assistant;
+
+        if let Some(last_msg) = self.conversation.last_mut().filter(is_partial_reply) {
+            last_msg.metrics = metrics.clone();
+            last_msg.context_length = context_length;
+            self.last_assistant_response = Some(last_msg.content.clone());
+        }
+
+        if let Some(session) = &mut self.current_session {
+            session.set_last_response_metrics(metrics, context_length);
+        }
+
+        self.is_sending = false;
+        self.pending_response_id = None;
+        self.typing_indicator.clear();
+        self.mark_unsaved_changes();
+        self.add_info_toast("Generation stopped".to_string());
+    }
+
     pub fn mark_unsaved_changes(&mut self) {
         self.unsaved_changes = true;
     }
 
+    /// Whether a streaming reply has gone `autosave_seconds` without being saved
+    pub fn autosave_due(&self) -> bool {
+        if !self.auto_save_enabled || !self.is_sending || self.autosave_seconds == 0 {
+            return false;
+        }
+        let since = self
+            .last_saved_at
+            .into_iter()
+            .chain(self.current_message_start_time)
+            .max();
+        since.is_some_and(|since| since.elapsed().as_secs() >= self.autosave_seconds)
+    }
+
     pub fn has_unsaved_session(&self) -> bool {
         self.current_session.is_some() && self.unsaved_changes
     }
 
+    /// Unsaved changes to a session, or a conversation that was never saved at all
+    pub fn has_unsaved_conversation(&self) -> bool {
+        let never_saved = self.current_session.is_none()
+            && self
+                .conversation
+                .iter()
+                .any(|msg| msg.role == ChatRole::User);
+
+        self.has_unsaved_session() || never_saved
+    }
+
     pub fn create_new_session(&mut self) -> anyhow::Result<()> {
         let model_name = self
             .get_selected_model()
             .map(|m| m.clone())
             .unwrap_or_else(|| "unknown".to_string());
 
-        // Create session with current system prompt
+        // Create session with current system prompt, or the active template's
+        self.apply_prompt_template();
         let mut new_session = ChatSession::new(model_name, self.system_prompt.clone());
+        new_session.params = self.params.clone();
 
-        // Associate with current snippet if available
-        if let Some(snippet) = &self.current_snippet {
-            new_session = new_session.with_snippet(snippet, "Current Snippet".to_string());
+        // Associate with the attached snippets if any
+        if !self.attached_snippets.is_empty() {
+            new_session = new_session.with_snippets(&self.attached_snippets);
         }
 
+        self.remember_scroll_position();
         self.conversation.clear();
         self.current_session = Some(new_session);
+        self.focused_message = None;
         self.scroll_position = 0;
+        self.follow_output = true;
         self.unsaved_changes = false;
+        self.last_saved_at = None;
         // Clear copy functionality state
         self.last_assistant_response = None;
 
@@ -552,21 +1155,11 @@ impl OllamaState {
                 .filter(|s| s.is_favorited)
                 .collect(),
             HistoryFilter::CurrentSnippet => {
-                if let Some(snippet) = &self.current_snippet {
-                    use std::collections::hash_map::DefaultHasher;
-                    use std::hash::{Hash, Hasher};
-
-                    let mut hasher = DefaultHasher::new();
-                    snippet.hash(&mut hasher);
-                    let snippet_hash = format!("{:x}", hasher.finish());
-
-                    self.saved_sessions
-                        .iter()
-                        .filter(|s| s.snippet_hash.as_ref() == Some(&snippet_hash))
-                        .collect()
-                } else {
-                    Vec::new()
-                }
+                let hashes = self.attached_hashes();
+                self.saved_sessions
+                    .iter()
+                    .filter(|s| hashes.iter().any(|hash| s.involves_snippet(hash)))
+                    .collect()
             }
         }
     }
@@ -574,27 +1167,11 @@ impl OllamaState {
     /// Copy the last assistant response to clipboard
     pub fn copy_last_response(&mut self) -> bool {
         if let Some(response) = &self.last_assistant_response {
-            use std::io::Write;
-            use std::process::{Command, Stdio};
-
-            let commands = [
-                ("xclip", vec!["-selection", "clipboard"]),
-                ("wl-copy", vec![]),
-                ("termux-clipboard-set", vec![]),
-            ];
-
-            for (cmd, args) in &commands {
-                if let Ok(mut process) = Command::new(cmd).args(args).stdin(Stdio::piped()).spawn()
-                {
-                    if let Some(stdin) = process.stdin.as_mut() {
-                        if stdin.write_all(response.as_bytes()).is_ok() {
-                            // Set visual feedback state with timestamp
-                            self.copy_button_pressed = true;
-                            self.copy_button_pressed_at = Some(std::time::Instant::now());
-                            return true;
-                        }
-                    }
-                }
+            if crate::handlers::keys::ClipboardHandler::copy_to_clipboard(response) {
+                // Set visual feedback state with timestamp
+                self.copy_button_pressed = true;
+                self.copy_button_pressed_at = Some(std::time::Instant::now());
+                return true;
             }
             false
         } else {
@@ -602,6 +1179,57 @@ impl OllamaState {
         }
     }
 
+    /// Fenced code blocks of the last response, largest first
+    pub fn last_response_code_blocks(&self) -> Vec<CodeBlock> {
+        let mut blocks = self
+            .last_assistant_response
+            .as_deref()
+            .map(extract_code_blocks)
+            .unwrap_or_default();
+        blocks.sort_by_key(|block| std::cmp::Reverse(block.code.len()));
+        blocks
+    }
+
+    /// Content hashes of the attached snippets
+    pub fn attached_hashes(&self) -> Vec<String> {
+        self.attached_snippets
+            .iter()
+            .map(|snippet| snippet_content_hash(&snippet.content))
+            .collect()
+    }
+
+    /// Attaches the snippet, or detaches it when already attached; returns true if now attached
+    pub fn toggle_attached_snippet(&mut self, snippet: AttachedSnippet) -> bool {
+        let attached = match self
+            .attached_snippets
+            .iter()
+            .position(|s| s.id == snippet.id)
+        {
+            Some(index) => {
+                self.attached_snippets.remove(index);
+                false
+            }
+            None => {
+                self.attached_snippets.push(snippet);
+                true
+            }
+        };
+        self.snippet_panel_scroll = 0;
+        if let Some(session) = &mut self.current_session {
+            session.set_snippets(&self.attached_snippets);
+            self.unsaved_changes = true;
+        }
+        attached
+    }
+
+    /// Lines shown by the snippet panel: a header plus the code of each attached snippet
+    pub fn snippet_panel_line_count(&self) -> usize {
+        self.attached_snippets
+            .iter()
+            .map(|snippet| snippet.content.lines().count() + 1)
+            .sum()
+    }
+
     /// Reset copy button visual feedback after timeout
     pub fn update_copy_button_feedback(&mut self) {
         if self.copy_button_pressed {
@@ -617,16 +1245,13 @@ impl OllamaState {
 
 pub fn render_ollama_popup(f: &mut Frame, app: &App, area: Rect) {
     if let Some(ollama_state) = &app.ollama_state {
-        // Always render toast notifications, even when popup is not showing
-        render_toast_notifications(f, ollama_state, area);
-
         if !ollama_state.show_popup {
             return;
         }
 
         // Make the popup take up most of the screen for better UX
-        let popup_width = area.width.min(160).max(120);
-        let popup_height = area.height.min(60).max(40);
+        let popup_width = area.width.min(160);
+        let popup_height = area.height.min(60);
         let popup_x = (area.width - popup_width) / 2;
         let popup_y = (area.height - popup_height) / 2;
         let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
@@ -637,12 +1262,12 @@ pub fn render_ollama_popup(f: &mut Frame, app: &App, area: Rect) {
             .title("  Ollama AI Assistant")
             .title_style(
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(palette().foam)
                     .add_modifier(Modifier::BOLD),
             )
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Cyan));
+            .border_style(Style::default().fg(palette().foam));
 
         f.render_widget(main_block.clone(), popup_area);
 
@@ -650,6 +1275,10 @@ pub fn render_ollama_popup(f: &mut Frame, app: &App, area: Rect) {
 
         if ollama_state.show_save_prompt {
             render_save_prompt(f, inner_area);
+        } else if let Some(index) = ollama_state.code_block_picker {
+            render_code_block_picker(f, ollama_state, index, inner_area);
+        } else if let Some(picker) = &ollama_state.snippet_picker {
+            render_snippet_picker(f, app, ollama_state, picker, inner_area);
         } else if ollama_state.loading_models {
             render_loading_screen(f, ollama_state, inner_area);
         } else if ollama_state.models.is_empty() {
@@ -657,56 +1286,14 @@ pub fn render_ollama_popup(f: &mut Frame, app: &App, area: Rect) {
         } else {
             render_main_interface(f, app, inner_area);
         }
-    }
-}
-
-fn render_toast_notifications(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
-    if ollama_state.toast_notifications.is_empty() {
-        return;
-    }
-
-    let notification_width = 50;
-    let notification_height = 3;
-    let right_margin = 2;
-    let bottom_margin = 2;
-
-    for (index, toast) in ollama_state.toast_notifications.iter().enumerate() {
-        let y_offset = (notification_height + 1) * index as u16;
-
-        let notification_area = Rect {
-            x: area.width.saturating_sub(notification_width + right_margin),
-            y: area
-                .height
-                .saturating_sub(bottom_margin + y_offset + notification_height),
-            width: notification_width,
-            height: notification_height,
-        };
 
-        // Don't render if it would go off-screen
-        if notification_area.y < area.y {
-            break;
+        if let Some(path) = &ollama_state.export_path_input {
+            let title = format!(
+                "Export {} chats to",
+                ollama_state.get_filtered_sessions().len()
+            );
+            crate::ui::components::render_prompt_bar(f, inner_area, &title, path, 0);
         }
-
-        let (icon, color) = match toast.notification_type {
-            NotificationType::Success => (" ", Color::Green),
-            NotificationType::Error => ("󰅙 ", Color::Red),
-            NotificationType::Info => (" ", Color::Blue),
-        };
-
-        let notification_block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(Style::default().fg(color))
-            .style(Style::default().bg(Color::Black));
-
-        let notification_text = Paragraph::new(format!("{} {}", icon, toast.message))
-            .block(notification_block)
-            .style(Style::default().fg(color))
-            .alignment(Alignment::Left)
-            .wrap(Wrap { trim: true });
-
-        f.render_widget(Clear, notification_area);
-        f.render_widget(notification_text, notification_area);
     }
 }
 
@@ -718,23 +1305,156 @@ fn render_save_prompt(f: &mut Frame, area: Rect) {
         Press 'N' to exit without saving\n\
         Press 'ESC' to cancel";
 
-    let save_prompt = Paragraph::new(save_prompt_text)
-        .alignment(Alignment::Center)
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )
+    let save_prompt = Paragraph::new(save_prompt_text)
+        .alignment(Alignment::Center)
+        .style(
+            Style::default()
+                .fg(palette().gold)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(
+            Block::default()
+                .title("󰀨 Unsaved Changes")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Double)
+                .border_style(Style::default().fg(palette().gold)),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(save_prompt, area);
+}
+
+fn render_code_block_picker(f: &mut Frame, ollama_state: &OllamaState, index: usize, area: Rect) {
+    let blocks = ollama_state.last_response_code_blocks();
+    let Some(block) = blocks.get(index) else {
+        return;
+    };
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(area);
+
+    let language = if block.info.trim().is_empty() {
+        "plain text".to_string()
+    } else {
+        block.info.trim().to_string()
+    };
+    let (action, hint) = match ollama_state.code_block_action {
+        CodeBlockAction::Save => (
+            "󰆓 Save code",
+            "←/→ or Ctrl+B: Choose block • Enter: Save as snippet • Esc: Cancel",
+        ),
+        CodeBlockAction::Copy => (
+            " Copy code",
+            "←/→ or Ctrl+K: Choose block • Enter: Copy • Esc: Cancel",
+        ),
+    };
+
+    let preview = Paragraph::new(block.code.clone())
+        .style(Style::default().fg(palette().text))
+        .block(
+            Block::default()
+                .title(format!(
+                    " {} block {}/{} • {} • {} lines ",
+                    action,
+                    index + 1,
+                    blocks.len(),
+                    language,
+                    block.code.lines().count()
+                ))
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Double)
+                .border_style(Style::default().fg(palette().gold)),
+        );
+    f.render_widget(preview, layout[0]);
+
+    let hint = Paragraph::new(hint)
+        .style(Style::default().fg(palette().muted))
+        .alignment(Alignment::Center);
+    f.render_widget(hint, layout[1]);
+}
+
+fn render_snippet_picker(
+    f: &mut Frame,
+    app: &App,
+    ollama_state: &OllamaState,
+    picker: &SnippetPicker,
+    area: Rect,
+) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+    let filter = Paragraph::new(format!("{}▎", picker.filter))
+        .style(Style::default().fg(palette().text))
+        .block(
+            Block::default()
+                .title(" 󰍉 Filter ")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(palette().subtle)),
+        );
+    f.render_widget(filter, layout[0]);
+
+    let candidates = snippet_picker_candidates(app, &picker.filter);
+    let items: Vec<ListItem> = candidates
+        .iter()
+        .map(|snippet| {
+            let attached = ollama_state
+                .attached_snippets
+                .iter()
+                .any(|s| s.id == snippet.id);
+            let (mark, mark_color) = if attached {
+                ("[✓] ", palette().foam)
+            } else {
+                ("[ ] ", palette().muted)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(mark, Style::default().fg(mark_color)),
+                Span::styled(
+                    format!("{} ", snippet.language.icon()),
+                    Style::default().fg(palette().foam),
+                ),
+                Span::styled(snippet.title.clone(), Style::default().fg(palette().text)),
+                Span::styled(
+                    format!("  {}", app.notebook_location(snippet.notebook_id)),
+                    Style::default().fg(palette().muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
         .block(
             Block::default()
-                .title("󰀨 Unsaved Changes")
+                .title(format!(
+                    " 󰐕 Attach snippets ({} attached) ",
+                    ollama_state.attached_snippets.len()
+                ))
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Double)
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(Style::default().fg(palette().gold)),
         )
-        .wrap(Wrap { trim: true });
+        .highlight_style(
+            Style::default()
+                .bg(palette().highlight_low)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    let selected = (!candidates.is_empty()).then_some(picker.selected.min(candidates.len() - 1));
+    let mut list_state = ListState::default().with_selected(selected);
+    f.render_stateful_widget(list, layout[1], &mut list_state);
 
-    f.render_widget(save_prompt, area);
+    let hint = Paragraph::new("↑↓: Choose • Enter: Attach/Detach • Esc: Done")
+        .style(Style::default().fg(palette().muted))
+        .alignment(Alignment::Center);
+    f.render_widget(hint, layout[2]);
 }
 
 fn render_loading_screen(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
@@ -745,7 +1465,7 @@ fn render_loading_screen(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
         .alignment(Alignment::Center)
         .style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(palette().gold)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -764,7 +1484,7 @@ fn render_error_screen(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
 
     let error_text = Paragraph::new(message)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Red))
+        .style(Style::default().fg(palette().love))
         .wrap(Wrap { trim: true });
 
     f.render_widget(error_text, area);
@@ -788,7 +1508,7 @@ fn render_sidebar(f: &mut Frame, app: &App, area: Rect) {
             .title(" 󰍻 Options")
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Blue));
+            .border_style(Style::default().fg(palette().iris));
 
         f.render_widget(sidebar_block.clone(), area);
 
@@ -800,7 +1520,7 @@ fn render_sidebar(f: &mut Frame, app: &App, area: Rect) {
             .constraints([
                 Constraint::Length(5), // Navigation
                 Constraint::Min(5),    // Content
-                Constraint::Length(5), // Shortcuts
+                Constraint::Length(6), // Shortcuts, up to four lines
             ])
             .split(sidebar_inner);
 
@@ -832,11 +1552,11 @@ fn render_sidebar_navigation(f: &mut Frame, ollama_state: &OllamaState, area: Re
             let is_selected = &ollama_state.active_panel == panel;
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(palette().base)
+                    .bg(palette().foam)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(palette().subtle)
             };
 
             let content = format!(" {} {}", icon, label);
@@ -846,7 +1566,7 @@ fn render_sidebar_navigation(f: &mut Frame, ollama_state: &OllamaState, area: Re
 
     let navigation_block = Block::default()
         .borders(Borders::BOTTOM)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(Style::default().fg(palette().muted))
         .title(" Navigation ");
 
     let navigation_list = List::new(panel_items)
@@ -866,6 +1586,9 @@ fn render_sidebar_shortcuts(f: &mut Frame, ollama_state: &OllamaState, area: Rec
                 "Ctrl+N: New chat",
                 "Ctrl+S: Save session",
                 "Ctrl+L: Clear chat",
+                "Alt+↑/↓: Focus message",
+                "Alt+B: Bookmark message",
+                "Alt+N/P: Next/prev bookmark",
                 "Enter: Send message",
                 "Esc: Exit (save prompt)",
             ]
@@ -880,6 +1603,7 @@ fn render_sidebar_shortcuts(f: &mut Frame, ollama_state: &OllamaState, area: Rec
                 "N: New chat",
                 "Enter: Load session",
                 "Delete: Delete session",
+                "Ctrl+E: Export listed to markdown",
                 "Esc: Exit (save prompt)",
             ]
         }
@@ -887,16 +1611,20 @@ fn render_sidebar_shortcuts(f: &mut Frame, ollama_state: &OllamaState, area: Rec
             vec![
                 "Tab: Switch panel",
                 "Enter: Edit system prompt",
+                "[/]: Prompt template",
+                "h: Edit Ollama host",
+                "t/T o/O c/C: Tune generation",
                 "Space: Toggle auto-save",
+                "i/I: Auto-save interval",
                 "Ctrl+R: Refresh",
                 "Esc: Exit (save prompt)",
             ]
         }
     };
 
-    // Create a nicely formatted shortcut text with 3 lines
+    // Create a nicely formatted shortcut text, three shortcuts per line
     let shortcut_text = shortcuts
-        .chunks(3) // Split into groups of 3 for 3 lines
+        .chunks(3)
         .map(|chunk| chunk.join(" • "))
         .collect::<Vec<_>>()
         .join("\n");
@@ -904,12 +1632,12 @@ fn render_sidebar_shortcuts(f: &mut Frame, ollama_state: &OllamaState, area: Rec
     let shortcuts_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(Style::default().fg(palette().muted))
         .title(" Shortcuts ");
 
     let shortcuts_paragraph = Paragraph::new(shortcut_text)
         .block(shortcuts_block)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(palette().muted))
         .alignment(Alignment::Left)
         .wrap(Wrap { trim: true });
 
@@ -932,7 +1660,7 @@ fn render_chat_options(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let header = Paragraph::new(" 󰭻 Current Chat")
         .style(
             Style::default()
-                .fg(Color::Green)
+                .fg(palette().pine)
                 .add_modifier(Modifier::BOLD),
         )
         .alignment(Alignment::Center);
@@ -963,7 +1691,7 @@ fn render_model_info(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let model_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Blue))
+        .border_style(Style::default().fg(palette().iris))
         .title(" Model ");
 
     let model_text = Paragraph::new(model_info)
@@ -978,13 +1706,13 @@ fn render_available_models(f: &mut Frame, ollama_state: &OllamaState, area: Rect
     let models_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(palette().foam))
         .title(" Available Models ");
 
     if ollama_state.loading_models {
         let loading_text = Paragraph::new("  Loading models...")
             .block(models_block)
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(palette().gold))
             .alignment(Alignment::Center);
         f.render_widget(loading_text, area);
         return;
@@ -993,7 +1721,7 @@ fn render_available_models(f: &mut Frame, ollama_state: &OllamaState, area: Rect
     if ollama_state.models.is_empty() {
         let error_text = Paragraph::new("󰅙 No models found\nInstall with:\nollama pull llama2")
             .block(models_block)
-            .style(Style::default().fg(Color::Red))
+            .style(Style::default().fg(palette().love))
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
         f.render_widget(error_text, area);
@@ -1009,15 +1737,15 @@ fn render_available_models(f: &mut Frame, ollama_state: &OllamaState, area: Rect
             let is_selected = index == ollama_state.selected_model_index;
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(palette().base)
+                    .bg(palette().foam)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(palette().text)
             };
 
             let icon = if is_selected { "" } else { "" };
-            let content = format!(" {} {}", icon, model);
+            let content = format!(" {} {}  {}", icon, model.name, model.describe());
             ListItem::new(content).style(style)
         })
         .collect();
@@ -1083,7 +1811,7 @@ fn render_session_info(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let session_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Magenta))
+        .border_style(Style::default().fg(palette().rose))
         .title(" Session ");
 
     let session_text = Paragraph::new(session_info)
@@ -1122,9 +1850,9 @@ fn render_chat_status(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .border_style(if ollama_state.is_sending {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(palette().gold)
         } else {
-            Style::default().fg(Color::Green)
+            Style::default().fg(palette().pine)
         })
         .title(" Status ");
 
@@ -1152,7 +1880,7 @@ fn render_history_manager(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
     let header = Paragraph::new(format!(" Chat History ({})", sessions_count))
         .style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(palette().foam)
                 .add_modifier(Modifier::BOLD),
         )
         .alignment(Alignment::Center);
@@ -1177,11 +1905,11 @@ fn render_history_filters(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
             let is_selected = &ollama_state.history_filter == filter;
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette().gold)
                     .add_modifier(Modifier::BOLD)
-                    .bg(Color::DarkGray)
+                    .bg(palette().muted)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(palette().subtle)
             };
 
             let content = format!("{} {}", icon, label);
@@ -1192,7 +1920,7 @@ fn render_history_filters(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
     let filter_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Blue))
+        .border_style(Style::default().fg(palette().iris))
         .title(" Filters (←/→ to navigate) ");
 
     let filter_list = List::new(filter_items)
@@ -1206,12 +1934,12 @@ fn render_search_input(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let search_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Yellow))
+        .border_style(Style::default().fg(palette().gold))
         .title("  Search ");
 
     let search_text = Paragraph::new(format!("{}_", ollama_state.search_query))
         .block(search_block)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(palette().text));
 
     f.render_widget(search_text, area);
 }
@@ -1236,13 +1964,13 @@ fn render_sessions_list(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         let empty_block = Block::default()
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(Style::default().fg(Color::DarkGray))
+            .border_style(Style::default().fg(palette().muted))
             .title(" Sessions ");
 
         let empty_text = Paragraph::new(empty_message)
             .block(empty_block)
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(palette().muted))
             .wrap(Wrap { trim: true });
 
         f.render_widget(empty_text, area);
@@ -1262,20 +1990,20 @@ fn render_sessions_list(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
 
             let (_border_style, text_style) = if is_current {
                 (
-                    Color::Green,
+                    palette().pine,
                     Style::default()
-                        .fg(Color::Green)
+                        .fg(palette().pine)
                         .add_modifier(Modifier::BOLD),
                 )
             } else if is_selected {
                 (
-                    Color::Yellow,
+                    palette().gold,
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(palette().gold)
                         .add_modifier(Modifier::BOLD),
                 )
             } else {
-                (Color::Gray, Style::default().fg(Color::White))
+                (palette().subtle, Style::default().fg(palette().text))
             };
 
             let indicators = format!(
@@ -1321,7 +2049,7 @@ fn render_sessions_list(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let sessions_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(palette().foam))
         .title(" Sessions ");
 
     let sessions_list = List::new(session_items)
@@ -1337,6 +2065,8 @@ fn render_settings_panel(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
         .constraints([
             Constraint::Length(1), // Header
             Constraint::Length(6), // Settings
+            Constraint::Length(3), // Ollama host
+            Constraint::Length(5), // Generation parameters
             Constraint::Length(4), // System prompt
             Constraint::Min(4),    // Storage stats
         ])
@@ -1346,7 +2076,7 @@ fn render_settings_panel(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
     let header = Paragraph::new(" Settings")
         .style(
             Style::default()
-                .fg(Color::Magenta)
+                .fg(palette().rose)
                 .add_modifier(Modifier::BOLD),
         )
         .alignment(Alignment::Center);
@@ -1355,18 +2085,114 @@ fn render_settings_panel(f: &mut Frame, ollama_state: &OllamaState, area: Rect)
     // Settings
     render_settings_options(f, ollama_state, layout[1]);
 
+    // Ollama host
+    render_host_section(f, ollama_state, layout[2]);
+
+    // Generation parameters
+    render_generation_params(f, ollama_state, layout[3]);
+
     // System prompt
-    render_system_prompt_section(f, ollama_state, layout[2]);
+    render_system_prompt_section(f, ollama_state, layout[4]);
 
     // Storage stats
-    render_storage_stats(f, ollama_state, layout[3]);
+    render_storage_stats(f, ollama_state, layout[5]);
+}
+
+fn render_generation_params(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
+    let params = &ollama_state.params;
+    let describe = |value: Option<String>, default: String| match value {
+        Some(value) => value,
+        None => format!("default ({})", default),
+    };
+
+    let params_text = format!(
+        " 󰔏 Temperature: {}  [t/T]\n 󰘦 Top P: {}  [o/O]\n 󰍛 Context: {} tokens  [c/C]",
+        describe(
+            params.temperature.map(|t| format!("{:.1}", t)),
+            format!("{:.1}", GenerationParams::DEFAULT_TEMPERATURE)
+        ),
+        describe(
+            params.top_p.map(|p| format!("{:.2}", p)),
+            format!("{:.2}", GenerationParams::DEFAULT_TOP_P)
+        ),
+        describe(
+            params.num_ctx.map(|n| n.to_string()),
+            GenerationParams::DEFAULT_NUM_CTX.to_string()
+        ),
+    );
+
+    let params_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(palette().pine))
+        .title(" Generation (x: Reset) ");
+
+    let params_paragraph = Paragraph::new(params_text)
+        .block(params_block)
+        .style(Style::default().fg(palette().subtle));
+
+    f.render_widget(params_paragraph, area);
+}
+
+fn render_host_section(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
+    let content = if ollama_state.editing_host {
+        &ollama_state.host_buffer
+    } else {
+        &ollama_state.host
+    };
+
+    let host_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(if ollama_state.editing_host {
+            Style::default().fg(palette().gold)
+        } else {
+            Style::default().fg(palette().foam)
+        })
+        .title(if ollama_state.editing_host {
+            " 󰒍 Editing Ollama Host (Enter: Save, Esc: Cancel) "
+        } else {
+            " 󰒍 Ollama Host (h: Edit) "
+        });
+
+    let host_text =
+        Paragraph::new(content.clone())
+            .block(host_block)
+            .style(if ollama_state.editing_host {
+                Style::default().fg(palette().text)
+            } else {
+                Style::default().fg(palette().subtle)
+            });
+
+    f.render_widget(host_text, area);
+}
+
+/// "now", "45s ago" or "3m ago"
+fn format_saved_ago(saved_at: Instant) -> String {
+    match saved_at.elapsed().as_secs() {
+        0..5 => "now".to_string(),
+        secs @ 5..60 => format!("{}s ago", secs),
+        secs => format!("{}m ago", secs / 60),
+    }
+}
+
+/// How often auto-save runs, e.g. "every 30s while streaming"
+pub fn autosave_interval_label(seconds: u64) -> String {
+    if seconds == 0 {
+        "when replies finish".to_string()
+    } else {
+        format!("every {}s while streaming", seconds)
+    }
 }
 
 fn render_settings_options(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let auto_save_status = if ollama_state.auto_save_enabled {
-        "󰗠 Enabled"
+        format!(
+            "󰗠 Enabled, {}",
+            autosave_interval_label(ollama_state.autosave_seconds)
+        )
     } else {
-        "󰅙 Disabled"
+        "󰅙 Disabled".to_string()
     };
 
     let model_count = ollama_state.models.len();
@@ -1376,7 +2202,7 @@ fn render_settings_options(f: &mut Frame, ollama_state: &OllamaState, area: Rect
         .clone();
 
     let settings_text = format!(
-        " 󰆓 Auto-save: {}\n  Available models: {}\n  Current model: {}\n 󰭻 Active conversations: {}\n  Storage: ~/.snix/ollama_chats/",
+        " 󰆓 Auto-save: {}\n  Available models: {}\n  Current model: {}\n 󰭻 Active conversations: {}\n  Storage: {}",
         auto_save_status,
         model_count,
         if current_model.len() > 15 {
@@ -1384,13 +2210,18 @@ fn render_settings_options(f: &mut Frame, ollama_state: &OllamaState, area: Rect
         } else {
             current_model
         },
-        ollama_state.saved_sessions.len()
+        ollama_state.saved_sessions.len(),
+        ollama_state
+            .chat_storage
+            .as_ref()
+            .map(|storage| storage.storage_dir.display().to_string())
+            .unwrap_or_else(|| "~/.snix/ollama_chats/".to_string())
     );
 
     let settings_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Blue))
+        .border_style(Style::default().fg(palette().iris))
         .title(" Configuration ");
 
     let settings_paragraph = Paragraph::new(settings_text)
@@ -1425,23 +2256,34 @@ fn render_system_prompt_section(f: &mut Frame, ollama_state: &OllamaState, area:
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .border_style(if ollama_state.editing_system_prompt {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(palette().gold)
         } else {
-            Style::default().fg(Color::Magenta)
+            Style::default().fg(palette().rose)
         })
-        .title(if ollama_state.editing_system_prompt {
-            "  Editing System Prompt (Enter: Save, Esc: Cancel) "
-        } else {
-            "  System Prompt (Enter: Edit) "
-        });
+        .title(
+            match (
+                ollama_state.active_prompt_template(),
+                ollama_state.editing_system_prompt,
+            ) {
+                (Some(template), true) => format!(
+                    "  Editing Template {} (Enter: Save, Esc: Cancel) ",
+                    template.name
+                ),
+                (Some(template), false) => {
+                    format!("  Template: {} ([/]: Change, Enter: Edit) ", template.name)
+                }
+                (None, true) => "  Editing System Prompt (Enter: Save, Esc: Cancel) ".to_string(),
+                (None, false) => "  System Prompt ([/]: Template, Enter: Edit) ".to_string(),
+            },
+        );
 
     let prompt_text = Paragraph::new(content.clone())
         .block(prompt_block)
         .wrap(Wrap { trim: true })
         .style(if ollama_state.editing_system_prompt {
-            Style::default().fg(Color::White)
+            Style::default().fg(palette().text)
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(palette().subtle)
         });
 
     f.render_widget(prompt_text, area);
@@ -1469,7 +2311,7 @@ fn render_storage_stats(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let stats_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Green))
+        .border_style(Style::default().fg(palette().pine))
         .title("  Storage Statistics ");
 
     let stats_paragraph = Paragraph::new(stats_text)
@@ -1495,14 +2337,14 @@ fn render_model_selection_prompt(f: &mut Frame, area: Rect) {
 
     let prompt = Paragraph::new(message)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Cyan))
+        .style(Style::default().fg(palette().foam))
         .wrap(Wrap { trim: true })
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .title(" 󰭻 Snix AI Chat ")
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(palette().foam)),
         );
 
     f.render_widget(prompt, area);
@@ -1530,12 +2372,75 @@ fn render_chat_interface(f: &mut Frame, app: &App, area: Rect) {
             .split(chat_area);
 
         render_chat_header(f, ollama_state, layout[0]);
-        render_chat_history(f, ollama_state, layout[1], scrollbar_area);
+
+        let history_area =
+            if ollama_state.show_snippet_panel && !ollama_state.attached_snippets.is_empty() {
+                let split = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Percentage(40), Constraint::Min(3)])
+                    .split(layout[1]);
+                render_snippet_panel(f, ollama_state, split[0]);
+                split[1]
+            } else {
+                layout[1]
+            };
+
+        render_chat_history(f, ollama_state, history_area, scrollbar_area);
         render_chat_input(f, ollama_state, layout[2]);
         render_chat_footer(f, ollama_state, layout[3]);
     }
 }
 
+/// Shows the attached snippets one after another, highlighted like the main preview
+fn render_snippet_panel(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
+    let mut lines = Vec::new();
+    for snippet in &ollama_state.attached_snippets {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "── {} {} ({} lines) ",
+                snippet.language.icon(),
+                snippet.title,
+                snippet.content.lines().count()
+            ),
+            Style::default()
+                .fg(palette().iris)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(crate::ui::code_snippets::highlight_snippet_lines(
+            snippet.id,
+            &snippet.content,
+            &snippet.language,
+        ));
+    }
+    let line_count = lines.len();
+    let scroll = ollama_state
+        .snippet_panel_scroll
+        .min(line_count.saturating_sub(1));
+    let title = match ollama_state.attached_snippets.as_slice() {
+        [snippet] => snippet.title.clone(),
+        snippets => format!("{} snippets", snippets.len()),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title(format!(
+            " 󰅩 {} ({} lines) • Shift+↑↓: Scroll • Ctrl+P: Hide ",
+            title, line_count
+        ))
+        .title_style(
+            Style::default()
+                .fg(palette().gold)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(Style::default().fg(palette().subtle));
+
+    let panel = Paragraph::new(lines.into_iter().skip(scroll).collect::<Vec<_>>())
+        .block(block)
+        .style(Style::default().bg(palette().surface));
+    f.render_widget(panel, area);
+}
+
 fn render_chat_header(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let model_name = ollama_state
         .get_selected_model()
@@ -1551,6 +2456,17 @@ fn render_chat_header(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         let session_info = if let Some(session) = &ollama_state.current_session {
             let mut info_parts = vec![format!("{} msgs", session.get_message_count())];
 
+            let bookmarks = ollama_state.bookmark_count();
+            if bookmarks > 0 {
+                info_parts.push(format!("󰃀 {}", bookmarks));
+            }
+
+            if ollama_state.unsaved_changes {
+                info_parts.push("● unsaved".to_string());
+            } else if let Some(saved_at) = ollama_state.last_saved_at {
+                info_parts.push(format!("󰆓 saved {}", format_saved_ago(saved_at)));
+            }
+
             if session.session_stats.total_messages > 0 {
                 if session.session_stats.average_tokens_per_second > 0.0 {
                     info_parts.push(format!(
@@ -1568,22 +2484,26 @@ fn render_chat_header(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         } else {
             " • New conversation".to_string()
         };
-        format!(" {}{}", model_name, session_info)
+        let temperature = match ollama_state.params.temperature {
+            Some(temperature) => format!(" • temp {:.1}", temperature),
+            None => " • temp default".to_string(),
+        };
+        format!(" {}{}{}", model_name, session_info, temperature)
     };
 
     let header_block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .border_style(if ollama_state.is_sending {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(palette().gold)
         } else {
-            Style::default().fg(Color::Cyan)
+            Style::default().fg(palette().foam)
         })
         .title(" Current Chat ");
 
     let header_text = Paragraph::new(header_content)
         .block(header_block.clone())
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(palette().text))
         .alignment(Alignment::Center);
 
     let header_layout = Layout::default()
@@ -1598,15 +2518,15 @@ fn render_chat_header(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
     let copy_button_style = if copy_button_available {
         if ollama_state.copy_button_pressed {
             Style::default()
-                .fg(Color::Green)
+                .fg(palette().pine)
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default()
-                .fg(Color::Blue)
+                .fg(palette().iris)
                 .add_modifier(Modifier::BOLD)
         }
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(palette().muted)
     };
 
     let copy_button_text = if ollama_state.copy_button_pressed {
@@ -1644,7 +2564,7 @@ fn render_chat_history(
         // Fallback rendering in case of panic
         let error_text =
             Paragraph::new("Error rendering chat history.\nPlease try creating a new session.")
-                .style(Style::default().fg(Color::Red))
+                .style(Style::default().fg(palette().love))
                 .alignment(Alignment::Center);
         f.render_widget(error_text, area);
         return;
@@ -1664,13 +2584,14 @@ fn render_chat_history_inner(
         .title(" 󰭻 Conversation ")
         .title_style(
             Style::default()
-                .fg(Color::Green)
+                .fg(palette().pine)
                 .add_modifier(Modifier::BOLD),
         )
-        .border_style(Style::default().fg(Color::Green));
+        .border_style(Style::default().fg(palette().pine));
 
     let chat_inner = chat_block.inner(area);
     f.render_widget(chat_block, area);
+    ollama_state.chat_width.set(chat_inner.width);
 
     // Create a snapshot of the conversation to prevent race conditions during streaming
     let conversation_snapshot = ollama_state.conversation.clone();
@@ -1678,7 +2599,7 @@ fn render_chat_history_inner(
     // Early return if no messages to render
     if conversation_snapshot.is_empty() {
         let empty_text = Paragraph::new("Start a conversation by typing below!")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(palette().muted))
             .alignment(Alignment::Center);
         f.render_widget(empty_text, chat_inner);
         return;
@@ -1689,9 +2610,7 @@ fn render_chat_history_inner(
     let message_heights: Vec<usize> = conversation_snapshot
         .iter()
         .map(|msg| {
-            let content_width = chat_inner.width.saturating_sub(4) as usize;
-            let wrapped_height = calculate_wrapped_height(&msg.content, content_width);
-            let height = wrapped_height + 2;
+            let height = message_height(&msg.content, chat_inner.width as usize);
             total_height += height + 1;
             height
         })
@@ -1759,13 +2678,18 @@ fn render_chat_history_inner(
                 } else {
                     "  You".to_string()
                 };
-                ("You", Style::default().fg(Color::Green), " ", title)
+                ("You", Style::default().fg(palette().pine), " ", title)
             }
             ChatRole::Assistant => {
                 let mut title_parts = vec!["  Assistant".to_string()];
 
                 if let Some(tps) = msg.metrics.tokens_per_second {
-                    title_parts.push(format!("{:.1} tok/s", tps));
+                    let approx = if msg.metrics.tokens_estimated {
+                        "~"
+                    } else {
+                        ""
+                    };
+                    title_parts.push(format!("{}{:.1} tok/s", approx, tps));
                 }
 
                 if let Some(response_time) = msg.metrics.response_time_ms {
@@ -1794,21 +2718,35 @@ fn render_chat_history_inner(
                     title_parts.get(0).unwrap_or(&default_title).clone()
                 };
 
-                ("Assistant", Style::default().fg(Color::Blue), "  ", title)
+                (
+                    "Assistant",
+                    Style::default().fg(palette().iris),
+                    "  ",
+                    title,
+                )
             }
             ChatRole::System => (
                 "System",
-                Style::default().fg(Color::Red),
+                Style::default().fg(palette().love),
                 " ",
                 "  System".to_string(),
             ),
         };
 
+        let mut title = vec![Span::styled(title_text, style.add_modifier(Modifier::BOLD))];
+        if msg.bookmarked {
+            title.push(Span::styled(" 󰃀 ", Style::default().fg(palette().gold)));
+        }
+        let border_type = if ollama_state.focused_message == Some(idx) {
+            ratatui::widgets::BorderType::Thick
+        } else {
+            ratatui::widgets::BorderType::Rounded
+        };
         let msg_block = Block::default()
             .borders(Borders::ALL)
-            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_type(border_type)
             .border_style(style)
-            .title(Span::styled(title_text, style.add_modifier(Modifier::BOLD)));
+            .title(Line::from(title));
 
         let msg_height = message_heights.get(idx).copied().unwrap_or(5);
         let visible_height = msg_height
@@ -1834,7 +2772,10 @@ fn render_chat_history_inner(
         {
             // Ensure minimum width and valid content before rendering
             let render_width = (inner_msg_area.width as usize).max(10);
-            let text = render_markdown(&msg.content, render_width);
+            let mut text = render_markdown(&msg.content, render_width);
+            if let Some(search) = &ollama_state.message_search {
+                highlight_query(&mut text, &search.query);
+            }
             let paragraph = Paragraph::new(text)
                 .wrap(Wrap { trim: false }) // Don't trim to preserve indentation
                 .scroll((first_line_offset.min(u16::MAX as usize) as u16, 0));
@@ -1863,11 +2804,11 @@ fn render_chat_history_inner(
             let typing_block = Block::default()
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .border_style(Style::default().fg(Color::Yellow))
+                .border_style(Style::default().fg(palette().gold))
                 .title(Span::styled(
                     "  Assistant",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(palette().gold)
                         .add_modifier(Modifier::BOLD),
                 ));
 
@@ -1877,7 +2818,7 @@ fn render_chat_history_inner(
             if !typing_inner.is_empty() {
                 let typing_text = Paragraph::new(ollama_state.typing_indicator.clone()).style(
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(palette().gold)
                         .add_modifier(Modifier::ITALIC),
                 );
                 f.render_widget(typing_text, typing_inner);
@@ -1916,9 +2857,9 @@ fn render_scrollbar(
     let safe_scrollbar_height = scrollbar_height.min(u16::MAX as usize);
     for y in 0..safe_scrollbar_height {
         let style = if y >= thumb_position && y < (thumb_position + thumb_size) {
-            Style::default().bg(Color::Cyan).fg(Color::White)
+            Style::default().bg(palette().foam).fg(palette().text)
         } else {
-            Style::default().bg(Color::DarkGray).fg(Color::Gray)
+            Style::default().bg(palette().muted).fg(palette().subtle)
         };
 
         f.render_widget(
@@ -1935,13 +2876,13 @@ fn render_chat_input(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         .title(" 󱆿 Type your message ")
         .title_style(
             Style::default()
-                .fg(Color::Blue)
+                .fg(palette().iris)
                 .add_modifier(Modifier::BOLD),
         )
         .border_style(if ollama_state.is_sending {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(palette().gold)
         } else {
-            Style::default().fg(Color::Blue)
+            Style::default().fg(palette().iris)
         });
 
     f.render_widget(input_block.clone(), area);
@@ -1953,9 +2894,9 @@ fn render_chat_input(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         .split(input_inner);
 
     let input_style = if ollama_state.is_sending {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(palette().muted)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(palette().text)
     };
 
     if ollama_state.is_sending {
@@ -1977,7 +2918,7 @@ fn render_chat_input(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         for ch in ollama_state.input_buffer.chars() {
             let char_style = if ch == ' ' {
                 // Make spaces slightly visible with background highlight
-                input_style.bg(ratatui::style::Color::DarkGray)
+                input_style.bg(palette().muted)
             } else {
                 input_style
             };
@@ -1996,44 +2937,89 @@ fn render_chat_input(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
         f.render_widget(input, input_layout[0]);
     }
 
-    // Show current snippet info if available
-    if let Some(snippet) = &ollama_state.current_snippet {
-        if !snippet.is_empty() {
-            let snippet_text = format!("󰎚 Snippet: {} lines", snippet.lines().count());
-            let snippet_info = Paragraph::new(snippet_text)
-                .style(
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::ITALIC),
-                )
-                .alignment(Alignment::Right);
-
-            f.render_widget(snippet_info, input_layout[1]);
+    // Show attached snippet info
+    let snippet_text = match ollama_state.attached_snippets.as_slice() {
+        [] => "󰐕 Ctrl+A: Attach snippets".to_string(),
+        snippets => {
+            let toggle_hint = if ollama_state.show_snippet_panel {
+                "hide"
+            } else {
+                "show"
+            };
+            let summary = match snippets {
+                [snippet] => format!("Snippet: {} lines", snippet.content.lines().count()),
+                _ => format!("{} snippets", snippets.len()),
+            };
+            format!("󰎚 {} • Ctrl+A: Attach • Ctrl+P: {}", summary, toggle_hint)
         }
-    }
+    };
+    let snippet_info = Paragraph::new(snippet_text)
+        .style(
+            Style::default()
+                .fg(palette().gold)
+                .add_modifier(Modifier::ITALIC),
+        )
+        .alignment(Alignment::Right);
+
+    f.render_widget(snippet_info, input_layout[1]);
 }
 
 fn render_chat_footer(f: &mut Frame, ollama_state: &OllamaState, area: Rect) {
+    if let Some(search) = &ollama_state.message_search {
+        render_message_search(f, search, area);
+        return;
+    }
+
     let shortcuts = if ollama_state.is_sending {
-        " Generating... • ↑↓: Scroll • Tab: Switch panels • Esc: Cancel".to_string()
+        " Generating... • ↑↓: Scroll • Tab: Switch panels • Esc: Stop (keeps partial reply)"
+            .to_string()
     } else {
         let copy_hint = if ollama_state.last_assistant_response.is_some() {
-            " • Ctrl+C: Copy response"
+            " • Ctrl+C: Copy response • Ctrl+K: Copy code • Ctrl+B: Save code as snippet • Ctrl+G: Regenerate"
         } else {
             ""
         };
         format!(
-            "↑↓: Scroll • PgUp/PgDn: Fast scroll • Tab: Switch panels • Ctrl+L: Clear{} • Enter: Send",
+            "↑↓: Scroll • PgUp/PgDn: Fast scroll • /: Find • Tab: Switch panels • Ctrl+L: Clear{} • Enter: Send",
             copy_hint
         )
     };
 
     let footer = Paragraph::new(shortcuts)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(palette().muted))
         .alignment(Alignment::Center);
     f.render_widget(footer, area);
 }
 
+/// Collects the fenced (```) code blocks of a markdown text in order
+pub fn extract_code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlock> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            match current.take() {
+                Some(block) => blocks.push(block),
+                None => {
+                    current = Some(CodeBlock {
+                        info: info.trim().to_string(),
+                        code: String::new(),
+                    })
+                }
+            }
+        } else if let Some(block) = current.as_mut() {
+            block.code.push_str(line);
+            block.code.push('\n');
+        }
+    }
+
+    // An unterminated fence still counts, e.g. when the reply was cut short
+    blocks.extend(current);
+    blocks.retain(|block| !block.code.trim().is_empty());
+    blocks
+}
+
 // Preprocess plain text to add intelligent formatting
 fn preprocess_plain_text_for_formatting(text: &str) -> String {
     // Safety check for empty or very short text
@@ -2176,20 +3162,13 @@ fn preprocess_plain_text_for_formatting(text: &str) -> String {
     }
 }
 
-// Enhanced markdown rendering with syntax highlighting and better formatting
-fn render_markdown(markdown: &str, width: usize) -> Text {
-    use syntect::{highlighting::ThemeSet, parsing::SyntaxSet};
-
-    static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(|| SyntaxSet::load_defaults_newlines());
-    static THEME_SET: Lazy<ThemeSet> = Lazy::new(|| ThemeSet::load_defaults());
-
+// Chat replies are often plain text, so add formatting before the shared markdown renderer
+fn render_markdown(markdown: &str, width: usize) -> Text<'static> {
     // Safety checks - return basic text if inputs are invalid
     if markdown.trim().is_empty() || width == 0 {
-        return Text::from(markdown);
+        return Text::from(markdown.to_string());
     }
 
-    let safe_width = width.max(10).min(1000); // Ensure reasonable width bounds
-
     // Preprocess plain text to add intelligent formatting - with error handling
     let processed_markdown = match panic::catch_unwind(panic::AssertUnwindSafe(|| {
         preprocess_plain_text_for_formatting(markdown)
@@ -2201,488 +3180,101 @@ fn render_markdown(markdown: &str, width: usize) -> Text {
         }
     };
 
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    options.insert(Options::ENABLE_TABLES);
-    options.insert(Options::ENABLE_FOOTNOTES);
-    options.insert(Options::ENABLE_TASKLISTS);
-
-    let parser = Parser::new_ext(&processed_markdown, options);
-
-    let mut text = Text::default();
-    let mut current_line = Line::default();
-    let mut current_style = Style::default().fg(Color::White);
-    let mut in_code_block = false;
-    let mut code_block_language: Option<String> = None;
-    let mut code_block_content = String::new();
-    let mut list_depth: usize = 0;
-    let mut in_blockquote = false;
-
-    // Get the "base16-ocean.dark" theme for syntax highlighting
-    let theme = THEME_SET
-        .themes
-        .get("base16-ocean.dark")
-        .unwrap_or_else(|| THEME_SET.themes.values().next().unwrap());
-
-    for event in parser {
-        match event {
-            Event::Start(Tag::Paragraph) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-
-                // Add blockquote prefix if we're in a blockquote
-                if in_blockquote {
-                    current_line
-                        .spans
-                        .push(Span::styled("▌ ", Style::default().fg(Color::DarkGray)));
-                }
-            }
-            Event::End(TagEnd::Paragraph) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-                text.lines.push(Line::default());
-            }
-            Event::Start(Tag::Heading { level, .. }) => {
-                let (level_style, prefix) = match level {
-                    HeadingLevel::H1 => (
-                        Style::default()
-                            .fg(Color::LightCyan)
-                            .add_modifier(Modifier::BOLD),
-                        "# ",
-                    ),
-                    HeadingLevel::H2 => (
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                        "## ",
-                    ),
-                    HeadingLevel::H3 => (
-                        Style::default()
-                            .fg(Color::LightBlue)
-                            .add_modifier(Modifier::BOLD),
-                        "### ",
-                    ),
-                    _ => (
-                        Style::default()
-                            .fg(Color::Blue)
-                            .add_modifier(Modifier::BOLD),
-                        "#### ",
-                    ),
-                };
-                current_style = level_style;
-
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-
-                current_line.spans.push(Span::styled(prefix, level_style));
-            }
-            Event::End(TagEnd::Heading(_)) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-                current_style = Style::default().fg(Color::White);
-                text.lines.push(Line::default());
-            }
-            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-
-                in_code_block = true;
-                code_block_language = if lang.is_empty() {
-                    None
-                } else {
-                    Some(lang.to_string())
-                };
-                code_block_content.clear();
-
-                // Add code block header with language indicator
-                let lang_display = code_block_language.as_deref().unwrap_or("text");
-                let header_line = Line::from(vec![
-                    Span::styled("┌─ ", Style::default().fg(Color::DarkGray)),
-                    Span::styled("", Style::default().fg(Color::LightGreen)),
-                    Span::styled(
-                        format!(" {} ", lang_display.to_uppercase()),
-                        Style::default()
-                            .fg(Color::Black)
-                            .bg(Color::LightGreen)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(" ", Style::default()),
-                ]);
-                text.lines.push(header_line);
-            }
-            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-
-                in_code_block = true;
-                code_block_language = None;
-                code_block_content.clear();
-
-                // Add simple code block header
-                let header_line = Line::from(vec![
-                    Span::styled("┌─ ", Style::default().fg(Color::DarkGray)),
-                    Span::styled("", Style::default().fg(Color::Gray)),
-                    Span::styled(
-                        " CODE ",
-                        Style::default()
-                            .fg(Color::White)
-                            .bg(Color::Gray)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(" ", Style::default()),
-                ]);
-                text.lines.push(header_line);
-            }
-            Event::End(TagEnd::CodeBlock) => {
-                if in_code_block {
-                    // Apply syntax highlighting to the collected code block content
-                    if let Some(ref lang) = code_block_language {
-                        if let Some(syntax) = SYNTAX_SET.find_syntax_by_token(lang) {
-                            render_highlighted_code_block(
-                                &mut text,
-                                &code_block_content,
-                                syntax,
-                                theme,
-                                &SYNTAX_SET,
-                            );
-                        } else {
-                            // Fallback to plain text with basic styling
-                            render_plain_code_block(&mut text, &code_block_content);
-                        }
-                    } else {
-                        // Plain code block
-                        render_plain_code_block(&mut text, &code_block_content);
-                    }
-
-                    // Add code block footer
-                    let footer_width = if safe_width > 2 { safe_width - 2 } else { 0 };
-                    let footer_line = Line::from(vec![
-                        Span::styled("└─", Style::default().fg(Color::DarkGray)),
-                        Span::styled(
-                            "─".repeat(footer_width),
-                            Style::default().fg(Color::DarkGray),
-                        ),
-                    ]);
-                    text.lines.push(footer_line);
-                    text.lines.push(Line::default());
-
-                    in_code_block = false;
-                    code_block_language = None;
-                    code_block_content.clear();
-                }
-            }
-            Event::Start(Tag::List(start_num)) => {
-                list_depth += 1;
-                if start_num.is_some() {
-                    // Ordered list - we'll handle numbering in list items
-                }
-            }
-            Event::End(TagEnd::List(_)) => {
-                list_depth = list_depth.saturating_sub(1);
-                if list_depth == 0 {
-                    text.lines.push(Line::default());
-                }
-            }
-            Event::Start(Tag::Item) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-
-                // Add indentation and bullet point based on depth
-                let indent = "  ".repeat(list_depth.saturating_sub(1));
-                current_line.spans.push(Span::styled(
-                    format!("{}• ", indent),
-                    Style::default().fg(Color::Yellow),
-                ));
-            }
-            Event::End(TagEnd::Item) => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-            }
-            Event::Start(Tag::BlockQuote(_)) => {
-                in_blockquote = true;
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-            }
-            Event::End(TagEnd::BlockQuote(_)) => {
-                in_blockquote = false;
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-                text.lines.push(Line::default());
-            }
-            Event::Start(Tag::Emphasis) => {
-                current_style = current_style.add_modifier(Modifier::ITALIC);
-            }
-            Event::End(TagEnd::Emphasis) => {
-                current_style = current_style.remove_modifier(Modifier::ITALIC);
-            }
-            Event::Start(Tag::Strong) => {
-                current_style = current_style.add_modifier(Modifier::BOLD);
-            }
-            Event::End(TagEnd::Strong) => {
-                current_style = current_style.remove_modifier(Modifier::BOLD);
-            }
-            Event::Start(Tag::Strikethrough) => {
-                current_style = current_style.add_modifier(Modifier::CROSSED_OUT);
-            }
-            Event::End(TagEnd::Strikethrough) => {
-                current_style = current_style.remove_modifier(Modifier::CROSSED_OUT);
-            }
-            Event::Code(text_str) => {
-                current_line.spans.push(Span::styled(
-                    format!("`{}`", text_str),
-                    Style::default()
-                        .fg(Color::Black)
-                        .bg(Color::LightMagenta)
-                        .add_modifier(Modifier::BOLD),
-                ));
-            }
-            Event::Text(text_str) => {
-                if in_code_block {
-                    // Collect code block content for later syntax highlighting
-                    code_block_content.push_str(&text_str);
-                } else {
-                    let text_content = text_str.to_string();
-                    if text_content.contains('\n') {
-                        for (i, line) in text_content.split('\n').enumerate() {
-                            if i > 0 {
-                                if !current_line.spans.is_empty() {
-                                    text.lines.push(current_line);
-                                    current_line = Line::default();
-                                }
-                                // Preserve blockquote prefix on new lines
-                                if in_blockquote {
-                                    current_line.spans.push(Span::styled(
-                                        "▌ ",
-                                        Style::default().fg(Color::DarkGray),
-                                    ));
-                                }
-                            }
-                            if !line.is_empty() {
-                                // Handle word wrapping for each line
-                                let wrapped_lines =
-                                    wrap_text_to_width(line, safe_width, in_blockquote);
-                                for (j, wrapped_line) in wrapped_lines.iter().enumerate() {
-                                    if j > 0 {
-                                        if !current_line.spans.is_empty() {
-                                            text.lines.push(current_line);
-                                            current_line = Line::default();
-                                        }
-                                        // Add blockquote prefix for continuation lines
-                                        if in_blockquote {
-                                            current_line.spans.push(Span::styled(
-                                                "▌ ",
-                                                Style::default().fg(Color::DarkGray),
-                                            ));
-                                        }
-                                    }
-                                    current_line
-                                        .spans
-                                        .push(Span::styled(wrapped_line.clone(), current_style));
-                                }
-                            }
-                        }
-                    } else {
-                        // Handle word wrapping for single lines
-                        let wrapped_lines =
-                            wrap_text_to_width(&text_content, safe_width, in_blockquote);
-                        for (i, wrapped_line) in wrapped_lines.iter().enumerate() {
-                            if i > 0 {
-                                if !current_line.spans.is_empty() {
-                                    text.lines.push(current_line);
-                                    current_line = Line::default();
-                                }
-                                // Add blockquote prefix for continuation lines
-                                if in_blockquote {
-                                    current_line.spans.push(Span::styled(
-                                        "▌ ",
-                                        Style::default().fg(Color::DarkGray),
-                                    ));
-                                }
-                            }
-                            current_line
-                                .spans
-                                .push(Span::styled(wrapped_line.clone(), current_style));
-                        }
-                    }
-                }
-            }
-            Event::SoftBreak => {
-                current_line.spans.push(Span::styled(" ", current_style));
-            }
-            Event::HardBreak => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-                // Preserve blockquote prefix on new lines
-                if in_blockquote {
-                    current_line
-                        .spans
-                        .push(Span::styled("▌ ", Style::default().fg(Color::DarkGray)));
-                }
-            }
-            Event::Rule => {
-                if !current_line.spans.is_empty() {
-                    text.lines.push(current_line);
-                    current_line = Line::default();
-                }
-                // Add horizontal rule
-                let rule_width = if safe_width > 4 {
-                    safe_width - 4
-                } else {
-                    safe_width
-                };
-                let rule_line = Line::from(vec![Span::styled(
-                    "─".repeat(rule_width),
-                    Style::default().fg(Color::DarkGray),
-                )]);
-                text.lines.push(rule_line);
-                text.lines.push(Line::default());
-            }
-            _ => {}
-        }
-    }
-
-    if !current_line.spans.is_empty() {
-        text.lines.push(current_line);
-    }
-
-    text
+    crate::ui::markdown::render_markdown(&processed_markdown, width)
 }
 
-// Helper function to render syntax-highlighted code blocks
-fn render_highlighted_code_block(
-    text: &mut Text,
-    code_content: &str,
-    syntax: &syntect::parsing::SyntaxReference,
-    theme: &syntect::highlighting::Theme,
-    syntax_set: &syntect::parsing::SyntaxSet,
-) {
-    use syntect::easy::HighlightLines;
-    use syntect::util::LinesWithEndings;
-
-    let mut highlighter = HighlightLines::new(syntax, theme);
-
-    for line in LinesWithEndings::from(code_content) {
-        if let Ok(highlighted) = highlighter.highlight_line(line, syntax_set) {
-            let mut spans = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+/// The find prompt shown in place of the footer while searching the chat
+fn render_message_search(f: &mut Frame, search: &MessageSearch, area: Rect) {
+    let count = if search.query.is_empty() {
+        String::new()
+    } else if search.matches.is_empty() {
+        "  no matches".to_string()
+    } else {
+        format!("  {}/{}", search.current + 1, search.matches.len())
+    };
 
-            for (style, content) in highlighted {
-                let fg_color = convert_syntect_color_to_ratatui(style.foreground);
-                let text_style = if style
-                    .font_style
-                    .contains(syntect::highlighting::FontStyle::BOLD)
-                {
-                    Style::default().fg(fg_color).add_modifier(Modifier::BOLD)
-                } else if style
-                    .font_style
-                    .contains(syntect::highlighting::FontStyle::ITALIC)
-                {
-                    Style::default().fg(fg_color).add_modifier(Modifier::ITALIC)
-                } else {
-                    Style::default().fg(fg_color)
-                };
+    let line = Line::from(vec![
+        Span::styled(" Find: ", Style::default().fg(palette().gold)),
+        Span::styled(search.query.clone(), Style::default().fg(palette().text)),
+        Span::styled(count, Style::default().fg(palette().subtle)),
+        Span::styled(
+            "  • Enter/↑: Older match • ↓: Newer match • Esc: Close",
+            Style::default().fg(palette().muted),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+
+    let query_width = UnicodeWidthStr::width(search.query.as_str()) as u16;
+    f.set_cursor_position((area.x + 7 + query_width, area.y));
+}
 
-                spans.push(Span::styled(content.to_string(), text_style));
-            }
+/// Byte ranges where `query` occurs in `text`, ignoring ASCII case
+fn find_ignore_case(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let needle = query.as_bytes();
+    let haystack = text.as_bytes();
+    let mut ranges = Vec::new();
+    if needle.is_empty() {
+        return ranges;
+    }
 
-            text.lines.push(Line::from(spans));
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        if text.is_char_boundary(start)
+            && haystack[start..start + needle.len()].eq_ignore_ascii_case(needle)
+        {
+            ranges.push((start, start + needle.len()));
+            start += needle.len();
         } else {
-            // Fallback for highlighting errors
-            text.lines.push(Line::from(vec![
-                Span::styled("│ ", Style::default().fg(Color::DarkGray)),
-                Span::styled(line.to_string(), Style::default().fg(Color::LightYellow)),
-            ]));
+            start += 1;
         }
     }
+    ranges
 }
 
-// Helper function to render plain code blocks
-fn render_plain_code_block(text: &mut Text, code_content: &str) {
-    for line in code_content.lines() {
-        let code_line = Line::from(vec![
-            Span::styled("│ ", Style::default().fg(Color::DarkGray)),
-            Span::styled(line.to_string(), Style::default().fg(Color::LightYellow)),
-        ]);
-        text.lines.push(code_line);
-    }
-}
-
-// Helper function to convert syntect colors to ratatui colors
-fn convert_syntect_color_to_ratatui(color: syntect::highlighting::Color) -> Color {
-    Color::Rgb(color.r, color.g, color.b)
-}
-
-// Helper function to intelligently wrap text to a given width
-fn wrap_text_to_width(text: &str, width: usize, in_blockquote: bool) -> Vec<String> {
-    if width == 0 || text.is_empty() {
-        return vec![text.to_string()];
-    }
-
-    // Account for blockquote prefix
-    let effective_width = if in_blockquote {
-        // Account for "▌ " prefix
-        width.saturating_sub(2)
-    } else {
-        width
-    };
-
-    if effective_width == 0 {
-        return vec![text.to_string()];
+/// Marks the occurrences of `query` in rendered message text
+fn highlight_query(text: &mut Text<'static>, query: &str) {
+    if query.is_empty() {
+        return;
     }
 
-    let words: Vec<&str> = text.split_whitespace().collect();
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-    let mut current_width = 0;
-
-    for word in words {
-        let word_width = UnicodeWidthStr::width(word);
+    for line in &mut text.lines {
+        let mut spans = Vec::with_capacity(line.spans.len());
+        for span in line.spans.drain(..) {
+            let ranges = find_ignore_case(&span.content, query);
+            if ranges.is_empty() {
+                spans.push(span);
+                continue;
+            }
 
-        // Check if we need to break the line
-        if current_width + word_width + 1 > effective_width && !current_line.is_empty() {
-            lines.push(current_line);
-            current_line = word.to_string();
-            current_width = word_width;
-        } else {
-            if !current_line.is_empty() {
-                current_line.push(' ');
-                current_width += 1;
+            let highlight = span.style.fg(palette().base).bg(palette().gold);
+            let mut position = 0;
+            for (start, end) in ranges {
+                if start > position {
+                    spans.push(Span::styled(
+                        span.content[position..start].to_string(),
+                        span.style,
+                    ));
+                }
+                spans.push(Span::styled(
+                    span.content[start..end].to_string(),
+                    highlight,
+                ));
+                position = end;
+            }
+            if position < span.content.len() {
+                spans.push(Span::styled(
+                    span.content[position..].to_string(),
+                    span.style,
+                ));
             }
-            current_line.push_str(word);
-            current_width += word_width;
         }
+        line.spans = spans;
     }
+}
 
-    if !current_line.is_empty() {
-        lines.push(current_line);
-    }
-
-    if lines.is_empty() {
-        vec![String::new()]
-    } else {
-        lines
-    }
+/// Lines a message box takes in a chat of the given inner width, borders included
+fn message_height(content: &str, chat_width: usize) -> usize {
+    calculate_wrapped_height(content, chat_width.saturating_sub(4)) + 2
 }
 
 fn calculate_wrapped_height(text: &str, width: usize) -> usize {
@@ -2724,9 +3316,8 @@ pub struct ChatStorage {
 
 impl ChatStorage {
     pub fn new() -> anyhow::Result<Self> {
-        let storage_dir = dirs::home_dir()
+        let storage_dir = crate::models::storage::snix_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?
-            .join(".snix")
             .join("ollama_chats");
 
         std::fs::create_dir_all(&storage_dir)?;
@@ -2798,39 +3389,40 @@ impl ChatStorage {
             ExportFormat::Json => Ok(serde_json::to_string_pretty(session)?),
             ExportFormat::Markdown => {
                 let mut output = String::new();
-                output.push_str(&format!("# {}\n\n", session.title));
-                output.push_str(&format!("**Model:** {}\n", session.model_name));
-                output.push_str(&format!(
-                    "**Created:** {}\n",
-                    session.created_at.format("%Y-%m-%d %H:%M:%S UTC")
-                ));
-                output.push_str(&format!(
-                    "**Updated:** {}\n",
-                    session.updated_at.format("%Y-%m-%d %H:%M:%S UTC")
-                ));
-
-                if let Some(snippet_title) = &session.snippet_title {
-                    output.push_str(&format!("**Code Snippet:** {}\n", snippet_title));
-                }
-
-                if !session.tags.is_empty() {
-                    output.push_str(&format!("**Tags:** {}\n", session.tags.join(", ")));
-                }
+                write_session_markdown(&mut output, session, "#");
+                Ok(output)
+            }
+        }
+    }
 
-                output.push_str("\n---\n\n");
+    /// One markdown document holding the given sessions, with a table of contents
+    pub fn export_all_sessions(&self, sessions: &[&ChatSession]) -> String {
+        let mut output = String::from("# Ollama Chats\n\n");
+        output.push_str(&format!(
+            "Exported {} • {} sessions\n\n## Contents\n\n",
+            Utc::now().format("%Y-%m-%d %H:%M UTC"),
+            sessions.len()
+        ));
 
-                for msg in &session.conversation {
-                    let role = match msg.role {
-                        ChatRole::User => " **User**",
-                        ChatRole::Assistant => "  **Assistant**",
-                        ChatRole::System => " **System**",
-                    };
-                    output.push_str(&format!("{}\n\n{}\n\n---\n\n", role, msg.content));
-                }
+        for (index, session) in sessions.iter().enumerate() {
+            output.push_str(&format!(
+                "{}. [{}](#chat-{}) — {} • {} messages • {}\n",
+                index + 1,
+                session.title,
+                session.id,
+                session.model_name,
+                session.conversation.len(),
+                session.updated_at.format("%Y-%m-%d")
+            ));
+        }
+        output.push_str("\n---\n\n");
 
-                Ok(output)
-            }
+        for session in sessions {
+            output.push_str(&format!("<a id=\"chat-{}\"></a>\n\n", session.id));
+            write_session_markdown(&mut output, session, "##");
+            output.push('\n');
         }
+        output
     }
 
     pub fn get_storage_stats(&self) -> anyhow::Result<StorageStats> {
@@ -2858,6 +3450,43 @@ impl ChatStorage {
     }
 }
 
+/// Appends a session as markdown: a heading at `heading` level, metadata, then every message
+fn write_session_markdown(output: &mut String, session: &ChatSession, heading: &str) {
+    output.push_str(&format!("{} {}\n\n", heading, session.title));
+    output.push_str(&format!("**Model:** {}\n", session.model_name));
+    output.push_str(&format!(
+        "**Created:** {}\n",
+        session.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    output.push_str(&format!(
+        "**Updated:** {}\n",
+        session.updated_at.format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+
+    if let Some(snippet_title) = &session.snippet_title {
+        output.push_str(&format!("**Code Snippet:** {}\n", snippet_title));
+    }
+
+    if !session.tags.is_empty() {
+        output.push_str(&format!("**Tags:** {}\n", session.tags.join(", ")));
+    }
+
+    output.push_str("\n---\n\n");
+
+    for msg in &session.conversation {
+        let role = match msg.role {
+            ChatRole::User => " **User**",
+            ChatRole::Assistant => "  **Assistant**",
+            ChatRole::System => " **System**",
+        };
+        let bookmark = if msg.bookmarked { " (bookmarked)" } else { "" };
+        output.push_str(&format!(
+            "{}{}\n\n{}\n\n---\n\n",
+            role, bookmark, msg.content
+        ));
+    }
+}
+
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub enum ExportFormat {
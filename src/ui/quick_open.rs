@@ -0,0 +1,126 @@
+use crate::app::App;
+use crate::search::quick_open_matches;
+use crate::ui::colors::{language_color, palette};
+use crate::ui::components::render_text_input;
+use crate::ui::search::highlight_indices;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, List, ListItem, ListState, Paragraph, Widget},
+};
+
+/// Render the Ctrl+P quick open picker as a floating popup
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let popup_width = 80.min(area.width);
+    let popup_height = 20.min(area.height);
+
+    let popup_area = Rect::new(
+        area.x + (area.width - popup_width) / 2,
+        area.y + (area.height - popup_height) / 3,
+        popup_width,
+        popup_height,
+    );
+
+    Clear.render(popup_area, frame.buffer_mut());
+
+    let block = Block::bordered()
+        .title(" 󰈞 Quick Open ")
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().iris).bg(palette().surface));
+
+    let inner_area = block.inner(popup_area);
+    block.render(popup_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .split(inner_area);
+
+    let [prompt_area, input_area] =
+        Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]).areas(chunks[0]);
+    Paragraph::new("❯ ")
+        .style(Style::default().fg(palette().iris).bold())
+        .render(prompt_area, frame.buffer_mut());
+    render_text_input(
+        frame,
+        input_area,
+        &app.input_buffer,
+        app.input_cursor,
+        Style::default().fg(palette().text),
+    );
+
+    let matches = quick_open_matches(app, &app.input_buffer);
+    Paragraph::new(format!(
+        "{}/{} snippets",
+        matches.len(),
+        app.snippet_database.snippets.len()
+    ))
+    .style(Style::default().fg(palette().muted))
+    .render(chunks[1], frame.buffer_mut());
+
+    if matches.is_empty() {
+        Paragraph::new("No snippet title or tag matches")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(palette().gold))
+            .render(chunks[2], frame.buffer_mut());
+    } else {
+        let items: Vec<ListItem> = matches
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (snippet_id, indices))| {
+                let snippet = app.snippet_database.snippets.get(snippet_id)?;
+                let is_selected = i == app.quick_open_selected;
+                let name_style = if is_selected {
+                    Style::default().fg(palette().text).bold()
+                } else {
+                    Style::default().fg(palette().text)
+                };
+
+                let mut spans = vec![
+                    Span::styled(
+                        if is_selected { "→ " } else { "  " },
+                        Style::default().fg(palette().gold),
+                    ),
+                    Span::styled(
+                        format!("{} ", snippet.language.icon()),
+                        Style::default().fg(language_color(&snippet.language)),
+                    ),
+                ];
+                spans.extend(highlight_indices(
+                    &snippet.title,
+                    indices,
+                    name_style,
+                    Style::default().fg(palette().love).bold(),
+                ));
+                spans.push(Span::styled(
+                    format!("  {}", app.notebook_path(snippet.notebook_id)),
+                    Style::default().fg(palette().subtle),
+                ));
+
+                Some(ListItem::new(Line::from(spans)).style(if is_selected {
+                    Style::default().bg(palette().overlay)
+                } else {
+                    Style::default()
+                }))
+            })
+            .collect();
+
+        frame.render_stateful_widget(
+            List::new(items),
+            chunks[2],
+            &mut ListState::default().with_selected(Some(app.quick_open_selected)),
+        );
+    }
+
+    Paragraph::new("↑↓ Select  ⏎ Open in editor  Esc Close")
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().muted))
+        .render(chunks[3], frame.buffer_mut());
+}
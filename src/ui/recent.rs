@@ -0,0 +1,82 @@
+use crate::app::{App, RECENT_SNIPPET_LIMIT};
+use crate::ui::colors::palette;
+use crate::ui::components::render_bottom_bar;
+use crate::ui::start_page::format_time_ago;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Cell, Paragraph, Row, Table, TableState, Widget},
+};
+
+/// Render the most recently opened snippets, newest first
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let main_area = frame.area();
+    let recent = app.recent_snippets(RECENT_SNIPPET_LIMIT);
+
+    let block = Block::bordered()
+        .title(format!(" Recent Snippets ({}) ", recent.len()))
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(inner_area);
+
+    if recent.is_empty() {
+        Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No snippets opened yet").fg(palette().subtle),
+            Line::from("Snippets you open show up here").fg(palette().muted),
+        ])
+        .alignment(Alignment::Center)
+        .render(chunks[0], frame.buffer_mut());
+    } else {
+        let rows: Vec<Row> = recent
+            .iter()
+            .map(|snippet| {
+                Row::new(vec![
+                    Cell::from(format!("{} {}", snippet.language.icon(), snippet.title))
+                        .style(Style::default().fg(palette().text)),
+                    Cell::from(app.notebook_path(snippet.notebook_id))
+                        .style(Style::default().fg(palette().subtle)),
+                    Cell::from(snippet.language.display_name())
+                        .style(Style::default().fg(palette().foam)),
+                    Cell::from(format_time_ago(&snippet.accessed_at))
+                        .style(Style::default().fg(palette().muted)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(2),
+                Constraint::Fill(2),
+                Constraint::Length(14),
+                Constraint::Length(14),
+            ],
+        )
+        .header(
+            Row::new(vec!["Snippet", "Notebook", "Language", "Opened"])
+                .style(Style::default().fg(palette().gold).bold())
+                .bottom_margin(1),
+        )
+        .row_highlight_style(
+            Style::default()
+                .fg(palette().love)
+                .bg(palette().highlight_low)
+                .bold(),
+        )
+        .highlight_symbol("▶ ");
+
+        let mut table_state =
+            TableState::default().with_selected(Some(app.selected_recent_snippet));
+        frame.render_stateful_widget(table, chunks[0], &mut table_state);
+    }
+
+    render_bottom_bar(frame, chunks[1], app);
+}
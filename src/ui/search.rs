@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::app::{App, InputMode, SearchResultType};
+use crate::ui::components::{sanitize_for_display, text_width_before_cursor};
 
 pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
@@ -28,9 +29,9 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
     let dialog_block = Block::default()
         .title("  Telescope Search ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(crate::ui::colors::RosePine::IRIS))
+        .border_style(Style::default().fg(crate::ui::colors::palette().iris))
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .style(Style::default().bg(crate::ui::colors::RosePine::BASE));
+        .style(Style::default().bg(crate::ui::colors::palette().base));
 
     frame.render_widget(dialog_block, dialog_area);
 
@@ -63,18 +64,22 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
 
     // Render search query with cursor - improved debug mode
     let input_block = Block::bordered()
-        .title(" Search ")
+        .title(if app.regex_search {
+            " Search [regex] "
+        } else {
+            " Search "
+        })
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .style(
             Style::default()
-                .fg(crate::ui::colors::RosePine::IRIS)
-                .bg(crate::ui::colors::RosePine::SURFACE),
+                .fg(crate::ui::colors::palette().iris)
+                .bg(crate::ui::colors::palette().surface),
         );
 
     // Format the search query with a visible cursor indicator
     let display_text = if app.search_query.is_empty() {
-        "Type to search... ↑/↓: Navigate  ⏎: Select  Esc: Close".to_string()
+        "Type to search... ↑/↓: Navigate  ⏎: Select  Ctrl+R: Regex  Esc: Close".to_string()
     } else {
         format!(" {}", app.search_query)
     };
@@ -82,9 +87,9 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
     let search_paragraph = Paragraph::new(display_text)
         .style(
             Style::default()
-                .fg(crate::ui::colors::RosePine::GOLD)
+                .fg(crate::ui::colors::palette().gold)
                 .add_modifier(Modifier::BOLD)
-                .bg(crate::ui::colors::RosePine::SURFACE),
+                .bg(crate::ui::colors::palette().surface),
         )
         .block(input_block);
 
@@ -92,9 +97,9 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
 
     // Show cursor when in search mode
     if app.input_mode == InputMode::Search {
-        // Calculate cursor position based on search query
+        // The query follows the border and a space
         frame.set_cursor_position(ratatui::layout::Position {
-            x: input_area.x + 3 + app.search_query.len() as u16,
+            x: input_area.x + 2 + text_width_before_cursor(&app.search_query, app.input_cursor),
             y: input_area.y + 1,
         });
     }
@@ -113,8 +118,8 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(
                 Style::default()
-                    .fg(crate::ui::colors::RosePine::SUBTLE)
-                    .bg(crate::ui::colors::RosePine::SURFACE),
+                    .fg(crate::ui::colors::palette().subtle)
+                    .bg(crate::ui::colors::palette().surface),
             );
 
         frame.render_widget(results_block, results_area);
@@ -131,14 +136,23 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
             .iter()
             .enumerate()
             .map(|(i, result)| {
-                let icon = match result.result_type {
-                    SearchResultType::Notebook => "󰠮 ",
-                    SearchResultType::Snippet => "󰈮 ",
-                    SearchResultType::CodeContent => "󰧮 ",
-                };
-
                 let is_selected = i == app.selected_search_result;
 
+                // Snippet results show their language icon, notebooks a folder
+                let snippet = match result.result_type {
+                    SearchResultType::Notebook => None,
+                    SearchResultType::Snippet | SearchResultType::CodeContent => {
+                        app.snippet_database.snippets.get(&result.id)
+                    }
+                };
+                let icon = match snippet {
+                    Some(snippet) => format!("{} ", snippet.language.icon()),
+                    None => "󰠮 ".to_string(),
+                };
+                let icon_color = snippet
+                    .filter(|_| !is_selected)
+                    .map(|snippet| crate::ui::colors::language_color(&snippet.language));
+
                 // Get parent path for context
                 let parent_path = crate::search::get_parent_path(app, result.parent_id);
                 let path_display = if !parent_path.is_empty() {
@@ -148,74 +162,119 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
                 };
 
                 // Add language info for snippet results
-                let language_info = if let SearchResultType::Snippet
-                | SearchResultType::CodeContent = result.result_type
-                {
-                    if let Some(snippet) = app.snippet_database.snippets.get(&result.id) {
-                        format!(" ({}) ", snippet.language.display_name())
-                    } else {
-                        String::new()
-                    }
-                } else {
-                    String::new()
-                };
+                let language_info = snippet
+                    .map(|snippet| format!(" ({}) ", snippet.language.display_name()))
+                    .unwrap_or_default();
 
                 // Format the line - first the name/title then the path and context
-                let name_span = Span::styled(
-                    format!("{}{}{}", icon, result.name, language_info),
-                    if is_selected {
-                        Style::default()
-                            .fg(crate::ui::colors::RosePine::LOVE)
-                            .bold()
-                    } else {
-                        Style::default().fg(crate::ui::colors::RosePine::TEXT)
-                    },
-                );
+                let name_style = if is_selected {
+                    Style::default()
+                        .fg(crate::ui::colors::palette().love)
+                        .bold()
+                } else {
+                    Style::default().fg(crate::ui::colors::palette().text)
+                };
+                let match_style = Style::default()
+                    .fg(crate::ui::colors::palette().gold)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
 
                 let path_span = Span::styled(
                     path_display,
                     if is_selected {
                         Style::default()
-                            .fg(crate::ui::colors::RosePine::FOAM)
+                            .fg(crate::ui::colors::palette().foam)
                             .bold()
                     } else {
-                        Style::default().fg(crate::ui::colors::RosePine::SUBTLE)
+                        Style::default().fg(crate::ui::colors::palette().subtle)
                     },
                 );
 
-                let context_span = Span::styled(
-                    format!(" {}", result.match_context),
-                    if is_selected {
-                        Style::default()
-                            .fg(crate::ui::colors::RosePine::IRIS)
-                            .bold()
-                    } else {
-                        Style::default().fg(crate::ui::colors::RosePine::MUTED)
-                    },
-                );
+                let context_style = if is_selected {
+                    Style::default()
+                        .fg(crate::ui::colors::palette().iris)
+                        .bold()
+                } else {
+                    Style::default().fg(crate::ui::colors::palette().muted)
+                };
 
-                let line = Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         if is_selected { "→ " } else { "  " },
-                        Style::default().fg(crate::ui::colors::RosePine::GOLD),
+                        Style::default().fg(crate::ui::colors::palette().gold),
                     ),
-                    name_span,
-                    path_span,
-                    context_span,
-                ]);
+                    Span::styled(
+                        icon,
+                        icon_color.map_or(name_style, |color| Style::default().fg(color)),
+                    ),
+                ];
+                spans.extend(highlight_matches(
+                    app,
+                    &result.name,
+                    name_style,
+                    match_style,
+                ));
+                spans.push(Span::styled(language_info, name_style));
+                spans.push(path_span);
+
+                // Code matches list their surrounding lines below instead of a one-line excerpt
+                let mut lines = Vec::new();
+                if result.context_lines.is_empty() {
+                    spans.push(Span::styled(" ", context_style));
+                    // Only the matched text is highlighted, not labels like "Line 12: "
+                    let context = sanitize_for_display(&result.match_context);
+                    let (label, text) = match context.split_once(": ") {
+                        Some((label, text)) => (format!("{}: ", label), text),
+                        None => (String::new(), context.as_ref()),
+                    };
+                    spans.push(Span::styled(label, context_style));
+                    spans.extend(highlight_matches(app, text, context_style, match_style));
+                    lines.push(Line::from(spans));
+                } else {
+                    lines.push(Line::from(spans));
+                    let gutter_style = Style::default().fg(crate::ui::colors::palette().muted);
+                    let matched_style = if is_selected {
+                        context_style
+                    } else {
+                        Style::default().fg(crate::ui::colors::palette().text)
+                    };
+                    for context in &result.context_lines {
+                        let mut row = vec![Span::styled(
+                            format!("    {:>4} │ ", context.number),
+                            if context.is_match {
+                                Style::default().fg(crate::ui::colors::palette().gold)
+                            } else {
+                                gutter_style
+                            },
+                        )];
+                        if context.is_match {
+                            row.extend(highlight_matches(
+                                app,
+                                &sanitize_for_display(&context.text),
+                                matched_style,
+                                match_style,
+                            ));
+                        } else {
+                            row.push(Span::styled(
+                                sanitize_for_display(&context.text).into_owned(),
+                                gutter_style,
+                            ));
+                        }
+                        lines.push(Line::from(row));
+                    }
+                }
 
-                ListItem::new(line).style(if is_selected {
-                    Style::default().bg(crate::ui::colors::RosePine::OVERLAY)
+                ListItem::new(lines).style(if is_selected {
+                    Style::default().bg(crate::ui::colors::palette().overlay)
                 } else {
-                    Style::default().bg(crate::ui::colors::RosePine::SURFACE)
+                    Style::default().bg(crate::ui::colors::palette().surface)
                 })
             })
             .collect();
 
         let results_list = List::new(items)
-            .style(Style::default().bg(crate::ui::colors::RosePine::SURFACE))
+            .style(Style::default().bg(crate::ui::colors::palette().surface))
             .block(
-                Block::default().style(Style::default().bg(crate::ui::colors::RosePine::SURFACE)),
+                Block::default().style(Style::default().bg(crate::ui::colors::palette().surface)),
             );
 
         frame.render_stateful_widget(
@@ -233,8 +292,8 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(
                     Style::default()
-                        .fg(crate::ui::colors::RosePine::SUBTLE)
-                        .bg(crate::ui::colors::RosePine::SURFACE),
+                        .fg(crate::ui::colors::palette().subtle)
+                        .bg(crate::ui::colors::palette().surface),
                 );
 
             frame.render_widget(preview_block, preview_area);
@@ -251,27 +310,90 @@ pub fn render_floating_search(frame: &mut Frame, app: &mut App) {
     } else if app.search_query.is_empty() {
         render_search_help(frame, results_area, preview_area);
     } else {
-        let no_results_text = Paragraph::new("No results found. Try a different search query.")
-            .style(
-                Style::default()
-                    .fg(crate::ui::colors::RosePine::GOLD)
-                    .bg(crate::ui::colors::RosePine::SURFACE),
-            )
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(ratatui::widgets::BorderType::Rounded)
-                    .title(" Results ")
-                    .style(
-                        Style::default()
-                            .fg(crate::ui::colors::RosePine::SUBTLE)
-                            .bg(crate::ui::colors::RosePine::SURFACE),
-                    ),
-            );
+        let no_results_text = Paragraph::new(vec![
+            Line::from("No results found. Try a different search query."),
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("Press "),
+                Span::styled("⏎", Style::default().bold()),
+                Span::raw(format!(
+                    " to create a snippet titled \"{}\"",
+                    app.search_query.trim()
+                )),
+            ]),
+        ])
+        .style(
+            Style::default()
+                .fg(crate::ui::colors::palette().gold)
+                .bg(crate::ui::colors::palette().surface),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .title(" Results ")
+                .style(
+                    Style::default()
+                        .fg(crate::ui::colors::palette().subtle)
+                        .bg(crate::ui::colors::palette().surface),
+                ),
+        );
         frame.render_widget(no_results_text, results_area);
     }
 }
 
+/// Splits `text` into spans, emphasising the characters matched by the search query
+fn highlight_matches(
+    app: &App,
+    text: &str,
+    base_style: Style,
+    match_style: Style,
+) -> Vec<Span<'static>> {
+    let indices = crate::search::match_indices(app, text);
+    highlight_indices(text, &indices, base_style, match_style)
+}
+
+/// Splits `text` into spans, emphasising the characters at `indices` (sorted char positions)
+pub fn highlight_indices(
+    text: &str,
+    indices: &[usize],
+    base_style: Style,
+    match_style: Style,
+) -> Vec<Span<'static>> {
+    if indices.is_empty() {
+        return vec![Span::styled(text.to_string(), base_style)];
+    }
+
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut current_is_match = false;
+
+    for (i, c) in text.chars().enumerate() {
+        let is_match = indices.binary_search(&i).is_ok();
+        if is_match != current_is_match && !current.is_empty() {
+            let style = if current_is_match {
+                match_style
+            } else {
+                base_style
+            };
+            spans.push(Span::styled(std::mem::take(&mut current), style));
+        }
+        current_is_match = is_match;
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        let style = if current_is_match {
+            match_style
+        } else {
+            base_style
+        };
+        spans.push(Span::styled(current, style));
+    }
+
+    spans
+}
+
 /// Renders help text for the search dialog when search query is empty
 fn render_search_help(frame: &mut Frame, results_area: Rect, preview_area: Rect) {
     let help_block = Block::default()
@@ -297,7 +419,7 @@ fn render_search_help(frame: &mut Frame, results_area: Rect, preview_area: Rect)
         Line::from(vec![Span::styled(
             " Telescope Search:",
             Style::default()
-                .fg(Color::LightBlue)
+                .fg(crate::ui::colors::palette().foam)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(""),
@@ -305,15 +427,31 @@ fn render_search_help(frame: &mut Frame, results_area: Rect, preview_area: Rect)
         Line::from("Results update in real-time as you type"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                "↑/↓",
+                Style::default().fg(crate::ui::colors::palette().gold),
+            ),
             Span::raw(" Navigate results"),
         ]),
         Line::from(vec![
-            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                "Enter",
+                Style::default().fg(crate::ui::colors::palette().gold),
+            ),
             Span::raw(" Open selected item"),
         ]),
         Line::from(vec![
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                "Ctrl+R",
+                Style::default().fg(crate::ui::colors::palette().gold),
+            ),
+            Span::raw(" Toggle regex search"),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Esc",
+                Style::default().fg(crate::ui::colors::palette().gold),
+            ),
             Span::raw(" Close search"),
         ]),
     ]);
@@ -429,7 +567,7 @@ fn render_preview_content(
                         snippet.language.display_name()
                     ),
                     Style::default()
-                        .fg(crate::ui::colors::RosePine::TEXT)
+                        .fg(crate::ui::colors::palette().text)
                         .add_modifier(Modifier::BOLD),
                 )]);
 
@@ -458,8 +596,11 @@ fn render_preview_content(
                     );
                 } else {
                     frame.render_widget(
-                        Paragraph::new("(Empty snippet)")
-                            .style(Style::default().fg(Color::Gray).bg(Color::Reset)),
+                        Paragraph::new("(Empty snippet)").style(
+                            Style::default()
+                                .fg(crate::ui::colors::palette().subtle)
+                                .bg(Color::Reset),
+                        ),
                         chunks[1],
                     );
                 }
@@ -501,8 +642,8 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .style(
             Style::default()
-                .fg(crate::ui::colors::RosePine::SUBTLE)
-                .bg(crate::ui::colors::RosePine::SURFACE),
+                .fg(crate::ui::colors::palette().subtle)
+                .bg(crate::ui::colors::palette().surface),
         );
 
     frame.render_widget(recents_block, recents_area);
@@ -527,10 +668,10 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                 format!("{}", entry.query),
                 if is_selected {
                     Style::default()
-                        .fg(crate::ui::colors::RosePine::LOVE)
+                        .fg(crate::ui::colors::palette().love)
                         .bold()
                 } else {
-                    Style::default().fg(crate::ui::colors::RosePine::TEXT)
+                    Style::default().fg(crate::ui::colors::palette().text)
                 },
             );
 
@@ -538,10 +679,10 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                 format!(" ({} results)", entry.result_count),
                 if is_selected {
                     Style::default()
-                        .fg(crate::ui::colors::RosePine::GOLD)
+                        .fg(crate::ui::colors::palette().gold)
                         .bold()
                 } else {
-                    Style::default().fg(crate::ui::colors::RosePine::SUBTLE)
+                    Style::default().fg(crate::ui::colors::palette().subtle)
                 },
             );
 
@@ -550,11 +691,11 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                 format!(" - {}", entry.formatted_time()),
                 if is_selected {
                     Style::default()
-                        .fg(crate::ui::colors::RosePine::FOAM)
+                        .fg(crate::ui::colors::palette().foam)
                         .italic()
                 } else {
                     Style::default()
-                        .fg(crate::ui::colors::RosePine::MUTED)
+                        .fg(crate::ui::colors::palette().muted)
                         .italic()
                 },
             );
@@ -563,11 +704,11 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
             let line = Line::from(vec![
                 Span::styled(
                     if is_selected { "→ " } else { "  " },
-                    Style::default().fg(crate::ui::colors::RosePine::GOLD),
+                    Style::default().fg(crate::ui::colors::palette().gold),
                 ),
                 Span::styled(
                     "🔍 ",
-                    Style::default().fg(crate::ui::colors::RosePine::IRIS),
+                    Style::default().fg(crate::ui::colors::palette().iris),
                 ),
                 query_span,
                 count_span,
@@ -575,17 +716,17 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
             ]);
 
             ListItem::new(line).style(if is_selected {
-                Style::default().bg(crate::ui::colors::RosePine::OVERLAY)
+                Style::default().bg(crate::ui::colors::palette().overlay)
             } else {
-                Style::default().bg(crate::ui::colors::RosePine::SURFACE)
+                Style::default().bg(crate::ui::colors::palette().surface)
             })
         })
         .collect();
 
     // Create the list with rose pine styling
     let recents_list = List::new(items)
-        .style(Style::default().bg(crate::ui::colors::RosePine::SURFACE))
-        .block(Block::default().style(Style::default().bg(crate::ui::colors::RosePine::SURFACE)));
+        .style(Style::default().bg(crate::ui::colors::palette().surface))
+        .block(Block::default().style(Style::default().bg(crate::ui::colors::palette().surface)));
 
     frame.render_stateful_widget(
         recents_list,
@@ -600,8 +741,8 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .style(
             Style::default()
-                .fg(crate::ui::colors::RosePine::SUBTLE)
-                .bg(crate::ui::colors::RosePine::SURFACE),
+                .fg(crate::ui::colors::palette().subtle)
+                .bg(crate::ui::colors::palette().surface),
         );
 
     frame.render_widget(details_block, details_area);
@@ -617,52 +758,48 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
     // If a recent search is selected, show its details
     if let Some(entry) = app.recent_searches.get(app.selected_recent_search) {
         // Show detailed info about the selected search
-        let mut detail_lines = Vec::new();
-
-        // Query info
-        detail_lines.push(Line::from(vec![
-            Span::styled(
-                " Search Query: ",
-                Style::default()
-                    .fg(crate::ui::colors::RosePine::MUTED)
-                    .bold(),
-            ),
-            Span::styled(
-                &entry.query,
-                Style::default().fg(crate::ui::colors::RosePine::LOVE),
-            ),
-        ]));
-
-        detail_lines.push(Line::from(""));
-
-        // Search stats
-        detail_lines.push(Line::from(vec![
-            Span::styled(
-                "󰡦 Results: ",
-                Style::default()
-                    .fg(crate::ui::colors::RosePine::MUTED)
-                    .bold(),
-            ),
-            Span::styled(
-                entry.result_count.to_string(),
-                Style::default().fg(crate::ui::colors::RosePine::GOLD),
-            ),
-        ]));
-
-        detail_lines.push(Line::from(vec![
-            Span::styled(
-                "󱑇 Searched at: ",
-                Style::default()
-                    .fg(crate::ui::colors::RosePine::MUTED)
-                    .bold(),
-            ),
-            Span::styled(
-                entry.formatted_time(),
-                Style::default().fg(crate::ui::colors::RosePine::FOAM),
-            ),
-        ]));
-
-        detail_lines.push(Line::from(""));
+        let mut detail_lines = vec![
+            // Query info
+            Line::from(vec![
+                Span::styled(
+                    " Search Query: ",
+                    Style::default()
+                        .fg(crate::ui::colors::palette().muted)
+                        .bold(),
+                ),
+                Span::styled(
+                    &entry.query,
+                    Style::default().fg(crate::ui::colors::palette().love),
+                ),
+            ]),
+            Line::from(""),
+            // Search stats
+            Line::from(vec![
+                Span::styled(
+                    "󰡦 Results: ",
+                    Style::default()
+                        .fg(crate::ui::colors::palette().muted)
+                        .bold(),
+                ),
+                Span::styled(
+                    entry.result_count.to_string(),
+                    Style::default().fg(crate::ui::colors::palette().gold),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "󱑇 Searched at: ",
+                    Style::default()
+                        .fg(crate::ui::colors::palette().muted)
+                        .bold(),
+                ),
+                Span::styled(
+                    entry.formatted_time(),
+                    Style::default().fg(crate::ui::colors::palette().foam),
+                ),
+            ]),
+            Line::from(""),
+        ];
 
         // Last selected item info if available
         if let (Some(result_type), Some(result_id)) =
@@ -671,7 +808,7 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
             detail_lines.push(Line::from(vec![Span::styled(
                 "Last Selected: ",
                 Style::default()
-                    .fg(crate::ui::colors::RosePine::MUTED)
+                    .fg(crate::ui::colors::palette().muted)
                     .bold(),
             )]));
 
@@ -681,37 +818,37 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                         detail_lines.push(Line::from(vec![
                             Span::styled(
                                 "  󰺄 Notebook: ",
-                                Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
+                                Style::default().fg(crate::ui::colors::palette().subtle),
                             ),
                             Span::styled(
                                 &notebook.name,
-                                Style::default().fg(crate::ui::colors::RosePine::TEXT),
+                                Style::default().fg(crate::ui::colors::palette().text),
                             ),
                         ]));
 
-                        if let Some(desc) = &notebook.description {
-                            if !desc.is_empty() {
-                                detail_lines.push(Line::from(vec![
-                                    Span::styled(
-                                        "   Description: ",
-                                        Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
-                                    ),
-                                    Span::styled(
-                                        desc,
-                                        Style::default().fg(crate::ui::colors::RosePine::MUTED),
-                                    ),
-                                ]));
-                            }
+                        if let Some(desc) = &notebook.description
+                            && !desc.is_empty()
+                        {
+                            detail_lines.push(Line::from(vec![
+                                Span::styled(
+                                    "   Description: ",
+                                    Style::default().fg(crate::ui::colors::palette().subtle),
+                                ),
+                                Span::styled(
+                                    desc,
+                                    Style::default().fg(crate::ui::colors::palette().muted),
+                                ),
+                            ]));
                         }
 
                         detail_lines.push(Line::from(vec![
                             Span::styled(
                                 "   Snippets: ",
-                                Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
+                                Style::default().fg(crate::ui::colors::palette().subtle),
                             ),
                             Span::styled(
                                 notebook.snippet_count.to_string(),
-                                Style::default().fg(crate::ui::colors::RosePine::IRIS),
+                                Style::default().fg(crate::ui::colors::palette().iris),
                             ),
                         ]));
                     }
@@ -721,18 +858,18 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                         detail_lines.push(Line::from(vec![
                             Span::styled(
                                 "   Snippet: ",
-                                Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
+                                Style::default().fg(crate::ui::colors::palette().subtle),
                             ),
                             Span::styled(
                                 &snippet.title,
-                                Style::default().fg(crate::ui::colors::RosePine::TEXT),
+                                Style::default().fg(crate::ui::colors::palette().text),
                             ),
                         ]));
 
                         detail_lines.push(Line::from(vec![
                             Span::styled(
                                 "  󰘦 Language: ",
-                                Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
+                                Style::default().fg(crate::ui::colors::palette().subtle),
                             ),
                             Span::styled(
                                 format!(
@@ -740,34 +877,34 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                                     snippet.language.icon(),
                                     snippet.language.display_name()
                                 ),
-                                Style::default().fg(crate::ui::colors::RosePine::FOAM),
+                                Style::default().fg(crate::ui::colors::palette().foam),
                             ),
                         ]));
 
                         detail_lines.push(Line::from(vec![
                             Span::styled(
                                 "  󰉻 Lines: ",
-                                Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
+                                Style::default().fg(crate::ui::colors::palette().subtle),
                             ),
                             Span::styled(
                                 snippet.get_line_count().to_string(),
-                                Style::default().fg(crate::ui::colors::RosePine::GOLD),
+                                Style::default().fg(crate::ui::colors::palette().gold),
                             ),
                         ]));
 
-                        if let Some(desc) = &snippet.description {
-                            if !desc.is_empty() {
-                                detail_lines.push(Line::from(vec![
-                                    Span::styled(
-                                        "   Description: ",
-                                        Style::default().fg(crate::ui::colors::RosePine::SUBTLE),
-                                    ),
-                                    Span::styled(
-                                        desc,
-                                        Style::default().fg(crate::ui::colors::RosePine::MUTED),
-                                    ),
-                                ]));
-                            }
+                        if let Some(desc) = &snippet.description
+                            && !desc.is_empty()
+                        {
+                            detail_lines.push(Line::from(vec![
+                                Span::styled(
+                                    "   Description: ",
+                                    Style::default().fg(crate::ui::colors::palette().subtle),
+                                ),
+                                Span::styled(
+                                    desc,
+                                    Style::default().fg(crate::ui::colors::palette().muted),
+                                ),
+                            ]));
                         }
                     }
                 }
@@ -776,7 +913,7 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
             detail_lines.push(Line::from(vec![Span::styled(
                 " No item selected from this search yet",
                 Style::default()
-                    .fg(crate::ui::colors::RosePine::MUTED)
+                    .fg(crate::ui::colors::palette().muted)
                     .italic(),
             )]));
         }
@@ -785,12 +922,12 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
         detail_lines.push(Line::from(vec![Span::styled(
             "Press Enter to run this search again",
             Style::default()
-                .fg(crate::ui::colors::RosePine::IRIS)
+                .fg(crate::ui::colors::palette().iris)
                 .italic(),
         )]));
 
         let details_para = Paragraph::new(detail_lines)
-            .style(Style::default().bg(crate::ui::colors::RosePine::SURFACE))
+            .style(Style::default().bg(crate::ui::colors::palette().surface))
             .wrap(Wrap { trim: true });
 
         frame.render_widget(details_para, details_inner);
@@ -802,8 +939,8 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .style(
             Style::default()
-                .fg(crate::ui::colors::RosePine::SUBTLE)
-                .bg(crate::ui::colors::RosePine::SURFACE),
+                .fg(crate::ui::colors::palette().subtle)
+                .bg(crate::ui::colors::palette().surface),
         );
 
     frame.render_widget(recent_files_block, recent_files_area);
@@ -822,7 +959,7 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
     if recent_snippets.is_empty() {
         let empty_text = Paragraph::new("No snippets accessed yet. Press 's' to create some!")
             .alignment(ratatui::layout::Alignment::Center)
-            .style(Style::default().fg(crate::ui::colors::RosePine::MUTED));
+            .style(Style::default().fg(crate::ui::colors::palette().muted));
         frame.render_widget(empty_text, recent_files_inner);
     } else {
         let items: Vec<ListItem> = recent_snippets
@@ -875,12 +1012,12 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                 let spans = vec![
                     Span::styled(
                         format!("{} ", icon),
-                        Style::default().fg(crate::ui::colors::RosePine::GOLD),
+                        Style::default().fg(crate::ui::colors::palette().gold),
                     ),
                     Span::styled(
                         title,
                         Style::default()
-                            .fg(crate::ui::colors::RosePine::TEXT)
+                            .fg(crate::ui::colors::palette().text)
                             .bold(),
                     ),
                     Span::raw(" • "),
@@ -891,22 +1028,22 @@ fn render_recent_searches(frame: &mut Frame, content_area: Rect, app: &mut App)
                 ListItem::new(Line::from(spans)).style(
                     Style::default()
                         .fg(if i == 0 {
-                            crate::ui::colors::RosePine::LOVE
+                            crate::ui::colors::palette().love
                         } else {
-                            crate::ui::colors::RosePine::TEXT
+                            crate::ui::colors::palette().text
                         })
                         .bg(if i % 2 == 0 {
-                            crate::ui::colors::RosePine::HIGHLIGHT_LOW
+                            crate::ui::colors::palette().highlight_low
                         } else {
-                            crate::ui::colors::RosePine::SURFACE
+                            crate::ui::colors::palette().surface
                         }),
                 )
             })
             .collect();
 
         let recent_files_list = List::new(items)
-            .style(Style::default().fg(crate::ui::colors::RosePine::TEXT))
-            .highlight_style(Style::default().fg(crate::ui::colors::RosePine::GOLD));
+            .style(Style::default().fg(crate::ui::colors::palette().text))
+            .highlight_style(Style::default().fg(crate::ui::colors::palette().gold));
 
         frame.render_widget(recent_files_list, recent_files_inner);
     }
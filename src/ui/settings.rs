@@ -0,0 +1,164 @@
+use crate::app::App;
+use crate::models::backup::{RetentionPolicy, backups_dir};
+use crate::ui::colors::{Theme, active_theme, palette};
+use crate::ui::components::render_bottom_bar;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, List, ListItem, ListState, Paragraph, Widget},
+};
+
+/// Render the settings page with the theme picker, the exit backup toggle and the storage check
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(" Settings ")
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(2),
+        Constraint::Min(6),
+        Constraint::Length(2),
+        Constraint::Length(2),
+        Constraint::Length(3),
+    ])
+    .split(inner_area);
+
+    let content_area = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(60),
+        Constraint::Fill(1),
+    ])
+    .split(chunks[2])[1];
+
+    Paragraph::new("Color theme")
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(palette().love).bold())
+        .render(chunks[1], frame.buffer_mut());
+
+    let current = active_theme();
+    let items: Vec<ListItem> = Theme::ALL
+        .iter()
+        .map(|theme| {
+            let colors = theme.palette();
+            let marker = if *theme == current { "✓" } else { " " };
+
+            let mut spans = vec![
+                Span::styled(format!(" {} ", marker), Style::default().fg(palette().pine)),
+                Span::styled(
+                    format!("{:<14}", theme.name()),
+                    Style::default().fg(palette().text),
+                ),
+            ];
+
+            // Small swatch of the theme's accent colors
+            for color in [
+                colors.love,
+                colors.gold,
+                colors.rose,
+                colors.pine,
+                colors.foam,
+                colors.iris,
+            ] {
+                spans.push(Span::styled("██", Style::default().fg(color)));
+            }
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().subtle)),
+        )
+        .highlight_style(Style::default().bg(palette().highlight_low).bold())
+        .highlight_symbol("▶");
+
+    let mut list_state = ListState::default().with_selected(Some(app.selected_theme));
+    frame.render_stateful_widget(list, content_area, &mut list_state);
+
+    let (state, color) = if app.settings.backup_on_exit {
+        (
+            format!(
+                "on, keeping {}",
+                RetentionPolicy::from_settings(&app.settings).describe()
+            ),
+            palette().pine,
+        )
+    } else {
+        ("off".to_string(), palette().muted)
+    };
+    Paragraph::new(Line::from(vec![
+        Span::styled("Backup on exit: ", Style::default().fg(palette().text)),
+        Span::styled(state, Style::default().fg(color).bold()),
+        Span::styled(
+            format!(
+                "  ({})",
+                backups_dir()
+                    .map(|dir| dir.display().to_string())
+                    .unwrap_or_else(|| "~/.snix/backups".to_string())
+            ),
+            Style::default().fg(palette().muted),
+        ),
+    ]))
+    .alignment(Alignment::Center)
+    .render(chunks[3], frame.buffer_mut());
+
+    let storage_line = match &app.integrity_report {
+        None => Line::from(vec![
+            Span::styled("Storage check: ", Style::default().fg(palette().text)),
+            Span::styled(
+                "press v to compare the database with the files on disk",
+                Style::default().fg(palette().muted),
+            ),
+        ]),
+        Some(report) if report.is_clean() => Line::from(vec![
+            Span::styled("Storage check: ", Style::default().fg(palette().text)),
+            Span::styled("consistent", Style::default().fg(palette().pine).bold()),
+        ]),
+        Some(report) => {
+            let mut spans = vec![
+                Span::styled("Storage check: ", Style::default().fg(palette().text)),
+                Span::styled(
+                    format!(
+                        "{} missing files, {} orphan files",
+                        report.missing_files.len(),
+                        report.orphan_files.len()
+                    ),
+                    Style::default().fg(palette().love).bold(),
+                ),
+            ];
+            if !report.missing_files.is_empty() {
+                spans.push(Span::styled("  [r] ", Style::default().fg(palette().gold)));
+                spans.push(Span::styled(
+                    "Recreate",
+                    Style::default().fg(palette().muted),
+                ));
+            }
+            if !report.orphan_files.is_empty() {
+                spans.push(Span::styled("  [o] ", Style::default().fg(palette().gold)));
+                spans.push(Span::styled(
+                    "Import orphans",
+                    Style::default().fg(palette().muted),
+                ));
+            }
+            Line::from(spans)
+        }
+    };
+    Paragraph::new(storage_line)
+        .alignment(Alignment::Center)
+        .render(chunks[4], frame.buffer_mut());
+
+    render_bottom_bar(frame, chunks[5], app);
+}
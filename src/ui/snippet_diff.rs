@@ -0,0 +1,129 @@
+use crate::app::{App, CodeSnippetsState};
+use crate::ui::colors::palette;
+use crate::ui::components::sanitize_for_display;
+use crate::ui::version_history::{DiffLine, diff_lines};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Paragraph, Widget},
+};
+use uuid::Uuid;
+
+/// Render a unified diff of two snippets, `left` as the base
+pub fn render(frame: &mut Frame, app: &mut App, left: Uuid, right: Uuid) {
+    let (Some(old), Some(new)) = (
+        app.snippet_database.snippets.get(&left),
+        app.snippet_database.snippets.get(&right),
+    ) else {
+        app.code_snippets_state = CodeSnippetsState::NotebookList;
+        app.set_error_message("Snippet not found".to_string());
+        return;
+    };
+
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(" Compare Snippets ")
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let rows = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Min(5),
+        Constraint::Length(1),
+    ])
+    .split(inner_area);
+
+    let diff = diff_lines(&old.content, &new.content);
+    let removed = diff
+        .iter()
+        .filter(|(kind, _)| *kind == DiffLine::Removed)
+        .count();
+    let added = diff
+        .iter()
+        .filter(|(kind, _)| *kind == DiffLine::Added)
+        .count();
+
+    Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("- ", Style::default().fg(palette().love).bold()),
+            Span::styled(old.title.clone(), Style::default().fg(palette().text)),
+            Span::styled(
+                format!("  {}", app.notebook_location(old.notebook_id)),
+                Style::default().fg(palette().muted),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("+ ", Style::default().fg(palette().foam).bold()),
+            Span::styled(new.title.clone(), Style::default().fg(palette().text)),
+            Span::styled(
+                format!("  {}", app.notebook_location(new.notebook_id)),
+                Style::default().fg(palette().muted),
+            ),
+        ]),
+    ])
+    .render(rows[0], frame.buffer_mut());
+
+    let diff_title = if added == 0 && removed == 0 {
+        " Identical ".to_string()
+    } else {
+        format!(" +{} -{} ", added, removed)
+    };
+
+    let lines: Vec<Line> = diff
+        .into_iter()
+        .skip(app.content_scroll_position)
+        .map(|(kind, text)| {
+            let (prefix, color) = match kind {
+                DiffLine::Same => ("  ", palette().muted),
+                DiffLine::Removed => ("- ", palette().love),
+                DiffLine::Added => ("+ ", palette().foam),
+            };
+            Line::from(Span::styled(
+                format!("{}{}", prefix, sanitize_for_display(text)),
+                Style::default().fg(color),
+            ))
+        })
+        .collect();
+
+    Paragraph::new(lines)
+        .block(
+            Block::bordered()
+                .title(diff_title)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().subtle)),
+        )
+        .render(rows[1], frame.buffer_mut());
+
+    // Status line
+    let status = if let Some(error) = &app.error_message {
+        Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(palette().love),
+        ))
+    } else if let Some(success) = &app.success_message {
+        Line::from(Span::styled(
+            success.clone(),
+            Style::default().fg(palette().foam),
+        ))
+    } else {
+        Line::from(vec![
+            Span::styled("j/k PgUp/PgDn", Style::default().fg(palette().gold)),
+            Span::styled(" Scroll  ", Style::default().fg(palette().muted)),
+            Span::styled("s", Style::default().fg(palette().gold)),
+            Span::styled(" Swap sides  ", Style::default().fg(palette().muted)),
+            Span::styled("Esc", Style::default().fg(palette().gold)),
+            Span::styled(" Back", Style::default().fg(palette().muted)),
+        ])
+    };
+
+    Paragraph::new(status)
+        .alignment(Alignment::Center)
+        .render(rows[2], frame.buffer_mut());
+}
@@ -1,5 +1,5 @@
 use crate::app::App;
-use crate::ui::colors::RosePine;
+use crate::ui::colors::palette;
 use crate::ui::components::render_bottom_bar;
 use chrono::{DateTime, Utc};
 use ratatui::{
@@ -18,7 +18,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         .title(" snix - Template & Boilerplate Manager ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::HIGHLIGHT_HIGH));
+        .style(Style::default().fg(palette().highlight_high));
 
     let inner_area = block.inner(main_area);
     block.render(main_area, frame.buffer_mut());
@@ -43,7 +43,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         Constraint::Fill(1),
         Constraint::Length(10),
         Constraint::Length(2),
-        Constraint::Length(16),
+        Constraint::Length(21),
         Constraint::Fill(1),
     ])
     .split(content_area);
@@ -65,7 +65,7 @@ fn render_title(frame: &mut Frame, area: Rect) {
 
     let title = Paragraph::new(title_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::ROSE).bold());
+        .style(Style::default().fg(palette().rose).bold());
 
     title.render(area, frame.buffer_mut());
 }
@@ -87,7 +87,7 @@ fn render_disclaimer(frame: &mut Frame, area: Rect) {
 
     let disclaimer_paragraph = Paragraph::new(disclaimer)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::SUBTLE).italic());
+        .style(Style::default().fg(palette().subtle).italic());
 
     disclaimer_paragraph.render(area, frame.buffer_mut());
 }
@@ -111,8 +111,11 @@ fn render_menu(frame: &mut Frame, area: Rect, app: &App) {
         ("󰘦", "Boilerplates", "b"),
         ("󰓜", "Marketplace", "m"),
         ("", "Code Snippets", "s"),
+        ("", "Quick Snippet", "n"),
         ("", "Export/Import", "e"),
         ("󱞁", "Backup & Restore", "u"),
+        ("󰩺", "Trash", "t"),
+        ("󰄨", "Statistics", "d"),
         ("", "Settings", "c"),
         ("󰈆", "Exit", "q"),
     ];
@@ -122,9 +125,9 @@ fn render_menu(frame: &mut Frame, area: Rect, app: &App) {
         .enumerate()
         .map(|(i, (icon, name, key))| {
             let style = if i == app.selected_menu_item {
-                Style::default().fg(RosePine::LOVE).bold()
+                Style::default().fg(palette().love).bold()
             } else {
-                Style::default().fg(RosePine::TEXT)
+                Style::default().fg(palette().text)
             };
 
             let prefix = if i == app.selected_menu_item {
@@ -152,11 +155,11 @@ fn render_menu(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     let list = List::new(spaced_items)
-        .style(Style::default().fg(RosePine::TEXT))
+        .style(Style::default().fg(palette().text))
         .highlight_style(
             Style::default()
-                .fg(RosePine::LOVE)
-                .bg(RosePine::HIGHLIGHT_LOW)
+                .fg(palette().love)
+                .bg(palette().highlight_low)
                 .bold(),
         )
         .highlight_symbol("");
@@ -173,8 +176,11 @@ fn render_description(frame: &mut Frame, area: Rect, app: &App) {
         "Create, manage and deploy boilerplates for React, Vue, Angular, and more",
         "Discover community templates, frameworks, and starter projects",
         "Quick access to reusable code snippets and development patterns",
+        "Jot down a new snippet in your Inbox notebook straight away (Ctrl+N anywhere)",
         "Import and export snippets/notebooks in JSON or YAML format",
         "Backup and restore your data, view backup history, and manage backups",
+        "Restore deleted snippets and notebooks or purge them for good",
+        "Overview of your library by language, notebook, tag and recent use",
         "Customize your development workflow and preferences",
         "Save your work and exit the application",
     ];
@@ -183,7 +189,7 @@ fn render_description(frame: &mut Frame, area: Rect, app: &App) {
 
     let description_paragraph = Paragraph::new(*description)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(RosePine::MUTED));
+        .style(Style::default().fg(palette().muted));
 
     description_paragraph.render(area, frame.buffer_mut());
 }
@@ -198,23 +204,21 @@ fn render_recent_snippets(frame: &mut Frame, area: Rect, app: &App) {
     .split(area)[1];
 
     let block = Block::bordered()
-        .title(" ⏱ Recent Snippets [1-10 to open] ")
+        .title(" ⏱ Recent Snippets [1-10 to open • r for all] ")
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
-        .style(Style::default().fg(RosePine::SUBTLE));
+        .style(Style::default().fg(palette().subtle));
 
     let inner_area = block.inner(snippets_area);
     block.render(snippets_area, frame.buffer_mut());
 
     // Get most recently accessed snippets
-    let mut recent_snippets: Vec<_> = app.snippet_database.snippets.values().collect();
-    recent_snippets.sort_by(|a, b| b.accessed_at.cmp(&a.accessed_at));
-    recent_snippets.truncate(10);
+    let recent_snippets = app.recent_snippets(10);
 
     if recent_snippets.is_empty() {
         let empty_text = Paragraph::new("No snippets accessed yet. Press 's' to create some!")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(RosePine::MUTED));
+            .style(Style::default().fg(palette().muted));
         empty_text.render(inner_area, frame.buffer_mut());
         return;
     }
@@ -260,8 +264,8 @@ fn render_recent_snippets(frame: &mut Frame, area: Rect, app: &App) {
 
             let spans = vec![
                 Span::raw(format!("{} ", shortcut)),
-                Span::styled(format!("{} ", icon), Style::default().fg(RosePine::GOLD)),
-                Span::styled(title, Style::default().fg(RosePine::TEXT).bold()),
+                Span::styled(format!("{} ", icon), Style::default().fg(palette().gold)),
+                Span::styled(title, Style::default().fg(palette().text).bold()),
                 Span::raw(" • "),
                 notebook_span,
                 Span::raw(format!(" • {} • {} lines • {}", language, lines, ago)),
@@ -270,28 +274,28 @@ fn render_recent_snippets(frame: &mut Frame, area: Rect, app: &App) {
             ListItem::new(Line::from(spans)).style(
                 Style::default()
                     .fg(if i == 0 {
-                        RosePine::LOVE
+                        palette().love
                     } else {
-                        RosePine::TEXT
+                        palette().text
                     })
                     .bg(if i % 2 == 0 {
-                        RosePine::HIGHLIGHT_LOW
+                        palette().highlight_low
                     } else {
-                        RosePine::BASE
+                        palette().base
                     }),
             )
         })
         .collect();
 
     let list = List::new(items)
-        .style(Style::default().fg(RosePine::TEXT))
-        .highlight_style(Style::default().fg(RosePine::GOLD));
+        .style(Style::default().fg(palette().text))
+        .highlight_style(Style::default().fg(palette().gold));
 
     list.render(inner_area, frame.buffer_mut());
 }
 
 /// Format time difference as human-readable string
-fn format_time_ago(datetime: &DateTime<Utc>) -> String {
+pub(crate) fn format_time_ago(datetime: &DateTime<Utc>) -> String {
     let now = Utc::now();
     let duration = now.signed_duration_since(*datetime);
 
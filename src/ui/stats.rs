@@ -0,0 +1,167 @@
+use crate::app::App;
+use crate::models::stats::format_size;
+use crate::ui::colors::palette;
+use crate::ui::components::render_bottom_bar;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Cell, Paragraph, Row, Table, Widget},
+};
+
+/// Width of the bar drawn next to each count
+const BAR_WIDTH: usize = 16;
+
+/// Render the library overview with totals and the per-category breakdowns
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(" Statistics ")
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(inner_area);
+
+    if let Some(stats) = &app.library_stats {
+        let summary = [
+            ("", "Snippets", stats.total_snippets.to_string()),
+            ("", "Notebooks", stats.total_notebooks.to_string()),
+            ("", "Favorites", stats.favorites.to_string()),
+            ("󰦪", "Lines", stats.total_lines.to_string()),
+            ("󰆓", "Storage", format_size(stats.storage_bytes)),
+        ];
+        let mut spans = Vec::new();
+        for (icon, label, value) in summary {
+            spans.push(Span::styled(
+                format!(" {} {} ", icon, label),
+                Style::default().fg(palette().subtle),
+            ));
+            spans.push(Span::styled(
+                format!("{}   ", value),
+                Style::default().fg(palette().text).bold(),
+            ));
+        }
+        Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .block(
+                Block::bordered()
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(palette().subtle)),
+            )
+            .render(chunks[0], frame.buffer_mut());
+
+        let top = Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(chunks[1]);
+        let bottom =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(chunks[2]);
+
+        let recency: Vec<(String, usize)> = stats
+            .recency
+            .iter()
+            .map(|(label, count)| (label.to_string(), *count))
+            .collect();
+
+        render_breakdown(
+            frame,
+            top[0],
+            " By Language ",
+            &stats.by_language,
+            stats.total_snippets,
+            palette().foam,
+        );
+        render_breakdown(
+            frame,
+            top[1],
+            " By Notebook ",
+            &stats.by_notebook,
+            stats.total_snippets,
+            palette().iris,
+        );
+        render_breakdown(
+            frame,
+            bottom[0],
+            " Top Tags ",
+            &stats.top_tags,
+            stats.total_snippets,
+            palette().rose,
+        );
+        render_breakdown(
+            frame,
+            bottom[1],
+            " Last Accessed ",
+            &recency,
+            stats.total_snippets,
+            palette().gold,
+        );
+    }
+
+    render_bottom_bar(frame, chunks[3], app);
+}
+
+/// Draws one name/count table with a bar showing the share of all snippets
+fn render_breakdown(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    rows: &[(String, usize)],
+    total: usize,
+    color: Color,
+) {
+    let block = Block::bordered()
+        .title(title)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().subtle));
+
+    if rows.is_empty() {
+        Paragraph::new(Line::from("Nothing here yet").fg(palette().muted))
+            .alignment(Alignment::Center)
+            .block(block)
+            .render(area, frame.buffer_mut());
+        return;
+    }
+
+    let table_rows: Vec<Row> = rows
+        .iter()
+        .map(|(name, count)| {
+            let filled = if total > 0 {
+                (count * BAR_WIDTH).div_ceil(total)
+            } else {
+                0
+            };
+            Row::new(vec![
+                Cell::from(name.clone()).style(Style::default().fg(palette().text)),
+                Cell::from(count.to_string()).style(Style::default().fg(palette().text).bold()),
+                Cell::from(Line::from(vec![
+                    Span::styled("█".repeat(filled), Style::default().fg(color)),
+                    Span::styled(
+                        "░".repeat(BAR_WIDTH - filled),
+                        Style::default().fg(palette().highlight_low),
+                    ),
+                ])),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        table_rows,
+        [
+            Constraint::Fill(1),
+            Constraint::Length(6),
+            Constraint::Length(BAR_WIDTH as u16),
+        ],
+    )
+    .block(block);
+
+    frame.render_widget(table, area);
+}
@@ -0,0 +1,162 @@
+use crate::app::{App, TagPrompt};
+use crate::ui::colors::palette;
+use crate::ui::components::{render_bottom_bar, render_prompt_bar};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+        Widget,
+    },
+};
+
+/// Render the tag list next to the snippets carrying the selected tag
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(format!(" Tags ({}) ", app.tag_manager.tags.len()))
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(inner_area);
+
+    if app.tag_manager.tags.is_empty() {
+        Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No tags yet — press t on a snippet to add some.").fg(palette().subtle),
+        ])
+        .alignment(Alignment::Center)
+        .render(chunks[0], frame.buffer_mut());
+    } else {
+        let columns =
+            Layout::horizontal([Constraint::Percentage(45), Constraint::Fill(1)]).split(chunks[0]);
+        render_tag_list(frame, columns[0], app);
+        render_tagged_snippets(frame, columns[1], app);
+    }
+
+    render_bottom_bar(frame, chunks[1], app);
+
+    let title = match app.tag_prompt {
+        Some(TagPrompt::Rename(id)) => app
+            .tag_manager
+            .tags
+            .get(&id)
+            .map(|tag| format!("Rename {} to", tag.display_name())),
+        Some(TagPrompt::Merge(id)) => app
+            .tag_manager
+            .tags
+            .get(&id)
+            .map(|tag| format!("Merge {} into", tag.display_name())),
+        None => None,
+    };
+    if let Some(title) = title {
+        render_prompt_bar(
+            frame,
+            main_area,
+            &title,
+            &app.input_buffer,
+            app.input_cursor,
+        );
+    }
+}
+
+fn render_tag_list(frame: &mut Frame, area: Rect, app: &App) {
+    let rows: Vec<Row> = app
+        .sorted_tags()
+        .iter()
+        .map(|tag| {
+            Row::new(vec![
+                Cell::from(tag.display_name()).style(Style::default().fg(palette().rose)),
+                Cell::from(app.snippets_with_tag(tag.id).len().to_string())
+                    .style(Style::default().fg(palette().text)),
+                Cell::from(
+                    tag.last_used_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d")
+                        .to_string(),
+                )
+                .style(Style::default().fg(palette().muted)),
+            ])
+        })
+        .collect();
+
+    let border_color = if app.tag_snippets_focused {
+        palette().subtle
+    } else {
+        palette().iris
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(10),
+        ],
+    )
+    .header(
+        Row::new(vec!["Tag", "Snippets", "Last used"])
+            .style(Style::default().fg(palette().gold).bold()),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(border_color)),
+    )
+    .row_highlight_style(Style::default().bg(palette().highlight_low).bold())
+    .highlight_symbol("▶ ");
+
+    let mut table_state = TableState::default().with_selected(Some(app.selected_tag));
+    frame.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn render_tagged_snippets(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(tag) = app.sorted_tags().get(app.selected_tag).copied() else {
+        return;
+    };
+
+    let items: Vec<ListItem> = app
+        .snippets_with_tag(tag.id)
+        .iter()
+        .map(|snippet| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", snippet.language.icon()),
+                    Style::default().fg(palette().foam),
+                ),
+                Span::styled(snippet.title.clone(), Style::default().fg(palette().text)),
+                Span::styled(
+                    format!("  {}", app.notebook_location(snippet.notebook_id)),
+                    Style::default().fg(palette().muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let border_color = if app.tag_snippets_focused {
+        palette().iris
+    } else {
+        palette().subtle
+    };
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .title(format!(" {} ", tag.display_name()))
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(border_color)),
+        )
+        .highlight_style(Style::default().bg(palette().highlight_low).bold())
+        .highlight_symbol("▶ ");
+
+    let selected = app
+        .tag_snippets_focused
+        .then_some(app.selected_tagged_snippet);
+    let mut list_state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
@@ -0,0 +1,175 @@
+use crate::ui::colors::palette;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use std::time::Instant;
+
+/// Most toasts shown at once; the oldest is dropped first
+const MAX_TOASTS: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct ToastNotification {
+    pub message: String,
+    pub notification_type: NotificationType,
+    pub created_at: Instant,
+    pub duration_seconds: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationType {
+    Success,
+    Error,
+    Info,
+}
+
+impl ToastNotification {
+    pub fn new(
+        message: String,
+        notification_type: NotificationType,
+        duration_seconds: u64,
+    ) -> Self {
+        Self {
+            message,
+            notification_type,
+            created_at: Instant::now(),
+            duration_seconds,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created_at.elapsed().as_secs() >= self.duration_seconds
+    }
+}
+
+/// Stack of toasts that expire independently
+#[derive(Debug, Clone, Default)]
+pub struct Toasts {
+    notifications: Vec<ToastNotification>,
+    /// How many toasts were ever pushed, see `checkpoint`
+    pushed: usize,
+    /// Value of `pushed` when the last error was added
+    last_error: Option<usize>,
+}
+
+impl Toasts {
+    /// Adds a toast; the same message shown twice in a row just restarts its timer
+    pub fn push(&mut self, message: String, notification_type: NotificationType, seconds: u64) {
+        if notification_type == NotificationType::Error {
+            self.last_error = Some(self.pushed);
+        }
+        self.pushed += 1;
+
+        match self.notifications.last_mut() {
+            Some(last)
+                if last.message == message && last.notification_type == notification_type =>
+            {
+                last.created_at = Instant::now();
+            }
+            _ => {
+                self.notifications.push(ToastNotification::new(
+                    message,
+                    notification_type,
+                    seconds,
+                ));
+                if self.notifications.len() > MAX_TOASTS {
+                    self.notifications.remove(0);
+                }
+            }
+        }
+    }
+
+    pub fn success(&mut self, message: String) {
+        self.push(message, NotificationType::Success, 5);
+    }
+
+    /// Errors stay up longer than the other kinds
+    pub fn error(&mut self, message: String) {
+        self.push(message, NotificationType::Error, 8);
+    }
+
+    pub fn info(&mut self, message: String) {
+        self.push(message, NotificationType::Info, 5);
+    }
+
+    pub fn clean_expired(&mut self) {
+        self.notifications.retain(|toast| !toast.is_expired());
+    }
+
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ToastNotification> {
+        self.notifications.iter()
+    }
+
+    /// Marks the current point, so `error_since` can tell whether an action failed
+    pub fn checkpoint(&self) -> usize {
+        self.pushed
+    }
+
+    /// Whether an error toast was added after `checkpoint` was taken
+    pub fn error_since(&self, checkpoint: usize) -> bool {
+        self.last_error.is_some_and(|index| index >= checkpoint)
+    }
+}
+
+/// Draws toasts stacked upwards from the bottom-right corner, the first one lowest
+pub fn render<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    toasts: impl Iterator<Item = &'a ToastNotification>,
+) {
+    let notification_width = 50;
+    let notification_height = 3;
+    let right_margin = 2;
+    let bottom_margin = 2;
+
+    for (index, toast) in toasts.enumerate() {
+        let y_offset = (notification_height + 1) * index as u16;
+
+        let notification_area = Rect {
+            x: area.width.saturating_sub(notification_width + right_margin),
+            y: area
+                .height
+                .saturating_sub(bottom_margin + y_offset + notification_height),
+            width: notification_width.min(area.width),
+            height: notification_height,
+        };
+
+        // Don't render if it would go off-screen
+        if notification_area.y < area.y
+            || bottom_margin + y_offset + notification_height > area.height
+        {
+            break;
+        }
+
+        let (icon, color) = match toast.notification_type {
+            NotificationType::Success => (" ", palette().pine),
+            NotificationType::Error => ("󰅙 ", palette().love),
+            NotificationType::Info => (" ", palette().iris),
+        };
+
+        let notification_block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(color))
+            .style(Style::default().bg(palette().base));
+
+        let notification_text = Paragraph::new(format!("{} {}", icon, toast.message))
+            .block(notification_block)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true });
+
+        frame.render_widget(Clear, notification_area);
+        frame.render_widget(notification_text, notification_area);
+    }
+}
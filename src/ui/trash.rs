@@ -0,0 +1,92 @@
+use crate::app::App;
+use crate::models::TrashedItem;
+use crate::ui::colors::palette;
+use crate::ui::components::render_bottom_bar;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Cell, Paragraph, Row, Table, TableState, Widget},
+};
+
+/// Render the trash page listing deleted snippets and notebooks
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(format!(" Trash ({}) ", app.trash.len()))
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let chunks = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(inner_area);
+
+    if app.trash.is_empty() {
+        let empty = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("Trash is empty").fg(palette().subtle),
+            Line::from("Deleted snippets and notebooks show up here").fg(palette().muted),
+        ])
+        .alignment(Alignment::Center);
+        empty.render(chunks[0], frame.buffer_mut());
+    } else {
+        let rows: Vec<Row> = app
+            .trash
+            .iter()
+            .map(|entry| {
+                let (icon, kind, color) = match &entry.item {
+                    TrashedItem::Snippet(snippet) => {
+                        (snippet.language.icon(), "Snippet", palette().foam)
+                    }
+                    TrashedItem::Notebook(_) => ("", "Notebook", palette().iris),
+                };
+
+                Row::new(vec![
+                    Cell::from(format!("{} {}", icon, kind)).style(Style::default().fg(color)),
+                    Cell::from(entry.name().to_string()).style(Style::default().fg(palette().text)),
+                    Cell::from(entry.original_location.clone())
+                        .style(Style::default().fg(palette().subtle)),
+                    Cell::from(
+                        entry
+                            .deleted_at
+                            .with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string(),
+                    )
+                    .style(Style::default().fg(palette().muted)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(12),
+                Constraint::Fill(2),
+                Constraint::Fill(2),
+                Constraint::Length(17),
+            ],
+        )
+        .header(
+            Row::new(vec!["Type", "Name", "Original location", "Deleted"])
+                .style(Style::default().fg(palette().gold).bold())
+                .bottom_margin(1),
+        )
+        .row_highlight_style(
+            Style::default()
+                .fg(palette().love)
+                .bg(palette().highlight_low)
+                .bold(),
+        )
+        .highlight_symbol("▶ ");
+
+        let mut table_state = TableState::default().with_selected(Some(app.selected_trash_item));
+        frame.render_stateful_widget(table, chunks[0], &mut table_state);
+    }
+
+    render_bottom_bar(frame, chunks[1], app);
+}
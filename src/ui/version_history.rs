@@ -0,0 +1,199 @@
+use crate::app::{App, CodeSnippetsState};
+use crate::ui::colors::palette;
+use crate::ui::components::sanitize_for_display;
+use ratatui::widgets::Widget;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, List, ListItem, ListState, Paragraph, StatefulWidget},
+};
+use uuid::Uuid;
+
+/// Upper bound on the LCS table size before falling back to a plain preview
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DiffLine {
+    Same,
+    Removed,
+    Added,
+}
+
+/// Render the version history of a snippet with a diff against its current content
+pub fn render(frame: &mut Frame, app: &mut App, snippet_id: Uuid) {
+    let snippet = match app.snippet_database.snippets.get(&snippet_id) {
+        Some(snippet) => snippet,
+        None => {
+            app.code_snippets_state = CodeSnippetsState::NotebookList;
+            app.set_error_message("Snippet not found".to_string());
+            return;
+        }
+    };
+
+    let main_area = frame.area();
+
+    let block = Block::bordered()
+        .title(format!(" Version History: {} ", snippet.title))
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(palette().highlight_high));
+
+    let inner_area = block.inner(main_area);
+    block.render(main_area, frame.buffer_mut());
+
+    let rows = Layout::vertical([Constraint::Min(5), Constraint::Length(1)]).split(inner_area);
+    let columns = Layout::horizontal([Constraint::Length(32), Constraint::Min(20)]).split(rows[0]);
+
+    // Version list
+    let items: Vec<ListItem> = app
+        .snippet_versions
+        .iter()
+        .map(|version| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("v{:<4}", version.version),
+                    Style::default().fg(palette().gold),
+                ),
+                Span::styled(
+                    version
+                        .saved_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string(),
+                    Style::default().fg(palette().text),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .title(format!(" Versions ({}) ", app.snippet_versions.len()))
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().subtle)),
+        )
+        .highlight_style(Style::default().bg(palette().highlight_high).bold())
+        .highlight_symbol("› ");
+
+    let mut list_state = ListState::default().with_selected(Some(app.selected_version));
+    StatefulWidget::render(list, columns[0], frame.buffer_mut(), &mut list_state);
+
+    // Diff between the selected version and the current content
+    let old_content = app
+        .snippet_versions
+        .get(app.selected_version)
+        .and_then(|version| {
+            app.storage_manager
+                .as_ref()
+                .and_then(|storage| storage.load_snippet_version(version).ok())
+        })
+        .unwrap_or_default();
+
+    let diff_lines: Vec<Line> = diff_lines(&old_content, &snippet.content)
+        .into_iter()
+        .skip(app.content_scroll_position)
+        .map(|(kind, text)| {
+            let (prefix, color) = match kind {
+                DiffLine::Same => ("  ", palette().muted),
+                DiffLine::Removed => ("- ", palette().love),
+                DiffLine::Added => ("+ ", palette().foam),
+            };
+            Line::from(Span::styled(
+                format!("{}{}", prefix, sanitize_for_display(text)),
+                Style::default().fg(color),
+            ))
+        })
+        .collect();
+
+    let diff_title = match app.snippet_versions.get(app.selected_version) {
+        Some(version) => format!(" v{} → current ", version.version),
+        None => " Diff ".to_string(),
+    };
+
+    Paragraph::new(diff_lines)
+        .block(
+            Block::bordered()
+                .title(diff_title)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(palette().subtle)),
+        )
+        .render(columns[1], frame.buffer_mut());
+
+    // Status line
+    let status = if let Some(error) = &app.error_message {
+        Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(palette().love),
+        ))
+    } else if let Some(success) = &app.success_message {
+        Line::from(Span::styled(
+            success.clone(),
+            Style::default().fg(palette().foam),
+        ))
+    } else {
+        Line::from(vec![
+            Span::styled("↑/↓", Style::default().fg(palette().gold)),
+            Span::styled(" Select  ", Style::default().fg(palette().muted)),
+            Span::styled("PgUp/PgDn", Style::default().fg(palette().gold)),
+            Span::styled(" Scroll  ", Style::default().fg(palette().muted)),
+            Span::styled("Enter", Style::default().fg(palette().gold)),
+            Span::styled(" Restore  ", Style::default().fg(palette().muted)),
+            Span::styled("Esc", Style::default().fg(palette().gold)),
+            Span::styled(" Back", Style::default().fg(palette().muted)),
+        ])
+    };
+
+    Paragraph::new(status)
+        .alignment(Alignment::Center)
+        .render(rows[1], frame.buffer_mut());
+}
+
+/// Line-based diff of two texts using the longest common subsequence
+pub(crate) fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<(DiffLine, &'a str)> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (n, m) = (old_lines.len(), new_lines.len());
+
+    // Very large inputs would need a huge table, so just show both sides in full
+    if n.saturating_mul(m) > MAX_DIFF_CELLS {
+        return old_lines
+            .into_iter()
+            .map(|line| (DiffLine::Removed, line))
+            .chain(new_lines.into_iter().map(|line| (DiffLine::Added, line)))
+            .collect();
+    }
+
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_lines[i] == new_lines[j] {
+            result.push((DiffLine::Same, old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push((DiffLine::Removed, old_lines[i]));
+            i += 1;
+        } else {
+            result.push((DiffLine::Added, new_lines[j]));
+            j += 1;
+        }
+    }
+    result.extend(old_lines[i..].iter().map(|line| (DiffLine::Removed, *line)));
+    result.extend(new_lines[j..].iter().map(|line| (DiffLine::Added, *line)));
+
+    result
+}